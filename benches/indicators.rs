@@ -1,11 +1,23 @@
 use bencher::{benchmark_group, benchmark_main, Bencher};
 use rand::Rng;
 use ta::indicators::{
-    AverageTrueRange, BollingerBands, ChandelierExit, CommodityChannelIndex, EfficiencyRatio,
-    ExponentialMovingAverage, FastStochastic, KeltnerChannel, Maximum, MeanAbsoluteDeviation,
-    Minimum, MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume,
-    PercentagePriceOscillator, RateOfChange, RelativeStrengthIndex, SimpleMovingAverage,
-    SlowStochastic, StandardDeviation, TrueRange,
+    AccelerationBands, AccumulationDistribution, AccumulativeSwingIndex, AnchoredVwap, Aroon,
+    AtrBands, AverageDirectionalIndex, AverageTrueRange, BollingerBands, CandleAnatomy,
+    ChandelierExit, CommodityChannelIndex, DeMarker, EaseOfMovement, EfficiencyRatio,
+    ExponentialMovingAverage, FastStochastic, FisherTransform, ForecastOscillator, Fractals,
+    GarmanKlassVolatility, HalfTrend, HampelFilter, KaufmanAdaptiveMovingAverage, KeltnerChannel,
+    KnowSureThing, LinearRegression, LinearRegressionChannel, Mama, MassIndex, Maximum,
+    McClellanSummationIndex, McGinleyDynamic, MeanAbsoluteDeviation, Minimum, Momentum,
+    MoneyFlowIndex, MovingAverageConvergenceDivergence, NadarayaWatsonEnvelope,
+    NormalizedAverageTrueRange, OnBalanceVolume, ParkinsonVolatility, PercentagePriceOscillator,
+    PercentageVolumeOscillator, PercentileBands, PercentileRank, PriceVolumeTrend, RateOfChange,
+    RelativeStrengthIndex, RelativeVigorIndex, RelativeVolume, RogersSatchellVolatility,
+    RollingMedian, RollingMode, RollingSharpe, RollingSortino, RoofingFilter, RunningMovingAverage,
+    SessionRelativeVolume, SimpleMovingAverage, SlowStochastic, SpecialK, SslChannel,
+    StandardDeviation, StochasticOscillator, Streak, SuperSmoother, SwingIndex, TrueRange,
+    TrueStrengthIndex, TtmSqueeze, Variance, VerticalHorizontalFilter, Vidya, VolAdjMomentum,
+    VolatilityRatio, VolatilityRegime, VolumeRsi, VolumeWeightedMovingAverage, WilliamsPercentR,
+    YangZhangVolatility, ZScore, ZeroLagExponentialMovingAverage,
 };
 use ta::{DataItem, Next};
 
@@ -72,5 +84,67 @@ bench_indicators!(
     SimpleMovingAverage,
     SlowStochastic,
     StandardDeviation,
-    TrueRange
+    TrueRange,
+    HalfTrend,
+    RunningMovingAverage,
+    AverageDirectionalIndex,
+    NadarayaWatsonEnvelope,
+    Aroon,
+    SslChannel,
+    NormalizedAverageTrueRange,
+    VolatilityRegime,
+    VolatilityRatio,
+    WilliamsPercentR,
+    StochasticOscillator,
+    RollingMode,
+    ForecastOscillator,
+    AnchoredVwap,
+    RelativeVolume,
+    SessionRelativeVolume,
+    AccumulationDistribution,
+    KaufmanAdaptiveMovingAverage,
+    TtmSqueeze,
+    ZeroLagExponentialMovingAverage,
+    VolumeWeightedMovingAverage,
+    McClellanSummationIndex,
+    Streak,
+    CandleAnatomy,
+    EaseOfMovement,
+    KnowSureThing,
+    FisherTransform,
+    Momentum,
+    VolAdjMomentum,
+    PercentileBands,
+    LinearRegression,
+    HampelFilter,
+    LinearRegressionChannel,
+    MassIndex,
+    PercentageVolumeOscillator,
+    Fractals,
+    RelativeVigorIndex,
+    TrueStrengthIndex,
+    McGinleyDynamic,
+    Vidya,
+    RollingSharpe,
+    RollingSortino,
+    ParkinsonVolatility,
+    GarmanKlassVolatility,
+    YangZhangVolatility,
+    RogersSatchellVolatility,
+    Variance,
+    ZScore,
+    PercentileRank,
+    RollingMedian,
+    SwingIndex,
+    AccumulativeSwingIndex,
+    PriceVolumeTrend,
+    VolumeRsi,
+    VerticalHorizontalFilter,
+    AtrBands,
+    AccelerationBands,
+    SpecialK,
+    DeMarker,
+    SuperSmoother,
+    RoofingFilter,
+    Mama
 );
@@ -0,0 +1,212 @@
+//! Runtime throughput measurement of indicators.
+//!
+//! Unlike `cargo bench`, the [Harness](struct.Harness.html) can be used from a deployed
+//! application or a test to check that an indicator, e.g. with a different moving average
+//! plugged in, still meets a performance budget on the target machine.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::bench::Harness;
+//! use ta::indicators::ExponentialMovingAverage;
+//!
+//! let inputs: Vec<f64> = (0..1_000).map(|i| i as f64).collect();
+//! let mut ema = ExponentialMovingAverage::new(14).unwrap();
+//!
+//! let report = Harness::new(10).unwrap().run(&mut ema, &inputs);
+//! assert_eq!(report.updates(), 10_000);
+//!
+//! // e.g. fail a deployment check above 10µs per update
+//! if !report.within_budget(10_000.0) {
+//!     eprintln!("EMA over budget: {}", report);
+//! }
+//! ```
+//!
+//! Indicators consuming bars are given references:
+//!
+//! ```
+//! use ta::bench::Harness;
+//! use ta::indicators::AverageTrueRange;
+//! use ta::DataItem;
+//!
+//! let bars: Vec<DataItem> = (1..100)
+//!     .map(|i| {
+//!         let price = i as f64;
+//!         DataItem::builder()
+//!             .open(price)
+//!             .high(price + 1.0)
+//!             .low(price - 1.0)
+//!             .close(price)
+//!             .volume(1000.0)
+//!             .build()
+//!             .unwrap()
+//!     })
+//!     .collect();
+//! let refs: Vec<&DataItem> = bars.iter().collect();
+//!
+//! let report = Harness::new(1).unwrap().run(&mut AverageTrueRange::default(), &refs);
+//! assert_eq!(report.updates(), 99);
+//! ```
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::errors::{Result, TaError};
+use crate::{Next, Reset};
+
+/// Feeds inputs to an indicator a number of times and measures the time spent.
+#[derive(Debug, Clone)]
+pub struct Harness {
+    rounds: usize,
+    warmup_rounds: usize,
+}
+
+/// Result of a [Harness](struct.Harness.html) run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    updates: usize,
+    elapsed: Duration,
+}
+
+impl Harness {
+    /// Creates a harness feeding all the inputs `rounds` times. The indicator is reset before
+    /// every round.
+    pub fn new(rounds: usize) -> Result<Self> {
+        match rounds {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                rounds,
+                warmup_rounds: 1,
+            }),
+        }
+    }
+
+    /// Sets the number of unmeasured rounds run before the measured ones. Default is 1.
+    pub fn warmup_rounds(mut self, warmup_rounds: usize) -> Self {
+        self.warmup_rounds = warmup_rounds;
+        self
+    }
+
+    pub fn run<I, T>(&self, indicator: &mut I, inputs: &[T]) -> Report
+    where
+        I: Next<T> + Reset,
+        T: Copy,
+    {
+        for _ in 0..self.warmup_rounds {
+            Self::round(indicator, inputs);
+        }
+
+        let start = Instant::now();
+        for _ in 0..self.rounds {
+            Self::round(indicator, inputs);
+        }
+
+        Report {
+            updates: self.rounds * inputs.len(),
+            elapsed: start.elapsed(),
+        }
+    }
+
+    fn round<I: Next<T> + Reset, T: Copy>(indicator: &mut I, inputs: &[T]) {
+        indicator.reset();
+        for &input in inputs {
+            black_box(indicator.next(black_box(input)));
+        }
+    }
+}
+
+impl Report {
+    /// Number of measured updates.
+    pub fn updates(&self) -> usize {
+        self.updates
+    }
+
+    /// Total time of the measured updates.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Average time of an update in nanoseconds.
+    pub fn ns_per_update(&self) -> f64 {
+        if self.updates == 0 {
+            return 0.0;
+        }
+        self.elapsed.as_nanos() as f64 / self.updates as f64
+    }
+
+    /// Number of updates (e.g. bars) per second.
+    pub fn updates_per_sec(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs == 0.0 {
+            return f64::INFINITY;
+        }
+        self.updates as f64 / secs
+    }
+
+    /// Returns true when an update takes at most `max_ns_per_update` nanoseconds on average.
+    pub fn within_budget(&self, max_ns_per_update: f64) -> bool {
+        self.ns_per_update() <= max_ns_per_update
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} updates in {:?}: {:.1} ns/update, {:.0} updates/sec",
+            self.updates,
+            self.elapsed,
+            self.ns_per_update(),
+            self.updates_per_sec()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage;
+
+    #[test]
+    fn test_new() {
+        assert!(Harness::new(0).is_err());
+        assert!(Harness::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_run() {
+        let inputs = [1.0, 2.0, 3.0];
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+
+        let report = Harness::new(4)
+            .unwrap()
+            .warmup_rounds(0)
+            .run(&mut sma, &inputs);
+        assert_eq!(report.updates(), 12);
+        assert!(report.ns_per_update() >= 0.0);
+        assert!(report.updates_per_sec() > 0.0);
+    }
+
+    #[test]
+    fn test_report() {
+        let report = Report {
+            updates: 4,
+            elapsed: Duration::from_nanos(400),
+        };
+        assert_eq!(report.ns_per_update(), 100.0);
+        assert_eq!(report.updates_per_sec(), 10_000_000.0);
+        assert!(report.within_budget(100.0));
+        assert!(!report.within_budget(99.9));
+        assert_eq!(
+            format!("{}", report),
+            "4 updates in 400ns: 100.0 ns/update, 10000000 updates/sec"
+        );
+
+        let empty = Report {
+            updates: 0,
+            elapsed: Duration::from_nanos(0),
+        };
+        assert_eq!(empty.ns_per_update(), 0.0);
+    }
+}
@@ -0,0 +1,536 @@
+//! Exchange calendars and trading sessions.
+//!
+//! Session based indicators split the bars into sessions. A fixed interval of 24 hours is
+//! enough for a 24/7 crypto market, but not for an exchange that opens at 09:30 New York time,
+//! moves with daylight saving time, closes on weekends and holidays, or runs overnight like
+//! futures. A [Calendar](struct.Calendar.html) describes the trading hours of a market, so
+//! "daily" means a trading day of that market.
+//!
+//! A timestamp belongs to the next session that has not closed yet: bars of the pre-market, of
+//! a maintenance break, of a weekend or of a holiday belong to the next session. Use
+//! [is_open](struct.Calendar.html#method.is_open) to filter them out.
+//!
+//! Time zones are modeled as a fixed offset from UTC and an optional
+//! [daylight saving](enum.DaylightSaving.html) rule, and holidays are configured by the user,
+//! since they change every year.
+//!
+//! The calendar is used by [SessionLevels](../indicators/struct.SessionLevels.html) and the
+//! indicators built on it, the [anchored VWAP](../indicators/struct.AnchoredVwap.html) and the
+//! [Resampler](../struct.Resampler.html).
+//!
+//! # Example
+//!
+//! ```
+//! use ta::calendar::Calendar;
+//!
+//! let nyse = Calendar::us_equities().with_holiday(2024, 7, 4).unwrap();
+//!
+//! // 2024-07-03 13:30 UTC, 09:30 in New York during daylight saving time.
+//! let open = 1_720_013_400;
+//! assert!(nyse.is_open(open));
+//! assert!(!nyse.is_open(open - 60));
+//! assert_eq!(nyse.session_start(open - 60), open);
+//!
+//! // The session after the holiday is on the 5th.
+//! let holiday = open + 86_400;
+//! assert!(!nyse.is_open(holiday));
+//! assert_eq!(nyse.session_start(holiday), open + 2 * 86_400);
+//! ```
+
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const DAY: i64 = 24 * 60 * 60;
+const HOUR: i64 = 60 * 60;
+
+/// Trading hours of a market.
+///
+/// The trading hours are given in seconds of the local day. A session closing before it opens,
+/// e.g. from 17:00 to 16:00, runs overnight and belongs to the trading day of its close. The
+/// trading days are the weekdays of the calendar that are not holidays.
+///
+/// # Parameters
+///
+/// * _utc_offset_ - offset of the standard local time from UTC in seconds, e.g. `-5 * 3600`
+///   for New York.
+/// * _open_ - open of the session in seconds of the local day, from 0 to 86399.
+/// * _close_ - close of the session in seconds of the local day, from 1 to 86400 and different
+///   from _open_.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calendar {
+    utc_offset: i64,
+    daylight_saving: DaylightSaving,
+    open: i64,
+    close: i64,
+    breaks: Vec<(i64, i64)>,
+    weekdays: [bool; 7],
+    // Sorted local day numbers since the Unix epoch.
+    holidays: Vec<i64>,
+}
+
+/// Daylight saving time rule of a [Calendar](struct.Calendar.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DaylightSaving {
+    #[default]
+    None,
+    /// One hour ahead from the second Sunday of March to the first Sunday of November, at
+    /// 02:00 local time.
+    UnitedStates,
+    /// One hour ahead from the last Sunday of March to the last Sunday of October, at 01:00
+    /// UTC.
+    Europe,
+}
+
+impl Calendar {
+    pub fn new(utc_offset: i64, open: i64, close: i64) -> Result<Self> {
+        if !(0..DAY).contains(&open) || !(1..=DAY).contains(&close) || open == close {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            utc_offset,
+            daylight_saving: DaylightSaving::None,
+            open,
+            close,
+            breaks: Vec::new(),
+            weekdays: [true; 7],
+            holidays: Vec::new(),
+        })
+    }
+
+    /// Creates a calendar of a market trading around the clock every day, like crypto
+    /// currencies. The sessions are the UTC days.
+    pub fn always_open() -> Self {
+        Self::new(0, 0, DAY).unwrap()
+    }
+
+    /// Creates a calendar of the regular trading hours of the US stock exchanges, from 09:30 to
+    /// 16:00 New York time, Monday to Friday.
+    pub fn us_equities() -> Self {
+        Self::new(-5 * HOUR, 9 * HOUR + 30 * 60, 16 * HOUR)
+            .unwrap()
+            .with_daylight_saving(DaylightSaving::UnitedStates)
+            .with_weekdays([true, true, true, true, true, false, false])
+            .unwrap()
+    }
+
+    /// Creates a calendar of the European stock exchanges, from 09:00 to 17:30 Central European
+    /// time, Monday to Friday.
+    pub fn european_equities() -> Self {
+        Self::new(HOUR, 9 * HOUR, 17 * HOUR + 30 * 60)
+            .unwrap()
+            .with_daylight_saving(DaylightSaving::Europe)
+            .with_weekdays([true, true, true, true, true, false, false])
+            .unwrap()
+    }
+
+    /// Creates a calendar of the CME Globex futures, from 17:00 to 16:00 Chicago time, Sunday
+    /// evening to Friday. The hour from 16:00 to 17:00 is the daily maintenance break.
+    pub fn cme_globex() -> Self {
+        Self::new(-6 * HOUR, 17 * HOUR, 16 * HOUR)
+            .unwrap()
+            .with_daylight_saving(DaylightSaving::UnitedStates)
+            .with_weekdays([true, true, true, true, true, false, false])
+            .unwrap()
+    }
+
+    pub fn with_daylight_saving(mut self, daylight_saving: DaylightSaving) -> Self {
+        self.daylight_saving = daylight_saving;
+        self
+    }
+
+    /// Adds a break within the sessions, from _start_ to _end_ in seconds of the local day,
+    /// e.g. a lunch break.
+    pub fn with_break(mut self, start: i64, end: i64) -> Result<Self> {
+        if !(0..DAY).contains(&start) || end <= start || end > DAY {
+            return Err(TaError::InvalidParameter);
+        }
+        self.breaks.push((start, end));
+        Ok(self)
+    }
+
+    /// Sets the trading days of the week, starting with Monday. At least one day must be a
+    /// trading day.
+    pub fn with_weekdays(mut self, weekdays: [bool; 7]) -> Result<Self> {
+        if !weekdays.contains(&true) {
+            return Err(TaError::InvalidParameter);
+        }
+        self.weekdays = weekdays;
+        Ok(self)
+    }
+
+    /// Adds a holiday, a local date without session.
+    pub fn with_holiday(mut self, year: i64, month: u32, day: u32) -> Result<Self> {
+        let date = days_from_civil(year, month, day);
+        if !(1..=12).contains(&month) || day == 0 || civil_from_days(date) != (year, month, day) {
+            return Err(TaError::InvalidParameter);
+        }
+        if let Err(index) = self.holidays.binary_search(&date) {
+            self.holidays.insert(index, date);
+        }
+        Ok(self)
+    }
+
+    pub fn utc_offset(&self) -> i64 {
+        self.utc_offset
+    }
+
+    pub fn daylight_saving(&self) -> DaylightSaving {
+        self.daylight_saving
+    }
+
+    pub fn open(&self) -> i64 {
+        self.open
+    }
+
+    pub fn close(&self) -> i64 {
+        self.close
+    }
+
+    /// Returns the trading day of the session of a timestamp, the local date of its close in
+    /// days since the Unix epoch.
+    pub fn trading_day(&self, timestamp: i64) -> i64 {
+        let local = self.to_local(timestamp);
+        let mut day = local.div_euclid(DAY);
+        if local.rem_euclid(DAY) >= self.close {
+            day += 1;
+        }
+        // Terminates as at least one weekday is a trading day and the holidays are finite.
+        while !self.is_trading_day(day) {
+            day += 1;
+        }
+        day
+    }
+
+    /// Returns the open of the session of a timestamp.
+    pub fn session_start(&self, timestamp: i64) -> i64 {
+        let day = self.trading_day(timestamp);
+        if self.open < self.close {
+            self.to_utc(day * DAY + self.open)
+        } else {
+            self.to_utc((day - 1) * DAY + self.open)
+        }
+    }
+
+    /// Returns the close of the session of a timestamp.
+    pub fn session_end(&self, timestamp: i64) -> i64 {
+        self.to_utc(self.trading_day(timestamp) * DAY + self.close)
+    }
+
+    /// Returns whether the market is open at a timestamp.
+    pub fn is_open(&self, timestamp: i64) -> bool {
+        let time = self.to_local(timestamp).rem_euclid(DAY);
+        timestamp >= self.session_start(timestamp)
+            && !self
+                .breaks
+                .iter()
+                .any(|&(start, end)| start <= time && time < end)
+    }
+
+    fn is_trading_day(&self, day: i64) -> bool {
+        self.weekdays[weekday(day)] && self.holidays.binary_search(&day).is_err()
+    }
+
+    fn to_local(&self, timestamp: i64) -> i64 {
+        timestamp + self.utc_offset + self.daylight_saving_offset(timestamp)
+    }
+
+    fn to_utc(&self, local: i64) -> i64 {
+        let timestamp = local - self.utc_offset;
+        timestamp - self.daylight_saving_offset(timestamp)
+    }
+
+    fn daylight_saving_offset(&self, timestamp: i64) -> i64 {
+        let (start, end) = match self.daylight_saving {
+            DaylightSaving::None => return 0,
+            DaylightSaving::UnitedStates => {
+                let (year, _, _) = civil_from_days((timestamp + self.utc_offset).div_euclid(DAY));
+                let start = first_sunday(days_from_civil(year, 3, 1)) + 7;
+                let end = first_sunday(days_from_civil(year, 11, 1));
+                (
+                    start * DAY + 2 * HOUR - self.utc_offset,
+                    end * DAY + HOUR - self.utc_offset,
+                )
+            }
+            DaylightSaving::Europe => {
+                let (year, _, _) = civil_from_days(timestamp.div_euclid(DAY));
+                let start = first_sunday(days_from_civil(year, 4, 1)) - 7;
+                let end = first_sunday(days_from_civil(year, 11, 1)) - 7;
+                (start * DAY + HOUR, end * DAY + HOUR)
+            }
+        };
+        if start <= timestamp && timestamp < end {
+            HOUR
+        } else {
+            0
+        }
+    }
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self::always_open()
+    }
+}
+
+impl fmt::Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let offset = self.utc_offset.abs() / 60;
+        write!(
+            f,
+            "{:02}:{:02}-{:02}:{:02} UTC{}{:02}:{:02}",
+            self.open / HOUR,
+            self.open % HOUR / 60,
+            self.close / HOUR,
+            self.close % HOUR / 60,
+            if self.utc_offset < 0 { '-' } else { '+' },
+            offset / 60,
+            offset % 60
+        )
+    }
+}
+
+/// Sessions of a session based indicator, fixed intervals or the trading days of a
+/// [Calendar](struct.Calendar.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Sessions {
+    /// Intervals of _length_ seconds aligned on the Unix epoch shifted by _offset_.
+    Interval {
+        length: i64,
+        offset: i64,
+    },
+    Calendar(Calendar),
+}
+
+impl Sessions {
+    pub(crate) fn interval(length: i64, offset: i64) -> Result<Self> {
+        if length <= 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Sessions::Interval { length, offset })
+    }
+
+    /// Returns a number identifying the session of a timestamp, increasing with time.
+    pub fn session(&self, timestamp: i64) -> i64 {
+        match self {
+            Sessions::Interval { length, offset } => (timestamp - offset).div_euclid(*length),
+            Sessions::Calendar(calendar) => calendar.trading_day(timestamp),
+        }
+    }
+
+    /// Returns the start of the session of a timestamp.
+    pub fn session_start(&self, timestamp: i64) -> i64 {
+        match self {
+            Sessions::Interval { length, offset } => {
+                (timestamp - offset).div_euclid(*length) * length + offset
+            }
+            Sessions::Calendar(calendar) => calendar.session_start(timestamp),
+        }
+    }
+}
+
+impl fmt::Display for Sessions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Sessions::Interval { length, offset } => write!(f, "{}, {}", length, offset),
+            Sessions::Calendar(calendar) => write!(f, "{}", calendar),
+        }
+    }
+}
+
+// Monday is 0, the Unix epoch was a Thursday.
+fn weekday(day: i64) -> usize {
+    (day + 3).rem_euclid(7) as usize
+}
+
+fn first_sunday(day: i64) -> i64 {
+    day + (6 - weekday(day) as i64)
+}
+
+// Days since the Unix epoch of a date of the proleptic Gregorian calendar, see
+// http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let (month, day) = (month as i64, day as i64);
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-01-08, a Monday.
+    const MONDAY: i64 = 19_730 * DAY;
+
+    #[test]
+    fn test_civil() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 1, 8), 19_730);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(civil_from_days(19_730), (2024, 1, 8));
+        assert_eq!(civil_from_days(days_from_civil(2000, 2, 29)), (2000, 2, 29));
+        assert_eq!(weekday(19_730), 0);
+        assert_eq!(weekday(0), 3);
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Calendar::new(0, 0, 0).is_err());
+        assert!(Calendar::new(0, -1, 3600).is_err());
+        assert!(Calendar::new(0, 0, DAY + 1).is_err());
+        assert!(Calendar::new(0, 3600, 0).is_err());
+        assert!(Calendar::new(0, 3600, 1800).is_ok());
+
+        let calendar = Calendar::always_open();
+        assert!(calendar.clone().with_weekdays([false; 7]).is_err());
+        assert!(calendar.clone().with_break(3600, 3600).is_err());
+        assert!(calendar.clone().with_holiday(2023, 2, 29).is_err());
+        assert!(calendar.clone().with_holiday(2024, 13, 1).is_err());
+        assert!(calendar.with_holiday(2024, 2, 29).is_ok());
+    }
+
+    #[test]
+    fn test_always_open() {
+        let calendar = Calendar::always_open();
+
+        assert!(calendar.is_open(MONDAY - 1));
+        assert_eq!(calendar.trading_day(MONDAY - 1), 19_729);
+        assert_eq!(calendar.trading_day(MONDAY), 19_730);
+        assert_eq!(calendar.session_start(MONDAY + 5000), MONDAY);
+        assert_eq!(calendar.session_end(MONDAY), MONDAY + DAY);
+    }
+
+    #[test]
+    fn test_us_equities() {
+        let calendar = Calendar::us_equities();
+        // 09:30 EST
+        let open = MONDAY + 14 * HOUR + 30 * 60;
+
+        assert!(!calendar.is_open(open - 1));
+        assert!(calendar.is_open(open));
+        assert_eq!(calendar.session_start(open - 1), open);
+        assert_eq!(calendar.session_end(open), MONDAY + 21 * HOUR);
+        assert!(!calendar.is_open(MONDAY + 21 * HOUR));
+
+        // After the close, the next session.
+        assert_eq!(calendar.trading_day(MONDAY + 21 * HOUR), 19_731);
+
+        // Saturday and Sunday belong to the session of Monday.
+        assert_eq!(calendar.trading_day(MONDAY - DAY), 19_730);
+        assert_eq!(calendar.session_start(MONDAY - 2 * DAY), open);
+    }
+
+    #[test]
+    fn test_daylight_saving() {
+        let calendar = Calendar::us_equities();
+        // Friday 2024-03-08 and Monday 2024-03-11, before and after the switch.
+        let friday = days_from_civil(2024, 3, 8) * DAY;
+        let monday = days_from_civil(2024, 3, 11) * DAY;
+        assert_eq!(calendar.session_start(friday), friday + 14 * HOUR + 30 * 60);
+        assert_eq!(calendar.session_start(monday), monday + 13 * HOUR + 30 * 60);
+        // And back on Sunday 2024-11-03.
+        let monday = days_from_civil(2024, 11, 4) * DAY;
+        assert_eq!(calendar.session_start(monday), monday + 14 * HOUR + 30 * 60);
+
+        let calendar = Calendar::european_equities();
+        let day = days_from_civil(2024, 3, 29) * DAY;
+        assert_eq!(calendar.session_start(day), day + 8 * HOUR);
+        let day = days_from_civil(2024, 4, 1) * DAY;
+        assert_eq!(calendar.session_start(day), day + 7 * HOUR);
+        let day = days_from_civil(2024, 10, 28) * DAY;
+        assert_eq!(calendar.session_start(day), day + 8 * HOUR);
+    }
+
+    #[test]
+    fn test_overnight() {
+        let calendar = Calendar::cme_globex();
+        // Sunday 17:00 CST
+        let open = MONDAY - DAY + 23 * HOUR;
+
+        assert!(!calendar.is_open(open - 1));
+        assert!(calendar.is_open(open));
+        assert_eq!(calendar.trading_day(open), 19_730);
+        assert_eq!(calendar.session_start(MONDAY + 10 * HOUR), open);
+
+        // Monday 16:00 CST, the maintenance break before the session of Tuesday.
+        let close = MONDAY + 22 * HOUR;
+        assert!(calendar.is_open(close - 1));
+        assert!(!calendar.is_open(close));
+        assert_eq!(calendar.trading_day(close), 19_731);
+        assert_eq!(calendar.session_start(close), close + HOUR);
+
+        // Friday 16:00 CST to Sunday 17:00 CST
+        let friday_close = close + 4 * DAY;
+        assert!(!calendar.is_open(friday_close));
+        assert_eq!(calendar.session_start(friday_close), open + 7 * DAY);
+    }
+
+    #[test]
+    fn test_breaks_and_holidays() {
+        // 09:00 to 15:00 UTC with a lunch break, closed on Monday 2024-01-08.
+        let calendar = Calendar::new(0, 9 * HOUR, 15 * HOUR)
+            .unwrap()
+            .with_break(12 * HOUR, 13 * HOUR)
+            .unwrap()
+            .with_holiday(2024, 1, 8)
+            .unwrap();
+
+        assert!(!calendar.is_open(MONDAY + 10 * HOUR));
+        assert_eq!(calendar.trading_day(MONDAY + 10 * HOUR), 19_731);
+
+        let tuesday = MONDAY + DAY;
+        assert!(calendar.is_open(tuesday + 12 * HOUR - 1));
+        assert!(!calendar.is_open(tuesday + 12 * HOUR));
+        assert!(calendar.is_open(tuesday + 13 * HOUR));
+        assert_eq!(calendar.trading_day(tuesday + 12 * HOUR), 19_731);
+    }
+
+    #[test]
+    fn test_sessions() {
+        let sessions = Sessions::interval(3600, 1800).unwrap();
+        assert!(Sessions::interval(0, 0).is_err());
+        assert_eq!(sessions.session(1799), -1);
+        assert_eq!(sessions.session_start(1799), -1800);
+
+        let sessions = Sessions::Calendar(Calendar::us_equities());
+        assert_eq!(sessions.session(MONDAY + 15 * HOUR), 19_730);
+        assert_eq!(sessions.session_start(MONDAY), MONDAY + 14 * HOUR + 30 * 60);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", Calendar::us_equities()),
+            "09:30-16:00 UTC-05:00"
+        );
+        assert_eq!(
+            format!("{}", Calendar::always_open()),
+            "00:00-24:00 UTC+00:00"
+        );
+        let sessions = Sessions::interval(3600, 1800).unwrap();
+        assert_eq!(format!("{}", sessions), "3600, 1800");
+    }
+}
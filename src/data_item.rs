@@ -1,5 +1,5 @@
 use crate::errors::*;
-use crate::traits::{Close, High, Low, Open, Volume};
+use crate::traits::{Close, High, Low, Open, Timestamp, Volume};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -28,6 +28,24 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(item.volume(), 7500.0);
 /// ```
 ///
+/// The start time of the period is optional:
+///
+/// ```
+/// use ta::{DataItem, Timestamp};
+///
+/// let item = DataItem::builder()
+///     .open(20.0)
+///     .high(25.0)
+///     .low(15.0)
+///     .close(21.0)
+///     .volume(7500.0)
+///     .timestamp(1_600_000_000)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(item.timestamp(), Some(1_600_000_000));
+/// ```
+///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct DataItem {
@@ -36,6 +54,8 @@ pub struct DataItem {
     low: f64,
     close: f64,
     volume: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    timestamp: Option<i64>,
 }
 
 impl DataItem {
@@ -74,12 +94,19 @@ impl Volume for DataItem {
     }
 }
 
+impl Timestamp for DataItem {
+    fn timestamp(&self) -> Option<i64> {
+        self.timestamp
+    }
+}
+
 pub struct DataItemBuilder {
     open: Option<f64>,
     high: Option<f64>,
     low: Option<f64>,
     close: Option<f64>,
     volume: Option<f64>,
+    timestamp: Option<i64>,
 }
 
 impl DataItemBuilder {
@@ -90,6 +117,7 @@ impl DataItemBuilder {
             low: None,
             close: None,
             volume: None,
+            timestamp: None,
         }
     }
 
@@ -118,6 +146,12 @@ impl DataItemBuilder {
         self
     }
 
+    /// Sets the start time of the period, as a Unix timestamp in seconds.
+    pub fn timestamp(mut self, val: i64) -> Self {
+        self.timestamp = Some(val);
+        self
+    }
+
     pub fn build(self) -> Result<DataItem> {
         if let (Some(open), Some(high), Some(low), Some(close), Some(volume)) =
             (self.open, self.high, self.low, self.close, self.volume)
@@ -137,6 +171,7 @@ impl DataItemBuilder {
                     low,
                     close,
                     volume,
+                    timestamp: self.timestamp,
                 };
                 Ok(item)
             } else {
@@ -203,4 +238,24 @@ mod tests {
             assert_invalid(record)
         }
     }
+    #[test]
+    fn test_timestamp() {
+        let builder = DataItem::builder()
+            .open(20.0)
+            .high(25.0)
+            .low(15.0)
+            .close(21.0)
+            .volume(7500.0);
+
+        assert_eq!(builder.build().unwrap().timestamp(), None);
+
+        let builder = DataItem::builder()
+            .open(20.0)
+            .high(25.0)
+            .low(15.0)
+            .close(21.0)
+            .volume(7500.0)
+            .timestamp(60);
+        assert_eq!(builder.build().unwrap().timestamp(), Some(60));
+    }
 }
@@ -0,0 +1,192 @@
+use crate::errors::{Result, TaError};
+use crate::Next;
+
+/// Detects the drift of a long-running indicator from a fresh recomputation.
+///
+/// An indicator updated bar after bar for weeks accumulates floating point errors in its
+/// running sums, and a feed that drops or duplicates a bar leaves it in a state that no
+/// backtest reproduces. [run](#method.run) feeds a historical slice to a fresh instance of the
+/// indicator, then feeds the same _probe_ input to a clone of it and to a clone of the live
+/// instance, and compares both outputs. The live instance is left untouched; the recomputed one
+/// is returned so it can replace the live one when they drifted apart.
+///
+/// The history should cover at least the lookback of the indicator, and much more for
+/// indicators with an infinite memory such as the EMA, otherwise the recomputed instance is
+/// not warmed up and the drift is mostly its own. The probe is usually the next bar, or the
+/// last bar of the history when no new bar is available.
+///
+/// # Parameters
+///
+/// * _tolerance_ - absolute drift above which a resync is advised (0 or greater).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{DataItem, DriftCheck, Next};
+///
+/// let bar = |close: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(100.0)
+///         .build()
+///         .unwrap()
+/// };
+/// let history = [bar(1.0), bar(2.0), bar(3.0), bar(4.0)];
+///
+/// // The live SMA missed the third bar.
+/// let mut live = SimpleMovingAverage::new(3).unwrap();
+/// for i in [0, 1, 3] {
+///     live.next(&history[i]);
+/// }
+///
+/// let fresh = SimpleMovingAverage::new(3).unwrap();
+/// let report = DriftCheck::new(1e-9).unwrap().run(&live, fresh, &history, &bar(5.0));
+/// assert_eq!(report.recomputed, 4.0);
+/// assert!(report.needs_resync);
+///
+/// live = report.resynced;
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct DriftCheck {
+    tolerance: f64,
+}
+
+/// Result of a [DriftCheck](struct.DriftCheck.html).
+#[derive(Debug, Clone)]
+pub struct DriftReport<I> {
+    /// Output of the live instance for the probe.
+    pub live: f64,
+    /// Output of the recomputed instance for the probe.
+    pub recomputed: f64,
+    /// Absolute difference of the outputs, infinite when only one of them is NaN.
+    pub drift: f64,
+    /// The drift is above the tolerance.
+    pub needs_resync: bool,
+    /// Fresh instance fed with the history, without the probe.
+    pub resynced: I,
+}
+
+impl DriftCheck {
+    pub fn new(tolerance: f64) -> Result<Self> {
+        if tolerance.is_nan() || tolerance < 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self { tolerance })
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Recomputes _fresh_ over _history_ and compares it with _live_, see
+    /// [DriftCheck](struct.DriftCheck.html).
+    pub fn run<I, T>(&self, live: &I, mut fresh: I, history: &[T], probe: &T) -> DriftReport<I>
+    where
+        I: Clone + for<'a> Next<&'a T, Output = f64>,
+    {
+        for input in history {
+            fresh.next(input);
+        }
+
+        let live = live.clone().next(probe);
+        let recomputed = fresh.clone().next(probe);
+        let drift = match (live.is_nan(), recomputed.is_nan()) {
+            (true, true) => 0.0,
+            (false, false) => (live - recomputed).abs(),
+            _ => f64::INFINITY,
+        };
+
+        DriftReport {
+            live,
+            recomputed,
+            drift,
+            needs_resync: drift > self.tolerance,
+            resynced: fresh,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage, GeometricMean, SimpleMovingAverage};
+    use crate::test_helper::*;
+
+    fn bars(closes: &[f64]) -> Vec<Bar> {
+        closes
+            .iter()
+            .map(|&close| Bar::new().close(close))
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(DriftCheck::new(-1.0).is_err());
+        assert!(DriftCheck::new(f64::NAN).is_err());
+        assert!(DriftCheck::new(0.0).is_ok());
+    }
+
+    #[test]
+    fn test_run_in_sync() {
+        let history = bars(&[1.0, 2.0, 3.0, 4.0]);
+        let mut live = ExponentialMovingAverage::new(3).unwrap();
+        for bar in &history {
+            live.next(bar);
+        }
+
+        let fresh = ExponentialMovingAverage::new(3).unwrap();
+        let probe = Bar::new().close(5);
+        let report = DriftCheck::new(0.0)
+            .unwrap()
+            .run(&live, fresh, &history, &probe);
+        assert_eq!(report.live, report.recomputed);
+        assert_eq!(report.drift, 0.0);
+        assert!(!report.needs_resync);
+
+        // The live instance is not fed the probe.
+        assert_eq!(live.next(&probe), report.live);
+    }
+
+    #[test]
+    fn test_run_missed_bar() {
+        let history = bars(&[1.0, 2.0, 3.0, 4.0]);
+        let mut live = SimpleMovingAverage::new(3).unwrap();
+        for i in [0, 1, 3] {
+            live.next(&history[i]);
+        }
+
+        let fresh = SimpleMovingAverage::new(3).unwrap();
+        let probe = Bar::new().close(5);
+        let check = DriftCheck::new(0.5).unwrap();
+        let report = check.run(&live, fresh, &history, &probe);
+        assert_eq!(round(report.live), 3.667);
+        assert_eq!(report.recomputed, 4.0);
+        assert_eq!(round(report.drift), 0.333);
+        assert!(!report.needs_resync);
+
+        let mut resynced = report.resynced;
+        assert_eq!(resynced.next(&probe), 4.0);
+    }
+
+    #[test]
+    fn test_run_nan() {
+        let history = bars(&[1.0, 2.0]);
+        let mut live = GeometricMean::new(3).unwrap();
+        live.next(&Bar::new().close(-1));
+
+        let fresh = GeometricMean::new(3).unwrap();
+        let report =
+            DriftCheck::new(1.0)
+                .unwrap()
+                .run(&live, fresh, &history, &Bar::new().close(4));
+        assert!(report.live.is_nan());
+        assert_eq!(round(report.recomputed), 2.0);
+        assert_eq!(report.drift, f64::INFINITY);
+        assert!(report.needs_resync);
+    }
+}
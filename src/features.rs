@@ -0,0 +1,456 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::indicators::*;
+use crate::units::{Percent, Price, VolumeUnits};
+use crate::{Next, Reset};
+
+/// Output of an indicator flattened into named `f64` values.
+///
+/// Machine learning pipelines need every output as a row of numbers with a stable name per
+/// column. Single values are named after the indicator, the fields of multi-field outputs get
+/// the field name appended after a dot, e.g. `bb.upper`. Booleans are 0 or 1, directions 1 for up
+/// and -1 for down, and the [volatility regime](indicators/enum.Regime.html) -1, 0 or 1 from low
+/// to high.
+///
+/// Outputs that can be absent, `Option<T>`, produce the same names as `T`, with NaN values when
+/// they are absent, so every row has the same columns.
+///
+/// See [FeatureCollector](struct.FeatureCollector.html) to assemble the features of several
+/// indicators.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::BollingerBands;
+/// use ta::{Features, Next};
+///
+/// let mut bb = BollingerBands::new(3, 2.0).unwrap();
+/// let features = bb.next(2.0).features("bb");
+/// assert_eq!(
+///     features,
+///     vec![
+///         ("bb.average".to_string(), 2.0),
+///         ("bb.upper".to_string(), 2.0),
+///         ("bb.lower".to_string(), 2.0),
+///         ("bb.percent_b".to_string(), 0.5),
+///         ("bb.bandwidth".to_string(), 0.0),
+///     ]
+/// );
+/// ```
+pub trait Features {
+    /// Appends the named values of the output to _features_.
+    fn append_features(&self, name: &str, features: &mut Vec<(String, f64)>);
+
+    /// Appends the names of the values of an absent output to _features_, with NaN values.
+    fn append_missing(name: &str, features: &mut Vec<(String, f64)>)
+    where
+        Self: Sized;
+
+    /// Returns the named values of the output.
+    fn features(&self, name: &str) -> Vec<(String, f64)> {
+        let mut features = Vec::new();
+        self.append_features(name, &mut features);
+        features
+    }
+}
+
+impl Features for f64 {
+    fn append_features(&self, name: &str, features: &mut Vec<(String, f64)>) {
+        features.push((name.to_string(), *self));
+    }
+
+    fn append_missing(name: &str, features: &mut Vec<(String, f64)>) {
+        features.push((name.to_string(), f64::NAN));
+    }
+}
+
+impl Features for bool {
+    fn append_features(&self, name: &str, features: &mut Vec<(String, f64)>) {
+        features.push((name.to_string(), if *self { 1.0 } else { 0.0 }));
+    }
+
+    fn append_missing(name: &str, features: &mut Vec<(String, f64)>) {
+        f64::append_missing(name, features);
+    }
+}
+
+impl Features for usize {
+    fn append_features(&self, name: &str, features: &mut Vec<(String, f64)>) {
+        features.push((name.to_string(), *self as f64));
+    }
+
+    fn append_missing(name: &str, features: &mut Vec<(String, f64)>) {
+        f64::append_missing(name, features);
+    }
+}
+
+impl<T: Features> Features for Option<T> {
+    fn append_features(&self, name: &str, features: &mut Vec<(String, f64)>) {
+        match self {
+            Some(value) => value.append_features(name, features),
+            None => T::append_missing(name, features),
+        }
+    }
+
+    fn append_missing(name: &str, features: &mut Vec<(String, f64)>) {
+        T::append_missing(name, features);
+    }
+}
+
+// Types converted to a single value.
+macro_rules! impl_value_features {
+    ($($type:ty => |$value:ident| $convert:expr;)*) => {
+        $(
+            impl Features for $type {
+                fn append_features(&self, name: &str, features: &mut Vec<(String, f64)>) {
+                    let $value = self;
+                    features.push((name.to_string(), $convert));
+                }
+
+                fn append_missing(name: &str, features: &mut Vec<(String, f64)>) {
+                    f64::append_missing(name, features);
+                }
+            }
+        )*
+    };
+}
+
+impl_value_features! {
+    Price => |price| price.0;
+    Percent => |percent| percent.0;
+    VolumeUnits => |volume| volume.0;
+    TrendDirection => |direction| match direction {
+        TrendDirection::Up => 1.0,
+        TrendDirection::Down => -1.0,
+    };
+    BreakoutDirection => |direction| match direction {
+        BreakoutDirection::Up => 1.0,
+        BreakoutDirection::Down => -1.0,
+    };
+    SpikeDirection => |direction| match direction {
+        SpikeDirection::Up => 1.0,
+        SpikeDirection::Down => -1.0,
+    };
+    Regime => |regime| match regime {
+        Regime::Low => -1.0,
+        Regime::Normal => 0.0,
+        Regime::High => 1.0,
+    };
+}
+
+// Multi-field outputs, every field is a feature named `name.field`.
+macro_rules! impl_struct_features {
+    ($($type:ty { $($field:ident: $field_type:ty),* $(,)? })*) => {
+        $(
+            impl Features for $type {
+                fn append_features(&self, name: &str, features: &mut Vec<(String, f64)>) {
+                    $(
+                        self.$field
+                            .append_features(&format!("{}.{}", name, stringify!($field)), features);
+                    )*
+                }
+
+                fn append_missing(name: &str, features: &mut Vec<(String, f64)>) {
+                    $(
+                        <$field_type>::append_missing(
+                            &format!("{}.{}", name, stringify!($field)),
+                            features,
+                        );
+                    )*
+                }
+            }
+        )*
+    };
+}
+
+impl_struct_features! {
+    AccelerationBandsOutput { upper: f64, mid: f64, lower: f64 }
+    AroonOutput { up: f64, down: f64, oscillator: f64 }
+    AtrBandsOutput { upper: f64, mid: f64, lower: f64 }
+    AverageDirectionalIndexOutput { adx: f64, plus_di: f64, minus_di: f64 }
+    BollingerBandsOutput {
+        average: f64,
+        upper: f64,
+        lower: f64,
+        percent_b: f64,
+        bandwidth: f64,
+    }
+    CandleAnatomyOutput { clv: f64, body: f64, upper_wick: f64, lower_wick: f64 }
+    ChandelierExitOutput { long: f64, short: f64 }
+    DivergenceScoreOutput { slope: f64, correlation: f64 }
+    ExtremeOutput { value: f64, offset: usize }
+    FisherTransformOutput { fisher: f64, trigger: f64 }
+    FractalPoint { up: Option<f64>, down: Option<f64>, offset: usize }
+    HalfTrendOutput {
+        half_trend: f64,
+        upper: f64,
+        lower: f64,
+        direction: TrendDirection,
+        buy_signal: bool,
+        sell_signal: bool,
+    }
+    HampelFilterOutput { value: f64, median: f64, outlier: bool }
+    KeltnerChannelOutput { average: f64, upper: f64, lower: f64 }
+    KnowSureThingOutput { kst: f64, signal: f64 }
+    LinearRegressionOutput { slope: f64, intercept: f64, value: f64, forecast: f64 }
+    LinearRegressionChannelOutput { upper: f64, mid: f64, lower: f64 }
+    MamaOutput { mama: f64, fama: f64 }
+    MovingAverageConvergenceDivergenceOutput { macd: f64, signal: f64, histogram: f64 }
+    NadarayaWatsonEnvelopeOutput { average: f64, upper: f64, lower: f64 }
+    OpeningRangeBreakoutOutput {
+        high: f64,
+        low: f64,
+        complete: bool,
+        breakout: Option<BreakoutDirection>,
+    }
+    PercentagePriceOscillatorOutput { ppo: f64, signal: f64, histogram: f64 }
+    PercentageVolumeOscillatorOutput { pvo: f64, signal: f64, histogram: f64 }
+    PercentileBandsOutput { lower: f64, median: f64, upper: f64 }
+    PivotLevels { pivot: f64, r1: f64, r2: f64, r3: f64, s1: f64, s2: f64, s3: f64 }
+    PriceVolumeTrendOutput { pvt: f64, signal: Option<f64> }
+    RelativeVigorIndexOutput { rvi: f64, signal: f64 }
+    RollingBetaOutput { beta: f64, r_squared: f64 }
+    RollingDrawdownOutput { value: f64, drawdown: f64, runup: f64 }
+    RollingModeOutput { price: f64, share: f64 }
+    SessionOhlc { open: f64, high: f64, low: f64, close: f64 }
+    SessionLevelsOutput {
+        new_session: bool,
+        open: f64,
+        high: f64,
+        low: f64,
+        previous: Option<SessionOhlc>,
+    }
+    SslChannelOutput { up: f64, down: f64, direction: TrendDirection }
+    StochasticOscillatorOutput { k: f64, d: f64 }
+    TrueStrengthIndexOutput { tsi: f64, signal: f64 }
+    TtmSqueezeOutput { squeeze: bool, momentum: f64 }
+    VolatilityRegimeOutput { volatility: f64, percent_rank: f64, regime: Regime }
+    VolatilitySpikeEvent { direction: SpikeDirection, true_range: f64, atr: f64, ratio: f64 }
+    VolumeProfileOutput { poc: f64, value_area_high: f64, value_area_low: f64 }
+    ZoneDurationOutput { value: f64, overbought: usize, oversold: usize }
+}
+
+/// Collects the features of several indicators fed with the same bars.
+///
+/// Every indicator is added with a name, and [next](#method.next) returns the
+/// [features](trait.Features.html) of all of them for a bar, in the order they were added. The
+/// rows of a training matrix are the outputs of `next` for consecutive bars.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{BollingerBands, RelativeStrengthIndex};
+/// use ta::{DataItem, FeatureCollector};
+///
+/// let mut collector = FeatureCollector::new()
+///     .add("rsi", RelativeStrengthIndex::new(14).unwrap())
+///     .add("bb", BollingerBands::new(20, 2.0).unwrap());
+///
+/// let bar = DataItem::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let row = collector.next(&bar);
+/// let names: Vec<&str> = row.iter().map(|(name, _)| name.as_str()).collect();
+/// assert_eq!(
+///     names,
+///     vec!["rsi", "bb.average", "bb.upper", "bb.lower", "bb.percent_b", "bb.bandwidth"]
+/// );
+/// ```
+pub struct FeatureCollector<T> {
+    extractors: Vec<(String, Box<dyn Extractor<T>>)>,
+}
+
+impl<T> FeatureCollector<T> {
+    pub fn new() -> Self {
+        Self {
+            extractors: Vec::new(),
+        }
+    }
+
+    /// Adds an indicator whose features are named after _name_.
+    pub fn add<I, O>(mut self, name: &str, indicator: I) -> Self
+    where
+        I: for<'a> Next<&'a T, Output = O> + Reset + 'static,
+        O: Features + 'static,
+    {
+        let extractor = Indicator {
+            indicator,
+            output: PhantomData,
+        };
+        self.extractors
+            .push((name.to_string(), Box::new(extractor)));
+        self
+    }
+
+    /// Feeds the bar to every indicator and returns all their features.
+    pub fn next(&mut self, input: &T) -> Vec<(String, f64)> {
+        let mut features = Vec::new();
+        for (name, extractor) in self.extractors.iter_mut() {
+            extractor.extract(name, input, &mut features);
+        }
+        features
+    }
+}
+
+impl<T> Reset for FeatureCollector<T> {
+    fn reset(&mut self) {
+        for (_, extractor) in self.extractors.iter_mut() {
+            extractor.reset();
+        }
+    }
+}
+
+impl<T> Default for FeatureCollector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for FeatureCollector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<&str> = self
+            .extractors
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        f.debug_struct("FeatureCollector")
+            .field("indicators", &names)
+            .finish()
+    }
+}
+
+// Object safe view of an indicator, its output type is erased.
+trait Extractor<T> {
+    fn extract(&mut self, name: &str, input: &T, features: &mut Vec<(String, f64)>);
+    fn reset(&mut self);
+}
+
+struct Indicator<I, O> {
+    indicator: I,
+    output: PhantomData<fn() -> O>,
+}
+
+impl<T, I, O> Extractor<T> for Indicator<I, O>
+where
+    I: for<'a> Next<&'a T, Output = O> + Reset,
+    O: Features,
+{
+    fn extract(&mut self, name: &str, input: &T, features: &mut Vec<(String, f64)>) {
+        self.indicator.next(input).append_features(name, features);
+    }
+
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn names(features: &[(String, f64)]) -> Vec<&str> {
+        features.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_features() {
+        assert_eq!(1.5.features("x"), vec![("x".to_string(), 1.5)]);
+        assert_eq!(true.features("b"), vec![("b".to_string(), 1.0)]);
+        assert_eq!(Regime::Low.features("r"), vec![("r".to_string(), -1.0)]);
+
+        let output = ZoneDurationOutput {
+            value: 25.0,
+            overbought: 0,
+            oversold: 3,
+        };
+        assert_eq!(
+            output.features("rsi"),
+            vec![
+                ("rsi.value".to_string(), 25.0),
+                ("rsi.overbought".to_string(), 0.0),
+                ("rsi.oversold".to_string(), 3.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_features_missing() {
+        let event: Option<VolatilitySpikeEvent> = None;
+        let features = event.features("spike");
+        assert_eq!(
+            names(&features),
+            vec![
+                "spike.direction",
+                "spike.true_range",
+                "spike.atr",
+                "spike.ratio"
+            ]
+        );
+        assert!(features.iter().all(|(_, value)| value.is_nan()));
+
+        let output = SessionLevelsOutput {
+            new_session: true,
+            open: 1.0,
+            high: 2.0,
+            low: 0.5,
+            previous: None,
+        };
+        let features = output.features("session");
+        assert_eq!(features.len(), 8);
+        assert_eq!(features[5].0, "session.previous.high");
+        assert!(features[5].1.is_nan());
+    }
+
+    #[test]
+    fn test_collector() {
+        let mut collector = FeatureCollector::new()
+            .add("sma", SimpleMovingAverage::new(2).unwrap())
+            .add("max", Maximum::new(2).unwrap())
+            .add(
+                "pivot",
+                PivotPoints::new(60, 0, PivotMethod::Classic).unwrap(),
+            );
+
+        let bar = |close: f64, timestamp: i64| {
+            Bar::new()
+                .high(close)
+                .low(close)
+                .close(close)
+                .timestamp(timestamp)
+        };
+
+        let row = collector.next(&bar(4.0, 0));
+        assert_eq!(row.len(), 9);
+        assert_eq!(
+            &row[..2],
+            &[("sma".to_string(), 4.0), ("max".to_string(), 4.0)]
+        );
+        assert_eq!(row[2].0, "pivot.pivot");
+        assert!(row[2].1.is_nan());
+
+        let row = collector.next(&bar(6.0, 60));
+        assert_eq!(row[0].1, 5.0);
+        assert_eq!(row[2], ("pivot.pivot".to_string(), 4.0));
+
+        collector.reset();
+        assert_eq!(collector.next(&bar(2.0, 120))[0].1, 2.0);
+    }
+
+    #[test]
+    fn test_collector_debug() {
+        let collector: FeatureCollector<Bar> =
+            FeatureCollector::new().add("sma", SimpleMovingAverage::new(2).unwrap());
+        assert_eq!(
+            format!("{:?}", collector),
+            "FeatureCollector { indicators: [\"sma\"] }"
+        );
+    }
+}
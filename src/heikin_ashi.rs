@@ -0,0 +1,198 @@
+use std::fmt;
+
+use crate::{Close, DataItem, High, Low, Next, Open, Reset, Timestamp, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Converts bars into Heikin-Ashi bars.
+///
+/// Heikin-Ashi ("average bar") candles smooth the price action: the close is the average price
+/// of the bar and the open the midpoint of the previous Heikin-Ashi bar, so trends show as long
+/// runs of candles of the same color. The returned bars are [DataItem](struct.DataItem.html)s,
+/// so any indicator can be driven off Heikin-Ashi candles. The volume and the timestamp are
+/// passed through.
+///
+/// # Formula
+///
+/// HA close = (open + high + low + close) / 4
+///
+/// HA open = (previous HA open + previous HA close) / 2
+///
+/// HA high = max(high, HA open, HA close)
+///
+/// HA low = min(low, HA open, HA close)
+///
+/// The HA open of the first bar is (open + close) / 2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{Close, DataItem, HeikinAshi, Next, Open};
+///
+/// let bar = |open: f64, high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut heikin_ashi = HeikinAshi::new();
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+///
+/// let ha = heikin_ashi.next(&bar(10.0, 12.0, 9.0, 11.0));
+/// assert_eq!((ha.open(), ha.close()), (10.5, 10.5));
+/// sma.next(&ha);
+///
+/// let ha = heikin_ashi.next(&bar(11.0, 13.0, 10.0, 12.0));
+/// assert_eq!((ha.open(), ha.close()), (10.5, 11.5));
+/// assert_eq!(sma.next(&ha), 11.0);
+/// ```
+///
+/// # Links
+///
+/// * [Heikin-Ashi, Investopedia](https://www.investopedia.com/trading/heikin-ashi-better-candlestick/)
+///
+#[doc(alias = "HA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HeikinAshi {
+    // Open and close of the previous Heikin-Ashi bar.
+    previous: Option<(f64, f64)>,
+}
+
+impl HeikinAshi {
+    pub fn new() -> Self {
+        Self { previous: None }
+    }
+}
+
+impl Next<&DataItem> for HeikinAshi {
+    type Output = DataItem;
+
+    fn next(&mut self, input: &DataItem) -> Self::Output {
+        let close = (input.open() + input.high() + input.low() + input.close()) / 4.0;
+        let open = match self.previous {
+            Some((open, close)) => (open + close) / 2.0,
+            None => (input.open() + input.close()) / 2.0,
+        };
+        self.previous = Some((open, close));
+
+        let builder = DataItem::builder()
+            .open(open)
+            .high(input.high().max(open).max(close))
+            .low(input.low().min(open).min(close))
+            .close(close)
+            .volume(input.volume());
+        match input.timestamp() {
+            Some(timestamp) => builder.timestamp(timestamp),
+            None => builder,
+        }
+        .build()
+        // The input is a valid bar, so are the averages of its non negative prices.
+        .unwrap()
+    }
+}
+
+impl Reset for HeikinAshi {
+    fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+impl Default for HeikinAshi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for HeikinAshi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HA")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> DataItem {
+        DataItem::builder()
+            .open(open)
+            .high(high)
+            .low(low)
+            .close(close)
+            .volume(100.0)
+            .build()
+            .unwrap()
+    }
+
+    fn ohlc(bar: &DataItem) -> (f64, f64, f64, f64) {
+        (bar.open(), bar.high(), bar.low(), bar.close())
+    }
+
+    #[test]
+    fn test_next() {
+        let mut heikin_ashi = HeikinAshi::new();
+
+        let test_data = vec![
+            // input, expected
+            ((10.0, 12.0, 9.0, 11.0), (10.5, 12.0, 9.0, 10.5)),
+            ((11.0, 13.0, 10.0, 12.0), (10.5, 13.0, 10.0, 11.5)),
+            ((12.0, 12.5, 8.0, 9.0), (11.0, 12.5, 8.0, 10.375)),
+            // the HA open is above the high
+            ((9.0, 9.5, 8.5, 9.0), (10.6875, 10.6875, 8.5, 9.0)),
+        ];
+
+        for ((open, high, low, close), expected) in test_data {
+            let out = heikin_ashi.next(&bar(open, high, low, close));
+            assert_eq!(ohlc(&out), expected);
+            assert_eq!(out.volume(), 100.0);
+        }
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let mut heikin_ashi = HeikinAshi::new();
+        let input = DataItem::builder()
+            .open(10.0)
+            .high(10.0)
+            .low(10.0)
+            .close(10.0)
+            .volume(0.0)
+            .timestamp(60)
+            .build()
+            .unwrap();
+
+        assert_eq!(heikin_ashi.next(&input).timestamp(), Some(60));
+        assert_eq!(
+            heikin_ashi.next(&bar(10.0, 10.0, 10.0, 10.0)).timestamp(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut heikin_ashi = HeikinAshi::new();
+        heikin_ashi.next(&bar(10.0, 12.0, 9.0, 11.0));
+        heikin_ashi.next(&bar(11.0, 13.0, 10.0, 12.0));
+
+        heikin_ashi.reset();
+        let out = heikin_ashi.next(&bar(11.0, 13.0, 10.0, 12.0));
+        assert_eq!(out.open(), 11.5);
+    }
+
+    #[test]
+    fn test_default() {
+        HeikinAshi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", HeikinAshi::new()), "HA");
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage;
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Acceleration bands.
+///
+/// Developed by Price Headley, the bands widen each high and low by the relative range of its
+/// bar and average them over the period. The bands widen when the bars get longer, and a close
+/// outside of them marks an acceleration of the trend, while closes back inside the bands mark
+/// its end.
+///
+/// # Formula
+///
+/// upper = SMA(_period_) of high * (1 + 4 * (high - low) / (high + low))
+///
+/// mid = SMA(_period_) of close
+///
+/// lower = SMA(_period_) of low * (1 - 4 * (high - low) / (high + low))
+///
+/// A bar with high + low = 0 is not widened.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars of the averages (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AccelerationBands;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut bands = AccelerationBands::new(20).unwrap();
+/// // 4 * (9 - 7) / (9 + 7) = 0.5, so the high and the low are widened by 50%.
+/// let out = bands.next(&bar(9.0, 7.0, 8.0));
+/// assert_eq!(out.upper, 13.5);
+/// assert_eq!(out.mid, 8.0);
+/// assert_eq!(out.lower, 3.5);
+/// ```
+///
+#[doc(alias = "ABANDS")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AccelerationBands {
+    upper: SimpleMovingAverage,
+    mid: SimpleMovingAverage,
+    lower: SimpleMovingAverage,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccelerationBandsOutput {
+    pub upper: f64,
+    pub mid: f64,
+    pub lower: f64,
+}
+
+impl AccelerationBands {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            upper: SimpleMovingAverage::new(period)?,
+            mid: SimpleMovingAverage::new(period)?,
+            lower: SimpleMovingAverage::new(period)?,
+        })
+    }
+}
+
+impl Period for AccelerationBands {
+    fn period(&self) -> usize {
+        self.mid.period()
+    }
+}
+
+impl Lookback for AccelerationBands {
+    fn lookback(&self) -> usize {
+        self.mid.lookback()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for AccelerationBands {
+    type Output = AccelerationBandsOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low) = (input.high(), input.low());
+        let width = if high + low != 0.0 {
+            4.0 * (high - low) / (high + low)
+        } else {
+            0.0
+        };
+
+        AccelerationBandsOutput {
+            upper: self.upper.next(high * (1.0 + width)),
+            mid: self.mid.next(input.close()),
+            lower: self.lower.next(low * (1.0 - width)),
+        }
+    }
+}
+
+impl Reset for AccelerationBands {
+    fn reset(&mut self) {
+        self.upper.reset();
+        self.mid.reset();
+        self.lower.reset();
+    }
+}
+
+impl Default for AccelerationBands {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for AccelerationBands {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ABANDS({})", self.mid.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(AccelerationBands::new(0).is_err());
+        assert!(AccelerationBands::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut bands = AccelerationBands::new(2).unwrap();
+
+        let test_data = vec![
+            // high, low, close, upper, mid, lower
+            (10.0, 8.0, 9.0, 14.444, 9.0, 4.444),
+            (12.0, 10.0, 11.0, 15.404, 10.0, 5.404),
+            (11.0, 9.0, 10.0, 15.882, 10.5, 5.882),
+            (10.5, 9.5, 10.0, 14.0, 10.0, 6.5),
+        ];
+
+        for (high, low, close, upper, mid, lower) in test_data {
+            let out = bands.next(&bar(high, low, close));
+            assert_eq!(round(out.upper), upper);
+            assert_eq!(round(out.mid), mid);
+            assert_eq!(round(out.lower), lower);
+        }
+    }
+
+    #[test]
+    fn test_next_zero_prices() {
+        let mut bands = AccelerationBands::new(2).unwrap();
+        let out = bands.next(&bar(0.0, 0.0, 0.0));
+        assert_eq!(out.upper, 0.0);
+        assert_eq!(out.lower, 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bands = AccelerationBands::new(2).unwrap();
+        bands.next(&bar(10.0, 8.0, 9.0));
+
+        bands.reset();
+        let out = bands.next(&bar(9.0, 7.0, 8.0));
+        assert_eq!(out.upper, 13.5);
+        assert_eq!(out.mid, 8.0);
+        assert_eq!(out.lower, 3.5);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let bands = AccelerationBands::new(20).unwrap();
+        assert_eq!(bands.lookback(), 19);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(AccelerationBands::default().period(), 20);
+    }
+
+    #[test]
+    fn test_display() {
+        let bands = AccelerationBands::new(20).unwrap();
+        assert_eq!(format!("{}", bands), "ABANDS(20)");
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+
+use crate::units::{OutputUnit, VolumeUnits};
+use crate::{Close, High, Lookback, Low, Next, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Accumulation/Distribution line (A/D).
+///
+/// Developed by Marc Chaikin, the A/D line is a cumulative volume based indicator. Every period
+/// adds a part of its volume, depending on where the close is within the range of the period:
+/// a close at the high adds the whole volume, a close at the low subtracts it.
+///
+/// # Formula
+///
+/// CLV = ((close - low) - (high - close)) / (high - low)
+///
+/// A/D<sub>t</sub> = A/D<sub>t-1</sub> + CLV * volume
+///
+/// Where:
+///
+/// * _CLV_ - close location value, 0 when high equals low
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AccumulationDistribution;
+/// use ta::{DataItem, Next};
+///
+/// let mut ad = AccumulationDistribution::new();
+///
+/// let di1 = DataItem::builder()
+///     .high(3.0)
+///     .low(1.0)
+///     .close(2.5)
+///     .open(1.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let di2 = DataItem::builder()
+///     .high(3.0)
+///     .low(1.0)
+///     .close(1.0)
+///     .open(1.5)
+///     .volume(300.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(ad.next(&di1), 500.0);
+/// assert_eq!(ad.next(&di2), 200.0);
+/// ```
+///
+/// # Links
+///
+/// * [Accumulation/distribution index, Wikipedia](https://en.wikipedia.org/wiki/Accumulation/distribution_index)
+/// * [Accumulation Distribution Line, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:accumulation_distribution_line)
+///
+#[doc(alias = "AD")]
+#[doc(alias = "ADL")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AccumulationDistribution {
+    ad: f64,
+}
+
+impl AccumulationDistribution {
+    pub fn new() -> Self {
+        Self { ad: 0.0 }
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for AccumulationDistribution {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> f64 {
+        let range = input.high() - input.low();
+        if range != 0.0 {
+            let clv = ((input.close() - input.low()) - (input.high() - input.close())) / range;
+            self.ad += clv * input.volume();
+        }
+        self.ad
+    }
+}
+
+impl Lookback for AccumulationDistribution {
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+impl OutputUnit for AccumulationDistribution {
+    type Unit = VolumeUnits;
+}
+
+impl Default for AccumulationDistribution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for AccumulationDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AD")
+    }
+}
+
+impl Reset for AccumulationDistribution {
+    fn reset(&mut self) {
+        self.ad = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_bar() {
+        let mut ad = AccumulationDistribution::new();
+
+        // CLV = 1
+        let bar1 = Bar::new().high(4).low(2).close(4).volume(1000.0);
+        // CLV = 0
+        let bar2 = Bar::new().high(4).low(2).close(3).volume(5000.0);
+        // CLV = -0.5
+        let bar3 = Bar::new().high(6).low(2).close(3).volume(2000.0);
+        // high == low
+        let bar4 = Bar::new().high(3).low(3).close(3).volume(4000.0);
+
+        assert_eq!(ad.next(&bar1), 1000.0);
+        assert_eq!(ad.next(&bar2), 1000.0);
+        assert_eq!(ad.next(&bar3), 0.0);
+        assert_eq!(ad.next(&bar4), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ad = AccumulationDistribution::new();
+
+        let bar1 = Bar::new().high(4).low(2).close(4).volume(1000.0);
+        let bar2 = Bar::new().high(6).low(2).close(3).volume(2000.0);
+
+        assert_eq!(ad.next(&bar1), 1000.0);
+        assert_eq!(ad.next(&bar2), 0.0);
+
+        ad.reset();
+
+        assert_eq!(ad.next(&bar1), 1000.0);
+        assert_eq!(ad.next(&bar2), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        AccumulationDistribution::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ad = AccumulationDistribution::new();
+        assert_eq!(format!("{}", ad), "AD");
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SwingIndex;
+use crate::{Close, High, Lookback, Low, Next, Open, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Accumulative Swing Index (ASI).
+///
+/// The running total of Wilder's [Swing Index](struct.SwingIndex.html). It follows the price
+/// closely, but its swings confirm breakouts: a breakout of the price that is not confirmed by
+/// a breakout of the ASI is suspect. Its trend lines and swing points are drawn like those of
+/// the price.
+///
+/// # Formula
+///
+/// ASI = ASI<sub>prev</sub> + SI
+///
+/// # Parameters
+///
+/// * _limit_move_ - maximum price change allowed in one bar by the exchange (greater than 0).
+///   It only scales the index, so markets without a limit can use any fixed value. Default is 1.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AccumulativeSwingIndex;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |open: f64, high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut asi = AccumulativeSwingIndex::new(3.0).unwrap();
+/// assert_eq!(asi.next(&bar(10.0, 11.0, 9.5, 10.5)), 0.0);
+/// assert_eq!(asi.next(&bar(10.5, 12.0, 10.4, 11.8)).round(), 30.0);
+/// assert_eq!(asi.next(&bar(11.9, 12.2, 11.0, 11.2)).round(), 25.0);
+/// ```
+///
+#[doc(alias = "ASI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AccumulativeSwingIndex {
+    swing_index: SwingIndex,
+    asi: f64,
+}
+
+impl AccumulativeSwingIndex {
+    pub fn new(limit_move: f64) -> Result<Self> {
+        Ok(Self {
+            swing_index: SwingIndex::new(limit_move)?,
+            asi: 0.0,
+        })
+    }
+
+    pub fn limit_move(&self) -> f64 {
+        self.swing_index.limit_move()
+    }
+}
+
+impl Lookback for AccumulativeSwingIndex {
+    fn lookback(&self) -> usize {
+        self.swing_index.lookback()
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for AccumulativeSwingIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.asi += self.swing_index.next(input);
+        self.asi
+    }
+}
+
+impl Reset for AccumulativeSwingIndex {
+    fn reset(&mut self) {
+        self.swing_index.reset();
+        self.asi = 0.0;
+    }
+}
+
+impl Default for AccumulativeSwingIndex {
+    fn default() -> Self {
+        Self::new(1.0).unwrap()
+    }
+}
+
+impl fmt::Display for AccumulativeSwingIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ASI({})", self.swing_index.limit_move())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(AccumulativeSwingIndex::new(-1.0).is_err());
+        assert!(AccumulativeSwingIndex::new(3.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut asi = AccumulativeSwingIndex::new(3.0).unwrap();
+
+        assert_eq!(asi.next(&bar(10.0, 11.0, 9.5, 10.5)), 0.0);
+        assert_eq!(round(asi.next(&bar(10.5, 12.0, 10.4, 11.8))), 30.072);
+        assert_eq!(round(asi.next(&bar(11.9, 12.2, 11.0, 11.2))), 24.608);
+        assert_eq!(round(asi.next(&bar(11.0, 11.5, 10.0, 10.2))), 5.802);
+        assert_eq!(round(asi.next(&bar(10.2, 10.8, 10.1, 10.6))), 10.246);
+        assert_eq!(round(asi.next(&bar(11.5, 12.0, 11.4, 11.9))), 44.186);
+        assert_eq!(round(asi.next(&bar(10.5, 10.6, 10.0, 10.2))), 3.136);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut asi = AccumulativeSwingIndex::new(3.0).unwrap();
+        asi.next(&bar(10.0, 11.0, 9.5, 10.5));
+        asi.next(&bar(10.5, 12.0, 10.4, 11.8));
+
+        asi.reset();
+        assert_eq!(asi.next(&bar(11.9, 12.2, 11.0, 11.2)), 0.0);
+        assert_eq!(round(asi.next(&bar(11.0, 11.5, 10.0, 10.2))), -18.806);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(AccumulativeSwingIndex::default().limit_move(), 1.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let asi = AccumulativeSwingIndex::new(3.0).unwrap();
+        assert_eq!(format!("{}", asi), "ASI(3)");
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt;
+
+use crate::{Lookback, Next, Period, Reset, SetPeriod};
+
+/// Indicator with a period driven by another indicator.
+///
+/// Generalizes the adaptivity of [KAMA](struct.KaufmanAdaptiveMovingAverage.html) to any
+/// indicator implementing [SetPeriod](../trait.SetPeriod.html). On every period the _driver_
+/// (e.g. an [efficiency ratio](struct.EfficiencyRatio.html) or a
+/// [volatility regime](struct.VolatilityRegime.html)) consumes the input first, its output is
+/// mapped to a period by _map_, and the period is applied to the inner indicator before it
+/// consumes the same input.
+///
+/// Periods rejected by the inner indicator, e.g. 0, are ignored and the previous period is kept.
+///
+/// # Parameters
+///
+/// * _indicator_ - inner indicator, it must implement `SetPeriod`.
+/// * _driver_ - indicator producing `f64` values fed with the same input.
+/// * _map_ - function from the output of the driver to the period of the inner indicator.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Adaptive, EfficiencyRatio, ExponentialMovingAverage};
+/// use ta::{Next, Period};
+///
+/// let ema = ExponentialMovingAverage::new(10).unwrap();
+/// let er = EfficiencyRatio::new(4).unwrap();
+///
+/// // Short period while the market trends, long period while it is choppy.
+/// let mut ama = Adaptive::new(ema, er, |er: f64| 2 + ((1.0 - er) * 8.0).round() as usize);
+///
+/// assert_eq!(ama.next(10.0), 10.0);
+/// assert_eq!(ama.next(13.0), 12.0);
+/// assert_eq!(ama.period(), 2);
+///
+/// assert_eq!(ama.next(12.0), 12.0);
+/// assert_eq!(ama.period(), 6);
+/// ```
+///
+#[derive(Clone)]
+pub struct Adaptive<I, D, F = fn(f64) -> usize> {
+    indicator: I,
+    driver: D,
+    map: F,
+}
+
+impl<I, D, F> Adaptive<I, D, F>
+where
+    F: FnMut(f64) -> usize,
+{
+    pub fn new(indicator: I, driver: D, map: F) -> Self {
+        Self {
+            indicator,
+            driver,
+            map,
+        }
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+
+    pub fn driver(&self) -> &D {
+        &self.driver
+    }
+}
+
+impl<I: Period, D, F> Period for Adaptive<I, D, F> {
+    /// Returns the current period of the inner indicator.
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: Lookback, D: Lookback, F> Lookback for Adaptive<I, D, F> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback().max(self.driver.lookback())
+    }
+}
+
+impl<In, I, D, F> Next<In> for Adaptive<I, D, F>
+where
+    In: Copy,
+    I: Next<In> + SetPeriod,
+    D: Next<In, Output = f64>,
+    F: FnMut(f64) -> usize,
+{
+    type Output = I::Output;
+
+    fn next(&mut self, input: In) -> Self::Output {
+        let period = (self.map)(self.driver.next(input));
+        // Keep the previous period when the new one is rejected.
+        let _ = self.indicator.set_period(period);
+        self.indicator.next(input)
+    }
+}
+
+impl<I: Reset, D: Reset, F> Reset for Adaptive<I, D, F> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.driver.reset();
+    }
+}
+
+impl<I: fmt::Debug, D: fmt::Debug, F> fmt::Debug for Adaptive<I, D, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Adaptive")
+            .field("indicator", &self.indicator)
+            .field("driver", &self.driver)
+            .finish()
+    }
+}
+
+impl<I: fmt::Display, D: fmt::Display, F> fmt::Display for Adaptive<I, D, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ADAPTIVE({}, {})", self.indicator, self.driver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage, SimpleMovingAverage};
+    use crate::test_helper::*;
+
+    type Sma = SimpleMovingAverage;
+
+    fn to_period(value: f64) -> usize {
+        value as usize
+    }
+
+    // SMA of period 1 passes the input through, so the input is also the period.
+    fn adaptive() -> Adaptive<Sma, Sma> {
+        Adaptive::new(Sma::new(4).unwrap(), Sma::new(1).unwrap(), to_period)
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sma = adaptive();
+
+        assert_eq!(sma.next(2.0), 2.0);
+        assert_eq!(sma.period(), 2);
+        assert_eq!(sma.next(4.0), 3.0);
+        assert_eq!(sma.next(3.0), 3.0);
+        assert_eq!(sma.period(), 3);
+        assert_eq!(sma.next(1.0), 1.0);
+        assert_eq!(sma.period(), 1);
+    }
+
+    #[test]
+    fn test_next_rejected_period() {
+        let mut sma = adaptive();
+        sma.next(2.0);
+
+        // Period 0 is rejected, the SMA stays at period 2.
+        assert_eq!(sma.next(0.0), 1.0);
+        assert_eq!(sma.period(), 2);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let ema = ExponentialMovingAverage::new(9).unwrap();
+        let mut ema = Adaptive::new(ema, Sma::new(1).unwrap(), |_| 3);
+
+        assert_eq!(ema.next(&Bar::new().close(2)), 2.0);
+        assert_eq!(ema.next(&Bar::new().close(5)), 3.5);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let sma = Adaptive::new(Sma::new(4).unwrap(), Sma::new(10).unwrap(), to_period);
+        assert_eq!(sma.lookback(), 9);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = adaptive();
+        sma.next(2.0);
+        sma.next(4.0);
+
+        sma.reset();
+        assert_eq!(sma.next(2.0), 2.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = adaptive();
+        assert_eq!(format!("{}", indicator), "ADAPTIVE(SMA(4), SMA(1))");
+    }
+}
@@ -0,0 +1,293 @@
+use std::fmt;
+
+use crate::calendar::{Calendar, Sessions};
+use crate::errors::Result;
+use crate::units::{OutputUnit, Price};
+use crate::{Close, High, Lookback, Low, Next, Reset, Timestamp, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Anchored volume weighted average price (VWAP).
+///
+/// The average typical price weighted by volume, accumulated from an anchor point instead of
+/// over a fixed number of periods. The anchor is moved explicitly with
+/// [anchor](#method.anchor), or automatically at the start of every session when the indicator
+/// is created with [with_session](#method.with_session) (or [daily](#method.daily)), or with
+/// [with_calendar](#method.with_calendar) to start at the open of a market, and the inputs
+/// carry a [timestamp](../trait.Timestamp.html). Inputs without a timestamp never start a new
+/// session.
+///
+/// # Formula
+///
+/// VWAP = Σ (TP * volume) / Σ volume
+///
+/// Where:
+///
+/// * _TP_ - typical price, (high + low + close) / 3
+/// * the sums run from the anchor to the current period
+///
+/// While the accumulated volume is 0, the typical price of the current period is returned.
+///
+/// # Parameters
+///
+/// * _session_length_ - length of a session in seconds (integer greater than 0).
+/// * _session_offset_ - start of the sessions relative to the Unix epoch in seconds, e.g.
+///   `13 * 3600 + 30 * 60` for sessions starting at 13:30 UTC.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AnchoredVwap;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |close: f64, volume: f64, timestamp: i64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .timestamp(timestamp)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut vwap = AnchoredVwap::daily();
+/// assert_eq!(vwap.next(&bar(10.0, 100.0, 0)), 10.0);
+/// assert_eq!(vwap.next(&bar(13.0, 200.0, 3600)), 12.0);
+///
+/// // A new day starts a new session.
+/// assert_eq!(vwap.next(&bar(20.0, 100.0, 86_400)), 20.0);
+///
+/// // Anchor manually, e.g. at an earnings release.
+/// vwap.anchor();
+/// assert_eq!(vwap.next(&bar(30.0, 100.0, 90_000)), 30.0);
+/// ```
+///
+/// # Links
+///
+/// * [Anchored VWAP, StockCharts][avwap]
+///
+/// [avwap]: https://school.stockcharts.com/doku.php?id=technical_indicators:anchored_vwap
+///
+#[doc(alias = "AVWAP")]
+#[doc(alias = "VWAP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AnchoredVwap {
+    sessions: Option<Sessions>,
+    current_session: Option<i64>,
+    price_volume: f64,
+    volume: f64,
+}
+
+impl AnchoredVwap {
+    /// Creates a VWAP that is only anchored manually.
+    pub fn new() -> Self {
+        Self {
+            sessions: None,
+            current_session: None,
+            price_volume: 0.0,
+            volume: 0.0,
+        }
+    }
+
+    /// Creates a VWAP that is anchored at the start of every session.
+    pub fn with_session(session_length: i64, session_offset: i64) -> Result<Self> {
+        Ok(Self {
+            sessions: Some(Sessions::interval(session_length, session_offset)?),
+            ..Self::new()
+        })
+    }
+
+    /// Creates a VWAP that is anchored at the open of every trading day of a calendar.
+    pub fn with_calendar(calendar: Calendar) -> Self {
+        Self {
+            sessions: Some(Sessions::Calendar(calendar)),
+            ..Self::new()
+        }
+    }
+
+    /// Creates a VWAP that is anchored at midnight UTC every day.
+    pub fn daily() -> Self {
+        Self::with_session(24 * 60 * 60, 0).unwrap()
+    }
+
+    /// Anchors the VWAP at the next period.
+    pub fn anchor(&mut self) {
+        self.price_volume = 0.0;
+        self.volume = 0.0;
+    }
+
+    fn start_session(&mut self, timestamp: Option<i64>) {
+        if let (Some(sessions), Some(timestamp)) = (&self.sessions, timestamp) {
+            let session = sessions.session(timestamp);
+            if self
+                .current_session
+                .is_some_and(|current| current != session)
+            {
+                self.anchor();
+            }
+            self.current_session = Some(session);
+        }
+    }
+}
+
+impl<T: High + Low + Close + Volume + Timestamp> Next<&T> for AnchoredVwap {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.start_session(input.timestamp());
+
+        let tp = (input.high() + input.low() + input.close()) / 3.0;
+        self.price_volume += tp * input.volume();
+        self.volume += input.volume();
+
+        if self.volume == 0.0 {
+            tp
+        } else {
+            self.price_volume / self.volume
+        }
+    }
+}
+
+impl Reset for AnchoredVwap {
+    fn reset(&mut self) {
+        self.anchor();
+        self.current_session = None;
+    }
+}
+
+impl Lookback for AnchoredVwap {
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+impl OutputUnit for AnchoredVwap {
+    type Unit = Price;
+}
+
+impl Default for AnchoredVwap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for AnchoredVwap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.sessions {
+            Some(sessions) => write!(f, "AVWAP({})", sessions),
+            None => write!(f, "AVWAP"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(close: f64, volume: f64) -> Bar {
+        Bar::new()
+            .high(close)
+            .low(close)
+            .close(close)
+            .volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(AnchoredVwap::with_session(0, 0).is_err());
+        assert!(AnchoredVwap::with_session(3600, -1800).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vwap = AnchoredVwap::new();
+
+        assert_eq!(
+            vwap.next(&Bar::new().high(12).low(9).close(9).volume(0.0)),
+            10.0
+        );
+        assert_eq!(vwap.next(&bar(10.0, 100.0)), 10.0);
+        assert_eq!(vwap.next(&bar(13.0, 200.0)), 12.0);
+        assert_eq!(
+            vwap.next(&Bar::new().high(8).low(6).close(7).volume(300.0)),
+            9.5
+        );
+    }
+
+    #[test]
+    fn test_anchor() {
+        let mut vwap = AnchoredVwap::new();
+
+        vwap.next(&bar(10.0, 100.0));
+        vwap.anchor();
+        assert_eq!(vwap.next(&bar(13.0, 200.0)), 13.0);
+        assert_eq!(vwap.next(&bar(10.0, 100.0)), 12.0);
+    }
+
+    #[test]
+    fn test_session() {
+        // One hour sessions starting at half past.
+        let mut vwap = AnchoredVwap::with_session(3600, 1800).unwrap();
+
+        assert_eq!(vwap.next(&bar(10.0, 100.0).timestamp(1800)), 10.0);
+        assert_eq!(vwap.next(&bar(13.0, 200.0).timestamp(5399)), 12.0);
+        // Bars without a timestamp stay in the current session.
+        assert_eq!(vwap.next(&bar(6.0, 300.0)), 9.0);
+        assert_eq!(vwap.next(&bar(20.0, 100.0).timestamp(5400)), 20.0);
+        // Timestamps before the epoch.
+        assert_eq!(vwap.next(&bar(5.0, 100.0).timestamp(-1)), 5.0);
+        assert_eq!(vwap.next(&bar(7.0, 100.0).timestamp(-1800)), 6.0);
+    }
+
+    #[test]
+    fn test_with_calendar() {
+        let mut vwap = AnchoredVwap::with_calendar(Calendar::cme_globex());
+        // Monday 2024-01-08 00:00 UTC
+        let monday = 1_704_672_000;
+
+        assert_eq!(vwap.next(&bar(10.0, 100.0).timestamp(monday)), 10.0);
+        assert_eq!(
+            vwap.next(&bar(13.0, 200.0).timestamp(monday + 21 * 3600)),
+            12.0
+        );
+        // The maintenance break belongs to the session of Tuesday.
+        assert_eq!(
+            vwap.next(&bar(20.0, 100.0).timestamp(monday + 22 * 3600)),
+            20.0
+        );
+        assert_eq!(format!("{}", vwap), "AVWAP(17:00-16:00 UTC-06:00)");
+    }
+
+    #[test]
+    fn test_without_session() {
+        let mut vwap = AnchoredVwap::new();
+
+        vwap.next(&bar(10.0, 100.0).timestamp(0));
+        assert_eq!(vwap.next(&bar(13.0, 200.0).timestamp(86_400)), 12.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vwap = AnchoredVwap::daily();
+
+        vwap.next(&bar(10.0, 100.0).timestamp(0));
+        vwap.reset();
+        assert_eq!(vwap.next(&bar(13.0, 200.0).timestamp(86_400)), 13.0);
+        assert_eq!(vwap.next(&bar(10.0, 100.0).timestamp(86_401)), 12.0);
+    }
+
+    #[test]
+    fn test_default() {
+        AnchoredVwap::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", AnchoredVwap::new()), "AVWAP");
+        assert_eq!(format!("{}", AnchoredVwap::daily()), "AVWAP(86400, 0)");
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Maximum, Minimum};
+use crate::{High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Aroon indicator.
+///
+/// Developed by Tushar Chande, the Aroon indicator measures how many periods have passed since
+/// the highest high and the lowest low within the last _period_ periods. Aroon Up near 100 means
+/// new highs are being made, Aroon Down near 100 means new lows are being made.
+///
+/// # Formula
+///
+/// Aroon Up = (_period_ - periods since the highest high) / _period_ * 100
+///
+/// Aroon Down = (_period_ - periods since the lowest low) / _period_ * 100
+///
+/// Aroon Oscillator = Aroon Up - Aroon Down
+///
+/// The extremes are searched in the current and previous _period_ periods. When an extreme is
+/// repeated, the most recent one is used.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Aroon;
+/// use ta::{DataItem, Next};
+///
+/// let mut aroon = Aroon::new(5).unwrap();
+/// let bar = DataItem::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let out = aroon.next(&bar);
+/// assert_eq!(out.up, 100.0);
+/// assert_eq!(out.down, 100.0);
+/// assert_eq!(out.oscillator, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Aroon, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:aroon)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Aroon {
+    period: usize,
+    highest: Maximum,
+    lowest: Minimum,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AroonOutput {
+    pub up: f64,
+    pub down: f64,
+    pub oscillator: f64,
+}
+
+impl Aroon {
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            highest: Maximum::new(period + 1)?,
+            lowest: Minimum::new(period + 1)?,
+        })
+    }
+}
+
+impl Period for Aroon {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for Aroon {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low> Next<&T> for Aroon {
+    type Output = AroonOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let since_high = self.highest.next_with_offset(input.high()).offset;
+        let since_low = self.lowest.next_with_offset(input.low()).offset;
+
+        let period = self.period as f64;
+        let up = (period - since_high as f64) / period * 100.0;
+        let down = (period - since_low as f64) / period * 100.0;
+
+        AroonOutput {
+            up,
+            down,
+            oscillator: up - down,
+        }
+    }
+}
+
+impl Reset for Aroon {
+    fn reset(&mut self) {
+        self.highest.reset();
+        self.lowest.reset();
+    }
+}
+
+impl Default for Aroon {
+    fn default() -> Self {
+        Self::new(25).unwrap()
+    }
+}
+
+impl fmt::Display for Aroon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AROON({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Aroon::new(0).is_err());
+        assert!(Aroon::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut aroon = Aroon::new(4).unwrap();
+
+        let test_data = vec![
+            // high, low, up, down
+            (10.0, 8.0, 100.0, 100.0),
+            (11.0, 9.0, 100.0, 75.0),
+            (10.5, 7.0, 75.0, 100.0),
+            (10.0, 8.0, 50.0, 75.0),
+            (9.0, 8.5, 25.0, 50.0),
+            (9.5, 8.5, 0.0, 25.0),
+            (9.0, 8.0, 0.0, 0.0),
+            (9.0, 8.0, 0.0, 100.0),
+            (12.0, 9.0, 100.0, 75.0),
+        ];
+
+        for (high, low, up, down) in test_data {
+            let out = aroon.next(&Bar::new().high(high).low(low));
+            assert_eq!(out.up, up);
+            assert_eq!(out.down, down);
+            assert_eq!(out.oscillator, up - down);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut aroon = Aroon::new(4).unwrap();
+
+        aroon.next(&Bar::new().high(10).low(8));
+        aroon.next(&Bar::new().high(11).low(9));
+
+        aroon.reset();
+        let out = aroon.next(&Bar::new().high(5).low(4));
+        assert_eq!(out.up, 100.0);
+        assert_eq!(out.down, 100.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Aroon::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Aroon::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "AROON(14)");
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{AverageTrueRange, ExponentialMovingAverage};
+use crate::{Close, High, Lookback, Low, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// ATR bands.
+///
+/// A moving average of the close with bands _multiplier_ [average true
+/// ranges](struct.AverageTrueRange.html) above and below it. Unlike the
+/// [Keltner Channel](struct.KeltnerChannel.html), the average and the ATR have their own
+/// periods, and both use the same kind of moving average, an
+/// [EMA](struct.ExponentialMovingAverage.html) by default, or any moving average implementing
+/// [NewWithPeriod](../trait.NewWithPeriod.html). The bands are used as volatility-adjusted
+/// targets and stops, or as breakout levels.
+///
+/// # Formula
+///
+/// mid = MA(_ma_period_) of close
+///
+/// upper = mid + _multiplier_ * ATR(_atr_period_)
+///
+/// lower = mid - _multiplier_ * ATR(_atr_period_)
+///
+/// # Parameters
+///
+/// * _ma_period_ - period of the center moving average (integer greater than 0). Default is 20.
+/// * _atr_period_ - smoothing period of the ATR (integer greater than 0). Default is 14.
+/// * _multiplier_ - number of ATRs of the bands (greater than 0). Default is 2.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{AtrBands, SimpleMovingAverage as Sma};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut bands = AtrBands::<Sma>::new(2, 3, 2.0).unwrap();
+/// bands.next(&bar(10.0, 7.5, 9.0));
+/// let out = bands.next(&bar(11.0, 9.0, 9.5));
+/// assert_eq!(out.mid, 9.25);
+/// assert_eq!(out.upper, 13.75);
+/// assert_eq!(out.lower, 4.75);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AtrBands<MA = ExponentialMovingAverage> {
+    multiplier: f64,
+    ma: MA,
+    atr: AverageTrueRange<MA>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtrBandsOutput {
+    pub upper: f64,
+    pub mid: f64,
+    pub lower: f64,
+}
+
+impl<MA: NewWithPeriod> AtrBands<MA> {
+    pub fn new(ma_period: usize, atr_period: usize, multiplier: f64) -> Result<Self> {
+        if multiplier <= 0.0 || !multiplier.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            multiplier,
+            ma: MA::new_with_period(ma_period)?,
+            atr: AverageTrueRange::with_ma(atr_period)?,
+        })
+    }
+}
+
+impl<MA> AtrBands<MA> {
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    fn bands(&self, mid: f64, atr: f64) -> AtrBandsOutput {
+        AtrBandsOutput {
+            upper: mid + self.multiplier * atr,
+            mid,
+            lower: mid - self.multiplier * atr,
+        }
+    }
+}
+
+impl<MA: Lookback> Lookback for AtrBands<MA> {
+    fn lookback(&self) -> usize {
+        self.ma.lookback().max(self.atr.lookback())
+    }
+}
+
+impl<MA: Next<f64, Output = f64>> Next<f64> for AtrBands<MA> {
+    type Output = AtrBandsOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let mid = self.ma.next(input);
+        let atr = self.atr.next(input);
+        self.bands(mid, atr)
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: High + Low + Close> Next<&T> for AtrBands<MA> {
+    type Output = AtrBandsOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let mid = self.ma.next(input.close());
+        let atr = self.atr.next(input);
+        self.bands(mid, atr)
+    }
+}
+
+impl<MA: Reset> Reset for AtrBands<MA> {
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.atr.reset();
+    }
+}
+
+impl Default for AtrBands {
+    fn default() -> Self {
+        Self::new(20, 14, 2.0).unwrap()
+    }
+}
+
+impl<MA: Period> fmt::Display for AtrBands<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ATRBANDS({}, {}, {})",
+            self.ma.period(),
+            self.atr.period(),
+            self.multiplier
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+
+    test_indicator!(AtrBands);
+
+    #[test]
+    fn test_new() {
+        assert!(AtrBands::<Ema>::new(0, 14, 2.0).is_err());
+        assert!(AtrBands::<Ema>::new(20, 0, 2.0).is_err());
+        assert!(AtrBands::<Ema>::new(20, 14, 0.0).is_err());
+        assert!(AtrBands::<Ema>::new(20, 14, f64::NAN).is_err());
+        assert!(AtrBands::<Ema>::new(1, 1, 2.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        // Same periods and EMA as the Keltner Channel.
+        let mut bands = AtrBands::<Ema>::new(3, 3, 2.0).unwrap();
+
+        let test_data = vec![
+            // input, upper, mid, lower
+            (2.0, 2.0, 2.0, 2.0),
+            (5.0, 6.5, 3.5, 0.5),
+            (1.0, 7.75, 2.25, -3.25),
+            (6.25, 12.25, 4.25, -3.75),
+        ];
+
+        for (input, upper, mid, lower) in test_data {
+            let out = bands.next(input);
+            assert_eq!(round(out.upper), upper);
+            assert_eq!(round(out.mid), mid);
+            assert_eq!(round(out.lower), lower);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut bands = AtrBands::<Sma>::new(2, 3, 2.0).unwrap();
+
+        let test_data = vec![
+            // high, low, close, upper, mid, lower
+            (10.0, 7.5, 9.0, 14.0, 9.0, 4.0),
+            (11.0, 9.0, 9.5, 13.75, 9.25, 4.75),
+            (9.0, 5.0, 8.0, 14.75, 8.75, 2.75),
+        ];
+
+        for (high, low, close, upper, mid, lower) in test_data {
+            let out = bands.next(&Bar::new().high(high).low(low).close(close));
+            assert_eq!(round(out.upper), upper);
+            assert_eq!(round(out.mid), mid);
+            assert_eq!(round(out.lower), lower);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bands = AtrBands::<Sma>::new(2, 3, 2.0).unwrap();
+        bands.next(&Bar::new().high(10).low(7.5).close(9));
+        bands.next(&Bar::new().high(11).low(9).close(9.5));
+
+        bands.reset();
+        let out = bands.next(&Bar::new().high(11).low(9).close(9.5));
+        assert_eq!(out.upper, 13.5);
+        assert_eq!(out.mid, 9.5);
+        assert_eq!(out.lower, 5.5);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let bands = AtrBands::<Ema>::new(20, 14, 2.0).unwrap();
+        assert_eq!(bands.lookback(), 19);
+        let bands = AtrBands::<Ema>::new(5, 14, 2.0).unwrap();
+        assert_eq!(bands.lookback(), 14);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(AtrBands::default().multiplier(), 2.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let bands = AtrBands::<Ema>::new(20, 14, 2.5).unwrap();
+        assert_eq!(format!("{}", bands), "ATRBANDS(20, 14, 2.5)");
+    }
+}
@@ -0,0 +1,279 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{ExponentialMovingAverage, TrueRange};
+use crate::{Close, High, Lookback, Low, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Average directional index (ADX) with the directional indicators (+DI and -DI).
+///
+/// Developed by J. Welles Wilder, ADX measures the strength of a trend regardless of its
+/// direction, while +DI and -DI show whether the upward or downward movement prevails.
+///
+/// # Formula
+///
+/// +DM = high<sub>t</sub> - high<sub>t-1</sub> if it is greater than low<sub>t-1</sub> - low<sub>t</sub>
+/// and greater than 0, otherwise 0
+///
+/// -DM = low<sub>t-1</sub> - low<sub>t</sub> if it is greater than high<sub>t</sub> - high<sub>t-1</sub>
+/// and greater than 0, otherwise 0
+///
+/// +DI = 100 * MA(+DM) / MA(TR)
+///
+/// -DI = 100 * MA(-DM) / MA(TR)
+///
+/// DX = 100 * |+DI - -DI| / (+DI + -DI)
+///
+/// ADX = MA(DX)
+///
+/// Where:
+///
+/// * _MA_ - moving average with smoothing _period_, [EMA](struct.ExponentialMovingAverage.html) by default.
+///   Use [RunningMovingAverage](struct.RunningMovingAverage.html) for Wilder's smoothing.
+/// * _TR_ - [true range](struct.TrueRange.html)
+///
+/// The first period has no directional movement, so all outputs are 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{AverageDirectionalIndex, RunningMovingAverage as Rma};
+/// use ta::{DataItem, Next};
+///
+/// let mut adx = AverageDirectionalIndex::<Rma>::new(14).unwrap();
+/// let bar = DataItem::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let out = adx.next(&bar);
+/// assert_eq!(out.adx, 0.0);
+/// assert_eq!(out.plus_di, 0.0);
+/// assert_eq!(out.minus_di, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Average directional movement index, Wikipedia](https://en.wikipedia.org/wiki/Average_directional_movement_index)
+///
+#[doc(alias = "ADX")]
+#[doc(alias = "DMI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AverageDirectionalIndex<MA = ExponentialMovingAverage> {
+    true_range: TrueRange,
+    tr_ma: MA,
+    plus_dm_ma: MA,
+    minus_dm_ma: MA,
+    adx_ma: MA,
+    prev_high: Option<f64>,
+    prev_low: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AverageDirectionalIndexOutput {
+    pub adx: f64,
+    pub plus_di: f64,
+    pub minus_di: f64,
+}
+
+impl<MA: NewWithPeriod> AverageDirectionalIndex<MA> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            true_range: TrueRange::new(),
+            tr_ma: MA::new_with_period(period)?,
+            plus_dm_ma: MA::new_with_period(period)?,
+            minus_dm_ma: MA::new_with_period(period)?,
+            adx_ma: MA::new_with_period(period)?,
+            prev_high: None,
+            prev_low: None,
+        })
+    }
+}
+
+impl<MA: NewWithPeriod> NewWithPeriod for AverageDirectionalIndex<MA> {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl<MA: Period> Period for AverageDirectionalIndex<MA> {
+    fn period(&self) -> usize {
+        self.adx_ma.period()
+    }
+}
+
+impl<MA: Lookback> Lookback for AverageDirectionalIndex<MA> {
+    fn lookback(&self) -> usize {
+        self.true_range.lookback() + self.plus_dm_ma.lookback() + self.adx_ma.lookback()
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: High + Low + Close> Next<&T> for AverageDirectionalIndex<MA> {
+    type Output = AverageDirectionalIndexOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let tr = self.true_range.next(input);
+        let (high, low) = (input.high(), input.low());
+
+        let (prev_high, prev_low) = match (self.prev_high, self.prev_low) {
+            (Some(prev_high), Some(prev_low)) => (prev_high, prev_low),
+            _ => {
+                self.prev_high = Some(high);
+                self.prev_low = Some(low);
+                return AverageDirectionalIndexOutput {
+                    adx: 0.0,
+                    plus_di: 0.0,
+                    minus_di: 0.0,
+                };
+            }
+        };
+        self.prev_high = Some(high);
+        self.prev_low = Some(low);
+
+        let up_move = high - prev_high;
+        let down_move = prev_low - low;
+        let plus_dm = if up_move > down_move && up_move > 0.0 {
+            up_move
+        } else {
+            0.0
+        };
+        let minus_dm = if down_move > up_move && down_move > 0.0 {
+            down_move
+        } else {
+            0.0
+        };
+
+        let smoothed_tr = self.tr_ma.next(tr);
+        let smoothed_plus_dm = self.plus_dm_ma.next(plus_dm);
+        let smoothed_minus_dm = self.minus_dm_ma.next(minus_dm);
+
+        let (plus_di, minus_di) = if smoothed_tr == 0.0 {
+            (0.0, 0.0)
+        } else {
+            (
+                100.0 * smoothed_plus_dm / smoothed_tr,
+                100.0 * smoothed_minus_dm / smoothed_tr,
+            )
+        };
+
+        let di_sum = plus_di + minus_di;
+        let dx = if di_sum == 0.0 {
+            0.0
+        } else {
+            100.0 * (plus_di - minus_di).abs() / di_sum
+        };
+
+        AverageDirectionalIndexOutput {
+            adx: self.adx_ma.next(dx),
+            plus_di,
+            minus_di,
+        }
+    }
+}
+
+impl<MA: Reset> Reset for AverageDirectionalIndex<MA> {
+    fn reset(&mut self) {
+        self.true_range.reset();
+        self.tr_ma.reset();
+        self.plus_dm_ma.reset();
+        self.minus_dm_ma.reset();
+        self.adx_ma.reset();
+        self.prev_high = None;
+        self.prev_low = None;
+    }
+}
+
+impl Default for AverageDirectionalIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<MA: Period> fmt::Display for AverageDirectionalIndex<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ADX({})", self.adx_ma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage as Ema, RunningMovingAverage as Rma};
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(AverageDirectionalIndex::<Ema>::new(0).is_err());
+        assert!(AverageDirectionalIndex::<Ema>::new(1).is_ok());
+        assert!(AverageDirectionalIndex::<Rma>::new(0).is_err());
+        assert!(AverageDirectionalIndex::<Rma>::new(14).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut adx = AverageDirectionalIndex::<Rma>::new(2).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9);
+        let out = adx.next(&bar1);
+        assert_eq!((out.adx, out.plus_di, out.minus_di), (0.0, 0.0, 0.0));
+
+        // +DM = 2, -DM = 0, TR = 3
+        let bar2 = Bar::new().high(12).low(9).close(11);
+        let out = adx.next(&bar2);
+        assert_eq!(round(out.plus_di), 66.667);
+        assert_eq!(out.minus_di, 0.0);
+        assert_eq!(out.adx, 100.0);
+
+        // +DM = 0, -DM = 3, TR = 5
+        let bar3 = Bar::new().high(11).low(6).close(7);
+        let out = adx.next(&bar3);
+        assert_eq!(out.plus_di, 25.0);
+        assert_eq!(out.minus_di, 37.5);
+        assert_eq!(out.adx, 60.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut adx = AverageDirectionalIndex::<Ema>::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9);
+        let bar2 = Bar::new().high(12).low(9).close(11);
+        let bar3 = Bar::new().high(11).low(6).close(7);
+
+        adx.next(&bar1);
+        let out2 = adx.next(&bar2);
+        let out3 = adx.next(&bar3);
+
+        adx.reset();
+        adx.next(&bar1);
+        assert_eq!(adx.next(&bar2), out2);
+        assert_eq!(adx.next(&bar3), out3);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let adx = AverageDirectionalIndex::<Rma>::new(14).unwrap();
+        assert_eq!(adx.lookback(), 27);
+    }
+
+    #[test]
+    fn test_default() {
+        AverageDirectionalIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = AverageDirectionalIndex::<Rma>::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "ADX(14)");
+    }
+}
@@ -2,7 +2,8 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, TrueRange};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::units::{OutputUnit, Price};
+use crate::{Close, High, Lookback, Low, NewWithPeriod, Next, Period, Reset};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -11,20 +12,23 @@ use serde::{Deserialize, Serialize};
 ///
 /// A technical analysis volatility indicator, originally developed by J. Welles Wilder.
 /// The average true range is an N-day smoothed moving average of the true range values.
-/// This implementation uses exponential moving average.
+/// By default this implementation uses exponential moving average, but any moving average
+/// implementing [NewWithPeriod](../trait.NewWithPeriod.html) can be used instead with
+/// [with_ma](#method.with_ma), e.g. [RunningMovingAverage](struct.RunningMovingAverage.html)
+/// for Wilder's original smoothing.
 ///
 /// # Formula
 ///
-/// ATR(period)<sub>t</sub> = EMA(period) of TR<sub>t</sub>
+/// ATR(period)<sub>t</sub> = MA(period) of TR<sub>t</sub>
 ///
 /// Where:
 ///
-/// * _EMA(period)_ - [exponential moving average](struct.ExponentialMovingAverage.html) with smoothing period
+/// * _MA(period)_ - moving average with smoothing period, [EMA](struct.ExponentialMovingAverage.html) by default
 /// * _TR<sub>t</sub>_ - [true range](struct.TrueRange.html) for period _t_
 ///
 /// # Parameters
 ///
-/// * _period_ - smoothing period of EMA (integer greater than 0)
+/// * _period_ - smoothing period of the moving average (integer greater than 0)
 ///
 /// # Example
 ///
@@ -59,46 +63,69 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "ATR")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct AverageTrueRange {
+pub struct AverageTrueRange<MA = ExponentialMovingAverage> {
     true_range: TrueRange,
-    ema: ExponentialMovingAverage,
+    ma: MA,
 }
 
 impl AverageTrueRange {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_ma(period)
+    }
+}
+
+impl<MA: NewWithPeriod> AverageTrueRange<MA> {
+    /// Creates an ATR smoothed by the moving average `MA` instead of the default EMA.
+    pub fn with_ma(period: usize) -> Result<Self> {
         Ok(Self {
             true_range: TrueRange::new(),
-            ema: ExponentialMovingAverage::new(period)?,
+            ma: MA::new_with_period(period)?,
         })
     }
 }
 
-impl Period for AverageTrueRange {
+impl<MA: NewWithPeriod> NewWithPeriod for AverageTrueRange<MA> {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::with_ma(period)
+    }
+}
+
+impl<MA: Period> Period for AverageTrueRange<MA> {
     fn period(&self) -> usize {
-        self.ema.period()
+        self.ma.period()
     }
 }
 
-impl Next<f64> for AverageTrueRange {
+impl<MA: Lookback> Lookback for AverageTrueRange<MA> {
+    fn lookback(&self) -> usize {
+        self.true_range.lookback() + self.ma.lookback()
+    }
+}
+
+impl<MA> OutputUnit for AverageTrueRange<MA> {
+    type Unit = Price;
+}
+
+impl<MA: Next<f64, Output = f64>> Next<f64> for AverageTrueRange<MA> {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        self.ema.next(self.true_range.next(input))
+        self.ma.next(self.true_range.next(input))
     }
 }
 
-impl<T: High + Low + Close> Next<&T> for AverageTrueRange {
+impl<MA: Next<f64, Output = f64>, T: High + Low + Close> Next<&T> for AverageTrueRange<MA> {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
-        self.ema.next(self.true_range.next(input))
+        self.ma.next(self.true_range.next(input))
     }
 }
 
-impl Reset for AverageTrueRange {
+impl<MA: Reset> Reset for AverageTrueRange<MA> {
     fn reset(&mut self) {
         self.true_range.reset();
-        self.ema.reset();
+        self.ma.reset();
     }
 }
 
@@ -108,15 +135,16 @@ impl Default for AverageTrueRange {
     }
 }
 
-impl fmt::Display for AverageTrueRange {
+impl<MA: Period> fmt::Display for AverageTrueRange<MA> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ATR({})", self.ema.period())
+        write!(f, "ATR({})", self.ma.period())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::RunningMovingAverage as Rma;
     use crate::test_helper::*;
 
     test_indicator!(AverageTrueRange);
@@ -125,6 +153,8 @@ mod tests {
     fn test_new() {
         assert!(AverageTrueRange::new(0).is_err());
         assert!(AverageTrueRange::new(1).is_ok());
+        assert!(AverageTrueRange::<Rma>::with_ma(0).is_err());
+        assert!(AverageTrueRange::<Rma>::with_ma(1).is_ok());
     }
     #[test]
     fn test_next() {
@@ -139,6 +169,19 @@ mod tests {
         assert_eq!(atr.next(&bar3), 3.375);
     }
 
+    #[test]
+    fn test_next_with_rma() {
+        let mut atr = AverageTrueRange::<Rma>::with_ma(4).unwrap();
+
+        let bar1 = Bar::new().high(10).low(7.5).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(9.5);
+        let bar3 = Bar::new().high(9).low(5).close(8);
+
+        assert_eq!(atr.next(&bar1), 2.5);
+        assert_eq!(atr.next(&bar2), 2.375);
+        assert_eq!(round(atr.next(&bar3)), 2.906);
+    }
+
     #[test]
     fn test_reset() {
         let mut atr = AverageTrueRange::new(9).unwrap();
@@ -154,6 +197,13 @@ mod tests {
         assert_eq!(atr.next(&bar3), 45.0);
     }
 
+    #[test]
+    fn test_lookback() {
+        let atr = AverageTrueRange::new(14).unwrap();
+        assert_eq!(atr.period(), 14);
+        assert_eq!(atr.lookback(), 14);
+    }
+
     #[test]
     fn test_default() {
         AverageTrueRange::default();
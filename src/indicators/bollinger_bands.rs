@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::StandardDeviation as Sd;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Lookback, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +21,13 @@ use serde::{Deserialize, Serialize};
 ///  * _BB<sub>Upper Band</sub>_ = SMA + SD of observation * multipler (usually 2.0)
 ///  * _BB<sub>Lower Band</sub>_ = SMA - SD of observation * multipler (usually 2.0)
 ///
+/// Along with the bands, the output has:
+///
+///  * _%B_ = (price - lower) / (upper - lower), where the price is within the bands: 0 at the
+///    lower band, 1 at the upper band. It is 0.5 when the bands are flat.
+///  * _Bandwidth_ = (upper - lower) / middle, the width of the bands relative to the average. It
+///    is 0 when the average is 0.
+///
 /// # Example
 ///
 ///```
@@ -40,11 +47,13 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(out_1.average, 3.5);
 /// assert_eq!(out_1.upper, 6.5);
 /// assert_eq!(out_1.lower, 0.5);
+/// assert_eq!(out_1.percent_b, 0.75);
 /// ```
 ///
 /// # Links
 ///
 /// * [Bollinger Bands, Wikipedia](https://en.wikipedia.org/wiki/Bollinger_Bands)
+/// * [%B and BandWidth, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:bollinger_band_perce)
 #[doc(alias = "BB")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -59,6 +68,10 @@ pub struct BollingerBandsOutput {
     pub average: f64,
     pub upper: f64,
     pub lower: f64,
+    /// Position of the price within the bands, 0 at the lower band and 1 at the upper band.
+    pub percent_b: f64,
+    /// Width of the bands relative to the average.
+    pub bandwidth: f64,
 }
 
 impl BollingerBands {
@@ -81,17 +94,32 @@ impl Period for BollingerBands {
     }
 }
 
+impl Lookback for BollingerBands {
+    fn lookback(&self) -> usize {
+        self.sd.lookback()
+    }
+}
+
 impl Next<f64> for BollingerBands {
     type Output = BollingerBandsOutput;
 
     fn next(&mut self, input: f64) -> Self::Output {
         let sd = self.sd.next(input);
         let mean = self.sd.mean();
+        let upper = mean + sd * self.multiplier;
+        let lower = mean - sd * self.multiplier;
+        let width = upper - lower;
 
         Self::Output {
             average: mean,
-            upper: mean + sd * self.multiplier,
-            lower: mean - sd * self.multiplier,
+            upper,
+            lower,
+            percent_b: if width > 0.0 {
+                (input - lower) / width
+            } else {
+                0.5
+            },
+            bandwidth: if mean != 0.0 { width / mean } else { 0.0 },
         }
     }
 }
@@ -159,6 +187,27 @@ mod tests {
         assert_eq!(round(b.lower), 0.5);
         assert_eq!(round(c.lower), -0.733);
         assert_eq!(round(d.lower), -0.395);
+
+        assert_eq!(round(a.percent_b), 0.5);
+        assert_eq!(round(b.percent_b), 0.75);
+        assert_eq!(round(c.percent_b), 0.255);
+        assert_eq!(round(d.percent_b), 0.742);
+
+        assert_eq!(round(a.bandwidth), 0.0);
+        assert_eq!(round(b.bandwidth), 1.714);
+        assert_eq!(round(c.bandwidth), 2.55);
+        assert_eq!(round(d.bandwidth), 2.193);
+    }
+
+    #[test]
+    fn test_next_zero_average() {
+        let mut bb = BollingerBands::new(2, 2.0_f64).unwrap();
+
+        bb.next(-1.0);
+        let out = bb.next(1.0);
+        assert_eq!(out.average, 0.0);
+        assert_eq!(out.percent_b, 0.75);
+        assert_eq!(out.bandwidth, 0.0);
     }
 
     #[test]
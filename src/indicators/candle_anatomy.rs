@@ -0,0 +1,156 @@
+use std::fmt;
+
+use crate::{Close, High, Lookback, Low, Next, Open, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Candle anatomy.
+///
+/// Describes the shape of every bar with ratios to its range: where the close lies (close
+/// location value, CLV), how large the body is and how long the wicks are. Being independent of
+/// the price level, they are the raw features of candlestick pattern detectors and machine
+/// learning models.
+///
+/// # Formula
+///
+/// * _CLV_ = ((close - low) - (high - close)) / range, from -1 (close at the low) to 1 (close at
+///   the high)
+/// * _body_ = |close - open| / range
+/// * _upper wick_ = (high - max(open, close)) / range
+/// * _lower wick_ = (min(open, close) - low) / range
+///
+/// Where range = high - low. The body and the wicks add up to 1. When the range is 0, all the
+/// values are 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::CandleAnatomy;
+/// use ta::{DataItem, Next};
+///
+/// let mut anatomy = CandleAnatomy::new();
+///
+/// // Hammer: small body at the top of a long lower wick.
+/// let bar = DataItem::builder()
+///     .open(9.0)
+///     .high(10.0)
+///     .low(5.0)
+///     .close(9.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let out = anatomy.next(&bar);
+/// assert_eq!(out.clv, 0.8);
+/// assert_eq!(out.body, 0.1);
+/// assert_eq!(out.upper_wick, 0.1);
+/// assert_eq!(out.lower_wick, 0.8);
+/// ```
+///
+/// # Links
+///
+/// * [Close Location Value, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:accumulation_distribution_line)
+///
+#[doc(alias = "CLV")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CandleAnatomy {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandleAnatomyOutput {
+    pub clv: f64,
+    pub body: f64,
+    pub upper_wick: f64,
+    pub lower_wick: f64,
+}
+
+impl CandleAnatomy {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Lookback for CandleAnatomy {
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for CandleAnatomy {
+    type Output = CandleAnatomyOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (open, high, low, close) = (input.open(), input.high(), input.low(), input.close());
+        let range = high - low;
+
+        if range == 0.0 {
+            return CandleAnatomyOutput {
+                clv: 0.0,
+                body: 0.0,
+                upper_wick: 0.0,
+                lower_wick: 0.0,
+            };
+        }
+
+        CandleAnatomyOutput {
+            clv: ((close - low) - (high - close)) / range,
+            body: (close - open).abs() / range,
+            upper_wick: (high - open.max(close)) / range,
+            lower_wick: (open.min(close) - low) / range,
+        }
+    }
+}
+
+impl Reset for CandleAnatomy {
+    fn reset(&mut self) {}
+}
+
+impl Default for CandleAnatomy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for CandleAnatomy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ANATOMY")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut anatomy = CandleAnatomy::new();
+
+        let test_data = vec![
+            // open, high, low, close, clv, body, upper wick, lower wick
+            (2.0, 6.0, 2.0, 6.0, 1.0, 1.0, 0.0, 0.0),
+            (5.0, 6.0, 2.0, 3.0, -0.5, 0.5, 0.25, 0.25),
+            (3.0, 7.0, 2.0, 4.0, -0.2, 0.2, 0.6, 0.2),
+            (4.0, 4.0, 4.0, 4.0, 0.0, 0.0, 0.0, 0.0),
+        ];
+
+        for (open, high, low, close, clv, body, upper_wick, lower_wick) in test_data {
+            let bar = Bar::new().open(open).high(high).low(low).close(close);
+            let out = anatomy.next(&bar);
+            assert_eq!(round(out.clv), clv);
+            assert_eq!(round(out.body), body);
+            assert_eq!(round(out.upper_wick), upper_wick);
+            assert_eq!(round(out.lower_wick), lower_wick);
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        CandleAnatomy::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", CandleAnatomy::new()), "ANATOMY");
+    }
+}
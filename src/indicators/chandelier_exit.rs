@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::Result;
 use crate::indicators::{AverageTrueRange, Maximum, Minimum};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
 
 /// Chandelier Exit (CE).
 ///
@@ -93,6 +93,12 @@ impl Period for ChandelierExit {
     }
 }
 
+impl Lookback for ChandelierExit {
+    fn lookback(&self) -> usize {
+        self.atr.lookback().max(self.max.lookback())
+    }
+}
+
 impl<T: Low + High + Close> Next<&T> for ChandelierExit {
     type Output = ChandelierExitOutput;
 
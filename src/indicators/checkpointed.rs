@@ -0,0 +1,220 @@
+use std::fmt;
+
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Keeps a snapshot of an indicator to branch from.
+///
+/// Optimizers and backtests often warm up an indicator on a common history and then evaluate
+/// many continuations of it. This wrapper stores a copy of the state of the wrapped indicator
+/// with [checkpoint](#method.checkpoint), and puts it back with
+/// [restore_checkpoint](#method.restore_checkpoint) as many times as needed. Taking and
+/// restoring a checkpoint clone the whole state of the indicator, buffers included.
+///
+/// The snapshot is kept by this wrapper rather than by every indicator, so any `Clone`
+/// indicator gets it without carrying a second copy of its state, and a whole graph of
+/// indicators is branched at once by wrapping the value that owns them.
+///
+/// [Reset](../trait.Reset.html) resets the wrapped indicator and keeps the checkpoint.
+///
+/// # Parameters
+///
+/// * _indicator_ - indicator to snapshot, it must implement `Clone`.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Checkpointed, SimpleMovingAverage};
+/// use ta::Next;
+///
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+/// let mut sma = Checkpointed::new(sma);
+///
+/// sma.next(10.0);
+/// sma.checkpoint();
+///
+/// assert_eq!(sma.next(20.0), 15.0);
+/// assert!(sma.restore_checkpoint());
+/// assert_eq!(sma.next(30.0), 20.0);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Checkpointed<I> {
+    indicator: I,
+    checkpoint: Option<I>,
+}
+
+impl<I> Checkpointed<I> {
+    pub fn new(indicator: I) -> Self {
+        Self {
+            indicator,
+            checkpoint: None,
+        }
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+
+    pub fn has_checkpoint(&self) -> bool {
+        self.checkpoint.is_some()
+    }
+
+    /// Drops the stored checkpoint.
+    pub fn discard_checkpoint(&mut self) {
+        self.checkpoint = None;
+    }
+}
+
+impl<I: Clone> Checkpointed<I> {
+    /// Stores the current state, replacing the previous checkpoint.
+    pub fn checkpoint(&mut self) {
+        match self.checkpoint {
+            Some(ref mut checkpoint) => checkpoint.clone_from(&self.indicator),
+            None => self.checkpoint = Some(self.indicator.clone()),
+        }
+    }
+
+    /// Brings the indicator back to the last checkpoint. The checkpoint is kept, so it can be
+    /// restored again. Returns `false` and leaves the indicator untouched when there is no
+    /// checkpoint.
+    pub fn restore_checkpoint(&mut self) -> bool {
+        match self.checkpoint {
+            Some(ref checkpoint) => {
+                self.indicator.clone_from(checkpoint);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<I: Period> Period for Checkpointed<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: Lookback> Lookback for Checkpointed<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
+
+impl<In, I: Next<In>> Next<In> for Checkpointed<I> {
+    type Output = I::Output;
+
+    fn next(&mut self, input: In) -> Self::Output {
+        self.indicator.next(input)
+    }
+}
+
+impl<I: Reset> Reset for Checkpointed<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<I: Default> Default for Checkpointed<I> {
+    fn default() -> Self {
+        Self::new(I::default())
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Checkpointed<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.indicator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{AverageTrueRange, ExponentialMovingAverage, SimpleMovingAverage};
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_restore_without_checkpoint() {
+        let mut sma = Checkpointed::new(SimpleMovingAverage::new(2).unwrap());
+        sma.next(10.0);
+
+        assert!(!sma.has_checkpoint());
+        assert!(!sma.restore_checkpoint());
+        assert_eq!(sma.next(20.0), 15.0);
+    }
+
+    #[test]
+    fn test_branches() {
+        let mut ema = Checkpointed::new(ExponentialMovingAverage::new(3).unwrap());
+        let mut reference = ExponentialMovingAverage::new(3).unwrap();
+
+        for input in &[2.0, 5.0, 1.0] {
+            ema.next(*input);
+            reference.next(*input);
+        }
+        ema.checkpoint();
+
+        for branch in &[[6.25, 6.25], [4.0, 1.0], [0.0, 12.0]] {
+            assert!(ema.restore_checkpoint());
+            let mut expected = reference.clone();
+            for input in branch {
+                assert_eq!(ema.next(*input), expected.next(*input));
+            }
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_replaces_previous() {
+        let mut sma = Checkpointed::new(SimpleMovingAverage::new(2).unwrap());
+
+        sma.next(10.0);
+        sma.checkpoint();
+        sma.next(20.0);
+        sma.checkpoint();
+
+        sma.next(40.0);
+        assert!(sma.restore_checkpoint());
+        assert_eq!(sma.next(30.0), 25.0);
+
+        sma.discard_checkpoint();
+        assert!(!sma.restore_checkpoint());
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut atr = Checkpointed::new(AverageTrueRange::new(3).unwrap());
+
+        atr.next(&Bar::new().high(10).low(7.5).close(9));
+        atr.checkpoint();
+        assert_eq!(atr.next(&Bar::new().high(11).low(9).close(9.5)), 2.25);
+        atr.restore_checkpoint();
+        assert_eq!(atr.next(&Bar::new().high(11).low(9).close(9.5)), 2.25);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = Checkpointed::new(SimpleMovingAverage::new(2).unwrap());
+        sma.next(10.0);
+        sma.checkpoint();
+
+        sma.reset();
+        assert_eq!(sma.next(4.0), 4.0);
+        assert!(sma.has_checkpoint());
+        assert!(sma.restore_checkpoint());
+        assert_eq!(sma.next(20.0), 15.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Checkpointed::<SimpleMovingAverage>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = Checkpointed::new(SimpleMovingAverage::new(9).unwrap());
+        assert_eq!(format!("{}", sma), "SMA(9)");
+    }
+}
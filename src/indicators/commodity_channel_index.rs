@@ -3,9 +3,9 @@ use std::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::errors::Result;
+use crate::errors::{Result, TaError};
 use crate::indicators::{MeanAbsoluteDeviation, SimpleMovingAverage};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
 
 /// Commodity Channel Index (CCI)
 ///
@@ -17,11 +17,37 @@ use crate::{Close, High, Low, Next, Period, Reset};
 ///
 /// # Formula
 ///
-/// CCI(_period_) = (TP - SMA(_period_) of TP) / (MAD(_period_) * 0.015)
+/// CCI(_period_) = (TP - SMA(_period_) of TP) / (MAD(_period_) of TP * _constant_)
+///
+/// Where:
+///
+/// * _TP_ - typical price, (high + low + close) / 3
+/// * _MAD_ - [mean absolute deviation](struct.MeanAbsoluteDeviation.html)
 ///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _constant_ - scaling constant (greater than 0). Lambert chose 0.015 so that roughly 70 to 80
+///   percent of the values fall between -100 and 100. Default is 0.015.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::CommodityChannelIndex;
+/// use ta::{DataItem, Next};
+///
+/// let mut cci = CommodityChannelIndex::new(20, 0.015).unwrap();
+/// let bar = DataItem::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.0)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(cci.next(&bar), 0.0);
+/// ```
 ///
 /// # Links
 ///
@@ -31,17 +57,26 @@ use crate::{Close, High, Low, Next, Period, Reset};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct CommodityChannelIndex {
+    constant: f64,
     sma: SimpleMovingAverage,
     mad: MeanAbsoluteDeviation,
 }
 
 impl CommodityChannelIndex {
-    pub fn new(period: usize) -> Result<Self> {
+    pub fn new(period: usize, constant: f64) -> Result<Self> {
+        if !(constant > 0.0 && constant.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
         Ok(Self {
+            constant,
             sma: SimpleMovingAverage::new(period)?,
             mad: MeanAbsoluteDeviation::new(period)?,
         })
     }
+
+    pub fn constant(&self) -> f64 {
+        self.constant
+    }
 }
 
 impl Period for CommodityChannelIndex {
@@ -50,19 +85,25 @@ impl Period for CommodityChannelIndex {
     }
 }
 
+impl Lookback for CommodityChannelIndex {
+    fn lookback(&self) -> usize {
+        self.sma.lookback()
+    }
+}
+
 impl<T: Close + High + Low> Next<&T> for CommodityChannelIndex {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
         let tp = (input.close() + input.high() + input.low()) / 3.0;
         let sma = self.sma.next(tp);
-        let mad = self.mad.next(input);
+        let mad = self.mad.next(tp);
 
         if mad == 0.0 {
             return 0.0;
         }
 
-        (tp - sma) / (mad * 0.015)
+        (tp - sma) / (mad * self.constant)
     }
 }
 
@@ -75,13 +116,13 @@ impl Reset for CommodityChannelIndex {
 
 impl Default for CommodityChannelIndex {
     fn default() -> Self {
-        Self::new(20).unwrap()
+        Self::new(20, 0.015).unwrap()
     }
 }
 
 impl fmt::Display for CommodityChannelIndex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "CCI({})", self.sma.period())
+        write!(f, "CCI({}, {})", self.sma.period(), self.constant)
     }
 }
 
@@ -92,13 +133,16 @@ mod tests {
 
     #[test]
     fn test_new() {
-        assert!(CommodityChannelIndex::new(0).is_err());
-        assert!(CommodityChannelIndex::new(1).is_ok());
+        assert!(CommodityChannelIndex::new(0, 0.015).is_err());
+        assert!(CommodityChannelIndex::new(1, 0.0).is_err());
+        assert!(CommodityChannelIndex::new(1, f64::NAN).is_err());
+        assert!(CommodityChannelIndex::new(1, f64::INFINITY).is_err());
+        assert!(CommodityChannelIndex::new(1, 0.015).is_ok());
     }
 
     #[test]
     fn test_next_bar() {
-        let mut cci = CommodityChannelIndex::new(5).unwrap();
+        let mut cci = CommodityChannelIndex::new(5, 0.015).unwrap();
 
         let bar1 = Bar::new().high(2).low(1).close(1.5);
         assert_eq!(round(cci.next(&bar1)), 0.0);
@@ -119,9 +163,26 @@ mod tests {
         assert_eq!(round(cci.next(&bar6)), -126.126);
     }
 
+    #[test]
+    fn test_next_typical_price() {
+        let mut cci = CommodityChannelIndex::new(2, 0.015).unwrap();
+
+        // TP = 2, 4
+        cci.next(&Bar::new().high(3).low(0).close(3));
+        assert_eq!(round(cci.next(&Bar::new().high(6).low(0).close(6))), 66.667);
+    }
+
+    #[test]
+    fn test_constant() {
+        let mut cci = CommodityChannelIndex::new(5, 0.03).unwrap();
+
+        cci.next(&Bar::new().high(2).low(1).close(1.5));
+        assert_eq!(round(cci.next(&Bar::new().high(5).low(3).close(4))), 33.333);
+    }
+
     #[test]
     fn test_reset() {
-        let mut cci = CommodityChannelIndex::new(5).unwrap();
+        let mut cci = CommodityChannelIndex::new(5, 0.015).unwrap();
 
         let bar1 = Bar::new().high(2).low(1).close(1.5);
         let bar2 = Bar::new().high(5).low(3).close(4);
@@ -142,7 +203,7 @@ mod tests {
 
     #[test]
     fn test_display() {
-        let indicator = CommodityChannelIndex::new(10).unwrap();
-        assert_eq!(format!("{}", indicator), "CCI(10)");
+        let indicator = CommodityChannelIndex::new(10, 0.015).unwrap();
+        assert_eq!(format!("{}", indicator), "CCI(10, 0.015)");
     }
 }
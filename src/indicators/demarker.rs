@@ -0,0 +1,196 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage;
+use crate::{High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// DeMarker indicator (DeM).
+///
+/// Developed by Tom DeMarker, it compares the highs pushed above the previous highs with the
+/// lows pushed below the previous lows. It is near 1 when the market keeps making higher highs
+/// and near 0 when it keeps making lower lows; readings above 0.7 and below 0.3 are taken as
+/// overbought and oversold.
+///
+/// # Formula
+///
+/// DeMax = max(high - high<sub>prev</sub>, 0)
+///
+/// DeMin = max(low<sub>prev</sub> - low, 0)
+///
+/// DeM = SMA(_period_) of DeMax / (SMA(_period_) of DeMax + SMA(_period_) of DeMin)
+///
+/// The first bar has no previous bar, and the DeM is 0.5 while the highs and lows do not move
+/// out of the previous bars.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DeMarker;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64| {
+///     DataItem::builder()
+///         .open(low)
+///         .high(high)
+///         .low(low)
+///         .close(high)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut dem = DeMarker::new(3).unwrap();
+/// assert_eq!(dem.next(&bar(10.0, 8.0)), 0.5);
+/// assert_eq!(dem.next(&bar(11.0, 9.0)), 1.0);
+/// assert_eq!(dem.next(&bar(11.0, 6.0)), 0.25);
+/// ```
+///
+#[doc(alias = "DeM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DeMarker {
+    de_max: SimpleMovingAverage,
+    de_min: SimpleMovingAverage,
+    // High and low of the previous bar.
+    prev: Option<(f64, f64)>,
+}
+
+impl DeMarker {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            de_max: SimpleMovingAverage::new(period)?,
+            de_min: SimpleMovingAverage::new(period)?,
+            prev: None,
+        })
+    }
+}
+
+impl Period for DeMarker {
+    fn period(&self) -> usize {
+        self.de_max.period()
+    }
+}
+
+impl Lookback for DeMarker {
+    fn lookback(&self) -> usize {
+        self.de_max.period()
+    }
+}
+
+impl<T: High + Low> Next<&T> for DeMarker {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low) = (input.high(), input.low());
+        let (prev_high, prev_low) = match self.prev.replace((high, low)) {
+            Some(prev) => prev,
+            None => return 0.5,
+        };
+
+        let de_max = self.de_max.next((high - prev_high).max(0.0));
+        let de_min = self.de_min.next((prev_low - low).max(0.0));
+        if de_max + de_min > 0.0 {
+            de_max / (de_max + de_min)
+        } else {
+            0.5
+        }
+    }
+}
+
+impl Reset for DeMarker {
+    fn reset(&mut self) {
+        self.de_max.reset();
+        self.de_min.reset();
+        self.prev = None;
+    }
+}
+
+impl Default for DeMarker {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for DeMarker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DEM({})", self.de_max.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64) -> Bar {
+        Bar::new().high(high).low(low)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(DeMarker::new(0).is_err());
+        assert!(DeMarker::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dem = DeMarker::new(3).unwrap();
+
+        let test_data = vec![
+            // high, low, dem
+            (10.0, 8.0, 0.5),
+            (11.0, 9.0, 1.0),
+            (12.0, 10.5, 1.0),
+            (11.0, 9.5, 0.667),
+            (11.5, 8.0, 0.375),
+            // the first high change left the window
+            (13.0, 10.0, 0.444),
+        ];
+
+        for (high, low, expected) in test_data {
+            assert_eq!(round(dem.next(&bar(high, low))), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_inside_bars() {
+        let mut dem = DeMarker::new(3).unwrap();
+        dem.next(&bar(10.0, 8.0));
+        assert_eq!(dem.next(&bar(9.5, 8.5)), 0.5);
+        assert_eq!(dem.next(&bar(10.0, 9.0)), 1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dem = DeMarker::new(3).unwrap();
+        dem.next(&bar(10.0, 8.0));
+        dem.next(&bar(11.0, 9.0));
+
+        dem.reset();
+        assert_eq!(dem.next(&bar(11.0, 9.0)), 0.5);
+        assert_eq!(dem.next(&bar(10.0, 8.0)), 0.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let dem = DeMarker::new(14).unwrap();
+        assert_eq!(dem.lookback(), 14);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(DeMarker::default().period(), 14);
+    }
+
+    #[test]
+    fn test_display() {
+        let dem = DeMarker::new(14).unwrap();
+        assert_eq!(format!("{}", dem), "DEM(14)");
+    }
+}
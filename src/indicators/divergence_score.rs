@@ -0,0 +1,305 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Lookback, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Divergence strength between a price and an oscillator.
+///
+/// A divergence is a price making a higher high while its oscillator, e.g. an
+/// [RSI](struct.RelativeStrengthIndex.html), makes a lower high, or the price making a lower low
+/// while the oscillator makes a higher low. Instead of flagging each divergence, this indicator
+/// regresses the oscillator swings on the price swings over the last _swings_ swings, giving a
+/// graded score: a positive slope and correlation mean the oscillator confirms the price, a
+/// negative one means it diverges, and the correlation tells how consistently.
+///
+/// The input is a pair `(price, oscillator)` of the same period.
+///
+/// # Formula
+///
+/// A swing high is a price greater than the _strength_ prices before and after it, a swing low a
+/// price lower than them, so a swing is confirmed _strength_ periods later. Each swing high
+/// gives a pair of amplitudes, the changes of the price and of the oscillator since the
+/// previous swing high, and likewise for the swing lows.
+///
+/// slope = Σ (x<sub>i</sub> - x̄)(y<sub>i</sub> - ȳ) / Σ (x<sub>i</sub> - x̄)<sup>2</sup>
+///
+/// correlation = Σ (x<sub>i</sub> - x̄)(y<sub>i</sub> - ȳ) / √(Σ (x<sub>i</sub> - x̄)<sup>2</sup> Σ (y<sub>i</sub> - ȳ)<sup>2</sup>)
+///
+/// Where _x<sub>i</sub>_ and _y<sub>i</sub>_ are the price and the oscillator amplitudes of the
+/// last _swings_ pairs. Both are 0 until two pairs are known, and each is 0 while its
+/// denominator is 0.
+///
+/// # Parameters
+///
+/// * _strength_ - number of periods on each side of a swing (integer greater than 0). Default
+///   is 5.
+/// * _swings_ - number of swing amplitudes in the regression (integer greater than 1). Default
+///   is 5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{DivergenceScore, RelativeStrengthIndex};
+/// use ta::Next;
+///
+/// let mut rsi = RelativeStrengthIndex::new(14).unwrap();
+/// let mut divergence = DivergenceScore::new(2, 5).unwrap();
+///
+/// for price in &[10.0, 11.0, 12.5, 11.5, 10.5, 12.0, 13.5, 12.0, 11.0, 13.0, 14.0, 13.0, 12.0] {
+///     let out = divergence.next((*price, rsi.next(*price)));
+///     assert!(out.correlation >= -1.0 && out.correlation <= 1.0);
+/// }
+/// ```
+///
+/// # Links
+///
+/// * [Divergence, Investopedia](https://www.investopedia.com/terms/d/divergence.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DivergenceScore {
+    strength: usize,
+    // Latest 2 * strength + 1 pairs, the candidate swing is in the middle.
+    index: usize,
+    count: usize,
+    window: Box<[(f64, f64)]>,
+    last_high: Option<(f64, f64)>,
+    last_low: Option<(f64, f64)>,
+    swing_index: usize,
+    swing_count: usize,
+    amplitudes: Box<[(f64, f64)]>,
+    slope: f64,
+    correlation: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DivergenceScoreOutput {
+    /// Slope of the oscillator amplitudes against the price amplitudes.
+    pub slope: f64,
+    /// Correlation of the oscillator amplitudes with the price amplitudes, from -1 to 1.
+    pub correlation: f64,
+}
+
+impl DivergenceScore {
+    pub fn new(strength: usize, swings: usize) -> Result<Self> {
+        if strength == 0 || swings < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            strength,
+            index: 0,
+            count: 0,
+            window: vec![(0.0, 0.0); 2 * strength + 1].into_boxed_slice(),
+            last_high: None,
+            last_low: None,
+            swing_index: 0,
+            swing_count: 0,
+            amplitudes: vec![(0.0, 0.0); swings].into_boxed_slice(),
+            slope: 0.0,
+            correlation: 0.0,
+        })
+    }
+
+    pub fn strength(&self) -> usize {
+        self.strength
+    }
+
+    pub fn swings(&self) -> usize {
+        self.amplitudes.len()
+    }
+
+    fn push_amplitude(&mut self, amplitude: (f64, f64)) {
+        self.amplitudes[self.swing_index] = amplitude;
+        self.swing_index = if self.swing_index + 1 < self.amplitudes.len() {
+            self.swing_index + 1
+        } else {
+            0
+        };
+        if self.swing_count < self.amplitudes.len() {
+            self.swing_count += 1;
+        }
+        if self.swing_count < 2 {
+            return;
+        }
+
+        let amplitudes = &self.amplitudes[..self.swing_count];
+        let n = self.swing_count as f64;
+        let mean_x = amplitudes.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = amplitudes.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let mut covariance = 0.0;
+        let mut x_variance = 0.0;
+        let mut y_variance = 0.0;
+        for (x, y) in amplitudes {
+            let dx = x - mean_x;
+            let dy = y - mean_y;
+            covariance += dx * dy;
+            x_variance += dx * dx;
+            y_variance += dy * dy;
+        }
+
+        self.slope = if x_variance == 0.0 {
+            0.0
+        } else {
+            covariance / x_variance
+        };
+        self.correlation = if x_variance == 0.0 || y_variance == 0.0 {
+            0.0
+        } else {
+            covariance / (x_variance * y_variance).sqrt()
+        };
+    }
+}
+
+impl Lookback for DivergenceScore {
+    fn lookback(&self) -> usize {
+        // The earliest pairs of amplitudes need three swings of the same kind.
+        4 * self.strength + 2
+    }
+}
+
+impl Next<(f64, f64)> for DivergenceScore {
+    type Output = DivergenceScoreOutput;
+
+    fn next(&mut self, input: (f64, f64)) -> Self::Output {
+        let len = self.window.len();
+        self.window[self.index] = input;
+        self.index = if self.index + 1 < len {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < len {
+            self.count += 1;
+        }
+
+        if self.count == len {
+            // The oldest pair is at `index`.
+            let middle = (self.index + self.strength) % len;
+            let (price, oscillator) = self.window[middle];
+            let others = self
+                .window
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != middle)
+                .map(|(_, &(other, _))| other);
+
+            let mut high = true;
+            let mut low = true;
+            for other in others {
+                high &= price > other;
+                low &= price < other;
+            }
+
+            if high {
+                if let Some((prev_price, prev_oscillator)) = self.last_high {
+                    self.push_amplitude((price - prev_price, oscillator - prev_oscillator));
+                }
+                self.last_high = Some((price, oscillator));
+            } else if low {
+                if let Some((prev_price, prev_oscillator)) = self.last_low {
+                    self.push_amplitude((price - prev_price, oscillator - prev_oscillator));
+                }
+                self.last_low = Some((price, oscillator));
+            }
+        }
+
+        DivergenceScoreOutput {
+            slope: self.slope,
+            correlation: self.correlation,
+        }
+    }
+}
+
+impl Reset for DivergenceScore {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.last_high = None;
+        self.last_low = None;
+        self.swing_index = 0;
+        self.swing_count = 0;
+        self.slope = 0.0;
+        self.correlation = 0.0;
+    }
+}
+
+impl Default for DivergenceScore {
+    fn default() -> Self {
+        Self::new(5, 5).unwrap()
+    }
+}
+
+impl fmt::Display for DivergenceScore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DIVERGENCE({}, {})", self.strength, self.swings())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(DivergenceScore::new(0, 5).is_err());
+        assert!(DivergenceScore::new(1, 1).is_err());
+        assert!(DivergenceScore::new(1, 2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut divergence = DivergenceScore::new(1, 3).unwrap();
+
+        let test_data = vec![
+            // price, oscillator, slope, correlation
+            (1.0, 50.0, 0.0, 0.0),
+            (3.0, 70.0, 0.0, 0.0),
+            (2.0, 60.0, 0.0, 0.0),
+            (5.0, 65.0, 0.0, 0.0),
+            (3.0, 55.0, 0.0, 0.0),
+            (6.0, 62.0, 0.0, 0.0),
+            // higher highs and higher lows, lower oscillator swings
+            (4.0, 50.0, -1.0, -0.5),
+            // all the price amplitudes are 1
+            (8.0, 70.0, 0.0, 0.0),
+            // the oscillator confirms
+            (5.0, 60.0, 12.0, 0.99),
+            (7.0, 66.0, 5.5, 0.39),
+            (6.0, 58.0, 4.429, 0.893),
+        ];
+
+        for (price, oscillator, slope, correlation) in test_data {
+            let out = divergence.next((price, oscillator));
+            assert_eq!(round(out.slope), slope);
+            assert_eq!(round(out.correlation), correlation);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut divergence = DivergenceScore::new(1, 2).unwrap();
+        for &(price, oscillator) in &[(1.0, 1.0), (3.0, 5.0), (2.0, 2.0), (5.0, 4.0), (1.0, 0.0)] {
+            divergence.next((price, oscillator));
+        }
+        divergence.next((6.0, 0.0));
+
+        divergence.reset();
+        let out = divergence.next((1.0, 1.0));
+        assert_eq!((out.slope, out.correlation), (0.0, 0.0));
+        assert_eq!(divergence.last_high, None);
+    }
+
+    #[test]
+    fn test_default() {
+        DivergenceScore::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = DivergenceScore::new(5, 4).unwrap();
+        assert_eq!(format!("{}", indicator), "DIVERGENCE(5, 4)");
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage;
+use crate::{High, Lookback, Low, NewWithPeriod, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ease of Movement (EMV).
+///
+/// Developed by Richard Arms, EMV relates the change of price to the volume: it is high when the
+/// price rises on light volume, i.e. moves with ease, and close to 0 when heavy volume is needed
+/// to move the price. The single period values are smoothed by a moving average, by default an
+/// [SMA](struct.SimpleMovingAverage.html).
+///
+/// # Formula
+///
+/// distance moved = (high + low) / 2 - (high<sub>t-1</sub> + low<sub>t-1</sub>) / 2
+///
+/// box ratio = (volume / _volume_divisor_) / (high - low)
+///
+/// EMV = MA(_period_) of (distance moved / box ratio)
+///
+/// The distance moved of the first period is 0. A period without volume has an EMV of 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of the moving average (integer greater than 0). Default is 14.
+/// * _volume_divisor_ - scale of the volume, to keep EMV in a readable range whatever the usual
+///   volume of the market (greater than 0). Default is 100000000.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{EaseOfMovement, SimpleMovingAverage as Sma};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(low)
+///         .high(high)
+///         .low(low)
+///         .close(high)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut emv = EaseOfMovement::<Sma>::new(1, 100.0).unwrap();
+/// assert_eq!(emv.next(&bar(10.0, 8.0, 200.0)), 0.0);
+/// assert_eq!(emv.next(&bar(12.0, 9.0, 300.0)), 1.5);
+/// ```
+///
+/// # Links
+///
+/// * [Ease of Movement, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:ease_of_movement_emv)
+///
+#[doc(alias = "EMV")]
+#[doc(alias = "EOM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct EaseOfMovement<MA = SimpleMovingAverage> {
+    volume_divisor: f64,
+    ma: MA,
+    prev_mid: Option<f64>,
+}
+
+impl<MA: NewWithPeriod> EaseOfMovement<MA> {
+    pub fn new(period: usize, volume_divisor: f64) -> Result<Self> {
+        if !(volume_divisor > 0.0 && volume_divisor.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            volume_divisor,
+            ma: MA::new_with_period(period)?,
+            prev_mid: None,
+        })
+    }
+}
+
+impl<MA> EaseOfMovement<MA> {
+    pub fn volume_divisor(&self) -> f64 {
+        self.volume_divisor
+    }
+}
+
+impl<MA: Period> Period for EaseOfMovement<MA> {
+    fn period(&self) -> usize {
+        self.ma.period()
+    }
+}
+
+impl<MA: Lookback> Lookback for EaseOfMovement<MA> {
+    fn lookback(&self) -> usize {
+        1 + self.ma.lookback()
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: High + Low + Volume> Next<&T> for EaseOfMovement<MA> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let mid = (input.high() + input.low()) / 2.0;
+        let distance = self.prev_mid.map_or(0.0, |prev_mid| mid - prev_mid);
+        self.prev_mid = Some(mid);
+
+        let emv = if input.volume() == 0.0 {
+            0.0
+        } else {
+            distance * (input.high() - input.low()) * self.volume_divisor / input.volume()
+        };
+        self.ma.next(emv)
+    }
+}
+
+impl<MA: Reset> Reset for EaseOfMovement<MA> {
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.prev_mid = None;
+    }
+}
+
+impl Default for EaseOfMovement {
+    fn default() -> Self {
+        Self::new(14, 100_000_000.0).unwrap()
+    }
+}
+
+impl<MA: Period> fmt::Display for EaseOfMovement<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EMV({}, {})", self.ma.period(), self.volume_divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, volume: f64) -> Bar {
+        Bar::new().high(high).low(low).volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(EaseOfMovement::<Sma>::new(0, 100.0).is_err());
+        assert!(EaseOfMovement::<Sma>::new(14, 0.0).is_err());
+        assert!(EaseOfMovement::<Sma>::new(14, f64::NAN).is_err());
+        assert!(EaseOfMovement::<Ema>::new(1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut emv = EaseOfMovement::<Sma>::new(3, 100.0).unwrap();
+
+        let test_data = vec![
+            // high, low, volume, EMV
+            (10.0, 8.0, 200.0, 0.0),
+            (12.0, 9.0, 300.0, 0.75),
+            (11.0, 9.0, 100.0, 0.167),
+            // no volume
+            (11.0, 10.0, 0.0, 0.167),
+            (13.0, 11.0, 400.0, -0.083),
+            // no range
+            (13.0, 13.0, 100.0, 0.25),
+        ];
+
+        for (high, low, volume, expected) in test_data {
+            assert_eq!(round(emv.next(&bar(high, low, volume))), expected);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut emv = EaseOfMovement::<Sma>::new(3, 100.0).unwrap();
+        emv.next(&bar(10.0, 8.0, 200.0));
+        emv.next(&bar(12.0, 9.0, 300.0));
+
+        emv.reset();
+        assert_eq!(emv.next(&bar(12.0, 9.0, 300.0)), 0.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let emv = EaseOfMovement::<Sma>::new(14, 100.0).unwrap();
+        assert_eq!(emv.lookback(), 14);
+    }
+
+    #[test]
+    fn test_default() {
+        EaseOfMovement::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = EaseOfMovement::<Sma>::new(14, 10000.0).unwrap();
+        assert_eq!(format!("{}", indicator), "EMV(14, 10000)");
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::traits::{Close, Next, Period, Reset};
+use crate::traits::{Close, Lookback, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -9,10 +9,19 @@ use serde::{Deserialize, Serialize};
 ///
 /// It is calculated by dividing the price change over a period by the absolute sum of the price movements that occurred to achieve that change.
 /// The resulting ratio ranges between 0.0 and 1.0 with higher values representing a more efficient or trending market.
+/// It is the smoothing driver of [KAMA](struct.KaufmanAdaptiveMovingAverage.html).
+///
+/// # Formula
+///
+/// ER = |P<sub>t</sub> - P<sub>t-n</sub>| / Σ |P<sub>i</sub> - P<sub>i-1</sub>|
+///
+/// Where the sum runs over the last _n_ price changes. Until _period_ changes are seen, the
+/// window starts at the first price. The ratio of the first price is 1, and it is 0 while the
+/// price does not move.
 ///
 /// # Parameters
 ///
-/// * _period_ - number of periods (integer greater than 0)
+/// * _period_ - number of price changes (integer greater than 0). Default is 14.
 ///
 /// # Example
 ///
@@ -28,7 +37,6 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(er.next(18.0), 0.8);
 /// assert_eq!(er.next(19.0), 0.75);
 /// ```
-
 #[doc(alias = "ER")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -36,7 +44,9 @@ pub struct EfficiencyRatio {
     period: usize,
     index: usize,
     count: usize,
+    // Last period + 1 prices.
     deque: Box<[f64]>,
+    volatility: f64,
 }
 
 impl EfficiencyRatio {
@@ -47,7 +57,8 @@ impl EfficiencyRatio {
                 period,
                 index: 0,
                 count: 0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                deque: vec![0.0; period + 1].into_boxed_slice(),
+                volatility: 0.0,
             }),
         }
     }
@@ -59,36 +70,49 @@ impl Period for EfficiencyRatio {
     }
 }
 
+impl Lookback for EfficiencyRatio {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
 impl Next<f64> for EfficiencyRatio {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> f64 {
-        let first = if self.count >= self.period {
+        let len = self.deque.len();
+        let next_index = |index: usize| if index + 1 < len { index + 1 } else { 0 };
+
+        if self.count > 0 {
+            let last = self.deque[(self.index + len - 1) % len];
+            if self.count == len {
+                // The oldest price change leaves the window.
+                let oldest = self.deque[self.index];
+                self.volatility -= (self.deque[next_index(self.index)] - oldest).abs();
+            }
+            self.volatility += (input - last).abs();
+        }
+
+        self.deque[self.index] = input;
+        self.index = next_index(self.index);
+        if self.count < len {
+            self.count += 1;
+        }
+
+        if self.count == 1 {
+            return 1.0;
+        }
+        let first = if self.count == len {
             self.deque[self.index]
         } else {
-            self.count += 1;
             self.deque[0]
         };
-        self.deque[self.index] = input;
-
-        self.index = if self.index + 1 < self.period {
-            self.index + 1
+        if self.volatility > 0.0 {
+            // The running sum may be off by a rounding residue.
+            ((input - first).abs() / self.volatility).min(1.0)
         } else {
-            0
-        };
-
-        let mut volatility = 0.0;
-        let mut previous = first;
-        for n in &self.deque[self.index..self.count] {
-            volatility += (previous - n).abs();
-            previous = *n;
+            0.0
         }
-        for n in &self.deque[0..self.index] {
-            volatility += (previous - n).abs();
-            previous = *n;
-        }
-
-        (first - input).abs() / volatility
     }
 }
 
@@ -104,7 +128,8 @@ impl Reset for EfficiencyRatio {
     fn reset(&mut self) {
         self.index = 0;
         self.count = 0;
-        for i in 0..self.period {
+        self.volatility = 0.0;
+        for i in 0..self.deque.len() {
             self.deque[i] = 0.0;
         }
     }
@@ -149,6 +174,37 @@ mod tests {
         assert_eq!(round(er.next(6.0)), 1.0);
     }
 
+    #[test]
+    fn test_next_flat() {
+        let mut er = EfficiencyRatio::new(3).unwrap();
+
+        assert_eq!(er.next(0.0), 1.0);
+        assert_eq!(er.next(0.0), 0.0);
+        assert_eq!(er.next(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_long_series() {
+        // The running sum of the price movements stays in line with the window.
+        let mut er = EfficiencyRatio::new(5).unwrap();
+        let prices: Vec<f64> = (0..1000).map(|i| 100.0 + (i as f64 * 0.7).sin()).collect();
+
+        for (i, &price) in prices.iter().enumerate() {
+            let out = er.next(price);
+            if i >= 5 {
+                let window = &prices[i - 5..=i];
+                let volatility: f64 = window.windows(2).map(|w| (w[1] - w[0]).abs()).sum();
+                assert_eq!(round(out), round((price - window[0]).abs() / volatility));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookback() {
+        let er = EfficiencyRatio::new(10).unwrap();
+        assert_eq!(er.lookback(), 10);
+    }
+
     #[test]
     fn test_reset() {
         let mut er = EfficiencyRatio::new(3).unwrap();
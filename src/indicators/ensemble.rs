@@ -0,0 +1,375 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Maximum, Minimum, ZScore};
+use crate::{Lookback, Next, Reset};
+
+/// Composite score of several indicators, like a "technical rating".
+///
+/// Every indicator is added with a weight and a [normalization](enum.Normalization.html) that
+/// maps its output onto a common scale from -1 (bearish) to 1 (bullish). The score is the
+/// weighted average of the normalized outputs, also from -1 to 1. Oscillators with fixed bounds,
+/// such as the RSI, are normalized with their range, unbounded ones, such as the ROC, with their
+/// recent history.
+///
+/// The indicators are fed the same bars and must return `f64` values.
+///
+/// # Formula
+///
+/// score = Σ (w<sub>i</sub> * n<sub>i</sub>) / Σ w<sub>i</sub>
+///
+/// Where _w<sub>i</sub>_ is the weight and _n<sub>i</sub>_ the normalized output of the i-th
+/// indicator. The score of an empty ensemble is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Ensemble, Normalization, RateOfChange, RelativeStrengthIndex};
+/// use ta::{DataItem, Next};
+///
+/// let mut rating = Ensemble::new()
+///     .add(
+///         RelativeStrengthIndex::new(14).unwrap(),
+///         2.0,
+///         Normalization::Range { low: 0.0, high: 100.0 },
+///     )
+///     .unwrap()
+///     .add(RateOfChange::new(10).unwrap(), 1.0, Normalization::MinMax(50))
+///     .unwrap();
+///
+/// let bar = DataItem::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let score = rating.next(&bar);
+/// assert!(score >= -1.0 && score <= 1.0);
+/// assert_eq!(format!("{}", rating), "ENSEMBLE(RSI(14), ROC(10))");
+/// ```
+///
+pub struct Ensemble<T> {
+    members: Vec<Member<T>>,
+}
+
+/// Maps the output of an indicator of an [Ensemble](struct.Ensemble.html) onto -1 to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Normalization {
+    /// Maps the range from _low_ to _high_ linearly onto -1 to 1, values outside of it are
+    /// clamped, e.g. 0 to 100 for the RSI.
+    Range { low: f64, high: f64 },
+    /// Position of the output within the minimum and the maximum of the last _period_ outputs,
+    /// mapped onto -1 to 1. It is 0 when they are equal.
+    MinMax(usize),
+    /// Z-score of the output over the last _period_ outputs, divided by 3 and clamped to -1 to
+    /// 1, so 3 standard deviations make a full score. It is 0 when the outputs are flat.
+    ZScore(usize),
+}
+
+struct Member<T> {
+    name: String,
+    weight: f64,
+    indicator: Box<dyn Signal<T>>,
+    normalizer: Normalizer,
+}
+
+enum Normalizer {
+    Range { low: f64, high: f64 },
+    MinMax { max: Maximum, min: Minimum },
+    ZScore(ZScore),
+}
+
+impl<T> Ensemble<T> {
+    pub fn new() -> Self {
+        Self {
+            members: Vec::new(),
+        }
+    }
+
+    /// Adds an indicator with its weight (greater than 0) and normalization.
+    pub fn add<I>(mut self, indicator: I, weight: f64, normalization: Normalization) -> Result<Self>
+    where
+        I: for<'a> Next<&'a T, Output = f64> + Reset + Lookback + fmt::Display + 'static,
+    {
+        if !weight.is_finite() || weight <= 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+        let normalizer = match normalization {
+            Normalization::Range { low, high } => {
+                if !(low.is_finite() && high.is_finite() && low < high) {
+                    return Err(TaError::InvalidParameter);
+                }
+                Normalizer::Range { low, high }
+            }
+            Normalization::MinMax(period) => Normalizer::MinMax {
+                max: Maximum::new(period)?,
+                min: Minimum::new(period)?,
+            },
+            Normalization::ZScore(period) => {
+                Normalizer::ZScore(ZScore::new(period)?.with_clamp(3.0)?)
+            }
+        };
+
+        self.members.push(Member {
+            name: indicator.to_string(),
+            weight,
+            indicator: Box::new(indicator),
+            normalizer,
+        });
+        Ok(self)
+    }
+}
+
+impl Normalizer {
+    fn normalize(&mut self, value: f64) -> f64 {
+        match self {
+            Normalizer::Range { low, high } => {
+                (2.0 * (value - *low) / (*high - *low) - 1.0).clamp(-1.0, 1.0)
+            }
+            Normalizer::MinMax { max, min } => {
+                let (max, min) = (max.next(value), min.next(value));
+                if max > min {
+                    2.0 * (value - min) / (max - min) - 1.0
+                } else {
+                    0.0
+                }
+            }
+            Normalizer::ZScore(z_score) => z_score.next(value) / 3.0,
+        }
+    }
+
+    fn lookback(&self) -> usize {
+        match self {
+            Normalizer::Range { .. } => 0,
+            Normalizer::MinMax { max, .. } => max.lookback(),
+            Normalizer::ZScore(z_score) => z_score.lookback(),
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Normalizer::Range { .. } => {}
+            Normalizer::MinMax { max, min } => {
+                max.reset();
+                min.reset();
+            }
+            Normalizer::ZScore(z_score) => z_score.reset(),
+        }
+    }
+}
+
+impl<T> Lookback for Ensemble<T> {
+    fn lookback(&self) -> usize {
+        self.members
+            .iter()
+            .map(|member| member.indicator.signal_lookback() + member.normalizer.lookback())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl<T> Next<&T> for Ensemble<T> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let mut score = 0.0;
+        let mut weights = 0.0;
+        for member in self.members.iter_mut() {
+            let value = member.indicator.signal(input);
+            score += member.weight * member.normalizer.normalize(value);
+            weights += member.weight;
+        }
+        if weights > 0.0 {
+            score / weights
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<T> Reset for Ensemble<T> {
+    fn reset(&mut self) {
+        for member in self.members.iter_mut() {
+            member.indicator.reset_signal();
+            member.normalizer.reset();
+        }
+    }
+}
+
+impl<T> Default for Ensemble<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Ensemble<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let members: Vec<(&str, f64)> = self
+            .members
+            .iter()
+            .map(|member| (member.name.as_str(), member.weight))
+            .collect();
+        f.debug_struct("Ensemble")
+            .field("members", &members)
+            .finish()
+    }
+}
+
+impl<T> fmt::Display for Ensemble<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<&str> = self
+            .members
+            .iter()
+            .map(|member| member.name.as_str())
+            .collect();
+        write!(f, "ENSEMBLE({})", names.join(", "))
+    }
+}
+
+// Object safe view of an indicator returning `f64` values.
+trait Signal<T> {
+    fn signal(&mut self, input: &T) -> f64;
+    fn signal_lookback(&self) -> usize;
+    fn reset_signal(&mut self);
+}
+
+impl<T, I> Signal<T> for I
+where
+    I: for<'a> Next<&'a T, Output = f64> + Reset + Lookback,
+{
+    fn signal(&mut self, input: &T) -> f64 {
+        self.next(input)
+    }
+
+    fn signal_lookback(&self) -> usize {
+        self.lookback()
+    }
+
+    fn reset_signal(&mut self) {
+        self.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage as Sma;
+    use crate::test_helper::*;
+
+    fn identity() -> Sma {
+        Sma::new(1).unwrap()
+    }
+
+    fn bar(close: f64) -> Bar {
+        Bar::new().close(close)
+    }
+
+    #[test]
+    fn test_add() {
+        let range = Normalization::Range {
+            low: 0.0,
+            high: 100.0,
+        };
+        assert!(Ensemble::<Bar>::new().add(identity(), 0.0, range).is_err());
+        assert!(Ensemble::<Bar>::new()
+            .add(identity(), f64::NAN, range)
+            .is_err());
+        assert!(Ensemble::<Bar>::new()
+            .add(
+                identity(),
+                1.0,
+                Normalization::Range {
+                    low: 1.0,
+                    high: 1.0
+                }
+            )
+            .is_err());
+        assert!(Ensemble::<Bar>::new()
+            .add(identity(), 1.0, Normalization::MinMax(0))
+            .is_err());
+        assert!(Ensemble::<Bar>::new()
+            .add(identity(), 1.0, Normalization::ZScore(0))
+            .is_err());
+        assert!(Ensemble::<Bar>::new().add(identity(), 1.0, range).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ensemble = Ensemble::new()
+            .add(
+                identity(),
+                1.0,
+                Normalization::Range {
+                    low: 0.0,
+                    high: 10.0,
+                },
+            )
+            .unwrap()
+            .add(identity(), 3.0, Normalization::MinMax(3))
+            .unwrap();
+
+        assert_eq!(round(ensemble.next(&bar(2.0))), -0.15);
+        assert_eq!(round(ensemble.next(&bar(6.0))), 0.8);
+        assert_eq!(round(ensemble.next(&bar(4.0))), -0.05);
+        // clamped to the range
+        assert_eq!(round(ensemble.next(&bar(15.0))), 1.0);
+    }
+
+    #[test]
+    fn test_next_z_score() {
+        let mut ensemble = Ensemble::new()
+            .add(identity(), 1.0, Normalization::ZScore(3))
+            .unwrap();
+
+        assert_eq!(ensemble.next(&bar(2.0)), 0.0);
+        assert_eq!(round(ensemble.next(&bar(6.0))), 0.333);
+        assert_eq!(round(ensemble.next(&bar(4.0))), 0.0);
+        assert_eq!(round(ensemble.next(&bar(10.0))), 0.445);
+    }
+
+    #[test]
+    fn test_next_empty() {
+        let mut ensemble = Ensemble::new();
+        assert_eq!(ensemble.next(&bar(2.0)), 0.0);
+        assert_eq!(ensemble.lookback(), 0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let ensemble = Ensemble::<Bar>::new()
+            .add(Sma::new(5).unwrap(), 1.0, Normalization::ZScore(10))
+            .unwrap()
+            .add(Sma::new(20).unwrap(), 1.0, Normalization::MinMax(3))
+            .unwrap();
+        assert_eq!(ensemble.lookback(), 21);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ensemble = Ensemble::new()
+            .add(identity(), 1.0, Normalization::MinMax(3))
+            .unwrap();
+        ensemble.next(&bar(2.0));
+        ensemble.next(&bar(6.0));
+
+        ensemble.reset();
+        assert_eq!(ensemble.next(&bar(4.0)), 0.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let ensemble = Ensemble::<Bar>::new()
+            .add(Sma::new(5).unwrap(), 1.0, Normalization::ZScore(10))
+            .unwrap()
+            .add(Sma::new(20).unwrap(), 2.0, Normalization::MinMax(3))
+            .unwrap();
+        assert_eq!(format!("{}", ensemble), "ENSEMBLE(SMA(5), SMA(20))");
+        assert_eq!(
+            format!("{:?}", ensemble),
+            "Ensemble { members: [(\"SMA(5)\", 1.0), (\"SMA(20)\", 2.0)] }"
+        );
+    }
+}
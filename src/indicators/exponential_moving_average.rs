@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset, SetPeriod};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -51,7 +51,6 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [Exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Exponential_moving_average)
 ///
-
 #[doc(alias = "EMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -76,12 +75,35 @@ impl ExponentialMovingAverage {
     }
 }
 
+impl NewWithPeriod for ExponentialMovingAverage {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
 impl Period for ExponentialMovingAverage {
     fn period(&self) -> usize {
         self.period
     }
 }
 
+impl Lookback for ExponentialMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl SetPeriod for ExponentialMovingAverage {
+    fn set_period(&mut self, period: usize) -> Result<()> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        self.period = period;
+        self.k = 2.0 / (period + 1) as f64;
+        Ok(())
+    }
+}
+
 impl Next<f64> for ExponentialMovingAverage {
     type Output = f64;
 
@@ -152,6 +174,18 @@ mod tests {
         assert_eq!(ema.next(&bar2), 3.5);
     }
 
+    #[test]
+    fn test_set_period() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(ema.next(2.0), 2.0);
+        assert_eq!(ema.next(5.0), 3.5);
+
+        assert!(ema.set_period(0).is_err());
+        ema.set_period(4).unwrap();
+        assert_eq!(ema.period(), 4);
+        assert_eq!(ema.next(6.0), 4.5);
+    }
+
     #[test]
     fn test_reset() {
         let mut ema = ExponentialMovingAverage::new(5).unwrap();
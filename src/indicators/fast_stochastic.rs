@@ -2,7 +2,8 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{Maximum, Minimum};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::units::{OutputUnit, Percent};
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -64,6 +65,16 @@ impl Period for FastStochastic {
     }
 }
 
+impl Lookback for FastStochastic {
+    fn lookback(&self) -> usize {
+        self.maximum.lookback()
+    }
+}
+
+impl OutputUnit for FastStochastic {
+    type Unit = Percent;
+}
+
 impl Next<f64> for FastStochastic {
     type Output = f64;
 
@@ -0,0 +1,229 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::math;
+use crate::{High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Bound of the smoothed position, the transform is infinite at -1 and 1.
+const BOUND: f64 = 0.999;
+
+/// Fisher transform.
+///
+/// Developed by John Ehlers, the Fisher transform converts the position of the price within its
+/// range over the last _period_ periods into a nearly Gaussian distribution, so turning points
+/// stand out as sharp peaks. The trigger line is the previous value; crossings of the two are
+/// used as signals.
+///
+/// Bars are transformed by their median price, (high + low) / 2.
+///
+/// # Formula
+///
+/// x<sub>t</sub> = 0.66 * ((P<sub>t</sub> - L<sub>n</sub>) / (H<sub>n</sub> - L<sub>n</sub>) - 0.5) + 0.67 * x<sub>t-1</sub>
+///
+/// Fisher<sub>t</sub> = 0.5 * ln((1 + x<sub>t</sub>) / (1 - x<sub>t</sub>)) + 0.5 * Fisher<sub>t-1</sub>
+///
+/// trigger<sub>t</sub> = Fisher<sub>t-1</sub>
+///
+/// Where:
+///
+/// * _P<sub>t</sub>_ - price of the current period
+/// * _L<sub>n</sub>_, _H<sub>n</sub>_ - lowest and highest price of the last _n_ periods
+///
+/// The position is 0.5 while the price is constant over the period. _x_ is clamped to
+/// [-0.999, 0.999] so the logarithm stays finite.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::FisherTransform;
+/// use ta::Next;
+///
+/// let mut fisher = FisherTransform::new(3).unwrap();
+/// assert_eq!(fisher.next(10.0).fisher, 0.0);
+///
+/// let out = fisher.next(11.0);
+/// assert_eq!((out.fisher * 1000.0).round(), 343.0);
+/// assert_eq!(out.trigger, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Fisher Transform, Investopedia](https://www.investopedia.com/terms/f/fisher-transform.asp)
+///
+#[doc(alias = "Fisher")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct FisherTransform {
+    period: usize,
+    maximum: Maximum,
+    minimum: Minimum,
+    value: f64,
+    fisher: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FisherTransformOutput {
+    pub fisher: f64,
+    /// Fisher transform of the previous period.
+    pub trigger: f64,
+}
+
+impl FisherTransform {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            maximum: Maximum::new(period)?,
+            minimum: Minimum::new(period)?,
+            value: 0.0,
+            fisher: 0.0,
+        })
+    }
+}
+
+impl Period for FisherTransform {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for FisherTransform {
+    fn lookback(&self) -> usize {
+        self.maximum.lookback()
+    }
+}
+
+impl Next<f64> for FisherTransform {
+    type Output = FisherTransformOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let max = self.maximum.next(input);
+        let min = self.minimum.next(input);
+        let position = if max == min {
+            0.5
+        } else {
+            (input - min) / (max - min)
+        };
+
+        self.value = (0.66 * (position - 0.5) + 0.67 * self.value).clamp(-BOUND, BOUND);
+        let trigger = self.fisher;
+        self.fisher = 0.5 * math::ln((1.0 + self.value) / (1.0 - self.value)) + 0.5 * self.fisher;
+
+        FisherTransformOutput {
+            fisher: self.fisher,
+            trigger,
+        }
+    }
+}
+
+impl<T: High + Low> Next<&T> for FisherTransform {
+    type Output = FisherTransformOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next((input.high() + input.low()) / 2.0)
+    }
+}
+
+impl Reset for FisherTransform {
+    fn reset(&mut self) {
+        self.maximum.reset();
+        self.minimum.reset();
+        self.value = 0.0;
+        self.fisher = 0.0;
+    }
+}
+
+impl Default for FisherTransform {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for FisherTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FISHER({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(FisherTransform::new(0).is_err());
+        assert!(FisherTransform::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut fisher = FisherTransform::new(3).unwrap();
+
+        let test_data = vec![
+            // input, fisher, trigger
+            (10.0, 0.0, 0.0),
+            (11.0, 0.343, 0.0),
+            (12.0, 0.791, 0.343),
+            (11.0, 0.435, 0.791),
+            (13.0, 0.59, 0.435),
+            (14.0, 0.941, 0.59),
+            (12.0, 0.521, 0.941),
+            (10.0, -0.044, 0.521),
+            (9.0, -0.61, -0.044),
+        ];
+
+        for (input, expected_fisher, expected_trigger) in test_data {
+            let out = fisher.next(input);
+            assert_eq!(round(out.fisher), expected_fisher);
+            assert_eq!(round(out.trigger), expected_trigger);
+        }
+    }
+
+    #[test]
+    fn test_next_clamped() {
+        let mut fisher = FisherTransform::new(3).unwrap();
+        let mut out = fisher.next(0.0);
+        for i in 1..30 {
+            out = fisher.next(i as f64);
+        }
+        assert_eq!(fisher.value, BOUND);
+        assert_eq!(round(out.fisher), 7.6);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut fisher = FisherTransform::new(3).unwrap();
+        fisher.next(&Bar::new().high(10.5).low(9.5));
+        let out = fisher.next(&Bar::new().high(12).low(10));
+        assert_eq!(round(out.fisher), 0.343);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fisher = FisherTransform::new(3).unwrap();
+        fisher.next(10.0);
+        fisher.next(11.0);
+
+        fisher.reset();
+        let out = fisher.next(20.0);
+        assert_eq!((out.fisher, out.trigger), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        FisherTransform::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = FisherTransform::new(9).unwrap();
+        assert_eq!(format!("{}", indicator), "FISHER(9)");
+    }
+}
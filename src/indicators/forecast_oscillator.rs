@@ -0,0 +1,198 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::rolling_regression::RollingRegression;
+use crate::units::{OutputUnit, Percent};
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Forecast oscillator (FOSC).
+///
+/// Developed by Tushar Chande, the forecast oscillator is the percentage difference between the
+/// price and the time series forecast (TSF) made in the previous period, i.e. the linear
+/// regression of the last _period_ prices extended one period ahead. Positive values mean the
+/// price is moving faster than the regression trend forecasted.
+///
+/// # Formula
+///
+/// FOSC<sub>t</sub> = (p<sub>t</sub> - TSF<sub>t-1</sub>) / p<sub>t</sub> * 100
+///
+/// Where:
+///
+/// * _TSF<sub>t-1</sub>_ - value of the least squares line of the _period_ prices up to _t-1_,
+///   one period after its last price
+///
+/// The first output and outputs for a price of 0 are 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the regression (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ForecastOscillator;
+/// use ta::Next;
+///
+/// let mut fosc = ForecastOscillator::new(3).unwrap();
+/// assert_eq!(fosc.next(10.0), 0.0);
+/// assert_eq!(fosc.next(11.0).round(), 9.0);
+/// // TSF of 10 and 11 is 12
+/// assert_eq!(fosc.next(12.0), 0.0);
+/// // TSF of 10, 11 and 12 is 13
+/// assert_eq!(fosc.next(12.0).round(), -8.0);
+/// ```
+///
+/// # Links
+///
+/// * [Forecast Oscillator, Fidelity](https://www.fidelity.com/learning-center/trading-investing/technical-analysis/technical-indicator-guide/fo)
+///
+#[doc(alias = "FOSC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ForecastOscillator {
+    period: usize,
+    regression: RollingRegression,
+    forecast: Option<f64>,
+}
+
+impl ForecastOscillator {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                regression: RollingRegression::new(period),
+                forecast: None,
+            }),
+        }
+    }
+}
+
+impl Period for ForecastOscillator {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for ForecastOscillator {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl OutputUnit for ForecastOscillator {
+    type Unit = Percent;
+}
+
+impl Next<f64> for ForecastOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let oscillator = match self.forecast {
+            Some(forecast) if input != 0.0 => (input - forecast) / input * 100.0,
+            _ => 0.0,
+        };
+
+        self.regression.push(input);
+        self.forecast = Some(self.regression.forecast(1.0));
+
+        oscillator
+    }
+}
+
+impl<T: Close> Next<&T> for ForecastOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ForecastOscillator {
+    fn reset(&mut self) {
+        self.regression.clear();
+        self.forecast = None;
+    }
+}
+
+impl Default for ForecastOscillator {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for ForecastOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FOSC({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ForecastOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(ForecastOscillator::new(0).is_err());
+        assert!(ForecastOscillator::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut fosc = ForecastOscillator::new(3).unwrap();
+
+        assert_eq!(fosc.next(2.0), 0.0);
+        // TSF = 2
+        assert_eq!(fosc.next(4.0), 50.0);
+        // TSF = 6
+        assert_eq!(round(fosc.next(9.0)), 33.333);
+        // TSF of 2, 4, 9 = 12
+        assert_eq!(round(fosc.next(5.0)), -140.0);
+        // TSF of 4, 9, 5 = 7
+        assert_eq!(round(fosc.next(7.0)), 0.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut fosc = ForecastOscillator::new(3).unwrap();
+
+        assert_eq!(fosc.next(&Bar::new().close(2)), 0.0);
+        assert_eq!(fosc.next(&Bar::new().close(4)), 50.0);
+    }
+
+    #[test]
+    fn test_next_zero_price() {
+        let mut fosc = ForecastOscillator::new(3).unwrap();
+
+        fosc.next(1.0);
+        assert_eq!(fosc.next(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fosc = ForecastOscillator::new(3).unwrap();
+
+        fosc.next(2.0);
+        fosc.next(4.0);
+
+        fosc.reset();
+        assert_eq!(fosc.next(4.0), 0.0);
+        assert_eq!(fosc.next(2.0), -100.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ForecastOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ForecastOscillator::new(5).unwrap();
+        assert_eq!(format!("{}", indicator), "FOSC(5)");
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams Fractals.
+///
+/// Developed by Bill Williams, a fractal marks a local turning point: an up fractal is a bar
+/// whose high is above the highs of the _span_ bars on each side, a down fractal a bar whose
+/// low is below the lows of the _span_ bars on each side. A fractal is only confirmed once the
+/// _span_ bars after it are known, so it is reported _span_ bars late, with its offset.
+///
+/// # Formula
+///
+/// up fractal at t - n when high<sub>t-n</sub> > high<sub>i</sub> for every other i from
+/// t - 2n to t
+///
+/// down fractal at t - n when low<sub>t-n</sub> < low<sub>i</sub> for every other i from
+/// t - 2n to t
+///
+/// Where _n_ is the _span_. Equal highs or lows do not make a fractal.
+///
+/// # Parameters
+///
+/// * _span_ - number of bars on each side of a fractal (integer greater than 0). Default is 2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Fractals;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64| {
+///     DataItem::builder()
+///         .open(low)
+///         .high(high)
+///         .low(low)
+///         .close(high)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut fractals = Fractals::new(2).unwrap();
+/// assert!(fractals.next(&bar(10.0, 9.0)).is_none());
+/// assert!(fractals.next(&bar(11.0, 10.0)).is_none());
+/// assert!(fractals.next(&bar(13.0, 11.0)).is_none());
+/// assert!(fractals.next(&bar(12.0, 10.5)).is_none());
+///
+/// let point = fractals.next(&bar(11.5, 10.0)).unwrap();
+/// assert_eq!(point.up, Some(13.0));
+/// assert_eq!(point.down, None);
+/// assert_eq!(point.offset, 2);
+/// ```
+///
+/// # Links
+///
+/// * [Fractals, Investopedia](https://www.investopedia.com/terms/f/fractal.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Fractals {
+    span: usize,
+    // Highs and lows of the last 2 * span + 1 bars.
+    window: VecDeque<(f64, f64)>,
+}
+
+/// Fractal confirmed by [Fractals](struct.Fractals.html).
+///
+/// A bar can be both an up and a down fractal, at least one of them is set.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FractalPoint {
+    /// High of the bar, when it is an up fractal.
+    pub up: Option<f64>,
+    /// Low of the bar, when it is a down fractal.
+    pub down: Option<f64>,
+    /// Number of bars between the fractal and the current bar, the _span_.
+    pub offset: usize,
+}
+
+impl Fractals {
+    pub fn new(span: usize) -> Result<Self> {
+        match span {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                span,
+                window: VecDeque::with_capacity(2 * span + 1),
+            }),
+        }
+    }
+
+    pub fn span(&self) -> usize {
+        self.span
+    }
+}
+
+impl Period for Fractals {
+    fn period(&self) -> usize {
+        2 * self.span + 1
+    }
+}
+
+impl Lookback for Fractals {
+    fn lookback(&self) -> usize {
+        2 * self.span
+    }
+}
+
+impl<T: High + Low> Next<&T> for Fractals {
+    type Output = Option<FractalPoint>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        if self.window.len() == 2 * self.span + 1 {
+            self.window.pop_front();
+        }
+        self.window.push_back((input.high(), input.low()));
+        if self.window.len() < 2 * self.span + 1 {
+            return None;
+        }
+
+        let (high, low) = self.window[self.span];
+        let others = || {
+            self.window
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != self.span)
+                .map(|(_, bar)| bar)
+        };
+        let up = others().all(|&(other, _)| high > other).then_some(high);
+        let down = others().all(|&(_, other)| low < other).then_some(low);
+
+        if up.is_some() || down.is_some() {
+            Some(FractalPoint {
+                up,
+                down,
+                offset: self.span,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl Reset for Fractals {
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+impl Default for Fractals {
+    fn default() -> Self {
+        Self::new(2).unwrap()
+    }
+}
+
+impl fmt::Display for Fractals {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FRACTALS({})", self.span)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64) -> Bar {
+        Bar::new().high(high).low(low)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Fractals::new(0).is_err());
+        assert!(Fractals::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut fractals = Fractals::new(1).unwrap();
+
+        assert_eq!(fractals.next(&bar(10.0, 9.0)), None);
+        assert_eq!(fractals.next(&bar(12.0, 10.0)), None);
+        // up fractal at 12
+        assert_eq!(
+            fractals.next(&bar(11.0, 8.0)),
+            Some(FractalPoint {
+                up: Some(12.0),
+                down: None,
+                offset: 1
+            })
+        );
+        // down fractal at 8
+        assert_eq!(
+            fractals.next(&bar(11.0, 9.0)),
+            Some(FractalPoint {
+                up: None,
+                down: Some(8.0),
+                offset: 1
+            })
+        );
+        // 11 ties with the previous high
+        assert_eq!(fractals.next(&bar(11.0, 9.0)), None);
+    }
+
+    #[test]
+    fn test_next_both() {
+        let mut fractals = Fractals::new(1).unwrap();
+
+        fractals.next(&bar(10.0, 9.0));
+        fractals.next(&bar(11.0, 8.0));
+        let point = fractals.next(&bar(10.5, 8.5)).unwrap();
+        assert_eq!((point.up, point.down), (Some(11.0), Some(8.0)));
+    }
+
+    #[test]
+    fn test_next_ties() {
+        let mut fractals = Fractals::new(2).unwrap();
+
+        for &(high, low) in &[
+            (10.0, 9.0),
+            (12.0, 9.5),
+            (12.0, 10.0),
+            (11.0, 9.6),
+            (10.5, 9.8),
+        ] {
+            assert_eq!(fractals.next(&bar(high, low)), None);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fractals = Fractals::new(1).unwrap();
+        fractals.next(&bar(10.0, 9.0));
+        fractals.next(&bar(12.0, 10.0));
+
+        fractals.reset();
+        assert_eq!(fractals.next(&bar(11.0, 8.0)), None);
+        assert_eq!(fractals.next(&bar(12.0, 10.0)), None);
+        assert!(fractals.next(&bar(11.0, 9.0)).is_some());
+    }
+
+    #[test]
+    fn test_lookback() {
+        let fractals = Fractals::default();
+        assert_eq!(fractals.span(), 2);
+        assert_eq!(fractals.period(), 5);
+        assert_eq!(fractals.lookback(), 4);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Fractals::new(2).unwrap()), "FRACTALS(2)");
+    }
+}
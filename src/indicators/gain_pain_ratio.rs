@@ -0,0 +1,186 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling gain to pain ratio (GPR).
+///
+/// Sum of the returns over the last _period_ periods divided by the absolute sum of the
+/// negative ones, as popularized by Jack Schwager. It weighs the net gain against all the
+/// losses suffered on the way, so a ratio of 1 means the strategy endured as much losses as it
+/// made net profit. Values above 1 are good, negative values mean a net loss.
+///
+/// The inputs are returns, e.g. the monthly percentage changes of a strategy.
+///
+/// # Formula
+///
+/// GPR = Σ r<sub>i</sub> / Σ |min(r<sub>i</sub>, 0)|
+///
+/// When there are no losses in the window, the ratio is infinite, or 0 if all the returns are 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 12.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::GainPainRatio;
+/// use ta::Next;
+///
+/// let mut gpr = GainPainRatio::new(4).unwrap();
+/// gpr.next(0.02);
+/// gpr.next(-0.01);
+/// assert_eq!((gpr.next(0.03) * 1000.0).round(), 4000.0);
+/// ```
+///
+#[doc(alias = "GPR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct GainPainRatio {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl GainPainRatio {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for GainPainRatio {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for GainPainRatio {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for GainPainRatio {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        // Summed over the window on every period, so the ratio flips to infinite exactly
+        // when the last loss leaves it.
+        let mut sum = 0.0;
+        let mut pain = 0.0;
+        for &r in self.deque[..self.count].iter() {
+            sum += r;
+            pain -= r.min(0.0);
+        }
+
+        if pain > 0.0 {
+            sum / pain
+        } else if sum > 0.0 {
+            f64::INFINITY
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Reset for GainPainRatio {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for GainPainRatio {
+    fn default() -> Self {
+        Self::new(12).unwrap()
+    }
+}
+
+impl fmt::Display for GainPainRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GPR({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(GainPainRatio::new(0).is_err());
+        assert!(GainPainRatio::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut gpr = GainPainRatio::new(4).unwrap();
+
+        assert_eq!(gpr.next(0.02), f64::INFINITY);
+        assert_eq!(round(gpr.next(-0.01)), 1.0);
+        assert_eq!(round(gpr.next(0.03)), 4.0);
+        assert_eq!(round(gpr.next(-0.04)), 0.0);
+        // 0.02 left the window
+        assert_eq!(round(gpr.next(0.01)), -0.2);
+        // -0.01 left the window
+        assert_eq!(round(gpr.next(0.05)), 1.25);
+    }
+
+    #[test]
+    fn test_next_losses_leave_window() {
+        let mut gpr = GainPainRatio::new(2).unwrap();
+
+        assert_eq!(round(gpr.next(-0.03)), -1.0);
+        assert_eq!(round(gpr.next(0.01)), -0.667);
+        assert_eq!(gpr.next(0.02), f64::INFINITY);
+        assert_eq!(gpr.next(0.0), f64::INFINITY);
+        assert_eq!(gpr.next(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut gpr = GainPainRatio::new(4).unwrap();
+        gpr.next(0.02);
+        gpr.next(-0.01);
+
+        gpr.reset();
+        assert_eq!(gpr.next(-0.01), -1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(GainPainRatio::default().period(), 12);
+    }
+
+    #[test]
+    fn test_display() {
+        let gpr = GainPainRatio::new(12).unwrap();
+        assert_eq!(format!("{}", gpr), "GPR(12)");
+    }
+}
@@ -0,0 +1,197 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage;
+use crate::math;
+use crate::{Close, High, Lookback, Low, Next, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Garman-Klass volatility.
+///
+/// Range-based estimator of the volatility by Mark B. Garman and Michael J. Klass, computed
+/// from the open, high, low and close prices of the last _period_ bars. It extends the
+/// [Parkinson volatility](struct.ParkinsonVolatility.html) with the open-to-close move, which
+/// makes it several times more efficient than the close-to-close volatility. Like Parkinson it
+/// assumes no drift and no gaps between bars.
+///
+/// The output is annualized, e.g. 0.25 for a volatility of 25% a year. The prices must be
+/// positive.
+///
+/// # Formula
+///
+/// σ<sup>2</sup><sub>i</sub> = 0.5 * ln(H<sub>i</sub> / L<sub>i</sub>)<sup>2</sup> - (2 * ln(2) - 1) * ln(C<sub>i</sub> / O<sub>i</sub>)<sup>2</sup>
+///
+/// σ = √(Σ σ<sup>2</sup><sub>i</sub> / n * _bars_per_year_)
+///
+/// Where the sum runs over the _n_ bars of the window.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 20.
+/// * _bars_per_year_ - number of bars in a year, e.g. 252 for daily bars of stocks or 52 for
+///   weekly bars (greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::GarmanKlassVolatility;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |open: f64, high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut gk = GarmanKlassVolatility::new(2, 252.0).unwrap();
+/// assert_eq!((gk.next(&bar(100.0, 102.0, 99.0, 101.0)) * 1000.0).round(), 320.0);
+/// assert_eq!((gk.next(&bar(101.0, 101.5, 98.0, 98.5)) * 1000.0).round(), 314.0);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct GarmanKlassVolatility {
+    bars_per_year: f64,
+    sma: SimpleMovingAverage,
+}
+
+impl GarmanKlassVolatility {
+    pub fn new(period: usize, bars_per_year: f64) -> Result<Self> {
+        if bars_per_year <= 0.0 || !bars_per_year.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            bars_per_year,
+            sma: SimpleMovingAverage::new(period)?,
+        })
+    }
+
+    pub fn bars_per_year(&self) -> f64 {
+        self.bars_per_year
+    }
+}
+
+impl Period for GarmanKlassVolatility {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Lookback for GarmanKlassVolatility {
+    fn lookback(&self) -> usize {
+        self.sma.lookback()
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for GarmanKlassVolatility {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let range = math::ln(input.high() / input.low());
+        let body = math::ln(input.close() / input.open());
+        let variance = self
+            .sma
+            .next(0.5 * range * range - (2.0 * std::f64::consts::LN_2 - 1.0) * body * body);
+        // Only a bar with its open or close outside its range can weigh negatively.
+        (variance.max(0.0) * self.bars_per_year).sqrt()
+    }
+}
+
+impl Reset for GarmanKlassVolatility {
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
+
+impl Default for GarmanKlassVolatility {
+    fn default() -> Self {
+        Self::new(20, 252.0).unwrap()
+    }
+}
+
+impl fmt::Display for GarmanKlassVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GK({}, {})", self.sma.period(), self.bars_per_year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(GarmanKlassVolatility::new(0, 252.0).is_err());
+        assert!(GarmanKlassVolatility::new(20, 0.0).is_err());
+        assert!(GarmanKlassVolatility::new(20, f64::NAN).is_err());
+        assert!(GarmanKlassVolatility::new(1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut gk = GarmanKlassVolatility::new(3, 252.0).unwrap();
+
+        assert_eq!(round(gk.next(&bar(100.0, 102.0, 99.0, 101.0))), 0.32);
+        assert_eq!(round(gk.next(&bar(101.0, 101.5, 98.0, 98.5))), 0.314);
+        assert_eq!(round(gk.next(&bar(98.5, 103.0, 98.5, 103.0))), 0.291);
+        // the first bar left the window
+        assert_eq!(round(gk.next(&bar(103.0, 104.0, 102.0, 103.5))), 0.256);
+    }
+
+    #[test]
+    fn test_next_full_range_body() {
+        // The close to open move takes the whole range, the bar weighs less than Parkinson.
+        let mut gk = GarmanKlassVolatility::new(1, 1.0).unwrap();
+        let out = gk.next(&bar(100.0, 110.0, 100.0, 110.0));
+        let ln = (1.1f64).ln();
+        assert_eq!(
+            round(out),
+            round(((1.5 - 2.0 * 2f64.ln()) * ln * ln).sqrt())
+        );
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut gk = GarmanKlassVolatility::new(3, 252.0).unwrap();
+        assert_eq!(gk.next(&bar(100.0, 100.0, 100.0, 100.0)), 0.0);
+        assert_eq!(gk.next(&bar(100.0, 100.0, 100.0, 100.0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut gk = GarmanKlassVolatility::new(3, 252.0).unwrap();
+        gk.next(&bar(98.5, 103.0, 98.5, 103.0));
+
+        gk.reset();
+        assert_eq!(round(gk.next(&bar(100.0, 102.0, 99.0, 101.0))), 0.32);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let gk = GarmanKlassVolatility::new(20, 252.0).unwrap();
+        assert_eq!(gk.period(), 20);
+        assert_eq!(gk.lookback(), 19);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(GarmanKlassVolatility::default().bars_per_year(), 252.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let gk = GarmanKlassVolatility::new(20, 252.0).unwrap();
+        assert_eq!(format!("{}", gk), "GK(20, 252)");
+    }
+}
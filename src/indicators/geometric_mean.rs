@@ -0,0 +1,219 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::math;
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling geometric mean.
+///
+/// The n-th root of the product of the last _period_ inputs. It is the right average of
+/// ratios and growth factors, e.g. `1 + return`, where the arithmetic mean overstates the growth:
+/// a gain of 100% followed by a loss of 50% averages 25% arithmetically but 0% geometrically.
+/// The product is computed as a sum of logarithms, so it neither overflows nor underflows.
+///
+/// # Formula
+///
+/// GM = exp(Σ ln(x<sub>i</sub>) / n)
+///
+/// Where the sum and the count run over the last _period_ inputs. The inputs must be greater than 0; while
+/// the window holds an input less than or equal to 0 (or NaN), the output is NaN.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::GeometricMean;
+/// use ta::Next;
+///
+/// let mut mean = GeometricMean::new(2).unwrap();
+/// assert_eq!(mean.next(2.0), 2.0);
+/// assert_eq!(mean.next(0.5).round(), 1.0);
+/// assert_eq!(mean.next(8.0).round(), 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Geometric mean, Wikipedia](https://en.wikipedia.org/wiki/Geometric_mean)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct GeometricMean {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+    log_sum: f64,
+    // Number of inputs in the window that are not greater than 0.
+    invalid: usize,
+}
+
+impl GeometricMean {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+                log_sum: 0.0,
+                invalid: 0,
+            }),
+        }
+    }
+}
+
+impl NewWithPeriod for GeometricMean {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl Period for GeometricMean {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for GeometricMean {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for GeometricMean {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.count == self.period {
+            let old = self.deque[self.index];
+            if old > 0.0 {
+                self.log_sum -= math::ln(old);
+            } else {
+                self.invalid -= 1;
+            }
+        } else {
+            self.count += 1;
+        }
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        // Invalid inputs are kept out of the sum, so it recovers once they leave the window.
+        if input > 0.0 {
+            self.log_sum += math::ln(input);
+        } else {
+            self.invalid += 1;
+        }
+
+        if self.invalid > 0 {
+            return f64::NAN;
+        }
+        math::exp(self.log_sum / self.count as f64)
+    }
+}
+
+impl<T: Close> Next<&T> for GeometricMean {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for GeometricMean {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.log_sum = 0.0;
+        self.invalid = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for GeometricMean {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for GeometricMean {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GM({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(GeometricMean);
+
+    #[test]
+    fn test_new() {
+        assert!(GeometricMean::new(0).is_err());
+        assert!(GeometricMean::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mean = GeometricMean::new(3).unwrap();
+        assert_eq!(mean.next(2.0), 2.0);
+        assert_eq!(round(mean.next(8.0)), 4.0);
+        assert_eq!(round(mean.next(4.0)), 4.0);
+        assert_eq!(round(mean.next(1.0)), 3.175);
+        assert_eq!(round(mean.next(1e300) / 1e100), 1.587);
+        assert_eq!(round(mean.next(1e-300)), 1.0);
+    }
+
+    #[test]
+    fn test_next_invalid() {
+        let mut mean = GeometricMean::new(2).unwrap();
+        assert_eq!(mean.next(4.0), 4.0);
+        assert!(mean.next(0.0).is_nan());
+        assert!(mean.next(4.0).is_nan());
+        assert!(mean.next(-1.0).is_nan());
+        assert!(mean.next(f64::NAN).is_nan());
+        assert!(mean.next(4.0).is_nan());
+        assert_eq!(round(mean.next(4.0)), 4.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut mean = GeometricMean::new(2).unwrap();
+        assert_eq!(mean.next(&Bar::new().close(2)), 2.0);
+        assert_eq!(round(mean.next(&Bar::new().close(8))), 4.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mean = GeometricMean::new(2).unwrap();
+        mean.next(0.0);
+
+        mean.reset();
+        assert_eq!(round(mean.next(5.0)), 5.0);
+    }
+
+    #[test]
+    fn test_default() {
+        GeometricMean::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = GeometricMean::new(7).unwrap();
+        assert_eq!(format!("{}", indicator), "GM(7)");
+    }
+}
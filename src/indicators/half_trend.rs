@@ -0,0 +1,340 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{
+    AverageTrueRange, Maximum, Minimum, RunningMovingAverage, SimpleMovingAverage,
+};
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const ATR_PERIOD: usize = 100;
+
+/// Direction of a trend following indicator.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendDirection {
+    Up,
+    Down,
+}
+
+/// HalfTrend.
+///
+/// A trend following indicator, popularized on TradingView, that draws a stepped trend line
+/// from the highest highs and lowest lows of the last _amplitude_ periods. The trend flips once
+/// the moving average of highs (lows) crosses the extreme the line is currently tracking and the
+/// close confirms the move. A channel of ATR deviations is drawn around the line.
+///
+/// # Formula
+///
+/// While in an uptrend the line is the running maximum of the lowest low over _amplitude_ periods;
+/// the trend flips down when SMA(_amplitude_) of highs drops below that level and close is below
+/// the previous low. The downtrend is symmetrical.
+///
+/// * _upper_ = HalfTrend + ATR(100) / 2 * _channel_deviation_
+/// * _lower_ = HalfTrend - ATR(100) / 2 * _channel_deviation_
+///
+/// The ATR is smoothed with Wilder's [RMA](struct.RunningMovingAverage.html), like the original
+/// script.
+///
+/// # Parameters
+///
+/// * _amplitude_ - number of periods used to find extremes (integer greater than 0). Default is 2.
+/// * _channel_deviation_ - multiplier of the half ATR channel (non negative). Default is 2.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{HalfTrend, TrendDirection};
+/// use ta::{DataItem, Next};
+///
+/// let mut ht = HalfTrend::new(2, 2.0).unwrap();
+/// let bar = DataItem::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let out = ht.next(&bar);
+/// assert_eq!(out.half_trend, 9.0);
+/// assert_eq!(out.direction, TrendDirection::Up);
+/// assert!(!out.buy_signal);
+/// ```
+///
+/// # Links
+///
+/// * [HalfTrend, TradingView](https://www.tradingview.com/script/U1SJ8ubc-HalfTrend/)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HalfTrend {
+    amplitude: usize,
+    channel_deviation: f64,
+    atr: AverageTrueRange<RunningMovingAverage>,
+    highest: Maximum,
+    lowest: Minimum,
+    high_sma: SimpleMovingAverage,
+    low_sma: SimpleMovingAverage,
+    trend: TrendDirection,
+    next_trend: TrendDirection,
+    prev_trend: Option<TrendDirection>,
+    max_low_price: f64,
+    min_high_price: f64,
+    up: Option<f64>,
+    down: Option<f64>,
+    prev_high: Option<f64>,
+    prev_low: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HalfTrendOutput {
+    pub half_trend: f64,
+    pub upper: f64,
+    pub lower: f64,
+    pub direction: TrendDirection,
+    pub buy_signal: bool,
+    pub sell_signal: bool,
+}
+
+impl HalfTrend {
+    pub fn new(amplitude: usize, channel_deviation: f64) -> Result<Self> {
+        if !(channel_deviation >= 0.0 && channel_deviation.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            amplitude,
+            channel_deviation,
+            atr: AverageTrueRange::with_ma(ATR_PERIOD)?,
+            highest: Maximum::new(amplitude)?,
+            lowest: Minimum::new(amplitude)?,
+            high_sma: SimpleMovingAverage::new(amplitude)?,
+            low_sma: SimpleMovingAverage::new(amplitude)?,
+            trend: TrendDirection::Up,
+            next_trend: TrendDirection::Up,
+            prev_trend: None,
+            max_low_price: 0.0,
+            min_high_price: 0.0,
+            up: None,
+            down: None,
+            prev_high: None,
+            prev_low: None,
+        })
+    }
+
+    pub fn channel_deviation(&self) -> f64 {
+        self.channel_deviation
+    }
+}
+
+impl Period for HalfTrend {
+    fn period(&self) -> usize {
+        self.amplitude
+    }
+}
+
+impl Lookback for HalfTrend {
+    fn lookback(&self) -> usize {
+        self.atr.lookback().max(self.highest.lookback())
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for HalfTrend {
+    type Output = HalfTrendOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low, close) = (input.high(), input.low(), input.close());
+
+        let atr2 = self.atr.next(input) / 2.0;
+        let dev = self.channel_deviation * atr2;
+        let high_price = self.highest.next(high);
+        let low_price = self.lowest.next(low);
+        let high_ma = self.high_sma.next(high);
+        let low_ma = self.low_sma.next(low);
+
+        let prev_high = self.prev_high.unwrap_or(high);
+        let prev_low = self.prev_low.unwrap_or(low);
+        if self.prev_trend.is_none() {
+            self.max_low_price = prev_low;
+            self.min_high_price = prev_high;
+        }
+
+        match self.next_trend {
+            TrendDirection::Down => {
+                self.max_low_price = self.max_low_price.max(low_price);
+                if high_ma < self.max_low_price && close < prev_low {
+                    self.trend = TrendDirection::Down;
+                    self.next_trend = TrendDirection::Up;
+                    self.min_high_price = high_price;
+                }
+            }
+            TrendDirection::Up => {
+                self.min_high_price = self.min_high_price.min(high_price);
+                if low_ma > self.min_high_price && close > prev_high {
+                    self.trend = TrendDirection::Up;
+                    self.next_trend = TrendDirection::Down;
+                    self.max_low_price = low_price;
+                }
+            }
+        }
+
+        let flipped = self.prev_trend.is_some_and(|prev| prev != self.trend);
+        let half_trend = match self.trend {
+            TrendDirection::Up => {
+                let up = if flipped {
+                    self.down.unwrap_or(self.max_low_price)
+                } else {
+                    self.up
+                        .map_or(self.max_low_price, |up| up.max(self.max_low_price))
+                };
+                self.up = Some(up);
+                up
+            }
+            TrendDirection::Down => {
+                let down = if flipped {
+                    self.up.unwrap_or(self.min_high_price)
+                } else {
+                    self.down
+                        .map_or(self.min_high_price, |down| down.min(self.min_high_price))
+                };
+                self.down = Some(down);
+                down
+            }
+        };
+
+        self.prev_trend = Some(self.trend);
+        self.prev_high = Some(high);
+        self.prev_low = Some(low);
+
+        HalfTrendOutput {
+            half_trend,
+            upper: half_trend + dev,
+            lower: half_trend - dev,
+            direction: self.trend,
+            buy_signal: flipped && self.trend == TrendDirection::Up,
+            sell_signal: flipped && self.trend == TrendDirection::Down,
+        }
+    }
+}
+
+impl Reset for HalfTrend {
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.highest.reset();
+        self.lowest.reset();
+        self.high_sma.reset();
+        self.low_sma.reset();
+        self.trend = TrendDirection::Up;
+        self.next_trend = TrendDirection::Up;
+        self.prev_trend = None;
+        self.max_low_price = 0.0;
+        self.min_high_price = 0.0;
+        self.up = None;
+        self.down = None;
+        self.prev_high = None;
+        self.prev_low = None;
+    }
+}
+
+impl Default for HalfTrend {
+    fn default() -> Self {
+        Self::new(2, 2.0).unwrap()
+    }
+}
+
+impl fmt::Display for HalfTrend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "HALFTREND({}, {})",
+            self.amplitude, self.channel_deviation
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(HalfTrend::new(0, 2.0).is_err());
+        assert!(HalfTrend::new(2, -1.0).is_err());
+        assert!(HalfTrend::new(2, f64::NAN).is_err());
+        assert!(HalfTrend::new(2, f64::INFINITY).is_err());
+        assert!(HalfTrend::new(1, 0.0).is_ok());
+        assert!(HalfTrend::new(2, 2.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ht = HalfTrend::new(2, 2.0).unwrap();
+
+        let test_data = vec![
+            // high, low, close, half_trend, direction, buy, sell
+            (11.0, 9.0, 10.5, 9.0, TrendDirection::Up, false, false),
+            (12.0, 10.0, 11.5, 9.0, TrendDirection::Up, false, false),
+            (13.0, 11.0, 12.5, 9.0, TrendDirection::Up, false, false),
+            (14.0, 12.0, 13.5, 11.0, TrendDirection::Up, false, false),
+            (12.0, 10.0, 10.5, 11.0, TrendDirection::Up, false, false),
+            (10.0, 8.0, 8.5, 11.0, TrendDirection::Up, false, false),
+            (9.0, 7.0, 7.5, 11.0, TrendDirection::Down, false, true),
+            (12.0, 10.0, 11.5, 10.0, TrendDirection::Down, false, false),
+            (14.0, 12.0, 13.5, 10.0, TrendDirection::Up, true, false),
+        ];
+
+        for (high, low, close, value, direction, buy, sell) in test_data {
+            let bar = Bar::new().high(high).low(low).close(close);
+            let out = ht.next(&bar);
+            assert_eq!(out.half_trend, value);
+            assert_eq!(out.direction, direction);
+            assert_eq!(out.buy_signal, buy);
+            assert_eq!(out.sell_signal, sell);
+        }
+    }
+
+    #[test]
+    fn test_channel() {
+        let mut ht = HalfTrend::new(2, 2.0).unwrap();
+
+        let bar = Bar::new().high(11).low(9).close(10.5);
+        let out = ht.next(&bar);
+        // ATR of the first bar is its range: 2.0, half of it multiplied by deviation is 2.0
+        assert_eq!(out.upper, 11.0);
+        assert_eq!(out.lower, 7.0);
+
+        // Wilder's smoothing of a true range of 4.0: 2.0 + (4.0 - 2.0) / 100
+        let out = ht.next(&Bar::new().high(14).low(10).close(12));
+        assert_eq!(round(out.upper - out.half_trend), 2.02);
+        assert_eq!(round(out.half_trend - out.lower), 2.02);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ht = HalfTrend::new(2, 2.0).unwrap();
+
+        let bar1 = Bar::new().high(11).low(9).close(10.5);
+        let bar2 = Bar::new().high(12).low(10).close(11.5);
+
+        let out1 = ht.next(&bar1);
+        let out2 = ht.next(&bar2);
+
+        ht.reset();
+        assert_eq!(ht.next(&bar1), out1);
+        assert_eq!(ht.next(&bar2), out2);
+    }
+
+    #[test]
+    fn test_default() {
+        HalfTrend::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = HalfTrend::new(3, 2.5).unwrap();
+        assert_eq!(format!("{}", indicator), "HALFTREND(3, 2.5)");
+    }
+}
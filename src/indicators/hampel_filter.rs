@@ -0,0 +1,236 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::sorted_window::SortedWindow;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Scales the median absolute deviation to the standard deviation of normally distributed data.
+const MAD_SCALE: f64 = 1.4826;
+
+/// Hampel filter.
+///
+/// A robust outlier detector for cleaning data before it reaches indicators with a long memory,
+/// such as the EMA, where a single bad tick lingers for many periods. An input is an outlier
+/// when it is further than _multiplier_ robust standard deviations away from the median of the
+/// last _period_ inputs, itself included. The median and the median absolute deviation (MAD)
+/// are not moved by the outliers, unlike the mean and the standard deviation.
+///
+/// The output flags outliers and carries the cleaned value: the median for an outlier, the
+/// input otherwise. Feed the cleaned value to the next indicator to replace outliers, or the
+/// input to only flag them. The window keeps the original inputs.
+///
+/// # Formula
+///
+/// σ = 1.4826 * median(|x<sub>i</sub> - median(x)|)
+///
+/// outlier = |x - median(x)| > _multiplier_ * σ
+///
+/// Where _x_ are the last _period_ inputs. When more than half of the window is equal, σ is 0
+/// and any other value is an outlier.
+///
+/// # Parameters
+///
+/// * _period_ - number of inputs in the window (integer greater than 0). Default is 7.
+/// * _multiplier_ - number of robust standard deviations beyond which an input is an outlier
+///   (greater than 0). Default is 3.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ExponentialMovingAverage, HampelFilter};
+/// use ta::Next;
+///
+/// let mut hampel = HampelFilter::new(5, 3.0).unwrap();
+/// let mut ema = ExponentialMovingAverage::new(3).unwrap();
+///
+/// for price in &[1.0, 2.0, 3.0, 4.0] {
+///     ema.next(hampel.next(*price).value);
+/// }
+///
+/// // A bad tick is replaced by the median.
+/// let out = hampel.next(100.0);
+/// assert!(out.outlier);
+/// assert_eq!(out.value, 3.0);
+/// ema.next(out.value);
+/// ```
+///
+/// # Links
+///
+/// * [Hampel filter, Wikipedia](https://en.wikipedia.org/wiki/Median_absolute_deviation)
+///
+#[doc(alias = "MAD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HampelFilter {
+    period: usize,
+    multiplier: f64,
+    window: SortedWindow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HampelFilterOutput {
+    /// The median for an outlier, the input otherwise.
+    pub value: f64,
+    /// Median of the window.
+    pub median: f64,
+    pub outlier: bool,
+}
+
+impl HampelFilter {
+    pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        if period == 0 || multiplier.is_nan() || multiplier <= 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            multiplier,
+            window: SortedWindow::new(period),
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+}
+
+impl Period for HampelFilter {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for HampelFilter {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for HampelFilter {
+    type Output = HampelFilterOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.window.push(input);
+        let median = self.window.quantile(0.5);
+        let sigma = MAD_SCALE * self.window.median_absolute_deviation();
+        let outlier = (input - median).abs() > self.multiplier * sigma;
+
+        HampelFilterOutput {
+            value: if outlier { median } else { input },
+            median,
+            outlier,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for HampelFilter {
+    type Output = HampelFilterOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for HampelFilter {
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+impl Default for HampelFilter {
+    fn default() -> Self {
+        Self::new(7, 3.0).unwrap()
+    }
+}
+
+impl fmt::Display for HampelFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HAMPEL({}, {})", self.period, self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(HampelFilter::new(0, 3.0).is_err());
+        assert!(HampelFilter::new(5, 0.0).is_err());
+        assert!(HampelFilter::new(5, f64::NAN).is_err());
+        assert!(HampelFilter::new(1, 3.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut hampel = HampelFilter::new(5, 3.0).unwrap();
+
+        let test_data = vec![
+            // input, value, median, outlier
+            (1.0, 1.0, 1.0, false),
+            (2.0, 2.0, 1.5, false),
+            (3.0, 3.0, 2.0, false),
+            (4.0, 4.0, 2.5, false),
+            // median 3, MAD 1
+            (100.0, 3.0, 3.0, true),
+            // 2, 3, 4, 100, 5: median 4, MAD 1
+            (5.0, 5.0, 4.0, false),
+            // 3, 4, 100, 5, 9: median 5, MAD 2, 9 is within 3 * 2.965
+            (9.0, 9.0, 5.0, false),
+        ];
+
+        for (input, value, median, outlier) in test_data {
+            let out = hampel.next(input);
+            assert_eq!(out.value, value);
+            assert_eq!(out.median, median);
+            assert_eq!(out.outlier, outlier);
+        }
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut hampel = HampelFilter::new(3, 3.0).unwrap();
+        hampel.next(5.0);
+        hampel.next(5.0);
+
+        let out = hampel.next(5.1);
+        assert!(out.outlier);
+        assert_eq!(out.value, 5.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut hampel = HampelFilter::new(3, 2.0).unwrap();
+        hampel.next(&Bar::new().close(10));
+        hampel.next(&Bar::new().close(11));
+
+        let out = hampel.next(&Bar::new().close(30));
+        assert!(out.outlier);
+        assert_eq!(out.value, 11.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hampel = HampelFilter::new(3, 3.0).unwrap();
+        hampel.next(5.0);
+        hampel.next(5.0);
+
+        hampel.reset();
+        let out = hampel.next(8.0);
+        assert!(!out.outlier);
+        assert_eq!(out.median, 8.0);
+    }
+
+    #[test]
+    fn test_default() {
+        HampelFilter::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = HampelFilter::new(7, 3.0).unwrap();
+        assert_eq!(format!("{}", indicator), "HAMPEL(7, 3)");
+    }
+}
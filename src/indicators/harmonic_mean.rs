@@ -0,0 +1,217 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling harmonic mean.
+///
+/// The number of inputs divided by the sum of their reciprocals, over the last _period_
+/// inputs. It is the right average of rates and multiples, e.g. price to earnings ratios or
+/// prices paid with a fixed amount of money, where the arithmetic mean gives too much weight to
+/// the large values.
+///
+/// # Formula
+///
+/// HM = n / Σ (1 / x<sub>i</sub>)
+///
+/// Where the sum and the count run over the last _period_ inputs. The inputs must be greater than 0; while
+/// the window holds an input less than or equal to 0 (or NaN), the output is NaN.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::HarmonicMean;
+/// use ta::Next;
+///
+/// let mut mean = HarmonicMean::new(2).unwrap();
+/// assert_eq!(mean.next(2.0), 2.0);
+/// assert_eq!(mean.next(6.0), 3.0);
+/// assert_eq!(mean.next(3.0).round(), 4.0);
+/// ```
+///
+/// # Links
+///
+/// * [Harmonic mean, Wikipedia](https://en.wikipedia.org/wiki/Harmonic_mean)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HarmonicMean {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+    inverse_sum: f64,
+    // Number of inputs in the window that are not greater than 0.
+    invalid: usize,
+}
+
+impl HarmonicMean {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+                inverse_sum: 0.0,
+                invalid: 0,
+            }),
+        }
+    }
+}
+
+impl NewWithPeriod for HarmonicMean {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl Period for HarmonicMean {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for HarmonicMean {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for HarmonicMean {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.count == self.period {
+            let old = self.deque[self.index];
+            if old > 0.0 {
+                self.inverse_sum -= 1.0 / old;
+            } else {
+                self.invalid -= 1;
+            }
+        } else {
+            self.count += 1;
+        }
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        // Invalid inputs are kept out of the sum, so it recovers once they leave the window.
+        if input > 0.0 {
+            self.inverse_sum += 1.0 / input;
+        } else {
+            self.invalid += 1;
+        }
+
+        if self.invalid > 0 {
+            return f64::NAN;
+        }
+        self.count as f64 / self.inverse_sum
+    }
+}
+
+impl<T: Close> Next<&T> for HarmonicMean {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for HarmonicMean {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.inverse_sum = 0.0;
+        self.invalid = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for HarmonicMean {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for HarmonicMean {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HM({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(HarmonicMean);
+
+    #[test]
+    fn test_new() {
+        assert!(HarmonicMean::new(0).is_err());
+        assert!(HarmonicMean::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mean = HarmonicMean::new(3).unwrap();
+        assert_eq!(mean.next(2.0), 2.0);
+        assert_eq!(mean.next(6.0), 3.0);
+        assert_eq!(round(mean.next(3.0)), 3.0);
+        assert_eq!(round(mean.next(1.0)), 2.0);
+        assert_eq!(round(mean.next(12.0)), 2.118);
+    }
+
+    #[test]
+    fn test_next_invalid() {
+        let mut mean = HarmonicMean::new(2).unwrap();
+        assert_eq!(mean.next(4.0), 4.0);
+        assert!(mean.next(0.0).is_nan());
+        assert!(mean.next(4.0).is_nan());
+        assert!(mean.next(-1.0).is_nan());
+        assert!(mean.next(f64::NAN).is_nan());
+        assert!(mean.next(4.0).is_nan());
+        assert_eq!(round(mean.next(4.0)), 4.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut mean = HarmonicMean::new(2).unwrap();
+        assert_eq!(mean.next(&Bar::new().close(2)), 2.0);
+        assert_eq!(mean.next(&Bar::new().close(6)), 3.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mean = HarmonicMean::new(2).unwrap();
+        mean.next(0.0);
+
+        mean.reset();
+        assert_eq!(mean.next(5.0), 5.0);
+    }
+
+    #[test]
+    fn test_default() {
+        HarmonicMean::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = HarmonicMean::new(7).unwrap();
+        assert_eq!(format!("{}", indicator), "HM(7)");
+    }
+}
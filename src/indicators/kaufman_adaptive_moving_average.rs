@@ -0,0 +1,237 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::EfficiencyRatio;
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kaufman's adaptive moving average (KAMA).
+///
+/// Developed by Perry Kaufman, KAMA is an exponential moving average whose smoothing constant
+/// follows the [efficiency ratio](struct.EfficiencyRatio.html) of the price: it follows the
+/// price closely when the market trends, like an EMA of _fast_period_, and barely moves when the
+/// market is choppy, like an EMA of _slow_period_.
+///
+/// # Formula
+///
+/// ER = |p<sub>t</sub> - p<sub>t-n</sub>| / Σ |p<sub>i</sub> - p<sub>i-1</sub>|
+///
+/// SC = (ER * (fast SC - slow SC) + slow SC)<sup>2</sup>
+///
+/// KAMA<sub>t</sub> = KAMA<sub>t-1</sub> + SC * (p<sub>t</sub> - KAMA<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _n_ - _period_, the sum runs over the last _n_ price changes
+/// * _fast SC_ - 2 / (_fast_period_ + 1)
+/// * _slow SC_ - 2 / (_slow_period_ + 1)
+///
+/// The first KAMA is the first price. While there are less than _n_ price changes, the ER is
+/// computed on the available ones. When the price does not move, the ER is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of price changes of the efficiency ratio (integer greater than 0).
+///   Default is 10.
+/// * _fast_period_ - period of the fastest EMA (integer greater than 0). Default is 2.
+/// * _slow_period_ - period of the slowest EMA (integer greater than _fast_period_). Default is 30.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::KaufmanAdaptiveMovingAverage;
+/// use ta::Next;
+///
+/// let mut kama = KaufmanAdaptiveMovingAverage::new(3, 2, 6).unwrap();
+/// assert_eq!(kama.next(10.0), 10.0);
+///
+/// // Efficient move, smoothed like an EMA(2).
+/// assert_eq!(kama.next(19.0), 14.0);
+///
+/// // Back to the start, the efficiency is 0 and KAMA barely moves.
+/// assert_eq!(kama.next(10.0).round(), 14.0);
+/// ```
+///
+/// # Links
+///
+/// * [Kaufman's Adaptive Moving Average, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:kaufman_s_adaptive_moving_average)
+///
+#[doc(alias = "KAMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KaufmanAdaptiveMovingAverage {
+    er: EfficiencyRatio,
+    fast_period: usize,
+    slow_period: usize,
+    fast_sc: f64,
+    slow_sc: f64,
+    current: f64,
+    is_new: bool,
+}
+
+impl KaufmanAdaptiveMovingAverage {
+    pub fn new(period: usize, fast_period: usize, slow_period: usize) -> Result<Self> {
+        if fast_period == 0 || slow_period <= fast_period {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            er: EfficiencyRatio::new(period)?,
+            fast_period,
+            slow_period,
+            fast_sc: 2.0 / (fast_period + 1) as f64,
+            slow_sc: 2.0 / (slow_period + 1) as f64,
+            current: 0.0,
+            is_new: true,
+        })
+    }
+
+    pub fn fast_period(&self) -> usize {
+        self.fast_period
+    }
+
+    pub fn slow_period(&self) -> usize {
+        self.slow_period
+    }
+}
+
+impl NewWithPeriod for KaufmanAdaptiveMovingAverage {
+    /// Creates a KAMA with the default fast and slow periods, 2 and 30.
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period, 2, 30)
+    }
+}
+
+impl Period for KaufmanAdaptiveMovingAverage {
+    fn period(&self) -> usize {
+        self.er.period()
+    }
+}
+
+impl Lookback for KaufmanAdaptiveMovingAverage {
+    fn lookback(&self) -> usize {
+        self.er.lookback()
+    }
+}
+
+impl Next<f64> for KaufmanAdaptiveMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let er = self.er.next(input);
+        if self.is_new {
+            self.is_new = false;
+            self.current = input;
+        } else {
+            let sc = er * (self.fast_sc - self.slow_sc) + self.slow_sc;
+            let sc = sc * sc;
+            self.current += sc * (input - self.current);
+        }
+
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for KaufmanAdaptiveMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for KaufmanAdaptiveMovingAverage {
+    fn reset(&mut self) {
+        self.er.reset();
+        self.current = 0.0;
+        self.is_new = true;
+    }
+}
+
+impl Default for KaufmanAdaptiveMovingAverage {
+    fn default() -> Self {
+        Self::new(10, 2, 30).unwrap()
+    }
+}
+
+impl fmt::Display for KaufmanAdaptiveMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "KAMA({}, {}, {})",
+            self.er.period(),
+            self.fast_period,
+            self.slow_period
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Kama = KaufmanAdaptiveMovingAverage;
+
+    test_indicator!(Kama);
+
+    #[test]
+    fn test_new() {
+        assert!(Kama::new(0, 2, 30).is_err());
+        assert!(Kama::new(10, 0, 30).is_err());
+        assert!(Kama::new(10, 2, 2).is_err());
+        assert!(Kama::new(1, 1, 2).is_ok());
+        assert_eq!(Kama::new_with_period(5).unwrap().slow_period(), 30);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kama = Kama::new(3, 2, 6).unwrap();
+
+        assert_eq!(kama.next(10.0), 10.0);
+        assert_eq!(round(kama.next(11.0)), 10.444);
+        assert_eq!(round(kama.next(12.0)), 11.136);
+        assert_eq!(round(kama.next(11.0)), 11.113);
+        assert_eq!(round(kama.next(11.0)), 11.103);
+        assert_eq!(round(kama.next(13.0)), 11.426);
+        assert_eq!(round(kama.next(15.0)), 13.015);
+        assert_eq!(round(kama.next(14.0)), 13.275);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut kama = Kama::new(3, 2, 6).unwrap();
+        for _ in 0..5 {
+            assert_eq!(kama.next(10.0), 10.0);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut kama = Kama::new(3, 2, 6).unwrap();
+        assert_eq!(kama.next(&Bar::new().close(10)), 10.0);
+        assert_eq!(kama.next(&Bar::new().close(19)), 14.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kama = Kama::new(3, 2, 6).unwrap();
+        kama.next(10.0);
+        kama.next(19.0);
+
+        kama.reset();
+        assert_eq!(kama.next(5.0), 5.0);
+        assert_eq!(kama.next(14.0), 9.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Kama::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Kama::new(10, 2, 30).unwrap();
+        assert_eq!(format!("{}", indicator), "KAMA(10, 2, 30)");
+    }
+}
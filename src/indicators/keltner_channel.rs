@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{AverageTrueRange, ExponentialMovingAverage};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -84,6 +84,12 @@ impl Period for KeltnerChannel {
     }
 }
 
+impl Lookback for KeltnerChannel {
+    fn lookback(&self) -> usize {
+        self.atr.lookback().max(self.ema.lookback())
+    }
+}
+
 impl Next<f64> for KeltnerChannel {
     type Output = KeltnerChannelOutput;
 
@@ -0,0 +1,239 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RateOfChange, SimpleMovingAverage};
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Know Sure Thing (KST).
+///
+/// Developed by Martin Pring, KST is a momentum oscillator that sums four smoothed
+/// [rates of change](struct.RateOfChange.html) of increasing periods, weighted towards the
+/// longer ones, so it reflects the short, intermediate and long term momentum at once. A signal
+/// line is computed as an SMA of KST; crossings of the two are used as signals.
+///
+/// # Formula
+///
+/// KST = Σ _weight<sub>i</sub>_ * SMA(_sma_period<sub>i</sub>_) of ROC(_roc_period<sub>i</sub>_), for i from 1 to 4
+///
+/// signal = SMA(_signal_period_) of KST
+///
+/// # Parameters
+///
+/// * _roc_periods_ - periods of the four ROCs (integers greater than 0). Default is
+///   [10, 15, 20, 30].
+/// * _sma_periods_ - smoothing periods of the four ROCs (integers greater than 0). Default is
+///   [10, 10, 10, 15].
+/// * _weights_ - weights of the four smoothed ROCs. Default is [1.0, 2.0, 3.0, 4.0].
+/// * _signal_period_ - period of the signal line (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::KnowSureThing;
+/// use ta::Next;
+///
+/// let mut kst = KnowSureThing::new([1, 2, 3, 4], [1, 1, 2, 2], [1.0, 2.0, 3.0, 4.0], 2).unwrap();
+///
+/// let out = kst.next(10.0);
+/// assert_eq!((out.kst, out.signal), (0.0, 0.0));
+///
+/// // All the ROCs are 10%, the last two are averaged with their first value, 0.
+/// let out = kst.next(11.0);
+/// assert_eq!(out.kst.round(), 65.0);
+/// assert_eq!(out.signal.round(), 33.0);
+/// ```
+///
+/// # Links
+///
+/// * [Know Sure Thing, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:know_sure_thing_kst)
+///
+#[doc(alias = "KST")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KnowSureThing {
+    rocs: [RateOfChange; 4],
+    smas: [SimpleMovingAverage; 4],
+    weights: [f64; 4],
+    signal: SimpleMovingAverage,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnowSureThingOutput {
+    pub kst: f64,
+    pub signal: f64,
+}
+
+impl KnowSureThing {
+    pub fn new(
+        roc_periods: [usize; 4],
+        sma_periods: [usize; 4],
+        weights: [f64; 4],
+        signal_period: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            rocs: [
+                RateOfChange::new(roc_periods[0])?,
+                RateOfChange::new(roc_periods[1])?,
+                RateOfChange::new(roc_periods[2])?,
+                RateOfChange::new(roc_periods[3])?,
+            ],
+            smas: [
+                SimpleMovingAverage::new(sma_periods[0])?,
+                SimpleMovingAverage::new(sma_periods[1])?,
+                SimpleMovingAverage::new(sma_periods[2])?,
+                SimpleMovingAverage::new(sma_periods[3])?,
+            ],
+            weights,
+            signal: SimpleMovingAverage::new(signal_period)?,
+        })
+    }
+
+    pub fn weights(&self) -> [f64; 4] {
+        self.weights
+    }
+}
+
+impl Lookback for KnowSureThing {
+    fn lookback(&self) -> usize {
+        let kst = self
+            .rocs
+            .iter()
+            .zip(self.smas.iter())
+            .map(|(roc, sma)| roc.lookback() + sma.lookback())
+            .max()
+            .unwrap_or(0);
+        kst + self.signal.lookback()
+    }
+}
+
+impl Next<f64> for KnowSureThing {
+    type Output = KnowSureThingOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let mut kst = 0.0;
+        for i in 0..4 {
+            kst += self.weights[i] * self.smas[i].next(self.rocs[i].next(input));
+        }
+
+        KnowSureThingOutput {
+            kst,
+            signal: self.signal.next(kst),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for KnowSureThing {
+    type Output = KnowSureThingOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for KnowSureThing {
+    fn reset(&mut self) {
+        for roc in self.rocs.iter_mut() {
+            roc.reset();
+        }
+        for sma in self.smas.iter_mut() {
+            sma.reset();
+        }
+        self.signal.reset();
+    }
+}
+
+impl Default for KnowSureThing {
+    fn default() -> Self {
+        Self::new([10, 15, 20, 30], [10, 10, 10, 15], [1.0, 2.0, 3.0, 4.0], 9).unwrap()
+    }
+}
+
+impl fmt::Display for KnowSureThing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "KST(")?;
+        for (roc, sma) in self.rocs.iter().zip(self.smas.iter()) {
+            write!(f, "{}/{}, ", roc.period(), sma.period())?;
+        }
+        write!(f, "{})", self.signal.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn kst() -> KnowSureThing {
+        KnowSureThing::new([1, 2, 3, 4], [1, 1, 2, 2], [1.0, 2.0, 3.0, 4.0], 2).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let weights = [1.0, 2.0, 3.0, 4.0];
+        assert!(KnowSureThing::new([0, 2, 3, 4], [1, 1, 1, 1], weights, 1).is_err());
+        assert!(KnowSureThing::new([1, 2, 3, 4], [1, 1, 1, 0], weights, 1).is_err());
+        assert!(KnowSureThing::new([1, 2, 3, 4], [1, 1, 1, 1], weights, 0).is_err());
+        assert!(KnowSureThing::new([1, 1, 1, 1], [1, 1, 1, 1], weights, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kst = kst();
+
+        let test_data = vec![
+            // input, kst, signal
+            (10.0, 0.0, 0.0),
+            (11.0, 65.0, 32.5),
+            (12.0, 154.091, 109.545),
+            (11.0, 96.667, 125.379),
+            (13.0, 157.121, 126.894),
+            (14.0, 229.056, 193.089),
+            (12.0, 63.511, 146.284),
+        ];
+
+        for (input, expected_kst, expected_signal) in test_data {
+            let out = kst.next(input);
+            assert_eq!(round(out.kst), expected_kst);
+            assert_eq!(round(out.signal), expected_signal);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut kst = kst();
+        kst.next(&Bar::new().close(10));
+        assert_eq!(round(kst.next(&Bar::new().close(11)).kst), 65.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(kst().lookback(), 6);
+        assert_eq!(KnowSureThing::default().lookback(), 52);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kst = kst();
+        kst.next(10.0);
+        kst.next(11.0);
+
+        kst.reset();
+        assert_eq!(kst.next(20.0).kst, 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        KnowSureThing::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = KnowSureThing::default();
+        assert_eq!(
+            format!("{}", indicator),
+            "KST(10/10, 15/10, 20/10, 30/15, 9)"
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::rolling_regression::RollingRegression;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling linear regression.
+///
+/// Fits a least squares line to the last _period_ prices and returns its slope, its intercept,
+/// its value at the most recent price and its forecast for the next period. The slope is a
+/// trend filter: positive in an uptrend, negative in a downtrend. The sums of the regression
+/// are updated incrementally, so every update is `O(1)`.
+///
+/// # Formula
+///
+/// The prices are placed at x = 0, 1, ..., n - 1 from the oldest to the most recent one.
+///
+/// slope = (n * Σxy - Σx * Σy) / (n * Σx<sup>2</sup> - (Σx)<sup>2</sup>)
+///
+/// intercept = (Σy - slope * Σx) / n
+///
+/// value = intercept + slope * (n - 1)
+///
+/// forecast = intercept + slope * n
+///
+/// Where _n_ is the number of prices, _period_ once the window is full. With a single price,
+/// the slope is 0 and the line is that price.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the regression (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::LinearRegression;
+/// use ta::Next;
+///
+/// let mut linreg = LinearRegression::new(3).unwrap();
+/// linreg.next(1.0);
+/// linreg.next(3.0);
+/// let out = linreg.next(5.0);
+/// assert_eq!((out.slope, out.intercept), (2.0, 1.0));
+/// assert_eq!((out.value, out.forecast), (5.0, 7.0));
+/// ```
+///
+/// # Links
+///
+/// * [Linear Regression, Fidelity](https://www.fidelity.com/learning-center/trading-investing/technical-analysis/technical-indicator-guide/linear-regression)
+///
+#[doc(alias = "LINREG")]
+#[doc(alias = "LSMA")]
+#[doc(alias = "TSF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LinearRegression {
+    period: usize,
+    regression: RollingRegression,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegressionOutput {
+    /// Change of the line per period.
+    pub slope: f64,
+    /// Value of the line at the oldest price of the window.
+    pub intercept: f64,
+    /// Value of the line at the most recent price.
+    pub value: f64,
+    /// Value of the line one period after the most recent price.
+    pub forecast: f64,
+}
+
+impl LinearRegression {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                regression: RollingRegression::new(period),
+            }),
+        }
+    }
+}
+
+impl Period for LinearRegression {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for LinearRegression {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.regression.push(input);
+        let (slope, intercept) = self.regression.line();
+
+        LinearRegressionOutput {
+            slope,
+            intercept,
+            value: self.regression.forecast(0.0),
+            forecast: self.regression.forecast(1.0),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for LinearRegression {
+    fn reset(&mut self) {
+        self.regression.clear();
+    }
+}
+
+impl Default for LinearRegression {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for LinearRegression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LINREG({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(LinearRegression::new(0).is_err());
+        assert!(LinearRegression::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut linreg = LinearRegression::new(3).unwrap();
+
+        let test_data = vec![
+            // input, slope, intercept, value, forecast
+            (2.0, 0.0, 2.0, 2.0, 2.0),
+            (4.0, 2.0, 2.0, 4.0, 6.0),
+            (9.0, 3.5, 1.5, 8.5, 12.0),
+            // 4, 9, 5
+            (5.0, 0.5, 5.5, 6.5, 7.0),
+        ];
+
+        for (input, slope, intercept, value, forecast) in test_data {
+            let out = linreg.next(input);
+            assert_eq!(round(out.slope), slope);
+            assert_eq!(round(out.intercept), intercept);
+            assert_eq!(round(out.value), value);
+            assert_eq!(round(out.forecast), forecast);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut linreg = LinearRegression::new(2).unwrap();
+
+        linreg.next(&Bar::new().close(10));
+        let out = linreg.next(&Bar::new().close(8));
+        assert_eq!((out.slope, out.value, out.forecast), (-2.0, 8.0, 6.0));
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(LinearRegression::new(14).unwrap().lookback(), 13);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut linreg = LinearRegression::new(3).unwrap();
+        linreg.next(2.0);
+        linreg.next(4.0);
+
+        linreg.reset();
+        let out = linreg.next(7.0);
+        assert_eq!((out.slope, out.intercept), (0.0, 7.0));
+    }
+
+    #[test]
+    fn test_default() {
+        LinearRegression::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = LinearRegression::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "LINREG(20)");
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::rolling_regression::RollingRegression;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Linear regression channel, also known as standard error bands.
+///
+/// The [linear regression](struct.LinearRegression.html) line of the last _period_ prices, at
+/// the most recent price, with bands _multiplier_ standard errors above and below it. The
+/// standard error measures how far the prices stray from the line, so the channel is narrow
+/// in a steady trend and widens when the prices become noisy.
+///
+/// # Formula
+///
+/// SE = sqrt(Σ (y<sub>i</sub> - ŷ<sub>i</sub>)<sup>2</sup> / (n - 2))
+///
+/// mid = ŷ<sub>n-1</sub>
+///
+/// upper = mid + _multiplier_ * SE
+///
+/// lower = mid - _multiplier_ * SE
+///
+/// Where _y_ are the last _n_ prices, _n_ is _period_ once the window is full, and _ŷ_ is the
+/// least squares line. The standard error is 0 until there are 3 prices.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the regression (integer greater than 0). Default is 20.
+/// * _multiplier_ - number of standard errors of the bands (greater than 0). Default is 2.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::LinearRegressionChannel;
+/// use ta::Next;
+///
+/// let mut channel = LinearRegressionChannel::new(3, 2.0).unwrap();
+/// channel.next(2.0);
+/// channel.next(4.0);
+///
+/// let out = channel.next(9.0);
+/// assert_eq!(out.mid, 8.5);
+/// assert_eq!(out.upper.round(), 11.0);
+/// assert_eq!(out.lower.round(), 6.0);
+/// ```
+///
+/// # Links
+///
+/// * [Standard Error Bands, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:standard_error_bands)
+///
+#[doc(alias = "LRC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LinearRegressionChannel {
+    period: usize,
+    multiplier: f64,
+    regression: RollingRegression,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegressionChannelOutput {
+    pub upper: f64,
+    /// Value of the regression line at the most recent price.
+    pub mid: f64,
+    pub lower: f64,
+}
+
+impl LinearRegressionChannel {
+    pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        if period == 0 || multiplier.is_nan() || multiplier <= 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            multiplier,
+            regression: RollingRegression::new(period),
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+}
+
+impl Period for LinearRegressionChannel {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for LinearRegressionChannel {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for LinearRegressionChannel {
+    type Output = LinearRegressionChannelOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.regression.push(input);
+        let mid = self.regression.forecast(0.0);
+        let width = self.multiplier * self.regression.standard_error();
+
+        LinearRegressionChannelOutput {
+            upper: mid + width,
+            mid,
+            lower: mid - width,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for LinearRegressionChannel {
+    type Output = LinearRegressionChannelOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for LinearRegressionChannel {
+    fn reset(&mut self) {
+        self.regression.clear();
+    }
+}
+
+impl Default for LinearRegressionChannel {
+    fn default() -> Self {
+        Self::new(20, 2.0).unwrap()
+    }
+}
+
+impl fmt::Display for LinearRegressionChannel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LRC({}, {})", self.period, self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(LinearRegressionChannel::new(0, 2.0).is_err());
+        assert!(LinearRegressionChannel::new(20, 0.0).is_err());
+        assert!(LinearRegressionChannel::new(20, f64::NAN).is_err());
+        assert!(LinearRegressionChannel::new(1, 2.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut channel = LinearRegressionChannel::new(3, 2.0).unwrap();
+
+        let test_data = vec![
+            // input, upper, mid, lower
+            (2.0, 2.0, 2.0, 2.0),
+            (4.0, 4.0, 4.0, 4.0),
+            (9.0, 10.949, 8.5, 6.051),
+            // 4, 9, 5
+            (5.0, 13.848, 6.5, -0.848),
+        ];
+
+        for (input, upper, mid, lower) in test_data {
+            let out = channel.next(input);
+            assert_eq!(round(out.upper), upper);
+            assert_eq!(round(out.mid), mid);
+            assert_eq!(round(out.lower), lower);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut channel = LinearRegressionChannel::new(3, 1.0).unwrap();
+        for close in &[1.0, 2.0, 3.0] {
+            channel.next(&Bar::new().close(*close));
+        }
+
+        // a straight line
+        let out = channel.next(&Bar::new().close(4));
+        assert_eq!((out.upper, out.mid, out.lower), (4.0, 4.0, 4.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut channel = LinearRegressionChannel::new(3, 2.0).unwrap();
+        channel.next(2.0);
+        channel.next(4.0);
+        channel.next(9.0);
+
+        channel.reset();
+        let out = channel.next(5.0);
+        assert_eq!((out.upper, out.mid, out.lower), (5.0, 5.0, 5.0));
+    }
+
+    #[test]
+    fn test_default() {
+        LinearRegressionChannel::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = LinearRegressionChannel::new(20, 2.0).unwrap();
+        assert_eq!(format!("{}", indicator), "LRC(20, 2)");
+    }
+}
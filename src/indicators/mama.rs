@@ -0,0 +1,399 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::math;
+use crate::{Close, Lookback, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// MESA Adaptive Moving Average (MAMA) and Following Adaptive Moving Average (FAMA).
+///
+/// Developed by John Ehlers, MAMA is an exponential moving average whose smoothing factor
+/// follows the rate of change of the phase of the dominant cycle of the price, measured with a
+/// Hilbert transform. The phase advances slowly in a trend, so MAMA follows the price closely,
+/// and quickly in a range, so MAMA flattens. Each time the phase wraps around, MAMA jumps
+/// towards the price, which gives it a stepped look. FAMA applies half of the same factor to
+/// MAMA, and the crossings of MAMA and FAMA are used as signals.
+///
+/// # Formula
+///
+/// The price is smoothed with a 4-bar weighted average and detrended, split into its in-phase
+/// (I) and quadrature (Q) components with a Hilbert transform, and the dominant cycle period
+/// is measured from the rotation of the phasor (I, Q), so that the transform adapts to it.
+///
+/// phase = arctan(Q / I), in degrees
+///
+/// Δphase = max(phase<sub>t-1</sub> - phase<sub>t</sub>, 1)
+///
+/// α = max(_fast_limit_ / Δphase, _slow_limit_)
+///
+/// MAMA<sub>t</sub> = α * p<sub>t</sub> + (1 - α) * MAMA<sub>t-1</sub>
+///
+/// FAMA<sub>t</sub> = α / 2 * MAMA<sub>t</sub> + (1 - α / 2) * FAMA<sub>t-1</sub>
+///
+/// Where _p<sub>t</sub>_ is the input at a point of time _t_. MAMA and FAMA are the input for
+/// the first 5 inputs, while the transform has no history, and the measured period settles
+/// after about 32 inputs.
+///
+/// # Parameters
+///
+/// * _fast_limit_ - highest smoothing factor (greater than 0 and up to 1). Default is 0.5.
+/// * _slow_limit_ - lowest smoothing factor (greater than 0 and up to _fast_limit_). Default is
+///   0.05.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Mama;
+/// use ta::Next;
+///
+/// let mut mama = Mama::new(0.5, 0.05).unwrap();
+///
+/// for _ in 0..10 {
+///     let out = mama.next(10.0);
+///     assert_eq!(out.mama, 10.0);
+///     assert_eq!(out.fama, 10.0);
+/// }
+///
+/// // MAMA follows a jump of the price faster than FAMA.
+/// let out = mama.next(12.0);
+/// assert!(out.mama > out.fama);
+/// assert!(out.fama > 10.0);
+/// ```
+///
+#[doc(alias = "MAMA")]
+#[doc(alias = "FAMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Mama {
+    fast_limit: f64,
+    slow_limit: f64,
+    count: usize,
+    // The most recent values first.
+    prices: [f64; 4],
+    smooth: [f64; 7],
+    detrender: [f64; 7],
+    i1: [f64; 7],
+    q1: [f64; 7],
+    i2: f64,
+    q2: f64,
+    re: f64,
+    im: f64,
+    period: f64,
+    phase: f64,
+    mama: f64,
+    fama: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MamaOutput {
+    pub mama: f64,
+    pub fama: f64,
+}
+
+// Number of inputs before the Hilbert transform has a full history.
+const WARMUP: usize = 5;
+
+fn push(series: &mut [f64], value: f64) {
+    series.rotate_right(1);
+    series[0] = value;
+}
+
+fn hilbert_transform(series: &[f64; 7]) -> f64 {
+    0.0962 * series[0] + 0.5769 * series[2] - 0.5769 * series[4] - 0.0962 * series[6]
+}
+
+impl Mama {
+    pub fn new(fast_limit: f64, slow_limit: f64) -> Result<Self> {
+        if !(slow_limit > 0.0 && slow_limit <= fast_limit && fast_limit <= 1.0) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            fast_limit,
+            slow_limit,
+            count: 0,
+            prices: [0.0; 4],
+            smooth: [0.0; 7],
+            detrender: [0.0; 7],
+            i1: [0.0; 7],
+            q1: [0.0; 7],
+            i2: 0.0,
+            q2: 0.0,
+            re: 0.0,
+            im: 0.0,
+            period: 0.0,
+            phase: 0.0,
+            mama: 0.0,
+            fama: 0.0,
+        })
+    }
+
+    pub fn fast_limit(&self) -> f64 {
+        self.fast_limit
+    }
+
+    pub fn slow_limit(&self) -> f64 {
+        self.slow_limit
+    }
+
+    // Smoothing factor of the current input, from the rate of change of the phase.
+    fn alpha(&mut self) -> f64 {
+        let adjustment = 0.075 * self.period + 0.54;
+        push(
+            &mut self.detrender,
+            hilbert_transform(&self.smooth) * adjustment,
+        );
+
+        // In-phase and quadrature components.
+        push(
+            &mut self.q1,
+            hilbert_transform(&self.detrender) * adjustment,
+        );
+        push(&mut self.i1, self.detrender[3]);
+
+        // Advance the phase by 90 degrees.
+        let ji = hilbert_transform(&self.i1) * adjustment;
+        let jq = hilbert_transform(&self.q1) * adjustment;
+        let i2 = 0.2 * (self.i1[0] - jq) + 0.8 * self.i2;
+        let q2 = 0.2 * (self.q1[0] + ji) + 0.8 * self.q2;
+
+        // Homodyne discriminator.
+        self.re = 0.2 * (i2 * self.i2 + q2 * self.q2) + 0.8 * self.re;
+        self.im = 0.2 * (i2 * self.q2 - q2 * self.i2) + 0.8 * self.im;
+        self.i2 = i2;
+        self.q2 = q2;
+
+        let mut period = self.period;
+        if self.im != 0.0 && self.re != 0.0 {
+            period = 360.0 / math::atan(self.im / self.re).to_degrees();
+        }
+        // The period changes by 50% at most, and stays between 6 and 50 bars. A measurement
+        // spoiled by a non-finite input keeps the previous period.
+        if !period.is_finite() {
+            period = self.period;
+        }
+        period = period
+            .min(1.5 * self.period)
+            .max(0.67 * self.period)
+            .clamp(6.0, 50.0);
+        self.period = 0.2 * period + 0.8 * self.period;
+
+        let mut phase = self.phase;
+        if self.i1[0] != 0.0 {
+            phase = math::atan(self.q1[0] / self.i1[0]).to_degrees();
+        }
+        let delta_phase = (self.phase - phase).max(1.0);
+        self.phase = phase;
+
+        (self.fast_limit / delta_phase).max(self.slow_limit)
+    }
+}
+
+impl Lookback for Mama {
+    fn lookback(&self) -> usize {
+        32
+    }
+}
+
+impl Next<f64> for Mama {
+    type Output = MamaOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.count += 1;
+        push(&mut self.prices, input);
+
+        let smooth = if self.count < 4 {
+            input
+        } else {
+            (4.0 * self.prices[0] + 3.0 * self.prices[1] + 2.0 * self.prices[2] + self.prices[3])
+                / 10.0
+        };
+        push(&mut self.smooth, smooth);
+
+        if self.count <= WARMUP {
+            push(&mut self.detrender, 0.0);
+            push(&mut self.i1, 0.0);
+            push(&mut self.q1, 0.0);
+            self.mama = input;
+            self.fama = input;
+        } else {
+            let alpha = self.alpha();
+            self.mama = alpha * input + (1.0 - alpha) * self.mama;
+            self.fama = 0.5 * alpha * self.mama + (1.0 - 0.5 * alpha) * self.fama;
+        }
+
+        MamaOutput {
+            mama: self.mama,
+            fama: self.fama,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for Mama {
+    type Output = MamaOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Mama {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.prices = [0.0; 4];
+        self.smooth = [0.0; 7];
+        self.detrender = [0.0; 7];
+        self.i1 = [0.0; 7];
+        self.q1 = [0.0; 7];
+        self.i2 = 0.0;
+        self.q2 = 0.0;
+        self.re = 0.0;
+        self.im = 0.0;
+        self.period = 0.0;
+        self.phase = 0.0;
+        self.mama = 0.0;
+        self.fama = 0.0;
+    }
+}
+
+impl Default for Mama {
+    fn default() -> Self {
+        Self::new(0.5, 0.05).unwrap()
+    }
+}
+
+impl fmt::Display for Mama {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MAMA({}, {})", self.fast_limit, self.slow_limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Mama);
+
+    // A cycle of 20 bars on a rising trend.
+    fn price(i: usize) -> f64 {
+        100.0 + 10.0 * (i as f64 * 2.0 * std::f64::consts::PI / 20.0).sin() + 0.5 * i as f64
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Mama::new(0.5, 0.0).is_err());
+        assert!(Mama::new(0.5, 0.6).is_err());
+        assert!(Mama::new(1.5, 0.05).is_err());
+        assert!(Mama::new(f64::NAN, 0.05).is_err());
+        assert!(Mama::new(0.5, 0.5).is_ok());
+        assert!(Mama::new(1.0, 0.05).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mama = Mama::new(0.5, 0.05).unwrap();
+
+        let expected = vec![
+            // bar, mama, fama
+            (5, 112.005, 111.634),
+            (6, 112.258, 111.79),
+            (7, 111.924, 111.824),
+            (10, 107.868, 110.646),
+            (20, 102.679, 107.549),
+            (40, 111.251, 109.681),
+            (59, 120.641, 116.525),
+        ];
+
+        let outputs: Vec<MamaOutput> = (0..60).map(|i| mama.next(price(i))).collect();
+        for (i, out) in outputs.iter().take(5).enumerate() {
+            assert_eq!(out.mama, price(i));
+            assert_eq!(out.fama, price(i));
+        }
+        for (i, expected_mama, expected_fama) in expected {
+            assert_eq!(round(outputs[i].mama), expected_mama);
+            assert_eq!(round(outputs[i].fama), expected_fama);
+        }
+    }
+
+    #[test]
+    fn test_next_limits() {
+        // With equal limits MAMA is an EMA of smoothing factor 0.5.
+        let mut mama = Mama::new(0.5, 0.5).unwrap();
+        for i in 0..5 {
+            mama.next(price(i));
+        }
+        let out = mama.next(price(5));
+        assert_eq!(out.mama, 0.5 * price(5) + 0.5 * price(4));
+        assert_eq!(out.fama, 0.25 * out.mama + 0.75 * price(4));
+    }
+
+    #[test]
+    fn test_next_non_finite() {
+        let mut mama = Mama::default();
+        for i in 0..40 {
+            mama.next(price(i));
+        }
+        for &input in [
+            f64::NAN,
+            10.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            1e300,
+            10.0,
+        ]
+        .iter()
+        {
+            mama.next(input);
+        }
+        for i in 0..40 {
+            mama.next(price(i));
+        }
+
+        mama.reset();
+        let outputs: Vec<MamaOutput> = (0..6).map(|i| mama.next(price(i))).collect();
+        assert_eq!(round(outputs[5].mama), 112.005);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut mama = Mama::new(0.5, 0.05).unwrap();
+        for i in 0..6 {
+            let out = mama.next(&Bar::new().close(price(i)));
+            if i == 5 {
+                assert_eq!(round(out.mama), 112.005);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mama = Mama::new(0.5, 0.05).unwrap();
+        for i in 0..20 {
+            mama.next(price(i));
+        }
+
+        mama.reset();
+        let outputs: Vec<MamaOutput> = (0..6).map(|i| mama.next(price(i))).collect();
+        assert_eq!(outputs[0].mama, price(0));
+        assert_eq!(round(outputs[5].mama), 112.005);
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(Mama::default().lookback(), 32);
+    }
+
+    #[test]
+    fn test_default() {
+        let mama = Mama::default();
+        assert_eq!(mama.fast_limit(), 0.5);
+        assert_eq!(mama.slow_limit(), 0.05);
+    }
+
+    #[test]
+    fn test_display() {
+        let mama = Mama::new(0.5, 0.05).unwrap();
+        assert_eq!(format!("{}", mama), "MAMA(0.5, 0.05)");
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage;
+use crate::{High, Lookback, Low, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Mass Index (MI).
+///
+/// Developed by Donald Dorsey, the Mass Index spots trend reversals from the widening of the
+/// high - low range: it sums the ratio of a moving average of the range to a moving average of
+/// that average. Dorsey's "reversal bulge" is a rise of the index above 27 followed by a fall
+/// below 26.5. The smoothing uses an [EMA](struct.ExponentialMovingAverage.html) by default,
+/// but any moving average implementing [NewWithPeriod](../trait.NewWithPeriod.html) can be used
+/// instead.
+///
+/// # Formula
+///
+/// single = MA(_ema_period_) of (high - low)
+///
+/// double = MA(_ema_period_) of single
+///
+/// MI = Σ (single / double) over the last _sum_period_ periods
+///
+/// The ratio is 1 while the range has always been 0. During the first _sum_period_ periods
+/// fewer ratios are summed.
+///
+/// # Parameters
+///
+/// * _ema_period_ - smoothing period of both moving averages (integer greater than 0). Default
+///   is 9.
+/// * _sum_period_ - number of ratios summed (integer greater than 0). Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ExponentialMovingAverage as Ema, MassIndex};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64| {
+///     DataItem::builder()
+///         .open(low)
+///         .high(high)
+///         .low(low)
+///         .close(high)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut mi = MassIndex::<Ema>::new(2, 3).unwrap();
+/// assert_eq!(mi.next(&bar(12.0, 10.0)), 1.0);
+/// // single = 3.333, double = 2.889
+/// assert_eq!((mi.next(&bar(14.0, 10.0)) * 1000.0).round(), 2154.0);
+/// ```
+///
+/// # Links
+///
+/// * [Mass Index, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:mass_index)
+///
+#[doc(alias = "MI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MassIndex<MA = ExponentialMovingAverage> {
+    sum_period: usize,
+    single: MA,
+    double: MA,
+    index: usize,
+    sum: f64,
+    deque: Box<[f64]>,
+}
+
+impl<MA: NewWithPeriod> MassIndex<MA> {
+    pub fn new(ema_period: usize, sum_period: usize) -> Result<Self> {
+        if sum_period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            sum_period,
+            single: MA::new_with_period(ema_period)?,
+            double: MA::new_with_period(ema_period)?,
+            index: 0,
+            sum: 0.0,
+            deque: vec![0.0; sum_period].into_boxed_slice(),
+        })
+    }
+}
+
+impl<MA> MassIndex<MA> {
+    pub fn sum_period(&self) -> usize {
+        self.sum_period
+    }
+}
+
+impl<MA: Period> Period for MassIndex<MA> {
+    fn period(&self) -> usize {
+        self.single.period()
+    }
+}
+
+impl<MA: Lookback> Lookback for MassIndex<MA> {
+    fn lookback(&self) -> usize {
+        self.single.lookback() + self.double.lookback() + self.sum_period - 1
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: High + Low> Next<&T> for MassIndex<MA> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let single = self.single.next(input.high() - input.low());
+        let double = self.double.next(single);
+        let ratio = if double == 0.0 { 1.0 } else { single / double };
+
+        let old_ratio = self.deque[self.index];
+        self.deque[self.index] = ratio;
+        self.index = if self.index + 1 < self.sum_period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        self.sum = self.sum - old_ratio + ratio;
+        self.sum
+    }
+}
+
+impl<MA: Reset> Reset for MassIndex<MA> {
+    fn reset(&mut self) {
+        self.single.reset();
+        self.double.reset();
+        self.index = 0;
+        self.sum = 0.0;
+        for i in 0..self.sum_period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for MassIndex {
+    fn default() -> Self {
+        Self::new(9, 25).unwrap()
+    }
+}
+
+impl<MA: Period> fmt::Display for MassIndex<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MI({}, {})", self.single.period(), self.sum_period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+
+    fn bar(range: f64) -> Bar {
+        Bar::new().high(10.0 + range).low(10.0).close(10.0)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(MassIndex::<Ema>::new(0, 25).is_err());
+        assert!(MassIndex::<Ema>::new(9, 0).is_err());
+        assert!(MassIndex::<Ema>::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mi = MassIndex::<Ema>::new(2, 3).unwrap();
+
+        assert_eq!(mi.next(&bar(2.0)), 1.0);
+        assert_eq!(round(mi.next(&bar(4.0))), 2.154);
+        assert_eq!(round(mi.next(&bar(1.0))), 2.981);
+        // first ratio left the window
+        assert_eq!(round(mi.next(&bar(3.0))), 3.042);
+        assert_eq!(round(mi.next(&bar(3.0))), 2.94);
+    }
+
+    #[test]
+    fn test_next_with_sma() {
+        let mut mi = MassIndex::<Sma>::new(2, 2).unwrap();
+
+        // single: 2, 3, 4; double: 2, 2.5, 3.5
+        assert_eq!(mi.next(&bar(2.0)), 1.0);
+        assert_eq!(mi.next(&bar(4.0)), 1.0 + 3.0 / 2.5);
+        assert_eq!(round(mi.next(&bar(4.0))), round(3.0 / 2.5 + 4.0 / 3.5));
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut mi = MassIndex::<Ema>::new(9, 25).unwrap();
+        assert_eq!(mi.next(&bar(0.0)), 1.0);
+        assert_eq!(mi.next(&bar(0.0)), 2.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mi = MassIndex::<Ema>::new(2, 3).unwrap();
+        mi.next(&bar(2.0));
+        mi.next(&bar(4.0));
+
+        mi.reset();
+        assert_eq!(mi.next(&bar(4.0)), 1.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let mi = MassIndex::<Ema>::new(9, 25).unwrap();
+        assert_eq!(mi.period(), 9);
+        assert_eq!(mi.sum_period(), 25);
+        assert_eq!(mi.lookback(), 40);
+    }
+
+    #[test]
+    fn test_default() {
+        MassIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mi = MassIndex::<Ema>::new(9, 25).unwrap();
+        assert_eq!(format!("{}", mi), "MI(9, 25)");
+    }
+}
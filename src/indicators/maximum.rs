@@ -1,13 +1,19 @@
-use std::f64::INFINITY;
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{High, Next, Period, Reset};
+use crate::monotonic_queue::MonotonicQueue;
+use crate::{High, Lookback, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Returns the highest value in a given time frame.
 ///
+/// [next_with_offset](#method.next_with_offset) also returns how many periods ago the highest value
+/// was observed. When the highest value is repeated, the most recent one is used.
+///
+/// Only the values that can still become the highest are kept, so `next` is amortized `O(1)`,
+/// regardless of the period.
+///
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
@@ -29,9 +35,14 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct Maximum {
     period: usize,
-    max_index: usize,
-    cur_index: usize,
-    deque: Box<[f64]>,
+    queue: MonotonicQueue,
+}
+
+/// Extreme value of a time frame with how many periods ago it was observed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtremeOutput {
+    pub value: f64,
+    pub offset: usize,
 }
 
 impl Maximum {
@@ -40,25 +51,38 @@ impl Maximum {
             0 => Err(TaError::InvalidParameter),
             _ => Ok(Self {
                 period,
-                max_index: 0,
-                cur_index: 0,
-                deque: vec![-INFINITY; period].into_boxed_slice(),
+                queue: MonotonicQueue::max(period),
             }),
         }
     }
 
-    fn find_max_index(&self) -> usize {
-        let mut max = -INFINITY;
-        let mut index: usize = 0;
+    /// Returns how many periods ago the highest value of the time frame was observed, 0 for
+    /// the current period.
+    pub fn offset(&self) -> usize {
+        self.queue.offset()
+    }
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val > max {
-                max = val;
-                index = i;
-            }
+    /// Same as `next`, but also returns the offset of the highest value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::indicators::Maximum;
+    ///
+    /// let mut max = Maximum::new(3).unwrap();
+    /// max.next_with_offset(5.0);
+    /// max.next_with_offset(7.0);
+    ///
+    /// let out = max.next_with_offset(6.0);
+    /// assert_eq!(out.value, 7.0);
+    /// assert_eq!(out.offset, 1);
+    /// ```
+    pub fn next_with_offset(&mut self, input: f64) -> ExtremeOutput {
+        let value = self.next(input);
+        ExtremeOutput {
+            value,
+            offset: self.offset(),
         }
-
-        index
     }
 }
 
@@ -68,25 +92,17 @@ impl Period for Maximum {
     }
 }
 
+impl Lookback for Maximum {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
 impl Next<f64> for Maximum {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        self.deque[self.cur_index] = input;
-
-        if input > self.deque[self.max_index] {
-            self.max_index = self.cur_index;
-        } else if self.max_index == self.cur_index {
-            self.max_index = self.find_max_index();
-        }
-
-        self.cur_index = if self.cur_index + 1 < self.period {
-            self.cur_index + 1
-        } else {
-            0
-        };
-
-        self.deque[self.max_index]
+        self.queue.push(input)
     }
 }
 
@@ -100,9 +116,7 @@ impl<T: High> Next<&T> for Maximum {
 
 impl Reset for Maximum {
     fn reset(&mut self) {
-        for i in 0..self.period {
-            self.deque[i] = -INFINITY;
-        }
+        self.queue.clear();
     }
 }
 
@@ -146,6 +160,44 @@ mod tests {
         assert_eq!(max.next(-1.5), -1.0);
     }
 
+    #[test]
+    fn test_next_with_offset() {
+        let mut max = Maximum::new(3).unwrap();
+
+        let test_data = vec![
+            // input, value, offset
+            (4.0, 4.0, 0),
+            (1.2, 4.0, 1),
+            (3.0, 4.0, 2),
+            (2.0, 3.0, 1),
+            // the most recent of repeated values
+            (3.0, 3.0, 0),
+            (1.0, 3.0, 1),
+            (1.0, 3.0, 2),
+            (0.5, 1.0, 1),
+        ];
+
+        for (input, value, offset) in test_data {
+            let out = max.next_with_offset(input);
+            assert_eq!(out, ExtremeOutput { value, offset });
+            assert_eq!(max.offset(), offset);
+        }
+    }
+
+    #[test]
+    fn test_next_long_series() {
+        let values: Vec<f64> = (0..500).map(|i| ((i * 37) % 101) as f64).collect();
+        let mut max = Maximum::new(7).unwrap();
+
+        for (i, &value) in values.iter().enumerate() {
+            let window = &values[i.saturating_sub(6)..=i];
+            let expected = window.iter().cloned().fold(f64::MIN, f64::max);
+            let out = max.next_with_offset(value);
+            assert_eq!(out.value, expected);
+            assert_eq!(window[window.len() - 1 - out.offset], expected);
+        }
+    }
+
     #[test]
     fn test_next_with_bars() {
         fn bar(high: f64) -> Bar {
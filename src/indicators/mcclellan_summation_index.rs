@@ -0,0 +1,202 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// McClellan Summation Index (MSI).
+///
+/// A market breadth indicator developed by Sherman and Marian McClellan: the running total of
+/// the McClellan Oscillator, the difference between a fast and a slow EMA of the net advances
+/// (advancing issues minus declining issues) of a market. Rising summation means broad
+/// participation in an advance; its level and its crossings with a moving average are often
+/// used alone, without the oscillator.
+///
+/// The input is the net advances of every period, e.g. `advances - declines`, or the
+/// ratio-adjusted net advances `1000 * (advances - declines) / (advances + declines)`. The
+/// oscillator of the last period is available with [oscillator](#method.oscillator).
+///
+/// # Formula
+///
+/// oscillator = EMA(_fast_period_) - EMA(_slow_period_) of the net advances
+///
+/// MSI<sub>t</sub> = MSI<sub>t-1</sub> + oscillator<sub>t</sub>
+///
+/// The summation starts from 0.
+///
+/// # Parameters
+///
+/// * _fast_period_ - period of the fast EMA (integer greater than 0). Default is 19.
+/// * _slow_period_ - period of the slow EMA (integer greater than _fast_period_). Default is 39.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::McClellanSummationIndex;
+/// use ta::Next;
+///
+/// let mut msi = McClellanSummationIndex::new(3, 5).unwrap();
+/// assert_eq!(msi.next(100.0), 0.0);
+/// assert_eq!(msi.next(250.0).round(), 25.0);
+/// assert_eq!(msi.oscillator().round(), 25.0);
+/// ```
+///
+/// # Links
+///
+/// * [McClellan Summation Index, StockCharts](https://school.stockcharts.com/doku.php?id=market_indicators:mcclellan_summation)
+///
+#[doc(alias = "MSI")]
+#[doc(alias = "McClellan")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct McClellanSummationIndex {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    oscillator: f64,
+    summation: f64,
+}
+
+impl McClellanSummationIndex {
+    pub fn new(fast_period: usize, slow_period: usize) -> Result<Self> {
+        if fast_period == 0 || slow_period <= fast_period {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            fast_ema: Ema::new(fast_period)?,
+            slow_ema: Ema::new(slow_period)?,
+            oscillator: 0.0,
+            summation: 0.0,
+        })
+    }
+
+    pub fn fast_period(&self) -> usize {
+        self.fast_ema.period()
+    }
+
+    pub fn slow_period(&self) -> usize {
+        self.slow_ema.period()
+    }
+
+    /// Returns the McClellan Oscillator of the last period.
+    pub fn oscillator(&self) -> f64 {
+        self.oscillator
+    }
+}
+
+impl Lookback for McClellanSummationIndex {
+    fn lookback(&self) -> usize {
+        self.slow_ema.lookback()
+    }
+}
+
+impl Next<f64> for McClellanSummationIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.oscillator = self.fast_ema.next(input) - self.slow_ema.next(input);
+        self.summation += self.oscillator;
+        self.summation
+    }
+}
+
+impl<T: Close> Next<&T> for McClellanSummationIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for McClellanSummationIndex {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.oscillator = 0.0;
+        self.summation = 0.0;
+    }
+}
+
+impl Default for McClellanSummationIndex {
+    fn default() -> Self {
+        Self::new(19, 39).unwrap()
+    }
+}
+
+impl fmt::Display for McClellanSummationIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "MSI({}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Msi = McClellanSummationIndex;
+
+    test_indicator!(Msi);
+
+    #[test]
+    fn test_new() {
+        assert!(Msi::new(0, 39).is_err());
+        assert!(Msi::new(19, 19).is_err());
+        assert!(Msi::new(1, 2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut msi = Msi::new(3, 5).unwrap();
+
+        let test_data = vec![
+            // net advances, oscillator, summation
+            (100.0, 0.0, 0.0),
+            (200.0, 16.667, 16.667),
+            (-50.0, -22.222, -5.556),
+            (-300.0, -73.148, -78.704),
+            (150.0, -2.932, -81.636),
+            (400.0, 62.629, -19.007),
+        ];
+
+        for (input, oscillator, summation) in test_data {
+            assert_eq!(round(msi.next(input)), summation);
+            assert_eq!(round(msi.oscillator()), oscillator);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut msi = Msi::new(3, 5).unwrap();
+        assert_eq!(msi.next(&Bar::new().close(100)), 0.0);
+        assert_eq!(round(msi.next(&Bar::new().close(200))), 16.667);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut msi = Msi::new(3, 5).unwrap();
+        msi.next(100.0);
+        msi.next(200.0);
+
+        msi.reset();
+        assert_eq!(msi.oscillator(), 0.0);
+        assert_eq!(msi.next(200.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Msi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Msi::new(19, 39).unwrap();
+        assert_eq!(format!("{}", indicator), "MSI(19, 39)");
+    }
+}
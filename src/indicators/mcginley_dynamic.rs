@@ -0,0 +1,223 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset, SetPeriod};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// McGinley Dynamic.
+///
+/// Developed by John R. McGinley, a moving average that adjusts its speed to the market: the
+/// smoothing factor depends on the ratio of the price to the average, raised to the 4th power,
+/// so the average catches up quickly when the price falls away below it and follows slowly
+/// when the price runs above it. It implements [NewWithPeriod](../trait.NewWithPeriod.html),
+/// so it can smooth composite indicators like the [ATR](struct.AverageTrueRange.html).
+///
+/// # Formula
+///
+/// MD<sub>t</sub> = MD<sub>t-1</sub> + (p<sub>t</sub> - MD<sub>t-1</sub>) / (_period_ * (p<sub>t</sub> / MD<sub>t-1</sub>)<sup>4</sup>)
+///
+/// Where:
+///
+/// * _MD<sub>t</sub>_ - value of the McGinley Dynamic at a point of time _t_
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+///
+/// The first value is the first input. While the average or the input is 0, the average jumps
+/// to the input.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::McGinleyDynamic;
+/// use ta::Next;
+///
+/// let mut md = McGinleyDynamic::new(3).unwrap();
+/// assert_eq!(md.next(10.0), 10.0);
+/// assert_eq!((md.next(12.0) * 1000.0).round(), 10322.0);
+/// ```
+///
+/// # Links
+///
+/// * [McGinley Dynamic, Investopedia](https://www.investopedia.com/articles/forex/09/mcginley-dynamic-indicator.asp)
+///
+#[doc(alias = "MD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct McGinleyDynamic {
+    period: usize,
+    current: f64,
+    is_new: bool,
+}
+
+impl McGinleyDynamic {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                current: 0.0,
+                is_new: true,
+            }),
+        }
+    }
+}
+
+impl NewWithPeriod for McGinleyDynamic {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl Period for McGinleyDynamic {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for McGinleyDynamic {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl SetPeriod for McGinleyDynamic {
+    fn set_period(&mut self, period: usize) -> Result<()> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        self.period = period;
+        Ok(())
+    }
+}
+
+impl Next<f64> for McGinleyDynamic {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.is_new {
+            self.is_new = false;
+            self.current = input;
+            return self.current;
+        }
+
+        let ratio = input / self.current;
+        let ratio2 = ratio * ratio;
+        let denominator = self.period as f64 * ratio2 * ratio2;
+        self.current = if denominator == 0.0 || !denominator.is_finite() {
+            input
+        } else {
+            self.current + (input - self.current) / denominator
+        };
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for McGinleyDynamic {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for McGinleyDynamic {
+    fn reset(&mut self) {
+        self.current = 0.0;
+        self.is_new = true;
+    }
+}
+
+impl Default for McGinleyDynamic {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for McGinleyDynamic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MD({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::AverageTrueRange;
+    use crate::test_helper::*;
+
+    test_indicator!(McGinleyDynamic);
+
+    #[test]
+    fn test_new() {
+        assert!(McGinleyDynamic::new(0).is_err());
+        assert!(McGinleyDynamic::new(1).is_ok());
+        assert!(McGinleyDynamic::new_with_period(0).is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut md = McGinleyDynamic::new(3).unwrap();
+
+        assert_eq!(md.next(10.0), 10.0);
+        assert_eq!(round(md.next(12.0)), 10.322);
+        assert_eq!(round(md.next(11.0)), 10.497);
+        assert_eq!(round(md.next(15.0)), 10.857);
+        assert_eq!(round(md.next(14.0)), 11.236);
+
+        let mut md = McGinleyDynamic::new(3).unwrap();
+        assert_eq!(md.next(&Bar::new().close(10)), 10.0);
+        assert_eq!(round(md.next(&Bar::new().close(12))), 10.322);
+    }
+
+    #[test]
+    fn test_next_zero() {
+        let mut md = McGinleyDynamic::new(3).unwrap();
+        assert_eq!(md.next(0.0), 0.0);
+        assert_eq!(md.next(5.0), 5.0);
+        assert_eq!(md.next(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_set_period() {
+        let mut md = McGinleyDynamic::new(3).unwrap();
+        md.next(10.0);
+        assert!(md.set_period(0).is_err());
+        md.set_period(1).unwrap();
+        assert_eq!(md.period(), 1);
+        assert_eq!(round(md.next(12.0)), 10.965);
+    }
+
+    #[test]
+    fn test_atr_smoothing() {
+        let mut atr = AverageTrueRange::<McGinleyDynamic>::with_ma(3).unwrap();
+        assert_eq!(atr.next(&Bar::new().high(10).low(8).close(9)), 2.0);
+        assert_eq!(format!("{}", atr), "ATR(3)");
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut md = McGinleyDynamic::new(5).unwrap();
+
+        assert_eq!(md.next(4.0), 4.0);
+        md.next(10.0);
+        assert_ne!(md.next(4.0), 4.0);
+
+        md.reset();
+        assert_eq!(md.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        McGinleyDynamic::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let md = McGinleyDynamic::new(7).unwrap();
+        assert_eq!(format!("{}", md), "MD(7)");
+    }
+}
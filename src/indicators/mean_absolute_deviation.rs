@@ -4,7 +4,7 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Lookback, Next, Period, Reset};
 
 /// Mean Absolute Deviation (MAD)
 ///
@@ -58,12 +58,18 @@ impl Period for MeanAbsoluteDeviation {
     }
 }
 
+impl Lookback for MeanAbsoluteDeviation {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
 impl Next<f64> for MeanAbsoluteDeviation {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
         self.sum = if self.count < self.period {
-            self.count = self.count + 1;
+            self.count += 1;
             self.sum + input
         } else {
             self.sum + input - self.deque[self.index]
@@ -1,13 +1,20 @@
-use std::f64::INFINITY;
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Low, Next, Period, Reset};
+use crate::indicators::ExtremeOutput;
+use crate::monotonic_queue::MonotonicQueue;
+use crate::{Lookback, Low, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Returns the lowest value in a given time frame.
 ///
+/// [next_with_offset](#method.next_with_offset) also returns how many periods ago the lowest value
+/// was observed. When the lowest value is repeated, the most recent one is used.
+///
+/// Only the values that can still become the lowest are kept, so `next` is amortized `O(1)`,
+/// regardless of the period.
+///
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
@@ -28,9 +35,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct Minimum {
     period: usize,
-    min_index: usize,
-    cur_index: usize,
-    deque: Box<[f64]>,
+    queue: MonotonicQueue,
 }
 
 impl Minimum {
@@ -39,25 +44,38 @@ impl Minimum {
             0 => Err(TaError::InvalidParameter),
             _ => Ok(Self {
                 period,
-                min_index: 0,
-                cur_index: 0,
-                deque: vec![INFINITY; period].into_boxed_slice(),
+                queue: MonotonicQueue::min(period),
             }),
         }
     }
 
-    fn find_min_index(&self) -> usize {
-        let mut min = ::std::f64::INFINITY;
-        let mut index: usize = 0;
+    /// Returns how many periods ago the lowest value of the time frame was observed, 0 for
+    /// the current period.
+    pub fn offset(&self) -> usize {
+        self.queue.offset()
+    }
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val < min {
-                min = val;
-                index = i;
-            }
+    /// Same as `next`, but also returns the offset of the lowest value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::indicators::Minimum;
+    ///
+    /// let mut min = Minimum::new(3).unwrap();
+    /// min.next_with_offset(5.0);
+    /// min.next_with_offset(3.0);
+    ///
+    /// let out = min.next_with_offset(4.0);
+    /// assert_eq!(out.value, 3.0);
+    /// assert_eq!(out.offset, 1);
+    /// ```
+    pub fn next_with_offset(&mut self, input: f64) -> ExtremeOutput {
+        let value = self.next(input);
+        ExtremeOutput {
+            value,
+            offset: self.offset(),
         }
-
-        index
     }
 }
 
@@ -67,25 +85,17 @@ impl Period for Minimum {
     }
 }
 
+impl Lookback for Minimum {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
 impl Next<f64> for Minimum {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        self.deque[self.cur_index] = input;
-
-        if input < self.deque[self.min_index] {
-            self.min_index = self.cur_index;
-        } else if self.min_index == self.cur_index {
-            self.min_index = self.find_min_index();
-        }
-
-        self.cur_index = if self.cur_index + 1 < self.period {
-            self.cur_index + 1
-        } else {
-            0
-        };
-
-        self.deque[self.min_index]
+        self.queue.push(input)
     }
 }
 
@@ -99,9 +109,7 @@ impl<T: Low> Next<&T> for Minimum {
 
 impl Reset for Minimum {
     fn reset(&mut self) {
-        for i in 0..self.period {
-            self.deque[i] = INFINITY;
-        }
+        self.queue.clear();
     }
 }
 
@@ -146,6 +154,30 @@ mod tests {
         assert_eq!(min.next(0.0), -9.0);
     }
 
+    #[test]
+    fn test_next_with_offset() {
+        let mut min = Minimum::new(3).unwrap();
+
+        let test_data = vec![
+            // input, value, offset
+            (1.0, 1.0, 0),
+            (4.0, 1.0, 1),
+            (2.0, 1.0, 2),
+            (3.0, 2.0, 1),
+            // the most recent of repeated values
+            (2.0, 2.0, 0),
+            (4.0, 2.0, 1),
+            (4.0, 2.0, 2),
+            (5.0, 4.0, 1),
+        ];
+
+        for (input, value, offset) in test_data {
+            let out = min.next_with_offset(input);
+            assert_eq!(out, ExtremeOutput { value, offset });
+            assert_eq!(min.offset(), offset);
+        }
+    }
+
     #[test]
     fn test_next_with_bars() {
         fn bar(low: f64) -> Bar {
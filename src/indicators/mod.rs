@@ -7,6 +7,9 @@ pub use self::simple_moving_average::SimpleMovingAverage;
 mod standard_deviation;
 pub use self::standard_deviation::StandardDeviation;
 
+mod variance;
+pub use self::variance::{Variance, VarianceMode};
+
 mod mean_absolute_deviation;
 pub use self::mean_absolute_deviation::MeanAbsoluteDeviation;
 
@@ -17,7 +20,7 @@ mod minimum;
 pub use self::minimum::Minimum;
 
 mod maximum;
-pub use self::maximum::Maximum;
+pub use self::maximum::{ExtremeOutput, Maximum};
 
 mod fast_stochastic;
 pub use self::fast_stochastic::FastStochastic;
@@ -57,10 +60,263 @@ mod keltner_channel;
 pub use self::keltner_channel::{KeltnerChannel, KeltnerChannelOutput};
 
 mod rate_of_change;
-pub use self::rate_of_change::RateOfChange;
+pub use self::rate_of_change::{RateOfChange, RocMode};
 
 mod money_flow_index;
 pub use self::money_flow_index::MoneyFlowIndex;
 
 mod on_balance_volume;
 pub use self::on_balance_volume::OnBalanceVolume;
+
+mod half_trend;
+pub use self::half_trend::{HalfTrend, HalfTrendOutput, TrendDirection};
+
+mod running_moving_average;
+pub use self::running_moving_average::RunningMovingAverage;
+
+mod average_directional_index;
+pub use self::average_directional_index::{AverageDirectionalIndex, AverageDirectionalIndexOutput};
+
+mod nadaraya_watson_envelope;
+pub use self::nadaraya_watson_envelope::{NadarayaWatsonEnvelope, NadarayaWatsonEnvelopeOutput};
+
+mod aroon;
+pub use self::aroon::{Aroon, AroonOutput};
+
+mod ssl_channel;
+pub use self::ssl_channel::{SslChannel, SslChannelOutput};
+
+mod normalized_average_true_range;
+pub use self::normalized_average_true_range::NormalizedAverageTrueRange;
+
+mod volatility_regime;
+pub use self::volatility_regime::{Regime, VolatilityRegime, VolatilityRegimeOutput};
+
+mod volatility_ratio;
+pub use self::volatility_ratio::VolatilityRatio;
+
+mod williams_percent_r;
+pub use self::williams_percent_r::WilliamsPercentR;
+
+mod stochastic_oscillator;
+pub use self::stochastic_oscillator::{StochasticOscillator, StochasticOscillatorOutput};
+
+mod rolling_mode;
+pub use self::rolling_mode::{RollingMode, RollingModeOutput};
+
+mod stochastic_of;
+pub use self::stochastic_of::StochasticOf;
+
+mod sourced;
+pub use self::sourced::Sourced;
+
+mod forecast_oscillator;
+pub use self::forecast_oscillator::ForecastOscillator;
+
+mod anchored_vwap;
+pub use self::anchored_vwap::AnchoredVwap;
+
+mod relative_volume;
+pub use self::relative_volume::{RelativeVolume, SessionRelativeVolume};
+
+mod accumulation_distribution;
+pub use self::accumulation_distribution::AccumulationDistribution;
+
+mod checkpointed;
+pub use self::checkpointed::Checkpointed;
+
+mod typed;
+pub use self::typed::Typed;
+
+mod kaufman_adaptive_moving_average;
+pub use self::kaufman_adaptive_moving_average::KaufmanAdaptiveMovingAverage;
+
+mod ttm_squeeze;
+pub use self::ttm_squeeze::{TtmSqueeze, TtmSqueezeOutput};
+
+mod slope;
+pub use self::slope::Slope;
+
+mod zero_lag_exponential_moving_average;
+pub use self::zero_lag_exponential_moving_average::ZeroLagExponentialMovingAverage;
+
+mod zone_duration;
+pub use self::zone_duration::{ZoneDuration, ZoneDurationOutput};
+
+mod adaptive;
+pub use self::adaptive::Adaptive;
+
+mod volume_weighted_moving_average;
+pub use self::volume_weighted_moving_average::VolumeWeightedMovingAverage;
+
+mod mcclellan_summation_index;
+pub use self::mcclellan_summation_index::McClellanSummationIndex;
+
+mod streak;
+pub use self::streak::Streak;
+
+mod candle_anatomy;
+pub use self::candle_anatomy::{CandleAnatomy, CandleAnatomyOutput};
+
+mod ease_of_movement;
+pub use self::ease_of_movement::EaseOfMovement;
+
+mod spearman_correlation;
+pub use self::spearman_correlation::SpearmanCorrelation;
+
+mod volatility_spike;
+pub use self::volatility_spike::{SpikeDirection, VolatilitySpike, VolatilitySpikeEvent};
+
+mod know_sure_thing;
+pub use self::know_sure_thing::{KnowSureThing, KnowSureThingOutput};
+
+mod geometric_mean;
+pub use self::geometric_mean::GeometricMean;
+
+mod harmonic_mean;
+pub use self::harmonic_mean::HarmonicMean;
+
+mod session_levels;
+pub use self::session_levels::{SessionLevels, SessionLevelsOutput, SessionOhlc};
+
+mod opening_range_breakout;
+pub use self::opening_range_breakout::{
+    BreakoutDirection, OpeningRangeBreakout, OpeningRangeBreakoutOutput,
+};
+
+mod fisher_transform;
+pub use self::fisher_transform::{FisherTransform, FisherTransformOutput};
+
+mod divergence_score;
+pub use self::divergence_score::{DivergenceScore, DivergenceScoreOutput};
+
+mod momentum;
+pub use self::momentum::Momentum;
+
+mod vol_adj_momentum;
+pub use self::vol_adj_momentum::VolAdjMomentum;
+
+mod percentile_bands;
+pub use self::percentile_bands::{PercentileBands, PercentileBandsOutput};
+
+mod rolling_drawdown;
+pub use self::rolling_drawdown::{RollingDrawdown, RollingDrawdownOutput};
+
+mod pivot_points;
+pub use self::pivot_points::{PivotLevels, PivotMethod, PivotPoints};
+
+mod linear_regression;
+pub use self::linear_regression::{LinearRegression, LinearRegressionOutput};
+
+mod hampel_filter;
+pub use self::hampel_filter::{HampelFilter, HampelFilterOutput};
+
+mod linear_regression_channel;
+pub use self::linear_regression_channel::{LinearRegressionChannel, LinearRegressionChannelOutput};
+
+mod ensemble;
+pub use self::ensemble::{Ensemble, Normalization};
+
+mod risk_parity;
+pub use self::risk_parity::RiskParity;
+
+mod tail_ratio;
+pub use self::tail_ratio::TailRatio;
+
+mod gain_pain_ratio;
+pub use self::gain_pain_ratio::GainPainRatio;
+
+mod mass_index;
+pub use self::mass_index::MassIndex;
+
+mod percentage_volume_oscillator;
+pub use self::percentage_volume_oscillator::{
+    PercentageVolumeOscillator, PercentageVolumeOscillatorOutput,
+};
+
+mod fractals;
+pub use self::fractals::{FractalPoint, Fractals};
+
+mod relative_vigor_index;
+pub use self::relative_vigor_index::{RelativeVigorIndex, RelativeVigorIndexOutput};
+
+mod true_strength_index;
+pub use self::true_strength_index::{TrueStrengthIndex, TrueStrengthIndexOutput};
+
+mod mcginley_dynamic;
+pub use self::mcginley_dynamic::McGinleyDynamic;
+
+mod vidya;
+pub use self::vidya::Vidya;
+
+mod volume_profile;
+pub use self::volume_profile::{Binning, VolumeProfile, VolumeProfileOutput};
+
+mod rolling_correlation;
+pub use self::rolling_correlation::RollingCorrelation;
+
+mod rolling_beta;
+pub use self::rolling_beta::{RollingBeta, RollingBetaOutput};
+
+mod rolling_sharpe;
+pub use self::rolling_sharpe::RollingSharpe;
+
+mod rolling_sortino;
+pub use self::rolling_sortino::RollingSortino;
+
+mod parkinson_volatility;
+pub use self::parkinson_volatility::ParkinsonVolatility;
+
+mod garman_klass_volatility;
+pub use self::garman_klass_volatility::GarmanKlassVolatility;
+
+mod yang_zhang_volatility;
+pub use self::yang_zhang_volatility::YangZhangVolatility;
+
+mod rogers_satchell_volatility;
+pub use self::rogers_satchell_volatility::RogersSatchellVolatility;
+
+mod z_score;
+pub use self::z_score::{FlatWindow, ZScore};
+
+mod percentile_rank;
+pub use self::percentile_rank::PercentileRank;
+
+mod rolling_median;
+pub use self::rolling_median::RollingMedian;
+
+mod swing_index;
+pub use self::swing_index::SwingIndex;
+
+mod accumulative_swing_index;
+pub use self::accumulative_swing_index::AccumulativeSwingIndex;
+
+mod price_volume_trend;
+pub use self::price_volume_trend::{PriceVolumeTrend, PriceVolumeTrendOutput};
+
+mod volume_rsi;
+pub use self::volume_rsi::VolumeRsi;
+
+mod vertical_horizontal_filter;
+pub use self::vertical_horizontal_filter::VerticalHorizontalFilter;
+
+mod atr_bands;
+pub use self::atr_bands::{AtrBands, AtrBandsOutput};
+
+mod acceleration_bands;
+pub use self::acceleration_bands::{AccelerationBands, AccelerationBandsOutput};
+
+mod special_k;
+pub use self::special_k::SpecialK;
+
+mod demarker;
+pub use self::demarker::DeMarker;
+
+mod super_smoother;
+pub use self::super_smoother::SuperSmoother;
+
+mod roofing_filter;
+pub use self::roofing_filter::RoofingFilter;
+
+mod mama;
+pub use self::mama::{Mama, MamaOutput};
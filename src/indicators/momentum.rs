@@ -0,0 +1,194 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::traits::{Close, Lookback, Next, Period, Reset};
+use crate::units::{OutputUnit, Price};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Momentum (MOM)
+///
+/// The price change over the last _n_ periods, in price units. See
+/// [RateOfChange](struct.RateOfChange.html) for the change in percent.
+///
+/// # Formula
+///
+/// MOM = Price<sub>t</sub> - Price<sub>t-n</sub>
+///
+/// Where:
+///
+/// * MOM - current value of Momentum indicator
+/// * P<sub>t</sub> - price at the moment
+/// * P<sub>t-n</sub> - price _n_ periods ago, or the first price while less than _n_ periods
+///   are known
+///
+/// # Parameters
+///
+/// * _period_ - number of periods integer greater than 0. Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Momentum;
+/// use ta::Next;
+///
+/// let mut mom = Momentum::new(2).unwrap();
+/// assert_eq!(mom.next(10.0), 0.0);  //  0
+/// assert_eq!(mom.next(9.5), -0.5);  //  9.5 - 10
+/// assert_eq!(mom.next(12.0), 2.0);  //  12 - 10
+/// assert_eq!(mom.next(11.0), 1.5);  //  11 - 9.5
+/// ```
+///
+/// # Links
+///
+/// * [Momentum, Wikipedia](https://en.wikipedia.org/wiki/Momentum_(technical_analysis))
+///
+#[doc(alias = "MOM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Momentum {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+impl Momentum {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for Momentum {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for Momentum {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl OutputUnit for Momentum {
+    type Unit = Price;
+}
+
+impl Next<f64> for Momentum {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> f64 {
+        let previous = if self.count > self.period {
+            self.deque[self.index]
+        } else {
+            self.count += 1;
+            if self.count == 1 {
+                input
+            } else {
+                self.deque[0]
+            }
+        };
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        input - previous
+    }
+}
+
+impl<T: Close> Next<&T> for Momentum {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> f64 {
+        self.next(input.close())
+    }
+}
+
+impl Default for Momentum {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for Momentum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MOM({})", self.period)
+    }
+}
+
+impl Reset for Momentum {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Momentum);
+
+    #[test]
+    fn test_new() {
+        assert!(Momentum::new(0).is_err());
+        assert!(Momentum::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_f64() {
+        let mut mom = Momentum::new(3).unwrap();
+
+        assert_eq!(round(mom.next(10.0)), 0.0);
+        assert_eq!(round(mom.next(10.4)), 0.4);
+        assert_eq!(round(mom.next(10.57)), 0.57);
+        assert_eq!(round(mom.next(10.8)), 0.8);
+        assert_eq!(round(mom.next(10.9)), 0.5);
+        assert_eq!(round(mom.next(10.0)), -0.57);
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut mom = Momentum::new(1).unwrap();
+
+        assert_eq!(mom.next(&Bar::new().close(10)), 0.0);
+        assert_eq!(mom.next(&Bar::new().close(12)), 2.0);
+        assert_eq!(mom.next(&Bar::new().close(11)), -1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mom = Momentum::new(3).unwrap();
+
+        mom.next(12.3);
+        mom.next(15.0);
+
+        mom.reset();
+
+        assert_eq!(round(mom.next(10.0)), 0.0);
+        assert_eq!(round(mom.next(10.4)), 0.4);
+    }
+
+    #[test]
+    fn test_display() {
+        let mom = Momentum::new(10).unwrap();
+        assert_eq!(format!("{}", mom), "MOM(10)");
+    }
+}
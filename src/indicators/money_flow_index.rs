@@ -1,7 +1,8 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, High, Low, Next, Period, Reset, Volume};
+use crate::units::{OutputUnit, Percent};
+use crate::{Close, High, Lookback, Low, Next, Period, Reset, Volume};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -27,10 +28,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// Money Flow Index(MFI) = PMF / (PMF + NMF) * 100
 ///
+/// The flows are summed over the last _period_ periods. The first period and periods without
+/// any money flow in the window give 50.
 ///
 /// # Parameters
 ///
-/// * _period_ - number of periods, integer greater than 0
+/// * _period_ - number of periods, integer greater than 0. Default is 14.
 ///
 /// # Example
 ///
@@ -46,13 +49,11 @@ use serde::{Deserialize, Serialize};
 ///             .open(1.5)
 ///             .volume(1000.0)
 ///             .build().unwrap();
-/// mfi.next(&di);
-///
+/// assert_eq!(mfi.next(&di), 50.0);
 /// ```
 /// # Links
 /// * [Money Flow Index, Wikipedia](https://en.wikipedia.org/wiki/Money_flow_index)
 /// * [Money Flow Index, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:money_flow_index_mfi)
-
 #[doc(alias = "MFI")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -89,6 +90,16 @@ impl Period for MoneyFlowIndex {
     }
 }
 
+impl Lookback for MoneyFlowIndex {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl OutputUnit for MoneyFlowIndex {
+    type Unit = Percent;
+}
+
 impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
     type Output = f64;
 
@@ -102,7 +113,7 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
         };
 
         if self.count < self.period {
-            self.count = self.count + 1;
+            self.count += 1;
             if self.count == 1 {
                 self.previous_typical_price = tp;
                 return 50.0;
@@ -129,9 +140,11 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
         }
         self.previous_typical_price = tp;
 
-        self.total_positive_money_flow
-            / (self.total_positive_money_flow + self.total_negative_money_flow)
-            * 100.0
+        let total_money_flow = self.total_positive_money_flow + self.total_negative_money_flow;
+        if total_money_flow == 0.0 {
+            return 50.0;
+        }
+        self.total_positive_money_flow / total_money_flow * 100.0
     }
 }
 
@@ -200,6 +213,18 @@ mod tests {
         assert_eq!(round(mfi.next(&bar8)), 60.87);
     }
 
+    #[test]
+    fn test_next_without_money_flow() {
+        let mut mfi = MoneyFlowIndex::new(2).unwrap();
+
+        let bar = Bar::new().high(2).low(1).close(1.5).volume(100.0);
+        assert_eq!(mfi.next(&bar), 50.0);
+        assert_eq!(mfi.next(&bar), 50.0);
+
+        let bar = Bar::new().high(3).low(2).close(2.5).volume(0.0);
+        assert_eq!(mfi.next(&bar), 50.0);
+    }
+
     #[test]
     fn test_reset() {
         let mut mfi = MoneyFlowIndex::new(3).unwrap();
@@ -207,13 +232,21 @@ mod tests {
         let bar1 = Bar::new().high(3).low(1).close(2).volume(500.0);
         let bar2 = Bar::new().high(2.3).low(2.0).close(2.3).volume(1000.0);
 
+        let bar3 = Bar::new().high(9).low(7).close(8).volume(200.0);
+        let bar4 = Bar::new().high(5).low(3).close(4).volume(500.0);
+
         assert_eq!(round(mfi.next(&bar1)), 50.0);
         assert_eq!(round(mfi.next(&bar2)), 100.0);
+        assert_eq!(round(mfi.next(&bar3)), 100.0);
+        assert_eq!(round(mfi.next(&bar4)), 65.517);
 
         mfi.reset();
 
+        // The flows of the previous bars are cleared from the window.
         assert_eq!(round(mfi.next(&bar1)), 50.0);
         assert_eq!(round(mfi.next(&bar2)), 100.0);
+        assert_eq!(round(mfi.next(&bar3)), 100.0);
+        assert_eq!(round(mfi.next(&bar4)), 65.517);
     }
 
     #[test]
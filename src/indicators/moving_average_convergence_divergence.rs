@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Lookback, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -117,6 +117,12 @@ impl Reset for MovingAverageConvergenceDivergence {
     }
 }
 
+impl Lookback for MovingAverageConvergenceDivergence {
+    fn lookback(&self) -> usize {
+        self.fast_ema.lookback().max(self.slow_ema.lookback()) + self.signal_ema.lookback()
+    }
+}
+
 impl Default for MovingAverageConvergenceDivergence {
     fn default() -> Self {
         Self::new(12, 26, 9).unwrap()
@@ -183,6 +189,12 @@ mod tests {
         assert_eq!(round(macd.next(3.0).into()), (0.21, 0.09, 0.13));
     }
 
+    #[test]
+    fn test_lookback() {
+        let macd = Macd::new(12, 26, 9).unwrap();
+        assert_eq!(macd.lookback(), 33);
+    }
+
     #[test]
     fn test_default() {
         Macd::default();
@@ -0,0 +1,268 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage;
+use crate::math;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Nadaraya-Watson Envelope (NWE).
+///
+/// A causal (non repainting) Nadaraya-Watson kernel regression of the last _period_ values with
+/// a Gaussian kernel, surrounded by an envelope of the mean absolute error between the input and
+/// the regression. Unlike moving average bands, the kernel weights decay smoothly with the age of
+/// a value, which is controlled by the _bandwidth_.
+///
+/// # Formula
+///
+/// NW<sub>t</sub> = Σ w<sub>i</sub> * p<sub>t-i</sub> / Σ w<sub>i</sub>, for i = 0.._period_ - 1
+///
+/// w<sub>i</sub> = exp(-i<sup>2</sup> / (2 * _bandwidth_<sup>2</sup>))
+///
+/// MAE<sub>t</sub> = SMA(_period_) of |p<sub>t</sub> - NW<sub>t</sub>|
+///
+/// * _upper_ = NW + MAE * _multiplier_
+/// * _lower_ = NW - MAE * _multiplier_
+///
+/// # Parameters
+///
+/// * _period_ - number of values used by the regression and MAE (integer greater than 0). Default is 500.
+/// * _bandwidth_ - bandwidth of the Gaussian kernel (greater than 0). Default is 8.0.
+/// * _multiplier_ - MAE multiplier of the envelope (non negative). Default is 3.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::NadarayaWatsonEnvelope;
+/// use ta::Next;
+///
+/// let mut nwe = NadarayaWatsonEnvelope::new(10, 8.0, 3.0).unwrap();
+///
+/// let out = nwe.next(2.0);
+/// assert_eq!(out.average, 2.0);
+/// assert_eq!(out.upper, 2.0);
+/// assert_eq!(out.lower, 2.0);
+///
+/// let out = nwe.next(2.0);
+/// assert_eq!(out.average, 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Kernel regression, Wikipedia](https://en.wikipedia.org/wiki/Kernel_regression#Nadaraya%E2%80%93Watson_kernel_regression)
+///
+#[doc(alias = "NWE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct NadarayaWatsonEnvelope {
+    period: usize,
+    bandwidth: f64,
+    multiplier: f64,
+    index: usize,
+    count: usize,
+    weights: Box<[f64]>,
+    deque: Box<[f64]>,
+    mae: SimpleMovingAverage,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NadarayaWatsonEnvelopeOutput {
+    pub average: f64,
+    pub upper: f64,
+    pub lower: f64,
+}
+
+impl NadarayaWatsonEnvelope {
+    pub fn new(period: usize, bandwidth: f64, multiplier: f64) -> Result<Self> {
+        if !(bandwidth > 0.0
+            && bandwidth.is_finite()
+            && multiplier >= 0.0
+            && multiplier.is_finite())
+        {
+            return Err(TaError::InvalidParameter);
+        }
+        let mae = SimpleMovingAverage::new(period)?;
+        let weights = (0..period)
+            .map(|i| math::exp(-((i * i) as f64) / (2.0 * bandwidth * bandwidth)))
+            .collect::<Vec<f64>>();
+
+        Ok(Self {
+            period,
+            bandwidth,
+            multiplier,
+            index: 0,
+            count: 0,
+            weights: weights.into_boxed_slice(),
+            deque: vec![0.0; period].into_boxed_slice(),
+            mae,
+        })
+    }
+
+    pub fn bandwidth(&self) -> f64 {
+        self.bandwidth
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+}
+
+impl Period for NadarayaWatsonEnvelope {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for NadarayaWatsonEnvelope {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for NadarayaWatsonEnvelope {
+    type Output = NadarayaWatsonEnvelopeOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.deque[self.index] = input;
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        // Walk from the most recent value backwards, so the i-th value gets the i-th weight.
+        let mut sum = 0.0;
+        let mut weight_sum = 0.0;
+        let mut position = self.index;
+        for weight in &self.weights[..self.count] {
+            sum += weight * self.deque[position];
+            weight_sum += weight;
+            position = if position == 0 {
+                self.period - 1
+            } else {
+                position - 1
+            };
+        }
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let average = sum / weight_sum;
+        let mae = self.mae.next((input - average).abs()) * self.multiplier;
+
+        Self::Output {
+            average,
+            upper: average + mae,
+            lower: average - mae,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for NadarayaWatsonEnvelope {
+    type Output = NadarayaWatsonEnvelopeOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for NadarayaWatsonEnvelope {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+        self.mae.reset();
+    }
+}
+
+impl Default for NadarayaWatsonEnvelope {
+    fn default() -> Self {
+        Self::new(500, 8.0, 3.0).unwrap()
+    }
+}
+
+impl fmt::Display for NadarayaWatsonEnvelope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "NWE({}, {}, {})",
+            self.period, self.bandwidth, self.multiplier
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(NadarayaWatsonEnvelope);
+
+    #[test]
+    fn test_new() {
+        assert!(NadarayaWatsonEnvelope::new(0, 8.0, 3.0).is_err());
+        assert!(NadarayaWatsonEnvelope::new(10, 0.0, 3.0).is_err());
+        assert!(NadarayaWatsonEnvelope::new(10, 8.0, -1.0).is_err());
+        assert!(NadarayaWatsonEnvelope::new(10, f64::NAN, 3.0).is_err());
+        assert!(NadarayaWatsonEnvelope::new(10, 8.0, f64::NAN).is_err());
+        assert!(NadarayaWatsonEnvelope::new(10, 8.0, f64::INFINITY).is_err());
+        assert!(NadarayaWatsonEnvelope::new(1, 8.0, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut nwe = NadarayaWatsonEnvelope::new(3, 1.0, 2.0).unwrap();
+
+        // weights: 1.0, exp(-0.5) = 0.607, exp(-2) = 0.135
+        let a = nwe.next(2.0);
+        let b = nwe.next(4.0);
+        let c = nwe.next(6.0);
+        let d = nwe.next(2.0);
+
+        assert_eq!(round(a.average), 2.0);
+        assert_eq!(round(b.average), 3.245);
+        assert_eq!(round(c.average), 4.993);
+        assert_eq!(round(d.average), 3.548);
+
+        assert_eq!(round(b.upper), 4.0);
+        assert_eq!(round(b.lower), 2.49);
+        assert_eq!(round(d.upper), 5.755);
+        assert_eq!(round(d.lower), 1.341);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut nwe = NadarayaWatsonEnvelope::new(3, 1.0, 2.0).unwrap();
+
+        assert_eq!(round(nwe.next(&Bar::new().close(2)).average), 2.0);
+        assert_eq!(round(nwe.next(&Bar::new().close(4)).average), 3.245);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut nwe = NadarayaWatsonEnvelope::new(3, 1.0, 2.0).unwrap();
+
+        nwe.next(2.0);
+        nwe.next(4.0);
+        nwe.next(6.0);
+
+        nwe.reset();
+        assert_eq!(nwe.next(2.0).average, 2.0);
+        assert_eq!(round(nwe.next(4.0).average), 3.245);
+    }
+
+    #[test]
+    fn test_default() {
+        NadarayaWatsonEnvelope::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let nwe = NadarayaWatsonEnvelope::new(50, 8.0, 3.0).unwrap();
+        assert_eq!(format!("{}", nwe), "NWE(50, 8, 3)");
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{AverageTrueRange, ExponentialMovingAverage};
+use crate::units::{OutputUnit, Percent};
+use crate::{Close, High, Lookback, Low, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Normalized average true range (NATR).
+///
+/// [Average true range](struct.AverageTrueRange.html) expressed as a percentage of the close
+/// price, which makes it comparable across instruments and across long price histories.
+///
+/// # Formula
+///
+/// NATR(period)<sub>t</sub> = ATR(period)<sub>t</sub> / close<sub>t</sub> * 100
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of the moving average (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ExponentialMovingAverage as Ema, NormalizedAverageTrueRange};
+/// use ta::{DataItem, Next};
+///
+/// let mut natr = NormalizedAverageTrueRange::<Ema>::new(3).unwrap();
+/// let bar = DataItem::builder()
+///     .open(9.5)
+///     .high(10.0)
+///     .low(9.0)
+///     .close(9.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// // ATR = 1.0
+/// assert_eq!(natr.next(&bar), 1.0 / 9.5 * 100.0);
+/// ```
+///
+/// # Links
+///
+/// * [NATR, TA-Lib](https://ta-lib.org/function.html)
+///
+#[doc(alias = "NATR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct NormalizedAverageTrueRange<MA = ExponentialMovingAverage> {
+    atr: AverageTrueRange<MA>,
+}
+
+impl<MA: NewWithPeriod> NormalizedAverageTrueRange<MA> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            atr: AverageTrueRange::with_ma(period)?,
+        })
+    }
+}
+
+impl<MA: NewWithPeriod> NewWithPeriod for NormalizedAverageTrueRange<MA> {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl<MA: Period> Period for NormalizedAverageTrueRange<MA> {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl<MA: Lookback> Lookback for NormalizedAverageTrueRange<MA> {
+    fn lookback(&self) -> usize {
+        self.atr.lookback()
+    }
+}
+
+impl<MA> OutputUnit for NormalizedAverageTrueRange<MA> {
+    type Unit = Percent;
+}
+
+impl<MA: Next<f64, Output = f64>> Next<f64> for NormalizedAverageTrueRange<MA> {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let atr = self.atr.next(input);
+        if input == 0.0 {
+            return 0.0;
+        }
+        atr / input * 100.0
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: High + Low + Close> Next<&T>
+    for NormalizedAverageTrueRange<MA>
+{
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let atr = self.atr.next(input);
+        if input.close() == 0.0 {
+            return 0.0;
+        }
+        atr / input.close() * 100.0
+    }
+}
+
+impl<MA: Reset> Reset for NormalizedAverageTrueRange<MA> {
+    fn reset(&mut self) {
+        self.atr.reset();
+    }
+}
+
+impl Default for NormalizedAverageTrueRange {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<MA: Period> fmt::Display for NormalizedAverageTrueRange<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NATR({})", self.atr.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage as Ema, RunningMovingAverage as Rma};
+    use crate::test_helper::*;
+
+    test_indicator!(NormalizedAverageTrueRange);
+
+    #[test]
+    fn test_new() {
+        assert!(NormalizedAverageTrueRange::<Ema>::new(0).is_err());
+        assert!(NormalizedAverageTrueRange::<Ema>::new(1).is_ok());
+        assert!(NormalizedAverageTrueRange::<Rma>::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut natr = NormalizedAverageTrueRange::<Ema>::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(7.5).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(9.5);
+        let bar3 = Bar::new().high(9).low(5).close(8);
+
+        // ATR: 2.5, 2.25, 3.375
+        assert_eq!(round(natr.next(&bar1)), 27.778);
+        assert_eq!(round(natr.next(&bar2)), 23.684);
+        assert_eq!(round(natr.next(&bar3)), 42.188);
+    }
+
+    #[test]
+    fn test_next_zero_close() {
+        let mut natr = NormalizedAverageTrueRange::<Ema>::new(3).unwrap();
+        assert_eq!(natr.next(&Bar::new().high(1).low(0).close(0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut natr = NormalizedAverageTrueRange::<Ema>::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(7.5).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(9.5);
+
+        natr.next(&bar1);
+        natr.next(&bar2);
+
+        natr.reset();
+        assert_eq!(round(natr.next(&bar1)), 27.778);
+    }
+
+    #[test]
+    fn test_default() {
+        NormalizedAverageTrueRange::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = NormalizedAverageTrueRange::<Ema>::new(8).unwrap();
+        assert_eq!(format!("{}", indicator), "NATR(8)");
+    }
+}
@@ -0,0 +1,344 @@
+use std::fmt;
+
+use crate::calendar::Calendar;
+use crate::errors::{Result, TaError};
+use crate::indicators::SessionLevels;
+use crate::{Close, High, Lookback, Low, Next, Open, Reset, Timestamp};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Opening range breakout (ORB).
+///
+/// The opening range is the high and the low of the bars starting in the first _range_length_
+/// seconds of a session. Once it is complete, a close above its high is an upward breakout and
+/// a close below its low a downward breakout. A breakout is reported on the bar that crosses
+/// the level, so the close must come back inside the range before the same breakout is
+/// reported again.
+///
+/// Sessions are tracked by [SessionLevels](struct.SessionLevels.html), optionally following a
+/// [Calendar](../calendar/struct.Calendar.html) with [with_calendar](#method.with_calendar). Inputs without a
+/// timestamp belong to the opening range while it is not complete. When the first bar of a
+/// session starts after the opening range, e.g. when the data starts in the middle of a
+/// session, the range is that bar.
+///
+/// # Parameters
+///
+/// * _range_length_ - length of the opening range in seconds (integer greater than 0). Default
+///   is 1800, 30 minutes.
+/// * _session_length_ - length of a session in seconds (integer greater than 0). Default is
+///   86400, one day.
+/// * _session_offset_ - start of the sessions relative to the Unix epoch in seconds. Default is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{BreakoutDirection, OpeningRangeBreakout};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64, close: f64, timestamp: i64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .timestamp(timestamp)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// // 15 minutes opening range of daily sessions
+/// let mut orb = OpeningRangeBreakout::daily(900).unwrap();
+/// orb.next(&bar(10.5, 9.5, 10.0, 0));
+/// orb.next(&bar(11.0, 10.0, 10.8, 600));
+///
+/// let out = orb.next(&bar(11.5, 10.8, 11.2, 900));
+/// assert!(out.complete);
+/// assert_eq!((out.high, out.low), (11.0, 9.5));
+/// assert_eq!(out.breakout, Some(BreakoutDirection::Up));
+/// ```
+///
+/// # Links
+///
+/// * [Opening Range Breakout, Investopedia](https://www.investopedia.com/terms/o/openingrange.asp)
+///
+#[doc(alias = "ORB")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct OpeningRangeBreakout {
+    range_length: i64,
+    session: SessionLevels,
+    high: f64,
+    low: f64,
+    complete: bool,
+    prev_close: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpeningRangeBreakoutOutput {
+    /// High of the opening range, so far when it is not complete.
+    pub high: f64,
+    /// Low of the opening range, so far when it is not complete.
+    pub low: f64,
+    /// The opening range of the session is complete.
+    pub complete: bool,
+    pub breakout: Option<BreakoutDirection>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakoutDirection {
+    Up,
+    Down,
+}
+
+impl OpeningRangeBreakout {
+    pub fn new(range_length: i64, session_length: i64, session_offset: i64) -> Result<Self> {
+        Self::with_session(
+            range_length,
+            SessionLevels::new(session_length, session_offset)?,
+        )
+    }
+
+    /// Creates an ORB of sessions starting at midnight UTC every day.
+    pub fn daily(range_length: i64) -> Result<Self> {
+        Self::new(range_length, 24 * 60 * 60, 0)
+    }
+
+    /// Creates an ORB of the trading days of a calendar, the opening range starting at the open
+    /// of the market.
+    pub fn with_calendar(range_length: i64, calendar: Calendar) -> Result<Self> {
+        Self::with_session(range_length, SessionLevels::with_calendar(calendar))
+    }
+
+    fn with_session(range_length: i64, session: SessionLevels) -> Result<Self> {
+        if range_length <= 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            range_length,
+            session,
+            high: 0.0,
+            low: 0.0,
+            complete: false,
+            prev_close: None,
+        })
+    }
+
+    pub fn range_length(&self) -> i64 {
+        self.range_length
+    }
+}
+
+impl Lookback for OpeningRangeBreakout {
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+impl<T: Open + High + Low + Close + Timestamp> Next<&T> for OpeningRangeBreakout {
+    type Output = OpeningRangeBreakoutOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let levels = self.session.next(input);
+        if levels.new_session {
+            self.complete = false;
+            self.prev_close = None;
+        }
+
+        let in_range = match input.timestamp() {
+            Some(timestamp) => {
+                timestamp - self.session.session_start(timestamp) < self.range_length
+            }
+            None => !self.complete,
+        };
+        if !self.complete {
+            // Until the range is complete, it is the range of the session.
+            if levels.new_session || in_range {
+                self.high = levels.high;
+                self.low = levels.low;
+            }
+            self.complete = !in_range;
+        }
+
+        let close = input.close();
+        let breakout = match self.prev_close {
+            Some(prev) if self.complete && close > self.high && prev <= self.high => {
+                Some(BreakoutDirection::Up)
+            }
+            Some(prev) if self.complete && close < self.low && prev >= self.low => {
+                Some(BreakoutDirection::Down)
+            }
+            _ => None,
+        };
+        self.prev_close = Some(close);
+
+        OpeningRangeBreakoutOutput {
+            high: self.high,
+            low: self.low,
+            complete: self.complete,
+            breakout,
+        }
+    }
+}
+
+impl Reset for OpeningRangeBreakout {
+    fn reset(&mut self) {
+        self.session.reset();
+        self.high = 0.0;
+        self.low = 0.0;
+        self.complete = false;
+        self.prev_close = None;
+    }
+}
+
+impl Default for OpeningRangeBreakout {
+    fn default() -> Self {
+        Self::daily(30 * 60).unwrap()
+    }
+}
+
+impl fmt::Display for OpeningRangeBreakout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ORB({}, {})", self.range_length, self.session.sessions())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64, timestamp: i64) -> Bar {
+        Bar::new()
+            .open(close)
+            .high(high)
+            .low(low)
+            .close(close)
+            .timestamp(timestamp)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(OpeningRangeBreakout::new(0, 3600, 0).is_err());
+        assert!(OpeningRangeBreakout::new(60, 0, 0).is_err());
+        assert!(OpeningRangeBreakout::new(60, 3600, 1800).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        // 2 minutes opening range of hourly sessions
+        let mut orb = OpeningRangeBreakout::new(120, 3600, 0).unwrap();
+
+        let test_data = vec![
+            // high, low, close, timestamp, range high, range low, complete, breakout
+            (10.5, 9.5, 10.0, 0, 10.5, 9.5, false, None),
+            (11.0, 9.8, 10.2, 60, 11.0, 9.5, false, None),
+            (10.9, 10.1, 10.5, 120, 11.0, 9.5, true, None),
+            (
+                11.4,
+                10.4,
+                11.3,
+                180,
+                11.0,
+                9.5,
+                true,
+                Some(BreakoutDirection::Up),
+            ),
+            (11.6, 11.1, 11.5, 240, 11.0, 9.5, true, None),
+            (11.5, 10.0, 10.1, 300, 11.0, 9.5, true, None),
+            (
+                10.1,
+                9.0,
+                9.2,
+                360,
+                11.0,
+                9.5,
+                true,
+                Some(BreakoutDirection::Down),
+            ),
+            (
+                11.3,
+                9.2,
+                11.2,
+                420,
+                11.0,
+                9.5,
+                true,
+                Some(BreakoutDirection::Up),
+            ),
+            // next session
+            (12.0, 11.0, 11.5, 3600, 12.0, 11.0, false, None),
+            (11.6, 10.0, 10.5, 3660, 12.0, 10.0, false, None),
+            (
+                12.5,
+                11.0,
+                12.4,
+                3720,
+                12.0,
+                10.0,
+                true,
+                Some(BreakoutDirection::Up),
+            ),
+        ];
+
+        for (high, low, close, timestamp, range_high, range_low, complete, breakout) in test_data {
+            let out = orb.next(&bar(high, low, close, timestamp));
+            assert_eq!(out.high, range_high);
+            assert_eq!(out.low, range_low);
+            assert_eq!(out.complete, complete);
+            assert_eq!(out.breakout, breakout);
+        }
+    }
+
+    #[test]
+    fn test_next_late_start() {
+        let mut orb = OpeningRangeBreakout::new(120, 3600, 0).unwrap();
+
+        let out = orb.next(&bar(10.5, 9.5, 10.0, 600));
+        assert!(out.complete);
+        assert_eq!((out.high, out.low), (10.5, 9.5));
+        assert_eq!(out.breakout, None);
+
+        let out = orb.next(&bar(11.0, 10.0, 10.8, 660));
+        assert_eq!(out.breakout, Some(BreakoutDirection::Up));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut orb = OpeningRangeBreakout::new(120, 3600, 0).unwrap();
+        orb.next(&bar(10.5, 9.5, 10.0, 0));
+        orb.next(&bar(10.5, 9.5, 10.0, 120));
+
+        orb.reset();
+        let out = orb.next(&bar(20.0, 19.0, 19.5, 180));
+        assert_eq!((out.high, out.low), (20.0, 19.0));
+    }
+
+    #[test]
+    fn test_default() {
+        OpeningRangeBreakout::default();
+    }
+
+    #[test]
+    fn test_with_calendar() {
+        assert!(OpeningRangeBreakout::with_calendar(0, Calendar::us_equities()).is_err());
+
+        // Monday 2024-01-08 09:30 EST
+        let open = 1_704_672_000 + 14 * 3600 + 1800;
+        let mut orb = OpeningRangeBreakout::with_calendar(900, Calendar::us_equities()).unwrap();
+        orb.next(&bar(10.5, 9.5, 10.0, open - 3600));
+        orb.next(&bar(11.0, 10.0, 10.8, open + 600));
+
+        let out = orb.next(&bar(11.5, 10.8, 11.2, open + 900));
+        assert!(out.complete);
+        assert_eq!((out.high, out.low), (11.0, 9.5));
+        assert_eq!(out.breakout, Some(BreakoutDirection::Up));
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = OpeningRangeBreakout::new(900, 86400, 48600).unwrap();
+        assert_eq!(format!("{}", indicator), "ORB(900, 86400, 48600)");
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage;
+use crate::math;
+use crate::{High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Parkinson volatility.
+///
+/// Range-based estimator of the volatility by Michael Parkinson, computed from the high and
+/// low prices of the last _period_ bars instead of the closes. The range holds more
+/// information than the close-to-close change, so it needs fewer bars for the same accuracy,
+/// but it assumes there are no price gaps between bars and no drift, so it underestimates the
+/// volatility of markets that gap overnight.
+///
+/// The output is annualized, e.g. 0.25 for a volatility of 25% a year. The prices must be
+/// positive.
+///
+/// # Formula
+///
+/// σ = √(Σ ln(H<sub>i</sub> / L<sub>i</sub>)<sup>2</sup> / (4 * ln(2) * n) * _bars_per_year_)
+///
+/// Where the sum runs over the _n_ bars of the window.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 20.
+/// * _bars_per_year_ - number of bars in a year, e.g. 252 for daily bars of stocks or 52 for
+///   weekly bars (greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ParkinsonVolatility;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64| {
+///     DataItem::builder()
+///         .open(low)
+///         .high(high)
+///         .low(low)
+///         .close(high)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut parkinson = ParkinsonVolatility::new(2, 252.0).unwrap();
+/// assert_eq!((parkinson.next(&bar(102.0, 100.0)) * 1000.0).round(), 189.0);
+/// assert_eq!((parkinson.next(&bar(101.0, 99.0)) * 1000.0).round(), 190.0);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ParkinsonVolatility {
+    bars_per_year: f64,
+    sma: SimpleMovingAverage,
+}
+
+impl ParkinsonVolatility {
+    pub fn new(period: usize, bars_per_year: f64) -> Result<Self> {
+        if bars_per_year <= 0.0 || !bars_per_year.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            bars_per_year,
+            sma: SimpleMovingAverage::new(period)?,
+        })
+    }
+
+    pub fn bars_per_year(&self) -> f64 {
+        self.bars_per_year
+    }
+}
+
+impl Period for ParkinsonVolatility {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Lookback for ParkinsonVolatility {
+    fn lookback(&self) -> usize {
+        self.sma.lookback()
+    }
+}
+
+impl<T: High + Low> Next<&T> for ParkinsonVolatility {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let range = math::ln(input.high() / input.low());
+        let variance = self.sma.next(range * range) / (4.0 * std::f64::consts::LN_2);
+        (variance.max(0.0) * self.bars_per_year).sqrt()
+    }
+}
+
+impl Reset for ParkinsonVolatility {
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
+
+impl Default for ParkinsonVolatility {
+    fn default() -> Self {
+        Self::new(20, 252.0).unwrap()
+    }
+}
+
+impl fmt::Display for ParkinsonVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PARKINSON({}, {})",
+            self.sma.period(),
+            self.bars_per_year
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64) -> Bar {
+        Bar::new().high(high).low(low).close(low)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ParkinsonVolatility::new(0, 252.0).is_err());
+        assert!(ParkinsonVolatility::new(20, 0.0).is_err());
+        assert!(ParkinsonVolatility::new(20, f64::NAN).is_err());
+        assert!(ParkinsonVolatility::new(1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut parkinson = ParkinsonVolatility::new(3, 252.0).unwrap();
+
+        assert_eq!(round(parkinson.next(&bar(102.0, 100.0))), 0.189);
+        assert_eq!(round(parkinson.next(&bar(101.0, 99.0))), 0.19);
+        assert_eq!(round(parkinson.next(&bar(105.0, 100.0))), 0.31);
+        // (102, 100) left the window
+        assert_eq!(round(parkinson.next(&bar(100.0, 100.0))), 0.29);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut parkinson = ParkinsonVolatility::new(3, 252.0).unwrap();
+        assert_eq!(parkinson.next(&bar(100.0, 100.0)), 0.0);
+        assert_eq!(parkinson.next(&bar(100.0, 100.0)), 0.0);
+    }
+
+    #[test]
+    fn test_annualization() {
+        let mut daily = ParkinsonVolatility::new(3, 1.0).unwrap();
+        let mut yearly = ParkinsonVolatility::new(3, 252.0).unwrap();
+        let input = bar(102.0, 100.0);
+        assert_eq!(
+            round(daily.next(&input) * 252.0f64.sqrt()),
+            round(yearly.next(&input))
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut parkinson = ParkinsonVolatility::new(3, 252.0).unwrap();
+        parkinson.next(&bar(105.0, 100.0));
+
+        parkinson.reset();
+        assert_eq!(round(parkinson.next(&bar(102.0, 100.0))), 0.189);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let parkinson = ParkinsonVolatility::new(20, 252.0).unwrap();
+        assert_eq!(parkinson.period(), 20);
+        assert_eq!(parkinson.lookback(), 19);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(ParkinsonVolatility::default().bars_per_year(), 252.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let parkinson = ParkinsonVolatility::new(20, 252.0).unwrap();
+        assert_eq!(format!("{}", parkinson), "PARKINSON(20, 252)");
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Lookback, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -117,6 +117,12 @@ impl Reset for PercentagePriceOscillator {
     }
 }
 
+impl Lookback for PercentagePriceOscillator {
+    fn lookback(&self) -> usize {
+        self.fast_ema.lookback().max(self.slow_ema.lookback()) + self.signal_ema.lookback()
+    }
+}
+
 impl Default for PercentagePriceOscillator {
     fn default() -> Self {
         Self::new(12, 26, 9).unwrap()
@@ -0,0 +1,227 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage;
+use crate::{Lookback, NewWithPeriod, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Percentage Volume Oscillator (PVO).
+///
+/// The [PPO](struct.PercentagePriceOscillator.html) applied to the volume: the difference
+/// between a fast and a slow moving average of the volume, as a percentage of the slow one. A
+/// positive PVO means the volume is above its longer term average, which confirms a price
+/// breakout. The moving averages are [EMAs](struct.ExponentialMovingAverage.html) by default,
+/// but any moving average implementing [NewWithPeriod](../trait.NewWithPeriod.html) can be used
+/// instead.
+///
+/// # Formula
+///
+/// PVO = (MA(_fast_period_) of volume - MA(_slow_period_) of volume) / MA(_slow_period_) of volume * 100
+///
+/// signal = MA(_signal_period_) of PVO
+///
+/// histogram = PVO - signal
+///
+/// The PVO is 0 while the slow average of the volume is 0.
+///
+/// # Parameters
+///
+/// * _fast_period_ - period for the fast MA. Default is 12.
+/// * _slow_period_ - period for the slow MA. Default is 26.
+/// * _signal_period_ - period for the signal MA. Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ExponentialMovingAverage as Ema, PercentageVolumeOscillator as Pvo};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |volume: f64| {
+///     DataItem::builder()
+///         .open(10.0)
+///         .high(10.0)
+///         .low(10.0)
+///         .close(10.0)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut pvo = Pvo::<Ema>::new(3, 6, 4).unwrap();
+///
+/// assert_eq!(pvo.next(&bar(2000.0)).pvo, 0.0);
+/// let out = pvo.next(&bar(3000.0));
+/// assert_eq!((out.pvo * 1000.0).round(), 9375.0);
+/// ```
+///
+/// # Links
+///
+/// * [Percentage Volume Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:percentage_volume_oscillator_pvo)
+///
+#[doc(alias = "PVO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PercentageVolumeOscillator<MA = ExponentialMovingAverage> {
+    fast_ma: MA,
+    slow_ma: MA,
+    signal_ma: MA,
+}
+
+impl<MA: NewWithPeriod> PercentageVolumeOscillator<MA> {
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        Ok(Self {
+            fast_ma: MA::new_with_period(fast_period)?,
+            slow_ma: MA::new_with_period(slow_period)?,
+            signal_ma: MA::new_with_period(signal_period)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentageVolumeOscillatorOutput {
+    pub pvo: f64,
+    pub signal: f64,
+    pub histogram: f64,
+}
+
+impl From<PercentageVolumeOscillatorOutput> for (f64, f64, f64) {
+    fn from(po: PercentageVolumeOscillatorOutput) -> Self {
+        (po.pvo, po.signal, po.histogram)
+    }
+}
+
+impl<MA: Next<f64, Output = f64>> Next<f64> for PercentageVolumeOscillator<MA> {
+    type Output = PercentageVolumeOscillatorOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let fast_val = self.fast_ma.next(input);
+        let slow_val = self.slow_ma.next(input);
+
+        let pvo = if slow_val == 0.0 {
+            0.0
+        } else {
+            (fast_val - slow_val) / slow_val * 100.0
+        };
+        let signal = self.signal_ma.next(pvo);
+        let histogram = pvo - signal;
+
+        PercentageVolumeOscillatorOutput {
+            pvo,
+            signal,
+            histogram,
+        }
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: Volume> Next<&T> for PercentageVolumeOscillator<MA> {
+    type Output = PercentageVolumeOscillatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.volume())
+    }
+}
+
+impl<MA: Reset> Reset for PercentageVolumeOscillator<MA> {
+    fn reset(&mut self) {
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+    }
+}
+
+impl<MA: Lookback> Lookback for PercentageVolumeOscillator<MA> {
+    fn lookback(&self) -> usize {
+        self.fast_ma.lookback().max(self.slow_ma.lookback()) + self.signal_ma.lookback()
+    }
+}
+
+impl Default for PercentageVolumeOscillator {
+    fn default() -> Self {
+        Self::new(12, 26, 9).unwrap()
+    }
+}
+
+impl<MA: Period> fmt::Display for PercentageVolumeOscillator<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PVO({}, {}, {})",
+            self.fast_ma.period(),
+            self.slow_ma.period(),
+            self.signal_ma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+    type Pvo = PercentageVolumeOscillator<Ema>;
+
+    fn round3(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        (round(nums.0), round(nums.1), round(nums.2))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Pvo::new(0, 1, 1).is_err());
+        assert!(Pvo::new(1, 0, 1).is_err());
+        assert!(Pvo::new(1, 1, 0).is_err());
+        assert!(Pvo::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        assert_eq!(round3(pvo.next(2000.0).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round3(pvo.next(3000.0).into()), (9.375, 3.75, 5.625));
+        assert_eq!(round3(pvo.next(4200.0).into()), (18.264, 9.555, 8.708));
+        assert_eq!(round3(pvo.next(7000.0).into()), (28.625, 17.183, 11.442));
+        assert_eq!(round3(pvo.next(6700.0).into()), (24.006, 19.912, 4.093));
+        assert_eq!(round3(pvo.next(6500.0).into()), (17.842, 19.084, -1.242));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut pvo = PercentageVolumeOscillator::<Sma>::new(1, 2, 1).unwrap();
+
+        assert_eq!(pvo.next(&Bar::new().volume(0.0)).pvo, 0.0);
+        assert_eq!(pvo.next(&Bar::new().volume(100.0)).pvo, 100.0);
+        let out = pvo.next(&Bar::new().volume(300.0));
+        assert_eq!(round3(out.into()), (50.0, 50.0, 0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        assert_eq!(round3(pvo.next(2000.0).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round3(pvo.next(3000.0).into()), (9.375, 3.75, 5.625));
+
+        pvo.reset();
+
+        assert_eq!(round3(pvo.next(2000.0).into()), (0.0, 0.0, 0.0));
+        assert_eq!(round3(pvo.next(3000.0).into()), (9.375, 3.75, 5.625));
+    }
+
+    #[test]
+    fn test_lookback() {
+        let pvo = Pvo::new(12, 26, 9).unwrap();
+        assert_eq!(pvo.lookback(), 33);
+    }
+
+    #[test]
+    fn test_default() {
+        PercentageVolumeOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Pvo::new(13, 30, 10).unwrap();
+        assert_eq!(format!("{}", indicator), "PVO(13, 30, 10)");
+    }
+}
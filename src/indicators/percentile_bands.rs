@@ -0,0 +1,221 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::sorted_window::SortedWindow;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Percentile bands.
+///
+/// A channel made of the _lower_ and _upper_ percentiles of the prices over the last _period_
+/// periods, around their median. Unlike the highest high and the lowest low of a Donchian
+/// channel, the bands ignore a few extreme prices, so a single spike does not widen the channel
+/// for _period_ periods.
+///
+/// Bars are fed by their close. Wrap the bands in [Sourced](struct.Sourced.html) to use the
+/// typical price or another [Source](../enum.Source.html) instead.
+///
+/// # Formula
+///
+/// The _p_ percentile of _n_ sorted prices _x<sub>0</sub>_ ≤ ... ≤ _x<sub>n-1</sub>_ is
+/// interpolated linearly between the prices around the position _h_ = (_n_ - 1) * _p_ / 100:
+///
+/// P = x<sub>⌊h⌋</sub> + (h - ⌊h⌋) * (x<sub>⌊h⌋+1</sub> - x<sub>⌊h⌋</sub>)
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _lower_ - percentile of the lower band, from 0 to 100. Default is 25.
+/// * _upper_ - percentile of the upper band, from _lower_ to 100. Default is 75.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::PercentileBands;
+/// use ta::Next;
+///
+/// let mut bands = PercentileBands::new(4, 25.0, 75.0).unwrap();
+/// bands.next(10.0);
+/// bands.next(12.0);
+/// bands.next(11.0);
+///
+/// let out = bands.next(15.0);
+/// assert_eq!(out.lower, 10.75);
+/// assert_eq!(out.median, 11.5);
+/// assert_eq!(out.upper, 12.75);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PercentileBands {
+    period: usize,
+    lower: f64,
+    upper: f64,
+    window: SortedWindow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentileBandsOutput {
+    pub lower: f64,
+    pub median: f64,
+    pub upper: f64,
+}
+
+impl PercentileBands {
+    pub fn new(period: usize, lower: f64, upper: f64) -> Result<Self> {
+        if period == 0 || !(0.0..=100.0).contains(&lower) || !(lower..=100.0).contains(&upper) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            lower,
+            upper,
+            window: SortedWindow::new(period),
+        })
+    }
+
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+}
+
+impl Period for PercentileBands {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for PercentileBands {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for PercentileBands {
+    type Output = PercentileBandsOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.window.push(input);
+
+        PercentileBandsOutput {
+            lower: self.window.quantile(self.lower / 100.0),
+            median: self.window.quantile(0.5),
+            upper: self.window.quantile(self.upper / 100.0),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for PercentileBands {
+    type Output = PercentileBandsOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for PercentileBands {
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+impl Default for PercentileBands {
+    fn default() -> Self {
+        Self::new(20, 25.0, 75.0).unwrap()
+    }
+}
+
+impl fmt::Display for PercentileBands {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PCT_BANDS({}, {}, {})",
+            self.period, self.lower, self.upper
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::Sourced;
+    use crate::test_helper::*;
+    use crate::Source;
+
+    #[test]
+    fn test_new() {
+        assert!(PercentileBands::new(0, 25.0, 75.0).is_err());
+        assert!(PercentileBands::new(10, -1.0, 75.0).is_err());
+        assert!(PercentileBands::new(10, 25.0, 101.0).is_err());
+        assert!(PercentileBands::new(10, 75.0, 25.0).is_err());
+        assert!(PercentileBands::new(1, 0.0, 100.0).is_ok());
+        assert!(PercentileBands::new(10, 50.0, 50.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut bands = PercentileBands::new(4, 25.0, 75.0).unwrap();
+
+        let test_data = vec![
+            // input, lower, median, upper
+            (10.0, 10.0, 10.0, 10.0),
+            (12.0, 10.5, 11.0, 11.5),
+            (11.0, 10.5, 11.0, 11.5),
+            (15.0, 10.75, 11.5, 12.75),
+            (13.0, 11.75, 12.5, 13.5),
+            (9.0, 10.5, 12.0, 13.5),
+            (14.0, 12.0, 13.5, 14.25),
+        ];
+
+        for (input, lower, median, upper) in test_data {
+            let out = bands.next(input);
+            assert_eq!(round(out.lower), lower);
+            assert_eq!(round(out.median), median);
+            assert_eq!(round(out.upper), upper);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut bands = PercentileBands::new(3, 0.0, 100.0).unwrap();
+        bands.next(&Bar::new().close(2));
+        bands.next(&Bar::new().close(6));
+        let out = bands.next(&Bar::new().close(3));
+        assert_eq!((out.lower, out.median, out.upper), (2.0, 3.0, 6.0));
+
+        let mut typical = Sourced::new(
+            PercentileBands::new(2, 0.0, 100.0).unwrap(),
+            Source::Typical,
+        );
+        typical.next(&Bar::new().high(4).low(1).close(1));
+        let out = typical.next(&Bar::new().high(6).low(3).close(3));
+        assert_eq!((out.lower, out.upper), (2.0, 4.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bands = PercentileBands::new(4, 25.0, 75.0).unwrap();
+        bands.next(10.0);
+        bands.next(12.0);
+
+        bands.reset();
+        let out = bands.next(20.0);
+        assert_eq!((out.lower, out.median, out.upper), (20.0, 20.0, 20.0));
+    }
+
+    #[test]
+    fn test_default() {
+        PercentileBands::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = PercentileBands::new(20, 10.0, 90.0).unwrap();
+        assert_eq!(format!("{}", indicator), "PCT_BANDS(20, 10, 90)");
+    }
+}
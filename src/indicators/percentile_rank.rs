@@ -0,0 +1,183 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::sorted_window::SortedWindow;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Percentile rank.
+///
+/// Percentage of the previous _period_ values that are below the current value, from 0 when
+/// the value is the lowest of the window to 100 when it is above all of them. It tells how
+/// unusual a value is compared to its own history, whatever its scale, e.g. to rank the
+/// one-period price change in the Connors RSI, or to filter regimes on the rank of a
+/// volatility measure.
+///
+/// The previous values are kept sorted, so a value is ranked in `O(log n)`.
+///
+/// # Formula
+///
+/// PR = 100 * (number of the previous _period_ values below x) / _period_
+///
+/// While the history is not full, the value is ranked against the available values. The first
+/// rank is 50.
+///
+/// # Parameters
+///
+/// * _period_ - number of previous values (integer greater than 0). Default is 100.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::PercentileRank;
+/// use ta::Next;
+///
+/// let mut rank = PercentileRank::new(4).unwrap();
+/// assert_eq!(rank.next(3.0), 50.0);
+/// assert_eq!(rank.next(5.0), 100.0);
+/// assert_eq!(rank.next(1.0), 0.0);
+/// assert_eq!(rank.next(4.0).round(), 67.0);
+/// assert_eq!(rank.next(2.0), 25.0);
+/// ```
+///
+#[doc(alias = "PercentRank")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PercentileRank {
+    history: SortedWindow,
+}
+
+impl PercentileRank {
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            history: SortedWindow::new(period),
+        })
+    }
+}
+
+impl Period for PercentileRank {
+    fn period(&self) -> usize {
+        self.history.capacity()
+    }
+}
+
+impl Lookback for PercentileRank {
+    fn lookback(&self) -> usize {
+        self.history.capacity()
+    }
+}
+
+impl Next<f64> for PercentileRank {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let rank = match self.history.len() {
+            0 => 50.0,
+            len => 100.0 * self.history.count_below(input) as f64 / len as f64,
+        };
+        self.history.push(input);
+        rank
+    }
+}
+
+impl<T: Close> Next<&T> for PercentileRank {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for PercentileRank {
+    fn reset(&mut self) {
+        self.history.clear();
+    }
+}
+
+impl Default for PercentileRank {
+    fn default() -> Self {
+        Self::new(100).unwrap()
+    }
+}
+
+impl fmt::Display for PercentileRank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PERCENTRANK({})", self.history.capacity())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(PercentileRank);
+
+    #[test]
+    fn test_new() {
+        assert!(PercentileRank::new(0).is_err());
+        assert!(PercentileRank::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rank = PercentileRank::new(3).unwrap();
+
+        assert_eq!(rank.next(3.0), 50.0);
+        assert_eq!(rank.next(5.0), 100.0);
+        assert_eq!(rank.next(5.0), 50.0);
+        assert_eq!(round(rank.next(4.0)), 33.333);
+        // 3 left the window
+        assert_eq!(rank.next(6.0), 100.0);
+        assert_eq!(rank.next(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_ties() {
+        // Equal values are not below.
+        let mut rank = PercentileRank::new(3).unwrap();
+        for _ in 0..3 {
+            rank.next(2.0);
+        }
+        assert_eq!(rank.next(2.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut rank = PercentileRank::new(3).unwrap();
+        rank.next(&Bar::new().close(10));
+        assert_eq!(rank.next(&Bar::new().close(9)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rank = PercentileRank::new(3).unwrap();
+        rank.next(3.0);
+        rank.next(5.0);
+
+        rank.reset();
+        assert_eq!(rank.next(5.0), 50.0);
+        assert_eq!(rank.next(4.0), 0.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let rank = PercentileRank::new(100).unwrap();
+        assert_eq!(rank.lookback(), 100);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(PercentileRank::default().period(), 100);
+    }
+
+    #[test]
+    fn test_display() {
+        let rank = PercentileRank::new(100).unwrap();
+        assert_eq!(format!("{}", rank), "PERCENTRANK(100)");
+    }
+}
@@ -0,0 +1,394 @@
+use std::fmt;
+
+use crate::calendar::{Calendar, Sessions};
+use crate::errors::Result;
+use crate::indicators::SessionLevels;
+use crate::{Close, High, Lookback, Low, Next, Open, Reset, Timestamp};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pivot points.
+///
+/// Support and resistance levels computed from the high, the low and the close of the previous
+/// session, used by floor traders since before charts were common. The levels stay the same
+/// during the whole session and are recomputed when a new session starts.
+///
+/// Sessions are tracked by [SessionLevels](struct.SessionLevels.html), optionally following a
+/// [Calendar](../calendar/struct.Calendar.html) with [with_calendar](#method.with_calendar), so
+/// the levels of a stock come from the previous trading day. The output is `None`
+/// during the first session, which has no previous session. Use
+/// [PivotMethod::levels](enum.PivotMethod.html#method.levels) to compute the levels of known
+/// values directly.
+///
+/// # Formula
+///
+/// Classic (floor) pivots:
+///
+/// P = (H + L + C) / 3
+///
+/// R1 = 2 * P - L, S1 = 2 * P - H
+///
+/// R2 = P + (H - L), S2 = P - (H - L)
+///
+/// R3 = H + 2 * (P - L), S3 = L - 2 * (H - P)
+///
+/// Woodie pivots weigh the close twice, P = (H + L + 2 * C) / 4, and use the same levels.
+///
+/// Camarilla pivots are closer to the close, for mean reversion:
+///
+/// R<sub>n</sub> = C + (H - L) * 1.1 / d<sub>n</sub>, S<sub>n</sub> = C - (H - L) * 1.1 / d<sub>n</sub>
+///
+/// With _d_ = 12, 6 and 4 for the levels 1 to 3, and P = (H + L + C) / 3.
+///
+/// Where _H_, _L_ and _C_ are the high, the low and the close of the previous session.
+///
+/// # Parameters
+///
+/// * _session_length_ - length of a session in seconds (integer greater than 0). Default is
+///   86400, one day.
+/// * _session_offset_ - start of the sessions relative to the Unix epoch in seconds. Default is 0.
+/// * _method_ - formula of the levels. Default is
+///   [PivotMethod::Classic](enum.PivotMethod.html).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{PivotMethod, PivotPoints};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64, close: f64, timestamp: i64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .timestamp(timestamp)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut pivots = PivotPoints::daily(PivotMethod::Classic);
+/// assert_eq!(pivots.next(&bar(12.0, 9.0, 10.0, 0)), None);
+/// assert_eq!(pivots.next(&bar(13.0, 10.0, 11.0, 3600)), None);
+///
+/// // The next day, from the high, low and close of the previous day: 13, 9 and 11.
+/// let levels = pivots.next(&bar(12.5, 11.5, 12.0, 86_400)).unwrap();
+/// assert_eq!(levels.pivot, 11.0);
+/// assert_eq!((levels.r1, levels.s1), (13.0, 9.0));
+/// ```
+///
+/// # Links
+///
+/// * [Pivot Point, Investopedia](https://www.investopedia.com/terms/p/pivotpoint.asp)
+/// * [Camarilla pivot points, Wikipedia](https://en.wikipedia.org/wiki/Pivot_point_(technical_analysis))
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PivotPoints {
+    session: SessionLevels,
+    method: PivotMethod,
+    levels: Option<PivotLevels>,
+}
+
+/// Formula of [pivot points](struct.PivotPoints.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PivotMethod {
+    #[default]
+    Classic,
+    Woodie,
+    Camarilla,
+}
+
+/// Pivot point and its support and resistance levels.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PivotLevels {
+    pub pivot: f64,
+    pub r1: f64,
+    pub r2: f64,
+    pub r3: f64,
+    pub s1: f64,
+    pub s2: f64,
+    pub s3: f64,
+}
+
+impl PivotLevels {
+    /// Computes the classic floor pivot levels of a period.
+    pub fn classic(high: f64, low: f64, close: f64) -> Self {
+        let pivot = (high + low + close) / 3.0;
+        let range = high - low;
+        Self {
+            pivot,
+            r1: 2.0 * pivot - low,
+            r2: pivot + range,
+            r3: high + 2.0 * (pivot - low),
+            s1: 2.0 * pivot - high,
+            s2: pivot - range,
+            s3: low - 2.0 * (high - pivot),
+        }
+    }
+
+    /// Computes the Woodie pivot levels of a period.
+    pub fn woodie(high: f64, low: f64, close: f64) -> Self {
+        let pivot = (high + low + 2.0 * close) / 4.0;
+        let range = high - low;
+        Self {
+            pivot,
+            r1: 2.0 * pivot - low,
+            r2: pivot + range,
+            r3: high + 2.0 * (pivot - low),
+            s1: 2.0 * pivot - high,
+            s2: pivot - range,
+            s3: low - 2.0 * (high - pivot),
+        }
+    }
+
+    /// Computes the Camarilla pivot levels of a period.
+    pub fn camarilla(high: f64, low: f64, close: f64) -> Self {
+        let range = (high - low) * 1.1;
+        Self {
+            pivot: (high + low + close) / 3.0,
+            r1: close + range / 12.0,
+            r2: close + range / 6.0,
+            r3: close + range / 4.0,
+            s1: close - range / 12.0,
+            s2: close - range / 6.0,
+            s3: close - range / 4.0,
+        }
+    }
+}
+
+impl PivotMethod {
+    /// Computes the pivot levels of a period with this formula.
+    pub fn levels(&self, high: f64, low: f64, close: f64) -> PivotLevels {
+        match self {
+            PivotMethod::Classic => PivotLevels::classic(high, low, close),
+            PivotMethod::Woodie => PivotLevels::woodie(high, low, close),
+            PivotMethod::Camarilla => PivotLevels::camarilla(high, low, close),
+        }
+    }
+}
+
+impl fmt::Display for PivotMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            PivotMethod::Classic => "classic",
+            PivotMethod::Woodie => "woodie",
+            PivotMethod::Camarilla => "camarilla",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl PivotPoints {
+    pub fn new(session_length: i64, session_offset: i64, method: PivotMethod) -> Result<Self> {
+        Ok(Self {
+            session: SessionLevels::new(session_length, session_offset)?,
+            method,
+            levels: None,
+        })
+    }
+
+    /// Creates pivot points of sessions starting at midnight UTC every day.
+    pub fn daily(method: PivotMethod) -> Self {
+        Self::new(24 * 60 * 60, 0, method).unwrap()
+    }
+
+    /// Creates pivot points of the trading days of a calendar.
+    pub fn with_calendar(calendar: Calendar, method: PivotMethod) -> Self {
+        Self {
+            session: SessionLevels::with_calendar(calendar),
+            method,
+            levels: None,
+        }
+    }
+
+    pub fn method(&self) -> PivotMethod {
+        self.method
+    }
+
+    pub fn sessions(&self) -> &Sessions {
+        self.session.sessions()
+    }
+}
+
+impl Lookback for PivotPoints {
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+impl<T: Open + High + Low + Close + Timestamp> Next<&T> for PivotPoints {
+    type Output = Option<PivotLevels>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let session = self.session.next(input);
+        if session.new_session {
+            let method = self.method;
+            self.levels = session
+                .previous
+                .map(|previous| method.levels(previous.high, previous.low, previous.close));
+        }
+        self.levels
+    }
+}
+
+impl Reset for PivotPoints {
+    fn reset(&mut self) {
+        self.session.reset();
+        self.levels = None;
+    }
+}
+
+impl Default for PivotPoints {
+    fn default() -> Self {
+        Self::daily(PivotMethod::default())
+    }
+}
+
+impl fmt::Display for PivotPoints {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PIVOT({}, {})", self.sessions(), self.method)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64, timestamp: i64) -> Bar {
+        Bar::new()
+            .open(close)
+            .high(high)
+            .low(low)
+            .close(close)
+            .timestamp(timestamp)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(PivotPoints::new(0, 0, PivotMethod::Classic).is_err());
+        assert!(PivotPoints::new(3600, 1800, PivotMethod::Woodie).is_ok());
+    }
+
+    #[test]
+    fn test_classic() {
+        let levels = PivotLevels::classic(13.0, 9.0, 11.0);
+        assert_eq!(
+            levels,
+            PivotLevels {
+                pivot: 11.0,
+                r1: 13.0,
+                r2: 15.0,
+                r3: 17.0,
+                s1: 9.0,
+                s2: 7.0,
+                s3: 5.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_woodie() {
+        let levels = PivotLevels::woodie(13.0, 9.0, 12.0);
+        assert_eq!(
+            levels,
+            PivotLevels {
+                pivot: 11.5,
+                r1: 14.0,
+                r2: 15.5,
+                r3: 18.0,
+                s1: 10.0,
+                s2: 7.5,
+                s3: 6.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_camarilla() {
+        let levels = PivotLevels::camarilla(13.0, 9.0, 12.0);
+        assert_eq!(round(levels.pivot), 11.333);
+        assert_eq!(
+            [levels.r1, levels.r2, levels.r3].map(round),
+            [12.367, 12.733, 13.1]
+        );
+        assert_eq!(
+            [levels.s1, levels.s2, levels.s3].map(round),
+            [11.633, 11.267, 10.9]
+        );
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pivots = PivotPoints::new(3600, 0, PivotMethod::Classic).unwrap();
+
+        assert_eq!(pivots.next(&bar(12.0, 9.0, 10.0, 0)), None);
+        assert_eq!(pivots.next(&bar(13.0, 10.0, 11.0, 1800)), None);
+
+        let expected = PivotLevels::classic(13.0, 9.0, 11.0);
+        assert_eq!(pivots.next(&bar(20.0, 5.0, 6.0, 3600)), Some(expected));
+        // Unchanged during the session.
+        assert_eq!(pivots.next(&bar(21.0, 6.0, 7.0, 5400)), Some(expected));
+
+        let levels = pivots.next(&bar(10.0, 8.0, 9.0, 7200)).unwrap();
+        assert_eq!(levels, PivotLevels::classic(21.0, 5.0, 7.0));
+    }
+
+    #[test]
+    fn test_next_with_method() {
+        let mut pivots = PivotPoints::new(3600, 0, PivotMethod::Camarilla).unwrap();
+        pivots.next(&bar(13.0, 9.0, 12.0, 0));
+
+        let levels = pivots.next(&bar(12.0, 11.0, 11.5, 3600));
+        assert_eq!(levels, Some(PivotLevels::camarilla(13.0, 9.0, 12.0)));
+        assert_eq!(
+            PivotMethod::Woodie.levels(13.0, 9.0, 12.0),
+            PivotLevels::woodie(13.0, 9.0, 12.0)
+        );
+    }
+
+    #[test]
+    fn test_with_calendar() {
+        let mut pivots = PivotPoints::with_calendar(Calendar::cme_globex(), PivotMethod::Classic);
+        // Monday 2024-01-08 00:00 UTC, in the session of Monday opened Sunday 23:00 UTC.
+        let monday = 1_704_672_000;
+
+        pivots.next(&bar(12.0, 9.0, 10.0, monday - 3600));
+        assert_eq!(
+            pivots.next(&bar(13.0, 10.0, 11.0, monday + 21 * 3600)),
+            None
+        );
+        // 16:00 CST, the session of Tuesday.
+        let levels = pivots.next(&bar(12.0, 11.0, 11.5, monday + 22 * 3600));
+        assert_eq!(levels, Some(PivotLevels::classic(13.0, 9.0, 11.0)));
+        assert_eq!(
+            format!("{}", pivots),
+            "PIVOT(17:00-16:00 UTC-06:00, classic)"
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pivots = PivotPoints::new(3600, 0, PivotMethod::Classic).unwrap();
+        pivots.next(&bar(12.0, 9.0, 10.0, 0));
+        pivots.next(&bar(12.0, 9.0, 10.0, 3600));
+
+        pivots.reset();
+        assert_eq!(pivots.next(&bar(12.0, 9.0, 10.0, 7200)), None);
+    }
+
+    #[test]
+    fn test_default() {
+        PivotPoints::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = PivotPoints::new(3600, 1800, PivotMethod::Camarilla).unwrap();
+        assert_eq!(format!("{}", indicator), "PIVOT(3600, 1800, camarilla)");
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage;
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Price Volume Trend (PVT).
+///
+/// A cumulative volume indicator like the [OBV](struct.OnBalanceVolume.html), but instead of
+/// adding or subtracting the whole volume of a bar, it adds the volume weighted by the percent
+/// change of the close: a small move on heavy volume counts less than a large one. A rising PVT
+/// confirms an up trend, a PVT diverging from the price warns of a reversal.
+///
+/// Created [with a signal line](#method.with_signal), it also returns a moving average of the
+/// PVT, an [EMA](struct.ExponentialMovingAverage.html) by default, whose crossings with the PVT
+/// are used as signals.
+///
+/// # Formula
+///
+/// PVT = PVT<sub>prev</sub> + volume * (close - close<sub>prev</sub>) / close<sub>prev</sub>
+///
+/// signal = MA(_signal_period_) of PVT
+///
+/// The PVT starts at 0 on the first bar.
+///
+/// # Parameters
+///
+/// * _signal_period_ - period of the signal moving average, set with
+///   [with_signal](#method.with_signal) (integer greater than 0). No signal line by default.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{PriceVolumeTrend, SimpleMovingAverage as Sma};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut pvt = PriceVolumeTrend::new();
+/// assert_eq!(pvt.next(&bar(10.0, 1000.0)).pvt, 0.0);
+/// assert_eq!(pvt.next(&bar(11.0, 2000.0)).pvt, 200.0);
+///
+/// let mut pvt = PriceVolumeTrend::<Sma>::with_signal(2).unwrap();
+/// pvt.next(&bar(10.0, 1000.0));
+/// let out = pvt.next(&bar(11.0, 2000.0));
+/// assert_eq!(out.pvt, 200.0);
+/// assert_eq!(out.signal, Some(100.0));
+/// ```
+///
+#[doc(alias = "PVT")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PriceVolumeTrend<MA = ExponentialMovingAverage> {
+    pvt: f64,
+    prev_close: Option<f64>,
+    signal: Option<MA>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceVolumeTrendOutput {
+    pub pvt: f64,
+    /// Moving average of the PVT, `None` without a signal line.
+    pub signal: Option<f64>,
+}
+
+impl PriceVolumeTrend {
+    pub fn new() -> Self {
+        Self {
+            pvt: 0.0,
+            prev_close: None,
+            signal: None,
+        }
+    }
+}
+
+impl<MA: NewWithPeriod> PriceVolumeTrend<MA> {
+    pub fn with_signal(signal_period: usize) -> Result<Self> {
+        Ok(Self {
+            pvt: 0.0,
+            prev_close: None,
+            signal: Some(MA::new_with_period(signal_period)?),
+        })
+    }
+}
+
+impl<MA: Lookback> Lookback for PriceVolumeTrend<MA> {
+    fn lookback(&self) -> usize {
+        self.signal.as_ref().map_or(0, |signal| signal.lookback())
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: Close + Volume> Next<&T> for PriceVolumeTrend<MA> {
+    type Output = PriceVolumeTrendOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        if let Some(prev_close) = self.prev_close.replace(close) {
+            if prev_close != 0.0 {
+                self.pvt += input.volume() * (close - prev_close) / prev_close;
+            }
+        }
+
+        let pvt = self.pvt;
+        PriceVolumeTrendOutput {
+            pvt,
+            signal: self.signal.as_mut().map(|signal| signal.next(pvt)),
+        }
+    }
+}
+
+impl<MA: Reset> Reset for PriceVolumeTrend<MA> {
+    fn reset(&mut self) {
+        self.pvt = 0.0;
+        self.prev_close = None;
+        if let Some(signal) = self.signal.as_mut() {
+            signal.reset();
+        }
+    }
+}
+
+impl Default for PriceVolumeTrend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<MA: Period> fmt::Display for PriceVolumeTrend<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.signal {
+            Some(signal) => write!(f, "PVT({})", signal.period()),
+            None => write!(f, "PVT"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage as Sma;
+    use crate::test_helper::*;
+
+    fn bar(close: f64, volume: f64) -> Bar {
+        Bar::new().close(close).volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(PriceVolumeTrend::<Sma>::with_signal(0).is_err());
+        assert!(PriceVolumeTrend::<Sma>::with_signal(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pvt = PriceVolumeTrend::new();
+
+        let test_data = vec![
+            // close, volume, pvt
+            (10.0, 1000.0, 0.0),
+            (11.0, 2000.0, 200.0),
+            (9.9, 1000.0, 100.0),
+            // unchanged close
+            (9.9, 5000.0, 100.0),
+            (10.89, 3000.0, 400.0),
+        ];
+
+        for (close, volume, expected) in test_data {
+            let out = pvt.next(&bar(close, volume));
+            assert_eq!(round(out.pvt), expected);
+            assert_eq!(out.signal, None);
+        }
+    }
+
+    #[test]
+    fn test_next_signal() {
+        let mut pvt = PriceVolumeTrend::<Sma>::with_signal(2).unwrap();
+
+        let test_data = vec![
+            // close, volume, pvt, signal
+            (10.0, 1000.0, 0.0, 0.0),
+            (11.0, 2000.0, 200.0, 100.0),
+            (9.9, 1000.0, 100.0, 150.0),
+            (10.89, 3000.0, 400.0, 250.0),
+        ];
+
+        for (close, volume, expected_pvt, expected_signal) in test_data {
+            let out = pvt.next(&bar(close, volume));
+            assert_eq!(round(out.pvt), expected_pvt);
+            assert_eq!(out.signal.map(round), Some(expected_signal));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pvt = PriceVolumeTrend::<Sma>::with_signal(2).unwrap();
+        pvt.next(&bar(10.0, 1000.0));
+        pvt.next(&bar(11.0, 2000.0));
+
+        pvt.reset();
+        let out = pvt.next(&bar(11.0, 2000.0));
+        assert_eq!(out.pvt, 0.0);
+        assert_eq!(out.signal, Some(0.0));
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(PriceVolumeTrend::new().lookback(), 0);
+        let pvt = PriceVolumeTrend::<Sma>::with_signal(9).unwrap();
+        assert_eq!(pvt.lookback(), 8);
+    }
+
+    #[test]
+    fn test_default() {
+        PriceVolumeTrend::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", PriceVolumeTrend::new()), "PVT");
+        let pvt = PriceVolumeTrend::<Sma>::with_signal(9).unwrap();
+        assert_eq!(format!("{}", pvt), "PVT(9)");
+    }
+}
@@ -1,16 +1,22 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::traits::{Close, Next, Period, Reset};
+use crate::traits::{Close, Lookback, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Rate of Change (ROC)
 ///
+/// The price change over the last _n_ periods, in percent by default. Created
+/// [with the mode](#method.with_mode) [RocMode::Difference](enum.RocMode.html) it returns the
+/// raw difference instead, in price units, like [Momentum](struct.Momentum.html).
+///
 /// # Formula
 ///
 /// ROC = (Price<sub>t</sub> - Price<sub>t-n</sub>) / Price<sub>t-n</sub> * 100
 ///
+/// ROC<sub>difference</sub> = Price<sub>t</sub> - Price<sub>t-n</sub>
+///
 /// Where:
 ///
 /// * ROC - current value of Rate of Change indicator
@@ -19,7 +25,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Parameters
 ///
-/// * _period_ - number of periods integer greater than 0
+/// * _period_ - number of periods integer greater than 0. Default is 9.
+/// * _mode_ - [RocMode](enum.RocMode.html) of the change. Default is percent.
 ///
 /// # Example
 ///
@@ -34,6 +41,18 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(roc.next(20.0).round(), 106.0);  //  (20 - 9.7) / 9.7 * 100 = 106
 /// ```
 ///
+/// The raw difference:
+///
+/// ```
+/// use ta::indicators::{RateOfChange, RocMode};
+/// use ta::Next;
+///
+/// let mut roc = RateOfChange::with_mode(2, RocMode::Difference).unwrap();
+/// assert_eq!(roc.next(10.0), 0.0);
+/// assert_eq!(roc.next(9.5), -0.5);
+/// assert_eq!(roc.next(12.0), 2.0);
+/// ```
+///
 /// # Links
 ///
 /// * [Rate of Change, Wikipedia](https://en.wikipedia.org/wiki/Momentum_(technical_analysis))
@@ -43,23 +62,58 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct RateOfChange {
     period: usize,
+    mode: RocMode,
     index: usize,
     count: usize,
     deque: Box<[f64]>,
 }
 
+/// Unit of the change computed by [RateOfChange](struct.RateOfChange.html).
+///
+/// As the unit of the output depends on the mode, the indicator has no
+/// [OutputUnit](../units/trait.OutputUnit.html); use [Momentum](struct.Momentum.html) for a
+/// difference typed in price units.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RocMode {
+    /// Change relative to the price _n_ periods ago, in percent.
+    #[default]
+    Percent,
+    /// Difference with the price _n_ periods ago.
+    Difference,
+}
+
+impl fmt::Display for RocMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            RocMode::Percent => "percent",
+            RocMode::Difference => "difference",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 impl RateOfChange {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_mode(period, RocMode::Percent)
+    }
+
+    pub fn with_mode(period: usize, mode: RocMode) -> Result<Self> {
         match period {
             0 => Err(TaError::InvalidParameter),
             _ => Ok(Self {
                 period,
+                mode,
                 index: 0,
                 count: 0,
                 deque: vec![0.0; period].into_boxed_slice(),
             }),
         }
     }
+
+    pub fn mode(&self) -> RocMode {
+        self.mode
+    }
 }
 
 impl Period for RateOfChange {
@@ -68,6 +122,12 @@ impl Period for RateOfChange {
     }
 }
 
+impl Lookback for RateOfChange {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
 impl Next<f64> for RateOfChange {
     type Output = f64;
 
@@ -90,7 +150,10 @@ impl Next<f64> for RateOfChange {
             0
         };
 
-        (input - previous) / previous * 100.0
+        match self.mode {
+            RocMode::Percent => (input - previous) / previous * 100.0,
+            RocMode::Difference => input - previous,
+        }
     }
 }
 
@@ -110,7 +173,10 @@ impl Default for RateOfChange {
 
 impl fmt::Display for RateOfChange {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ROC({})", self.period)
+        match self.mode {
+            RocMode::Percent => write!(f, "ROC({})", self.period),
+            mode => write!(f, "ROC({}, {})", self.period, mode),
+        }
     }
 }
 
@@ -150,6 +216,18 @@ mod tests {
         assert_eq!(round(roc.next(10.0)), -5.393);
     }
 
+    #[test]
+    fn test_next_difference() {
+        let mut roc = RateOfChange::with_mode(3, RocMode::Difference).unwrap();
+
+        assert_eq!(round(roc.next(10.0)), 0.0);
+        assert_eq!(round(roc.next(10.4)), 0.4);
+        assert_eq!(round(roc.next(10.57)), 0.57);
+        assert_eq!(round(roc.next(10.8)), 0.8);
+        assert_eq!(round(roc.next(10.9)), 0.5);
+        assert_eq!(round(roc.next(10.0)), -0.57);
+    }
+
     #[test]
     fn test_next_bar() {
         fn bar(close: f64) -> Bar {
@@ -176,4 +254,19 @@ mod tests {
         assert_eq!(round(roc.next(10.4)), 4.0);
         assert_eq!(round(roc.next(10.57)), 5.7);
     }
+
+    #[test]
+    fn test_mode() {
+        assert_eq!(RateOfChange::default().mode(), RocMode::Percent);
+        let roc = RateOfChange::with_mode(3, RocMode::Difference).unwrap();
+        assert_eq!(roc.mode(), RocMode::Difference);
+        assert!(RateOfChange::with_mode(0, RocMode::Difference).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", RateOfChange::new(9).unwrap()), "ROC(9)");
+        let roc = RateOfChange::with_mode(9, RocMode::Difference).unwrap();
+        assert_eq!(format!("{}", roc), "ROC(9, difference)");
+    }
 }
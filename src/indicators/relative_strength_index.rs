@@ -1,8 +1,9 @@
 use std::fmt;
 
 use crate::errors::Result;
-use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::indicators::ExponentialMovingAverage;
+use crate::units::{OutputUnit, Percent};
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -16,17 +17,27 @@ use serde::{Deserialize, Serialize};
 ///
 /// The oscillator returns output in the range of 0..100.
 ///
+/// Bars are consumed by their close price. To compute the RSI of another value, e.g. volume or
+/// the output of another indicator, feed it as `f64` or wrap the RSI in
+/// [Sourced](struct.Sourced.html).
+///
+/// By default the up and down moves are smoothed with an exponential moving average, but any
+/// moving average implementing [NewWithPeriod](../trait.NewWithPeriod.html) can be used instead
+/// with [with_ma](#method.with_ma), e.g. [RunningMovingAverage](struct.RunningMovingAverage.html)
+/// for Wilder's original smoothing.
+///
 /// ![RSI](https://upload.wikimedia.org/wikipedia/commons/6/67/RSIwiki.gif)
 ///
 /// # Formula
 ///
-/// RSI<sub>t</sub> = EMA<sub>Ut</sub> * 100 / (EMA<sub>Ut</sub> + EMA<sub>Dt</sub>)
+/// RSI<sub>t</sub> = MA<sub>Ut</sub> * 100 / (MA<sub>Ut</sub> + MA<sub>Dt</sub>)
 ///
 /// Where:
 ///
 /// * RSI<sub>t</sub> - value of RSI indicator in a moment of time _t_
-/// * EMA<sub>Ut</sub> - value of [EMA](struct.ExponentialMovingAverage.html) of up periods in a moment of time _t_
-/// * EMA<sub>Dt</sub> - value of [EMA](struct.ExponentialMovingAverage.html) of down periods in a moment of time _t_
+/// * MA<sub>Ut</sub> - value of the moving average of up periods in a moment of time _t_,
+///   [EMA](struct.ExponentialMovingAverage.html) by default
+/// * MA<sub>Dt</sub> - value of the moving average of down periods in a moment of time _t_
 ///
 /// If current period has value higher than previous period, than:
 ///
@@ -71,33 +82,54 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "RSI")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct RelativeStrengthIndex {
-    period: usize,
-    up_ema_indicator: Ema,
-    down_ema_indicator: Ema,
+pub struct RelativeStrengthIndex<MA = ExponentialMovingAverage> {
+    up_ma: MA,
+    down_ma: MA,
     prev_val: f64,
     is_new: bool,
 }
 
 impl RelativeStrengthIndex {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_ma(period)
+    }
+}
+
+impl<MA: NewWithPeriod> RelativeStrengthIndex<MA> {
+    /// Creates an RSI smoothed by the moving average `MA` instead of the default EMA.
+    pub fn with_ma(period: usize) -> Result<Self> {
         Ok(Self {
-            period,
-            up_ema_indicator: Ema::new(period)?,
-            down_ema_indicator: Ema::new(period)?,
+            up_ma: MA::new_with_period(period)?,
+            down_ma: MA::new_with_period(period)?,
             prev_val: 0.0,
             is_new: true,
         })
     }
 }
 
-impl Period for RelativeStrengthIndex {
+impl<MA: NewWithPeriod> NewWithPeriod for RelativeStrengthIndex<MA> {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::with_ma(period)
+    }
+}
+
+impl<MA: Period> Period for RelativeStrengthIndex<MA> {
     fn period(&self) -> usize {
-        self.period
+        self.up_ma.period()
     }
 }
 
-impl Next<f64> for RelativeStrengthIndex {
+impl<MA: Period> Lookback for RelativeStrengthIndex<MA> {
+    fn lookback(&self) -> usize {
+        self.up_ma.period()
+    }
+}
+
+impl<MA> OutputUnit for RelativeStrengthIndex<MA> {
+    type Unit = Percent;
+}
+
+impl<MA: Next<f64, Output = f64>> Next<f64> for RelativeStrengthIndex<MA> {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
@@ -118,13 +150,13 @@ impl Next<f64> for RelativeStrengthIndex {
         }
 
         self.prev_val = input;
-        let up_ema = self.up_ema_indicator.next(up);
-        let down_ema = self.down_ema_indicator.next(down);
-        100.0 * up_ema / (up_ema + down_ema)
+        let up_ma = self.up_ma.next(up);
+        let down_ma = self.down_ma.next(down);
+        100.0 * up_ma / (up_ma + down_ma)
     }
 }
 
-impl<T: Close> Next<&T> for RelativeStrengthIndex {
+impl<MA: Next<f64, Output = f64>, T: Close> Next<&T> for RelativeStrengthIndex<MA> {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
@@ -132,12 +164,12 @@ impl<T: Close> Next<&T> for RelativeStrengthIndex {
     }
 }
 
-impl Reset for RelativeStrengthIndex {
+impl<MA: Reset> Reset for RelativeStrengthIndex<MA> {
     fn reset(&mut self) {
         self.is_new = true;
         self.prev_val = 0.0;
-        self.up_ema_indicator.reset();
-        self.down_ema_indicator.reset();
+        self.up_ma.reset();
+        self.down_ma.reset();
     }
 }
 
@@ -147,15 +179,16 @@ impl Default for RelativeStrengthIndex {
     }
 }
 
-impl fmt::Display for RelativeStrengthIndex {
+impl<MA: Period> fmt::Display for RelativeStrengthIndex<MA> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "RSI({})", self.period)
+        write!(f, "RSI({})", self.up_ma.period())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::RunningMovingAverage as Rma;
     use crate::test_helper::*;
 
     test_indicator!(RelativeStrengthIndex);
@@ -164,6 +197,8 @@ mod tests {
     fn test_new() {
         assert!(RelativeStrengthIndex::new(0).is_err());
         assert!(RelativeStrengthIndex::new(1).is_ok());
+        assert!(RelativeStrengthIndex::<Rma>::with_ma(0).is_err());
+        assert!(RelativeStrengthIndex::<Rma>::with_ma(1).is_ok());
     }
 
     #[test]
@@ -175,6 +210,14 @@ mod tests {
         assert_eq!(rsi.next(9.5).round(), 16.0);
     }
 
+    #[test]
+    fn test_next_with_rma() {
+        let mut rsi = RelativeStrengthIndex::<Rma>::with_ma(3).unwrap();
+        assert_eq!(rsi.next(10.0), 50.0);
+        assert_eq!(round(rsi.next(10.5)), 77.778);
+        assert_eq!(round(rsi.next(10.0)), 42.424);
+    }
+
     #[test]
     fn test_reset() {
         let mut rsi = RelativeStrengthIndex::new(3).unwrap();
@@ -0,0 +1,227 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage;
+use crate::{Close, High, Lookback, Low, Next, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Relative Vigor Index (RVI).
+///
+/// Developed by John Ehlers, the RVI measures the conviction of a trend from where bars close
+/// relative to where they open: in an uptrend prices tend to close above the open, in a
+/// downtrend below it. The close - open distance is compared to the high - low range, both
+/// smoothed by a symmetric weighting over 4 bars and averaged over _period_ bars. The signal
+/// line is the same symmetric weighting of the RVI; crossings of the two are used as signals.
+///
+/// # Formula
+///
+/// numerator = ((C - O) + 2 * (C<sub>t-1</sub> - O<sub>t-1</sub>) + 2 * (C<sub>t-2</sub> - O<sub>t-2</sub>) + (C<sub>t-3</sub> - O<sub>t-3</sub>)) / 6
+///
+/// denominator = ((H - L) + 2 * (H<sub>t-1</sub> - L<sub>t-1</sub>) + 2 * (H<sub>t-2</sub> - L<sub>t-2</sub>) + (H<sub>t-3</sub> - L<sub>t-3</sub>)) / 6
+///
+/// RVI = SMA(_period_) of numerator / SMA(_period_) of denominator
+///
+/// signal = (RVI + 2 * RVI<sub>t-1</sub> + 2 * RVI<sub>t-2</sub> + RVI<sub>t-3</sub>) / 6
+///
+/// While fewer than 4 bars are known, the missing bars are replaced by the first one. The RVI
+/// is 0 while the bars have no range.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods averaged (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RelativeVigorIndex;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |open: f64, high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut rvi = RelativeVigorIndex::new(2).unwrap();
+/// let out = rvi.next(&bar(10.0, 11.0, 9.0, 10.5));
+/// assert_eq!((out.rvi, out.signal), (0.25, 0.25));
+/// ```
+///
+/// # Links
+///
+/// * [Relative Vigor Index, Investopedia](https://www.investopedia.com/terms/r/relative_vigor_index.asp)
+///
+#[doc(alias = "RVI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RelativeVigorIndex {
+    numerator: SimpleMovingAverage,
+    denominator: SimpleMovingAverage,
+    // Last 4 values, the most recent one last.
+    close_open: Option<[f64; 4]>,
+    high_low: Option<[f64; 4]>,
+    rvi: Option<[f64; 4]>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelativeVigorIndexOutput {
+    pub rvi: f64,
+    pub signal: f64,
+}
+
+impl RelativeVigorIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            numerator: SimpleMovingAverage::new(period)?,
+            denominator: SimpleMovingAverage::new(period)?,
+            close_open: None,
+            high_low: None,
+            rvi: None,
+        })
+    }
+}
+
+// Pushes a value into the last 4 values, filling them with it on the first push.
+fn push(values: &mut Option<[f64; 4]>, value: f64) -> [f64; 4] {
+    let values = values.get_or_insert([value; 4]);
+    values.rotate_left(1);
+    values[3] = value;
+    *values
+}
+
+fn symmetric_weighting(values: [f64; 4]) -> f64 {
+    (values[0] + 2.0 * values[1] + 2.0 * values[2] + values[3]) / 6.0
+}
+
+impl Period for RelativeVigorIndex {
+    fn period(&self) -> usize {
+        self.numerator.period()
+    }
+}
+
+impl Lookback for RelativeVigorIndex {
+    fn lookback(&self) -> usize {
+        3 + self.numerator.lookback() + 3
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for RelativeVigorIndex {
+    type Output = RelativeVigorIndexOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close_open = push(&mut self.close_open, input.close() - input.open());
+        let high_low = push(&mut self.high_low, input.high() - input.low());
+
+        let numerator = self.numerator.next(symmetric_weighting(close_open));
+        let denominator = self.denominator.next(symmetric_weighting(high_low));
+        let rvi = if denominator == 0.0 {
+            0.0
+        } else {
+            numerator / denominator
+        };
+        let signal = symmetric_weighting(push(&mut self.rvi, rvi));
+
+        RelativeVigorIndexOutput { rvi, signal }
+    }
+}
+
+impl Reset for RelativeVigorIndex {
+    fn reset(&mut self) {
+        self.numerator.reset();
+        self.denominator.reset();
+        self.close_open = None;
+        self.high_low = None;
+        self.rvi = None;
+    }
+}
+
+impl Default for RelativeVigorIndex {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for RelativeVigorIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RVI({})", self.numerator.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RelativeVigorIndex::new(0).is_err());
+        assert!(RelativeVigorIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rvi = RelativeVigorIndex::new(2).unwrap();
+
+        let test_data = vec![
+            // open, high, low, close, rvi, signal
+            (10.0, 11.0, 9.0, 10.5, 0.25, 0.25),
+            (10.5, 12.0, 10.0, 11.5, 0.271, 0.253),
+            (11.5, 12.0, 11.0, 11.0, 0.283, 0.262),
+            (11.0, 13.0, 10.5, 12.5, 0.279, 0.273),
+            (12.5, 13.0, 11.5, 12.0, 0.262, 0.276),
+            (12.0, 12.5, 10.0, 10.5, 0.114, 0.246),
+        ];
+
+        for (open, high, low, close, expected_rvi, expected_signal) in test_data {
+            let out = rvi.next(&bar(open, high, low, close));
+            assert_eq!(round(out.rvi), expected_rvi);
+            assert_eq!(round(out.signal), expected_signal);
+        }
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut rvi = RelativeVigorIndex::new(2).unwrap();
+        let out = rvi.next(&bar(10.0, 10.0, 10.0, 10.0));
+        assert_eq!((out.rvi, out.signal), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rvi = RelativeVigorIndex::new(2).unwrap();
+        rvi.next(&bar(10.0, 11.0, 9.0, 10.5));
+        rvi.next(&bar(10.5, 12.0, 10.0, 11.5));
+
+        rvi.reset();
+        let out = rvi.next(&bar(10.0, 11.0, 9.0, 10.5));
+        assert_eq!((out.rvi, out.signal), (0.25, 0.25));
+    }
+
+    #[test]
+    fn test_lookback() {
+        let rvi = RelativeVigorIndex::new(10).unwrap();
+        assert_eq!(rvi.period(), 10);
+        assert_eq!(rvi.lookback(), 15);
+    }
+
+    #[test]
+    fn test_default() {
+        RelativeVigorIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rvi = RelativeVigorIndex::new(10).unwrap();
+        assert_eq!(format!("{}", rvi), "RVI(10)");
+    }
+}
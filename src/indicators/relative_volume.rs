@@ -0,0 +1,367 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Lookback, Next, Period, Reset, Timestamp, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Relative volume (RVOL).
+///
+/// The volume of the current period divided by the average volume of the previous _period_
+/// periods. A value of 2 means twice the usual volume. See
+/// [SessionRelativeVolume](struct.SessionRelativeVolume.html) to compare intraday volume with
+/// the same time of previous sessions instead.
+///
+/// # Formula
+///
+/// RVOL<sub>t</sub> = volume<sub>t</sub> / SMA(period) of volume<sub>t-1</sub>
+///
+/// While there is no previous volume, or its average is 0, the output is 1.
+///
+/// # Parameters
+///
+/// * _period_ - number of previous periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RelativeVolume;
+/// use ta::Next;
+///
+/// let mut rvol = RelativeVolume::new(2).unwrap();
+/// assert_eq!(rvol.next(100.0), 1.0);
+/// assert_eq!(rvol.next(200.0), 2.0);
+/// assert_eq!(rvol.next(450.0), 3.0);
+/// ```
+///
+#[doc(alias = "RVOL")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RelativeVolume {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum: f64,
+    deque: Box<[f64]>,
+}
+
+impl RelativeVolume {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for RelativeVolume {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for RelativeVolume {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for RelativeVolume {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let rvol = if self.sum > 0.0 {
+            input / (self.sum / self.count as f64)
+        } else {
+            1.0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            self.sum -= self.deque[self.index];
+        }
+        self.sum += input;
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        rvol
+    }
+}
+
+impl<T: Volume> Next<&T> for RelativeVolume {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.volume())
+    }
+}
+
+impl Reset for RelativeVolume {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for RelativeVolume {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RelativeVolume {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RVOL({})", self.period)
+    }
+}
+
+/// Relative volume at the time of day.
+///
+/// Intraday volume follows a daily pattern, usually high at the open and the close, so the
+/// volume of a bar is compared with the average volume of the bars that started at the same
+/// time of the previous _sessions_ sessions. The time of a bar is read from its
+/// [timestamp](../trait.Timestamp.html).
+///
+/// # Formula
+///
+/// RVOL<sub>t</sub> = volume<sub>t</sub> / average volume at the same time of day of the
+/// previous _sessions_ sessions
+///
+/// The time of day is the timestamp modulo _session_length_. While there is no previous volume
+/// at the time of day, or its average is 0, the output is 1. Bars without a timestamp are not
+/// recorded and give 1.
+///
+/// # Parameters
+///
+/// * _sessions_ - number of previous sessions (integer greater than 0). Default is 20.
+/// * _session_length_ - length of a session in seconds (integer greater than 0). Default is
+///   86400, one day.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SessionRelativeVolume;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |volume: f64, timestamp: i64| {
+///     DataItem::builder()
+///         .open(10.0)
+///         .high(10.0)
+///         .low(10.0)
+///         .close(10.0)
+///         .volume(volume)
+///         .timestamp(timestamp)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut rvol = SessionRelativeVolume::new(5, 86_400).unwrap();
+///
+/// // Day 1, 09:00 and 10:00
+/// rvol.next(&bar(1000.0, 9 * 3600));
+/// rvol.next(&bar(200.0, 10 * 3600));
+///
+/// // Day 2, compared with the same hour of day 1
+/// assert_eq!(rvol.next(&bar(500.0, 86_400 + 9 * 3600)), 0.5);
+/// assert_eq!(rvol.next(&bar(500.0, 86_400 + 10 * 3600)), 2.5);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SessionRelativeVolume {
+    sessions: usize,
+    session_length: i64,
+    history: BTreeMap<i64, TimeOfDayVolumes>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+struct TimeOfDayVolumes {
+    volumes: VecDeque<f64>,
+    sum: f64,
+}
+
+impl SessionRelativeVolume {
+    pub fn new(sessions: usize, session_length: i64) -> Result<Self> {
+        if sessions == 0 || session_length <= 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            sessions,
+            session_length,
+            history: BTreeMap::new(),
+        })
+    }
+
+    pub fn session_length(&self) -> i64 {
+        self.session_length
+    }
+}
+
+impl Period for SessionRelativeVolume {
+    fn period(&self) -> usize {
+        self.sessions
+    }
+}
+
+impl Lookback for SessionRelativeVolume {
+    fn lookback(&self) -> usize {
+        // The bars of a session are the times of day seen so far.
+        self.sessions * self.history.len().max(1)
+    }
+}
+
+impl<T: Volume + Timestamp> Next<&T> for SessionRelativeVolume {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let timestamp = match input.timestamp() {
+            Some(timestamp) => timestamp,
+            None => return 1.0,
+        };
+        let volume = input.volume();
+        let time_of_day = timestamp.rem_euclid(self.session_length);
+        let previous = self.history.entry(time_of_day).or_default();
+
+        let rvol = if previous.sum > 0.0 {
+            volume / (previous.sum / previous.volumes.len() as f64)
+        } else {
+            1.0
+        };
+
+        if previous.volumes.len() == self.sessions {
+            if let Some(oldest) = previous.volumes.pop_front() {
+                previous.sum -= oldest;
+            }
+        }
+        previous.volumes.push_back(volume);
+        previous.sum += volume;
+
+        rvol
+    }
+}
+
+impl Reset for SessionRelativeVolume {
+    fn reset(&mut self) {
+        self.history.clear();
+    }
+}
+
+impl Default for SessionRelativeVolume {
+    fn default() -> Self {
+        Self::new(20, 86_400).unwrap()
+    }
+}
+
+impl fmt::Display for SessionRelativeVolume {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SRVOL({}, {})", self.sessions, self.session_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RelativeVolume);
+
+    #[test]
+    fn test_new() {
+        assert!(RelativeVolume::new(0).is_err());
+        assert!(RelativeVolume::new(1).is_ok());
+
+        assert!(SessionRelativeVolume::new(0, 86_400).is_err());
+        assert!(SessionRelativeVolume::new(1, 0).is_err());
+        assert!(SessionRelativeVolume::new(1, 3600).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rvol = RelativeVolume::new(3).unwrap();
+
+        assert_eq!(rvol.next(&Bar::new().volume(0.0)), 1.0);
+        assert_eq!(rvol.next(&Bar::new().volume(100.0)), 1.0);
+        // average of 0 and 100
+        assert_eq!(rvol.next(&Bar::new().volume(100.0)), 2.0);
+        // average of 0, 100 and 100
+        assert_eq!(rvol.next(&Bar::new().volume(50.0)), 0.75);
+        // average of 100, 100 and 50
+        assert_eq!(rvol.next(&Bar::new().volume(250.0)), 3.0);
+    }
+
+    #[test]
+    fn test_next_session() {
+        let mut rvol = SessionRelativeVolume::new(2, 100).unwrap();
+
+        let bar = |volume, timestamp| Bar::new().volume(volume).timestamp(timestamp);
+
+        assert_eq!(rvol.next(&bar(100.0, 0)), 1.0);
+        assert_eq!(rvol.next(&bar(40.0, 50)), 1.0);
+        assert_eq!(rvol.next(&bar(300.0, 100)), 3.0);
+        assert_eq!(rvol.next(&bar(20.0, 150)), 0.5);
+        // average of the last 2 sessions: 300 and 200
+        assert_eq!(rvol.next(&bar(200.0, 200)), 1.0);
+        assert_eq!(rvol.next(&bar(500.0, 300)), 2.0);
+        // no timestamp
+        assert_eq!(rvol.next(&Bar::new().volume(1000.0)), 1.0);
+        // a new time of day
+        assert_eq!(rvol.next(&bar(10.0, 310)), 1.0);
+        // before the epoch, the same time of day as 50
+        assert_eq!(rvol.next(&bar(60.0, -50)), 2.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rvol = RelativeVolume::new(3).unwrap();
+        rvol.next(100.0);
+        rvol.reset();
+        assert_eq!(rvol.next(200.0), 1.0);
+
+        let mut rvol = SessionRelativeVolume::new(2, 100).unwrap();
+        rvol.next(&Bar::new().volume(100.0).timestamp(0));
+        rvol.reset();
+        assert_eq!(rvol.next(&Bar::new().volume(200.0).timestamp(100)), 1.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(RelativeVolume::new(20).unwrap().lookback(), 20);
+
+        let mut rvol = SessionRelativeVolume::new(5, 100).unwrap();
+        assert_eq!(rvol.lookback(), 5);
+        for timestamp in &[0, 25, 50, 75, 100] {
+            rvol.next(&Bar::new().volume(100.0).timestamp(*timestamp));
+        }
+        assert_eq!(rvol.lookback(), 20);
+    }
+
+    #[test]
+    fn test_default() {
+        RelativeVolume::default();
+        SessionRelativeVolume::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", RelativeVolume::new(10).unwrap()), "RVOL(10)");
+        let indicator = SessionRelativeVolume::new(10, 86_400).unwrap();
+        assert_eq!(format!("{}", indicator), "SRVOL(10, 86400)");
+    }
+}
@@ -0,0 +1,222 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Lookback, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Risk parity portfolio weights.
+///
+/// Tracks the volatility of a set of assets, each with its own copy of a volatility indicator,
+/// e.g. the [NATR](struct.NormalizedAverageTrueRange.html) of bars or the
+/// [standard deviation](struct.StandardDeviation.html) of returns, and returns the
+/// inverse-volatility weight of every asset on each period: the less volatile an asset, the
+/// larger its weight, so all assets contribute the same volatility to the portfolio. For
+/// uncorrelated assets these are the equal risk contribution (ERC) weights.
+///
+/// The input is one value per asset, in the same order on every period, and the output the
+/// weights in that order, adding up to 1. While the volatility of an asset is not positive,
+/// e.g. on the first period of a standard deviation, all assets get the same weight.
+///
+/// # Formula
+///
+/// w<sub>i</sub> = (1 / σ<sub>i</sub>) / Σ (1 / σ<sub>j</sub>)
+///
+/// Where σ<sub>i</sub> is the output of the volatility indicator of the i-th asset.
+///
+/// # Parameters
+///
+/// * _volatility_ - volatility indicator producing `f64` values, cloned for every asset.
+/// * _assets_ - number of assets (integer greater than 0).
+///
+/// # Panics
+///
+/// `next` panics if the number of inputs is not the number of assets.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{NormalizedAverageTrueRange, RiskParity};
+/// use ta::{DataItem, Next};
+///
+/// let natr = NormalizedAverageTrueRange::default();
+/// let mut risk_parity = RiskParity::new(natr, 2).unwrap();
+///
+/// let bar = |low: f64, high: f64| {
+///     DataItem::builder()
+///         .open(10.0)
+///         .high(high)
+///         .low(low)
+///         .close(10.0)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// // The first asset ranges twice as much as the second one.
+/// let weights = risk_parity.next(&[&bar(9.0, 11.0), &bar(9.5, 10.5)][..]);
+/// assert_eq!(weights, vec![1.0 / 3.0, 2.0 / 3.0]);
+/// ```
+///
+/// # Links
+///
+/// * [Risk parity, Wikipedia](https://en.wikipedia.org/wiki/Risk_parity)
+///
+#[doc(alias = "ERC")]
+#[doc(alias = "inverse volatility")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RiskParity<I> {
+    volatilities: Vec<I>,
+}
+
+impl<I: Clone> RiskParity<I> {
+    pub fn new(volatility: I, assets: usize) -> Result<Self> {
+        match assets {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                volatilities: vec![volatility; assets],
+            }),
+        }
+    }
+}
+
+impl<I> RiskParity<I> {
+    /// Returns the number of assets.
+    pub fn assets(&self) -> usize {
+        self.volatilities.len()
+    }
+}
+
+impl<I: Lookback> Lookback for RiskParity<I> {
+    fn lookback(&self) -> usize {
+        self.volatilities[0].lookback()
+    }
+}
+
+impl<In: Copy, I: Next<In, Output = f64>> Next<&[In]> for RiskParity<I> {
+    type Output = Vec<f64>;
+
+    fn next(&mut self, input: &[In]) -> Self::Output {
+        assert_eq!(
+            input.len(),
+            self.volatilities.len(),
+            "one input per asset expected"
+        );
+
+        let inverses: Vec<f64> = self
+            .volatilities
+            .iter_mut()
+            .zip(input)
+            .map(|(volatility, &value)| 1.0 / volatility.next(value))
+            .collect();
+
+        // Not positive volatilities give infinite or negative inverses.
+        if inverses
+            .iter()
+            .all(|inverse| inverse.is_finite() && *inverse > 0.0)
+        {
+            let total: f64 = inverses.iter().sum();
+            inverses.iter().map(|inverse| inverse / total).collect()
+        } else {
+            vec![1.0 / inverses.len() as f64; inverses.len()]
+        }
+    }
+}
+
+impl<I: Reset> Reset for RiskParity<I> {
+    fn reset(&mut self) {
+        for volatility in self.volatilities.iter_mut() {
+            volatility.reset();
+        }
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for RiskParity<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RP({}, {})", self.volatilities[0], self.assets())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{SimpleMovingAverage as Sma, StandardDeviation};
+
+    fn round_all(weights: Vec<f64>) -> Vec<f64> {
+        weights
+            .into_iter()
+            .map(|weight| (weight * 1000.0).round() / 1000.0)
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RiskParity::new(Sma::default(), 0).is_err());
+        assert_eq!(RiskParity::new(Sma::default(), 3).unwrap().assets(), 3);
+    }
+
+    #[test]
+    fn test_next() {
+        // SMA of period 1 passes the volatilities through.
+        let mut risk_parity = RiskParity::new(Sma::new(1).unwrap(), 3).unwrap();
+
+        assert_eq!(
+            round_all(risk_parity.next(&[0.1, 0.2, 0.4][..])),
+            vec![0.571, 0.286, 0.143]
+        );
+        assert_eq!(
+            round_all(risk_parity.next(&[0.3, 0.3, 0.3][..])),
+            vec![0.333, 0.333, 0.333]
+        );
+    }
+
+    #[test]
+    fn test_next_returns() {
+        let mut risk_parity = RiskParity::new(StandardDeviation::new(3).unwrap(), 2).unwrap();
+
+        // flat on the first period
+        assert_eq!(risk_parity.next(&[0.01, 0.02][..]), vec![0.5, 0.5]);
+        // SD: 0.01, 0.03
+        assert_eq!(
+            round_all(risk_parity.next(&[-0.01, -0.04][..])),
+            vec![0.75, 0.25]
+        );
+    }
+
+    #[test]
+    fn test_next_zero_volatility() {
+        let mut risk_parity = RiskParity::new(Sma::new(1).unwrap(), 2).unwrap();
+        assert_eq!(risk_parity.next(&[0.0, 0.2][..]), vec![0.5, 0.5]);
+        assert_eq!(risk_parity.next(&[f64::NAN, 0.2][..]), vec![0.5, 0.5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_next_wrong_number_of_inputs() {
+        let mut risk_parity = RiskParity::new(Sma::new(1).unwrap(), 2).unwrap();
+        risk_parity.next(&[0.1][..]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut risk_parity = RiskParity::new(StandardDeviation::new(3).unwrap(), 2).unwrap();
+        risk_parity.next(&[0.01, 0.02][..]);
+        risk_parity.next(&[-0.01, -0.04][..]);
+
+        risk_parity.reset();
+        assert_eq!(risk_parity.next(&[0.01, 0.02][..]), vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let risk_parity = RiskParity::new(Sma::new(20).unwrap(), 2).unwrap();
+        assert_eq!(risk_parity.lookback(), 19);
+    }
+
+    #[test]
+    fn test_display() {
+        let risk_parity = RiskParity::new(Sma::new(20).unwrap(), 2).unwrap();
+        assert_eq!(format!("{}", risk_parity), "RP(SMA(20), 2)");
+    }
+}
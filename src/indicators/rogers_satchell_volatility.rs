@@ -0,0 +1,189 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage;
+use crate::math;
+use crate::{Close, High, Lookback, Low, Next, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rogers-Satchell volatility.
+///
+/// Range-based estimator of the volatility by L. C. G. Rogers and S. E. Satchell, computed
+/// from the open, high, low and close prices of the last _period_ bars. Unlike the
+/// [Parkinson](struct.ParkinsonVolatility.html) and
+/// [Garman-Klass](struct.GarmanKlassVolatility.html) estimators it is not biased by a drift,
+/// so it stays accurate in trending markets, but it still ignores the gaps between bars.
+///
+/// The output is annualized, e.g. 0.25 for a volatility of 25% a year. The prices must be
+/// positive.
+///
+/// # Formula
+///
+/// σ<sup>2</sup><sub>i</sub> = ln(H<sub>i</sub> / C<sub>i</sub>) * ln(H<sub>i</sub> / O<sub>i</sub>) + ln(L<sub>i</sub> / C<sub>i</sub>) * ln(L<sub>i</sub> / O<sub>i</sub>)
+///
+/// σ = √(Σ σ<sup>2</sup><sub>i</sub> / n * _bars_per_year_)
+///
+/// Where the sum runs over the _n_ bars of the window.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 20.
+/// * _bars_per_year_ - number of bars in a year, e.g. 252 for daily bars of stocks or 52 for
+///   weekly bars (greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RogersSatchellVolatility;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |open: f64, high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut rs = RogersSatchellVolatility::new(2, 252.0).unwrap();
+/// assert_eq!((rs.next(&bar(100.0, 102.0, 99.0, 101.0)) * 1000.0).round(), 316.0);
+/// assert_eq!((rs.next(&bar(101.0, 101.5, 98.0, 98.5)) * 1000.0).round(), 297.0);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RogersSatchellVolatility {
+    bars_per_year: f64,
+    sma: SimpleMovingAverage,
+}
+
+impl RogersSatchellVolatility {
+    pub fn new(period: usize, bars_per_year: f64) -> Result<Self> {
+        if bars_per_year <= 0.0 || !bars_per_year.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            bars_per_year,
+            sma: SimpleMovingAverage::new(period)?,
+        })
+    }
+
+    pub fn bars_per_year(&self) -> f64 {
+        self.bars_per_year
+    }
+}
+
+/// Rogers-Satchell variance of a single bar, never negative for a consistent bar.
+pub(super) fn rogers_satchell_variance(open: f64, high: f64, low: f64, close: f64) -> f64 {
+    math::ln(high / close) * math::ln(high / open) + math::ln(low / close) * math::ln(low / open)
+}
+
+impl Period for RogersSatchellVolatility {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Lookback for RogersSatchellVolatility {
+    fn lookback(&self) -> usize {
+        self.sma.lookback()
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for RogersSatchellVolatility {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let variance = self.sma.next(rogers_satchell_variance(
+            input.open(),
+            input.high(),
+            input.low(),
+            input.close(),
+        ));
+        (variance.max(0.0) * self.bars_per_year).sqrt()
+    }
+}
+
+impl Reset for RogersSatchellVolatility {
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
+
+impl Default for RogersSatchellVolatility {
+    fn default() -> Self {
+        Self::new(20, 252.0).unwrap()
+    }
+}
+
+impl fmt::Display for RogersSatchellVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RS({}, {})", self.sma.period(), self.bars_per_year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RogersSatchellVolatility::new(0, 252.0).is_err());
+        assert!(RogersSatchellVolatility::new(20, -252.0).is_err());
+        assert!(RogersSatchellVolatility::new(1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rs = RogersSatchellVolatility::new(3, 252.0).unwrap();
+
+        assert_eq!(round(rs.next(&bar(100.0, 102.0, 99.0, 101.0))), 0.316);
+        assert_eq!(round(rs.next(&bar(101.0, 101.5, 98.0, 98.5))), 0.297);
+        // opened at the low and closed at the high, no variance
+        assert_eq!(round(rs.next(&bar(98.5, 103.0, 98.5, 103.0))), 0.242);
+        // the first bar left the window
+        assert_eq!(round(rs.next(&bar(103.0, 104.0, 102.0, 103.5))), 0.203);
+    }
+
+    #[test]
+    fn test_next_trending_bar() {
+        let mut rs = RogersSatchellVolatility::new(3, 252.0).unwrap();
+        assert_eq!(rs.next(&bar(100.0, 105.0, 100.0, 105.0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rs = RogersSatchellVolatility::new(3, 252.0).unwrap();
+        rs.next(&bar(101.0, 101.5, 98.0, 98.5));
+
+        rs.reset();
+        assert_eq!(round(rs.next(&bar(100.0, 102.0, 99.0, 101.0))), 0.316);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let rs = RogersSatchellVolatility::new(20, 252.0).unwrap();
+        assert_eq!(rs.period(), 20);
+        assert_eq!(rs.lookback(), 19);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(RogersSatchellVolatility::default().bars_per_year(), 252.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let rs = RogersSatchellVolatility::new(20, 252.0).unwrap();
+        assert_eq!(format!("{}", rs), "RS(20, 252)");
+    }
+}
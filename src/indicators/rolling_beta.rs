@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::rolling_covariance::RollingCovariance;
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling beta versus a benchmark.
+///
+/// The sensitivity of the returns of an asset to the returns of a benchmark over the last
+/// _period_ periods, e.g. a stock against its index: a beta of 1.5 means the asset moved 1.5%
+/// on average for every 1% of the benchmark. The R<sup>2</sup> is the share of the variance of
+/// the asset explained by the benchmark, from 0 to 1, telling how much the beta can be relied
+/// upon.
+///
+/// The input is a pair of returns `(asset, benchmark)` of the same period, e.g. the daily
+/// percentage changes of both closes.
+///
+/// # Formula
+///
+/// β = cov(asset, benchmark) / var(benchmark)
+///
+/// R<sup>2</sup> = ρ<sup>2</sup>
+///
+/// Where _ρ_ is the [correlation](struct.RollingCorrelation.html) of the returns over the
+/// window. Both are 0 while the benchmark is constant over the window, and the R<sup>2</sup>
+/// is 0 while the asset is.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 1). Default is 60.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingBeta;
+/// use ta::Next;
+///
+/// let mut beta = RollingBeta::new(3).unwrap();
+/// beta.next((0.02, 0.01));
+/// beta.next((-0.04, -0.02));
+///
+/// // The asset moves exactly twice as much as the benchmark.
+/// let out = beta.next((0.06, 0.03));
+/// assert_eq!((out.beta * 1000.0).round(), 2000.0);
+/// assert_eq!((out.r_squared * 1000.0).round(), 1000.0);
+/// ```
+///
+/// # Links
+///
+/// * [Beta (finance), Wikipedia](https://en.wikipedia.org/wiki/Beta_(finance))
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingBeta {
+    period: usize,
+    covariance: RollingCovariance,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RollingBetaOutput {
+    pub beta: f64,
+    pub r_squared: f64,
+}
+
+impl RollingBeta {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            covariance: RollingCovariance::new(period),
+        })
+    }
+}
+
+impl Period for RollingBeta {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for RollingBeta {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<(f64, f64)> for RollingBeta {
+    type Output = RollingBetaOutput;
+
+    fn next(&mut self, (asset, benchmark): (f64, f64)) -> Self::Output {
+        // The asset is regressed on the benchmark.
+        self.covariance.push(benchmark, asset);
+        let correlation = self.covariance.correlation();
+
+        RollingBetaOutput {
+            beta: self.covariance.slope(),
+            r_squared: correlation * correlation,
+        }
+    }
+}
+
+impl Reset for RollingBeta {
+    fn reset(&mut self) {
+        self.covariance.clear();
+    }
+}
+
+impl Default for RollingBeta {
+    fn default() -> Self {
+        Self::new(60).unwrap()
+    }
+}
+
+impl fmt::Display for RollingBeta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BETA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingBeta::new(0).is_err());
+        assert!(RollingBeta::new(1).is_err());
+        assert!(RollingBeta::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut beta = RollingBeta::new(4).unwrap();
+
+        let test_data = vec![
+            // asset, benchmark, beta, r_squared
+            (0.01, 0.02, 0.0, 0.0),
+            (0.03, -0.01, -0.667, 1.0),
+            (-0.03, 0.01, -1.0, 0.25),
+            (0.02, 0.03, -0.2, 0.017),
+            // (0.01, 0.02) left the window
+            (-0.01, -0.02, 0.119, 0.009),
+        ];
+
+        for (asset, benchmark, expected_beta, expected_r_squared) in test_data {
+            let out = beta.next((asset, benchmark));
+            assert_eq!(round(out.beta), expected_beta);
+            assert_eq!(round(out.r_squared), expected_r_squared);
+        }
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut beta = RollingBeta::new(3).unwrap();
+        beta.next((0.01, 0.0));
+        let out = beta.next((0.02, 0.0));
+        assert_eq!(out.beta, 0.0);
+        assert_eq!(out.r_squared, 0.0);
+
+        // constant asset, no variance to explain
+        let mut beta = RollingBeta::new(3).unwrap();
+        beta.next((0.01, 0.01));
+        let out = beta.next((0.01, 0.02));
+        assert_eq!(out.beta, 0.0);
+        assert_eq!(out.r_squared, 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut beta = RollingBeta::new(3).unwrap();
+        beta.next((0.01, 0.02));
+        beta.next((0.03, -0.01));
+
+        beta.reset();
+        beta.next((0.01, 0.01));
+        assert_eq!(round(beta.next((0.02, 0.02)).beta), 1.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let beta = RollingBeta::new(60).unwrap();
+        assert_eq!(beta.lookback(), 59);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(RollingBeta::default().period(), 60);
+    }
+
+    #[test]
+    fn test_display() {
+        let beta = RollingBeta::new(60).unwrap();
+        assert_eq!(format!("{}", beta), "BETA(60)");
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::rolling_covariance::RollingCovariance;
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling Pearson correlation.
+///
+/// The correlation of two series over the last _period_ periods, from -1 to 1: 1 when they
+/// move together along a straight line, -1 when they move in opposite directions and around 0
+/// when they are unrelated. Fed with the closes, or better the returns, of two assets it tracks
+/// the stability of a pair trade. Unlike the [Spearman correlation](struct.SpearmanCorrelation.html)
+/// it measures a linear relation and is sensitive to outliers, but it is updated in O(1).
+///
+/// The input is a pair of values `(x, y)` of the same period.
+///
+/// # Formula
+///
+/// ρ = cov(x, y) / (σ<sub>x</sub> * σ<sub>y</sub>)
+///
+/// Where the covariance and the standard deviations are computed over the window, from sums of
+/// deviations updated incrementally. The correlation is 0 while one of the series is constant
+/// over the window.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingCorrelation;
+/// use ta::Next;
+///
+/// let mut correlation = RollingCorrelation::new(3).unwrap();
+/// correlation.next((1.0, 10.0));
+/// correlation.next((2.0, 20.0));
+/// assert_eq!(correlation.next((3.0, 30.0)), 1.0);
+/// assert_eq!(correlation.next((4.0, 10.0)), -0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Pearson correlation coefficient, Wikipedia](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient)
+///
+#[doc(alias = "CORREL")]
+#[doc(alias = "Pearson")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingCorrelation {
+    period: usize,
+    covariance: RollingCovariance,
+}
+
+impl RollingCorrelation {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            covariance: RollingCovariance::new(period),
+        })
+    }
+}
+
+impl Period for RollingCorrelation {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for RollingCorrelation {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<(f64, f64)> for RollingCorrelation {
+    type Output = f64;
+
+    fn next(&mut self, (x, y): (f64, f64)) -> Self::Output {
+        self.covariance.push(x, y);
+        self.covariance.correlation()
+    }
+}
+
+impl Reset for RollingCorrelation {
+    fn reset(&mut self) {
+        self.covariance.clear();
+    }
+}
+
+impl Default for RollingCorrelation {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RollingCorrelation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CORREL({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RollingCorrelation::new(0).is_err());
+        assert!(RollingCorrelation::new(1).is_err());
+        assert!(RollingCorrelation::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut correlation = RollingCorrelation::new(4).unwrap();
+
+        assert_eq!(correlation.next((1.0, 3.0)), 0.0);
+        assert_eq!(round(correlation.next((2.0, 5.0))), 1.0);
+        assert_eq!(round(correlation.next((3.0, 4.0))), 0.5);
+        assert_eq!(round(correlation.next((4.0, 8.0))), 0.837);
+        // (1, 3) left the window
+        assert_eq!(round(correlation.next((5.0, 2.0))), -0.258);
+        assert_eq!(round(correlation.next((6.0, 1.0))), -0.626);
+    }
+
+    #[test]
+    fn test_next_constant() {
+        let mut correlation = RollingCorrelation::new(3).unwrap();
+        assert_eq!(correlation.next((1.0, 5.0)), 0.0);
+        assert_eq!(correlation.next((2.0, 5.0)), 0.0);
+        assert_eq!(correlation.next((3.0, 5.0)), 0.0);
+    }
+
+    #[test]
+    fn test_next_large_values() {
+        let mut correlation = RollingCorrelation::new(3).unwrap();
+        for i in 0..1000 {
+            let x = 1e9 + i as f64;
+            correlation.next((x, 2.0 * x));
+        }
+        assert_eq!(round(correlation.next((1e9 + 1000.0, 2e9 + 2000.0))), 1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut correlation = RollingCorrelation::new(3).unwrap();
+        correlation.next((1.0, 3.0));
+        correlation.next((2.0, 5.0));
+
+        correlation.reset();
+        assert_eq!(correlation.next((2.0, 1.0)), 0.0);
+        assert_eq!(round(correlation.next((1.0, 2.0))), -1.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let correlation = RollingCorrelation::new(20).unwrap();
+        assert_eq!(correlation.lookback(), 19);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(RollingCorrelation::default().period(), 20);
+    }
+
+    #[test]
+    fn test_display() {
+        let correlation = RollingCorrelation::new(20).unwrap();
+        assert_eq!(format!("{}", correlation), "CORREL(20)");
+    }
+}
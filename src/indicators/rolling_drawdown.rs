@@ -0,0 +1,241 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling maximum drawdown and run-up of an indicator.
+///
+/// Feeds the inputs to the wrapped indicator and measures how far its output retraced over the
+/// last _period_ periods: the drawdown is the largest fall from a peak to a later trough, and
+/// the run-up the largest rise from a trough to a later peak. They measure how stable a signal
+/// is, e.g. how deep the MACD histogram pulls back within a trend.
+///
+/// # Formula
+///
+/// drawdown = max (v<sub>i</sub> - v<sub>j</sub>) for i ≤ j
+///
+/// run-up = max (v<sub>j</sub> - v<sub>i</sub>) for i ≤ j
+///
+/// Where _v_ are the outputs of the indicator over the last _period_ periods, in the units of
+/// the indicator. Both are 0 or greater.
+///
+/// # Parameters
+///
+/// * _indicator_ - inner indicator producing `f64` values.
+/// * _period_ - number of outputs in the window (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{RollingDrawdown, SimpleMovingAverage};
+/// use ta::Next;
+///
+/// let sma = SimpleMovingAverage::new(1).unwrap();
+/// let mut drawdown = RollingDrawdown::new(sma, 3).unwrap();
+///
+/// drawdown.next(10.0);
+/// drawdown.next(12.0);
+/// let out = drawdown.next(9.0);
+/// assert_eq!((out.drawdown, out.runup), (3.0, 2.0));
+///
+/// // 10.0 left the window
+/// let out = drawdown.next(11.0);
+/// assert_eq!((out.drawdown, out.runup), (3.0, 2.0));
+/// ```
+///
+#[doc(alias = "MAE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingDrawdown<I> {
+    indicator: I,
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RollingDrawdownOutput {
+    /// Output of the indicator.
+    pub value: f64,
+    pub drawdown: f64,
+    pub runup: f64,
+}
+
+impl<I> RollingDrawdown<I> {
+    pub fn new(indicator: I, period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                indicator,
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+            }),
+        }
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<I> Period for RollingDrawdown<I> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<I: Lookback> Lookback for RollingDrawdown<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback() + self.period - 1
+    }
+}
+
+impl<In, I: Next<In, Output = f64>> Next<In> for RollingDrawdown<I> {
+    type Output = RollingDrawdownOutput;
+
+    fn next(&mut self, input: In) -> Self::Output {
+        let value = self.indicator.next(input);
+
+        self.deque[self.index] = value;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        // Walk the window from the oldest value to the most recent one.
+        let oldest = if self.count < self.period {
+            0
+        } else {
+            self.index
+        };
+        let mut peak = f64::NEG_INFINITY;
+        let mut trough = f64::INFINITY;
+        let mut drawdown: f64 = 0.0;
+        let mut runup: f64 = 0.0;
+        for i in 0..self.count {
+            let v = self.deque[(oldest + i) % self.period];
+            peak = peak.max(v);
+            trough = trough.min(v);
+            drawdown = drawdown.max(peak - v);
+            runup = runup.max(v - trough);
+        }
+
+        RollingDrawdownOutput {
+            value,
+            drawdown,
+            runup,
+        }
+    }
+}
+
+impl<I: Reset> Reset for RollingDrawdown<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl<I: Default> Default for RollingDrawdown<I> {
+    fn default() -> Self {
+        Self::new(I::default(), 20).unwrap()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for RollingDrawdown<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DRAWDOWN({}, {})", self.indicator, self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage as Sma;
+    use crate::test_helper::*;
+
+    fn drawdown(period: usize) -> RollingDrawdown<Sma> {
+        RollingDrawdown::new(Sma::new(1).unwrap(), period).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RollingDrawdown::new(Sma::new(1).unwrap(), 0).is_err());
+        assert!(RollingDrawdown::new(Sma::new(1).unwrap(), 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut drawdown = drawdown(4);
+
+        let test_data = vec![
+            // input, drawdown, runup
+            (1.0, 0.0, 0.0),
+            (3.0, 0.0, 2.0),
+            (2.0, 1.0, 2.0),
+            (5.0, 1.0, 4.0),
+            (1.0, 4.0, 3.0),
+            (4.0, 4.0, 3.0),
+            (6.0, 4.0, 5.0),
+        ];
+
+        for (input, expected_drawdown, expected_runup) in test_data {
+            let out = drawdown.next(input);
+            assert_eq!(out.value, input);
+            assert_eq!(out.drawdown, expected_drawdown);
+            assert_eq!(out.runup, expected_runup);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut drawdown = RollingDrawdown::new(Sma::new(2).unwrap(), 3).unwrap();
+        drawdown.next(&Bar::new().close(10));
+        drawdown.next(&Bar::new().close(20));
+        let out = drawdown.next(&Bar::new().close(4));
+        assert_eq!(out.value, 12.0);
+        assert_eq!((out.drawdown, out.runup), (3.0, 5.0));
+    }
+
+    #[test]
+    fn test_lookback() {
+        let drawdown = RollingDrawdown::new(Sma::new(5).unwrap(), 3).unwrap();
+        assert_eq!(drawdown.lookback(), 6);
+        assert_eq!(drawdown.period(), 3);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut drawdown = drawdown(3);
+        drawdown.next(10.0);
+        drawdown.next(5.0);
+
+        drawdown.reset();
+        let out = drawdown.next(8.0);
+        assert_eq!((out.drawdown, out.runup), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        RollingDrawdown::<Sma>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = RollingDrawdown::new(Sma::new(9).unwrap(), 20).unwrap();
+        assert_eq!(format!("{}", indicator), "DRAWDOWN(SMA(9), 20)");
+    }
+}
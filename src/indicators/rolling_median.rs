@@ -0,0 +1,169 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::median_heap::MedianHeap;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling median.
+///
+/// Median of the last _period_ values, a moving average that is not pulled by outliers: a
+/// single wick or bad tick moves the [SMA](struct.SimpleMovingAverage.html) by its size divided
+/// by the period, but it moves the median by one rank at most. It also keeps the steps of the
+/// price sharp instead of ramping them.
+///
+/// The lower and upper halves of the window are kept in two heaps, so an update is `O(log n)`.
+///
+/// # Formula
+///
+/// For the _n_ values of the window sorted as x<sub>1</sub>...x<sub>n</sub>:
+///
+/// * x<sub>(n+1)/2</sub> if _n_ is odd
+/// * (x<sub>n/2</sub> + x<sub>n/2+1</sub>) / 2 if _n_ is even
+///
+/// While the window is not full, the median of the available values is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of values (integer greater than 0). Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingMedian;
+/// use ta::Next;
+///
+/// let mut median = RollingMedian::new(3).unwrap();
+/// assert_eq!(median.next(10.0), 10.0);
+/// assert_eq!(median.next(11.0), 10.5);
+/// assert_eq!(median.next(10.5), 10.5);
+/// // A spike does not move the median.
+/// assert_eq!(median.next(50.0), 11.0);
+/// ```
+///
+#[doc(alias = "Median")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingMedian {
+    window: MedianHeap,
+}
+
+impl RollingMedian {
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            window: MedianHeap::new(period),
+        })
+    }
+}
+
+impl Period for RollingMedian {
+    fn period(&self) -> usize {
+        self.window.capacity()
+    }
+}
+
+impl Lookback for RollingMedian {
+    fn lookback(&self) -> usize {
+        self.window.capacity() - 1
+    }
+}
+
+impl Next<f64> for RollingMedian {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.window.push(input)
+    }
+}
+
+impl<T: Close> Next<&T> for RollingMedian {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RollingMedian {
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+impl Default for RollingMedian {
+    fn default() -> Self {
+        Self::new(5).unwrap()
+    }
+}
+
+impl fmt::Display for RollingMedian {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MEDIAN({})", self.window.capacity())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingMedian);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingMedian::new(0).is_err());
+        assert!(RollingMedian::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut median = RollingMedian::new(4).unwrap();
+
+        assert_eq!(median.next(4.0), 4.0);
+        assert_eq!(median.next(2.0), 3.0);
+        assert_eq!(median.next(7.0), 4.0);
+        assert_eq!(median.next(1.0), 3.0);
+        // 4 left the window
+        assert_eq!(median.next(9.0), 4.5);
+        assert_eq!(median.next(2.0), 4.5);
+    }
+
+    #[test]
+    fn test_next_period_1() {
+        let mut median = RollingMedian::new(1).unwrap();
+        assert_eq!(median.next(4.0), 4.0);
+        assert_eq!(median.next(-2.0), -2.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut median = RollingMedian::new(3).unwrap();
+        median.next(&Bar::new().close(10));
+        assert_eq!(median.next(&Bar::new().close(12)), 11.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut median = RollingMedian::new(3).unwrap();
+        median.next(4.0);
+        median.next(2.0);
+
+        median.reset();
+        assert_eq!(median.next(7.0), 7.0);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(RollingMedian::default().period(), 5);
+    }
+
+    #[test]
+    fn test_display() {
+        let median = RollingMedian::new(5).unwrap();
+        assert_eq!(format!("{}", median), "MEDIAN(5)");
+    }
+}
@@ -0,0 +1,321 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::price_histogram::PriceHistogram;
+use crate::{Close, Lookback, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling mode of prices.
+///
+/// Maintains a coarse histogram of the prices of the last _period_ periods, with buckets of
+/// _bucket_size_ width, and returns the most populated (modal) bucket together with its share
+/// of the window. When bars are given, every close is weighted by its volume, so the mode is the
+/// most traded price bucket. It is a lightweight alternative to a full
+/// [volume profile](struct.VolumeProfile.html).
+///
+/// # Formula
+///
+/// bucket = floor(price / _bucket_size_)
+///
+/// * _price_ - middle of the bucket with the largest weight. When several buckets have the same
+///   weight, the lowest one is returned.
+/// * _share_ - weight of the modal bucket / total weight of the window, from 0 to 1
+///
+/// The weight of a value is 1 when `f64` values are given, and the volume for bars.
+///
+/// # Profile queries
+///
+/// The histogram of the window can be queried between updates, e.g. by execution logic, with
+/// [histogram](#method.histogram), [volume_between](#method.volume_between),
+/// [node_above](#method.node_above) and [node_below](#method.node_below) for the nearest high
+/// volume nodes around a price, and [gaps](#method.gaps) for the low volume areas between them.
+/// A node is a bucket holding at least a given share of the weight of the window.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 100.
+/// * _bucket_size_ - width of the price buckets (greater than 0). Default is 1.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingMode;
+/// use ta::Next;
+///
+/// let mut mode = RollingMode::new(4, 1.0).unwrap();
+/// mode.next(10.2);
+/// mode.next(11.5);
+/// mode.next(10.8);
+///
+/// let out = mode.next(12.1);
+/// assert_eq!(out.price, 10.5);
+/// assert_eq!(out.share, 0.5);
+///
+/// // The nearest bucket above 11 with at least a quarter of the volume.
+/// assert_eq!(mode.node_above(11.0, 0.25), Some(12.5));
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingMode {
+    period: usize,
+    bucket_size: f64,
+    index: usize,
+    count: usize,
+    deque: Box<[(i64, f64)]>,
+    buckets: PriceHistogram,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RollingModeOutput {
+    pub price: f64,
+    pub share: f64,
+}
+
+impl RollingMode {
+    pub fn new(period: usize, bucket_size: f64) -> Result<Self> {
+        if period == 0 || !(bucket_size > 0.0 && bucket_size.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            bucket_size,
+            index: 0,
+            count: 0,
+            deque: vec![(0, 0.0); period].into_boxed_slice(),
+            buckets: PriceHistogram::new(0.0, bucket_size),
+        })
+    }
+
+    pub fn bucket_size(&self) -> f64 {
+        self.bucket_size
+    }
+
+    /// Returns the middle price and the weight of the non empty buckets, from the lowest price.
+    pub fn histogram(&self) -> Vec<(f64, f64)> {
+        self.buckets.histogram()
+    }
+
+    /// Returns the weight of the buckets overlapping the prices from _low_ to _high_.
+    pub fn volume_between(&self, low: f64, high: f64) -> f64 {
+        self.buckets.volume_between(low, high)
+    }
+
+    /// Returns the middle price of the nearest bucket above the bucket of _price_ holding at
+    /// least _min_share_ of the weight of the window.
+    pub fn node_above(&self, price: f64, min_share: f64) -> Option<f64> {
+        self.buckets.node_above(price, min_share)
+    }
+
+    /// Returns the middle price of the nearest bucket below the bucket of _price_ holding at
+    /// least _min_share_ of the weight of the window.
+    pub fn node_below(&self, price: f64, min_share: f64) -> Option<f64> {
+        self.buckets.node_below(price, min_share)
+    }
+
+    /// Returns the price ranges, from the lowest, where all the buckets hold at most
+    /// _max_share_ of the weight of the window, and that are surrounded by heavier buckets.
+    pub fn gaps(&self, max_share: f64) -> Vec<(f64, f64)> {
+        self.buckets.gaps(max_share)
+    }
+
+    fn add(&mut self, price: f64, weight: f64) -> RollingModeOutput {
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let (old_bucket, old_weight) = self.deque[self.index];
+            self.buckets.remove(old_bucket, old_weight);
+        }
+
+        let bucket = self.buckets.bin(price);
+        self.buckets.add(bucket, weight);
+
+        self.deque[self.index] = (bucket, weight);
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let mut total = 0.0;
+        let mut mode = (bucket, f64::NEG_INFINITY);
+        for (bucket, weight) in self.buckets.iter() {
+            total += weight;
+            if weight > mode.1 {
+                mode = (bucket, weight);
+            }
+        }
+
+        RollingModeOutput {
+            price: self.buckets.middle(mode.0),
+            share: if total > 0.0 { mode.1 / total } else { 0.0 },
+        }
+    }
+}
+
+impl Period for RollingMode {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for RollingMode {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for RollingMode {
+    type Output = RollingModeOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.add(input, 1.0)
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for RollingMode {
+    type Output = RollingModeOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.add(input.close(), input.volume())
+    }
+}
+
+impl Reset for RollingMode {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = (0, 0.0);
+        }
+        self.buckets.clear(0.0, self.bucket_size);
+    }
+}
+
+impl Default for RollingMode {
+    fn default() -> Self {
+        Self::new(100, 1.0).unwrap()
+    }
+}
+
+impl fmt::Display for RollingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MODE({}, {})", self.period, self.bucket_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingMode);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingMode::new(0, 1.0).is_err());
+        assert!(RollingMode::new(1, 0.0).is_err());
+        assert!(RollingMode::new(1, f64::NAN).is_err());
+        assert!(RollingMode::new(1, f64::INFINITY).is_err());
+        assert!(RollingMode::new(1, 0.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mode = RollingMode::new(3, 2.0).unwrap();
+
+        let test_data = vec![
+            // input, price, share
+            (5.0, 5.0, 1.0),
+            (8.5, 5.0, 0.5),
+            (9.9, 9.0, 0.667),
+            (4.1, 9.0, 0.667),
+            (5.5, 5.0, 0.667),
+            (-0.5, 5.0, 0.667),
+            (-1.0, -1.0, 0.667),
+        ];
+
+        for (input, price, share) in test_data {
+            let out = mode.next(input);
+            assert_eq!(out.price, price);
+            assert_eq!(round(out.share), share);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut mode = RollingMode::new(3, 1.0).unwrap();
+
+        mode.next(&Bar::new().close(10.5).volume(100.0));
+        mode.next(&Bar::new().close(11.2).volume(300.0));
+        let out = mode.next(&Bar::new().close(10.1).volume(100.0));
+        assert_eq!(out.price, 11.5);
+        assert_eq!(out.share, 0.6);
+
+        let out = mode.next(&Bar::new().close(10.7).volume(300.0));
+        assert_eq!(out.price, 10.5);
+        assert_eq!(round(out.share), 0.571);
+    }
+
+    #[test]
+    fn test_next_without_volume() {
+        let mut mode = RollingMode::new(3, 1.0).unwrap();
+        let out = mode.next(&Bar::new().close(10.5).volume(0.0));
+        assert_eq!(out.price, 10.5);
+        assert_eq!(out.share, 0.0);
+    }
+
+    #[test]
+    fn test_profile_queries() {
+        let mut mode = RollingMode::new(10, 1.0).unwrap();
+        for price in &[10.2, 10.5, 10.7, 11.3, 13.4, 13.6, 13.9, 14.1] {
+            mode.next(*price);
+        }
+
+        assert_eq!(
+            mode.histogram(),
+            vec![(10.5, 3.0), (11.5, 1.0), (13.5, 3.0), (14.5, 1.0)]
+        );
+
+        assert_eq!(mode.volume_between(10.0, 11.9), 4.0);
+        assert_eq!(mode.volume_between(11.5, 13.0), 4.0);
+        assert_eq!(mode.volume_between(12.0, 12.9), 0.0);
+        assert_eq!(mode.volume_between(13.0, 10.0), 0.0);
+
+        assert_eq!(mode.node_above(11.5, 0.25), Some(13.5));
+        assert_eq!(mode.node_above(13.5, 0.25), None);
+        assert_eq!(mode.node_above(13.5, 0.1), Some(14.5));
+        assert_eq!(mode.node_below(13.2, 0.25), Some(10.5));
+        assert_eq!(mode.node_below(10.9, 0.0), None);
+
+        // The light bucket 14 is on the edge of the profile, not a gap.
+        assert_eq!(mode.gaps(0.2), vec![(11.0, 13.0)]);
+        assert_eq!(mode.gaps(0.1), vec![(12.0, 13.0)]);
+        assert!(mode.gaps(0.5).is_empty());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mode = RollingMode::new(3, 1.0).unwrap();
+        mode.next(1.0);
+        mode.next(1.0);
+
+        mode.reset();
+        let out = mode.next(5.0);
+        assert_eq!(out.price, 5.5);
+        assert_eq!(out.share, 1.0);
+        assert_eq!(mode.next(1.0).price, 1.5);
+    }
+
+    #[test]
+    fn test_default() {
+        RollingMode::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = RollingMode::new(50, 0.25).unwrap();
+        assert_eq!(format!("{}", indicator), "MODE(50, 0.25)");
+    }
+}
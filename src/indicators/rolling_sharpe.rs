@@ -0,0 +1,252 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{StandardDeviation, VarianceMode};
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling Sharpe ratio.
+///
+/// The average excess return over the last _period_ periods per unit of volatility, annualized,
+/// as defined by William F. Sharpe. It compares strategies or assets of different risk: a ratio
+/// above 1 is usually considered good, a negative ratio means the returns did not beat the
+/// risk-free rate.
+///
+/// The inputs are returns, e.g. the daily percentage changes of a strategy. When bars are
+/// given, the simple returns of their closes are computed, so the first bar only sets the
+/// reference close and returns 0.
+///
+/// # Formula
+///
+/// Sharpe = (mean(r) - _risk_free_ / _bars_per_year_) / σ(r) * √_bars_per_year_
+///
+/// Where _σ_ is the sample standard deviation of the returns of the window, with n - 1 degrees
+/// of freedom. The ratio is 0 while there are less than 2 returns or they are all equal.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 1). Default is 252.
+/// * _bars_per_year_ - number of periods in a year, e.g. 252 for daily bars of stocks or 52
+///   for weekly bars (greater than 0). Default is 252.
+/// * _risk_free_ - annual risk-free rate, set with
+///   [with_risk_free](#method.with_risk_free), e.g. 0.02 for 2%. Default is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingSharpe;
+/// use ta::Next;
+///
+/// // Monthly returns
+/// let mut sharpe = RollingSharpe::new(4, 12.0).unwrap();
+/// sharpe.next(0.02);
+/// sharpe.next(-0.01);
+/// sharpe.next(0.03);
+/// assert_eq!((sharpe.next(0.0) * 1000.0).round(), 1897.0);
+/// ```
+///
+/// # Links
+///
+/// * [Sharpe ratio, Wikipedia](https://en.wikipedia.org/wiki/Sharpe_ratio)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingSharpe {
+    period: usize,
+    bars_per_year: f64,
+    risk_free: f64,
+    count: usize,
+    sd: StandardDeviation,
+    prev_close: Option<f64>,
+}
+
+impl RollingSharpe {
+    pub fn new(period: usize, bars_per_year: f64) -> Result<Self> {
+        if period < 2 || bars_per_year <= 0.0 || !bars_per_year.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            bars_per_year,
+            risk_free: 0.0,
+            count: 0,
+            sd: StandardDeviation::with_mode(period, VarianceMode::Sample)?,
+            prev_close: None,
+        })
+    }
+
+    /// Sets the annual risk-free rate.
+    pub fn with_risk_free(mut self, risk_free: f64) -> Result<Self> {
+        if !risk_free.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        self.risk_free = risk_free;
+        Ok(self)
+    }
+
+    pub fn bars_per_year(&self) -> f64 {
+        self.bars_per_year
+    }
+
+    pub fn risk_free(&self) -> f64 {
+        self.risk_free
+    }
+}
+
+impl Period for RollingSharpe {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for RollingSharpe {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for RollingSharpe {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let sd = self.sd.next(input);
+        if self.count < self.period {
+            self.count += 1;
+        }
+        if self.count < 2 || sd == 0.0 {
+            return 0.0;
+        }
+
+        let excess = self.sd.mean() - self.risk_free / self.bars_per_year;
+        excess / sd * self.bars_per_year.sqrt()
+    }
+}
+
+impl<T: Close> Next<&T> for RollingSharpe {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        match self.prev_close.replace(close) {
+            Some(prev) if prev != 0.0 => self.next(close / prev - 1.0),
+            _ => 0.0,
+        }
+    }
+}
+
+impl Reset for RollingSharpe {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.sd.reset();
+        self.prev_close = None;
+    }
+}
+
+impl Default for RollingSharpe {
+    fn default() -> Self {
+        Self::new(252, 252.0).unwrap()
+    }
+}
+
+impl fmt::Display for RollingSharpe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.risk_free == 0.0 {
+            write!(f, "SHARPE({}, {})", self.period, self.bars_per_year)
+        } else {
+            write!(
+                f,
+                "SHARPE({}, {}, {})",
+                self.period, self.bars_per_year, self.risk_free
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingSharpe);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingSharpe::new(1, 252.0).is_err());
+        assert!(RollingSharpe::new(20, 0.0).is_err());
+        assert!(RollingSharpe::new(20, f64::INFINITY).is_err());
+        assert!(RollingSharpe::new(2, 1.0).is_ok());
+
+        let sharpe = RollingSharpe::new(20, 252.0).unwrap();
+        assert!(sharpe.clone().with_risk_free(f64::NAN).is_err());
+        assert_eq!(sharpe.with_risk_free(0.02).unwrap().risk_free(), 0.02);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sharpe = RollingSharpe::new(4, 12.0).unwrap();
+
+        assert_eq!(sharpe.next(0.02), 0.0);
+        assert_eq!(round(sharpe.next(-0.01)), 0.816);
+        assert_eq!(round(sharpe.next(0.03)), 2.219);
+        assert_eq!(round(sharpe.next(0.0)), 1.897);
+        // 0.02 left the window
+        assert_eq!(round(sharpe.next(0.05)), 2.201);
+    }
+
+    #[test]
+    fn test_next_risk_free() {
+        let mut sharpe = RollingSharpe::new(4, 12.0)
+            .unwrap()
+            .with_risk_free(0.06)
+            .unwrap();
+
+        sharpe.next(0.02);
+        sharpe.next(-0.01);
+        sharpe.next(0.03);
+        assert_eq!(round(sharpe.next(0.0)), 0.949);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut sharpe = RollingSharpe::new(4, 12.0).unwrap();
+
+        assert_eq!(sharpe.next(&Bar::new().close(100)), 0.0);
+        assert_eq!(sharpe.next(&Bar::new().close(102)), 0.0);
+        assert_eq!(round(sharpe.next(&Bar::new().close(100.98))), 0.816);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut sharpe = RollingSharpe::new(3, 252.0).unwrap();
+        assert_eq!(sharpe.next(0.01), 0.0);
+        assert_eq!(sharpe.next(0.01), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sharpe = RollingSharpe::new(4, 12.0).unwrap();
+        sharpe.next(&Bar::new().close(100));
+        sharpe.next(&Bar::new().close(102));
+
+        sharpe.reset();
+        assert_eq!(sharpe.next(&Bar::new().close(50)), 0.0);
+        assert_eq!(sharpe.next(0.02), 0.0);
+        assert_eq!(round(sharpe.next(-0.01)), 0.816);
+    }
+
+    #[test]
+    fn test_default() {
+        let sharpe = RollingSharpe::default();
+        assert_eq!(sharpe.period(), 252);
+        assert_eq!(sharpe.bars_per_year(), 252.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let sharpe = RollingSharpe::new(36, 12.0).unwrap();
+        assert_eq!(format!("{}", sharpe), "SHARPE(36, 12)");
+        let sharpe = sharpe.with_risk_free(0.02).unwrap();
+        assert_eq!(format!("{}", sharpe), "SHARPE(36, 12, 0.02)");
+    }
+}
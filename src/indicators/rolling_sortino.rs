@@ -0,0 +1,317 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling Sortino ratio.
+///
+/// A variant of the [Sharpe ratio](struct.RollingSharpe.html) by Frank A. Sortino that only
+/// counts the volatility of the returns below a minimum acceptable return (MAR) as risk, so
+/// large gains do not lower the ratio. It is computed over the last _period_ periods and
+/// annualized.
+///
+/// The inputs are returns, e.g. the daily percentage changes of a strategy. When bars are
+/// given, the simple returns of their closes are computed, so the first bar only sets the
+/// reference close and returns 0.
+///
+/// # Formula
+///
+/// Sortino = (mean(r) - MAR) / DD * √_bars_per_year_
+///
+/// DD = √(Σ min(r<sub>i</sub> - MAR, 0)<sup>2</sup> / n)
+///
+/// Where:
+///
+/// * _MAR_ - _mar_ / _bars_per_year_, the minimum acceptable return of a period
+/// * _DD_ - downside deviation of the _n_ returns of the window
+///
+/// When no return of the window is below the MAR, the ratio is infinite if the mean return is
+/// above the MAR, otherwise 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 252.
+/// * _bars_per_year_ - number of periods in a year, e.g. 252 for daily bars of stocks or 52
+///   for weekly bars (greater than 0). Default is 252.
+/// * _mar_ - annual minimum acceptable return, set with [with_mar](#method.with_mar), e.g. the
+///   risk-free rate. Default is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingSortino;
+/// use ta::Next;
+///
+/// // Monthly returns
+/// let mut sortino = RollingSortino::new(4, 12.0).unwrap();
+/// sortino.next(0.02);
+/// sortino.next(-0.01);
+/// sortino.next(0.03);
+/// // DD = 0.005, only -0.01 is a loss
+/// assert_eq!((sortino.next(0.0) * 1000.0).round(), 6928.0);
+/// ```
+///
+/// # Links
+///
+/// * [Sortino ratio, Wikipedia](https://en.wikipedia.org/wiki/Sortino_ratio)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingSortino {
+    period: usize,
+    bars_per_year: f64,
+    mar: f64,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+    sum: f64,
+    // Sum of the squared shortfalls below the MAR, and number of returns below it.
+    downside: f64,
+    downside_count: usize,
+    prev_close: Option<f64>,
+}
+
+impl RollingSortino {
+    pub fn new(period: usize, bars_per_year: f64) -> Result<Self> {
+        if period == 0 || bars_per_year <= 0.0 || !bars_per_year.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            bars_per_year,
+            mar: 0.0,
+            index: 0,
+            count: 0,
+            deque: vec![0.0; period].into_boxed_slice(),
+            sum: 0.0,
+            downside: 0.0,
+            downside_count: 0,
+            prev_close: None,
+        })
+    }
+
+    /// Sets the annual minimum acceptable return.
+    pub fn with_mar(mut self, mar: f64) -> Result<Self> {
+        if !mar.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        self.mar = mar;
+        Ok(self)
+    }
+
+    pub fn bars_per_year(&self) -> f64 {
+        self.bars_per_year
+    }
+
+    pub fn mar(&self) -> f64 {
+        self.mar
+    }
+
+    fn shortfall(&self, r: f64) -> Option<f64> {
+        let shortfall = r - self.mar / self.bars_per_year;
+        if shortfall < 0.0 {
+            Some(shortfall * shortfall)
+        } else {
+            None
+        }
+    }
+}
+
+impl Period for RollingSortino {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for RollingSortino {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for RollingSortino {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old = self.deque[self.index];
+            self.sum -= old;
+            if let Some(shortfall) = self.shortfall(old) {
+                self.downside -= shortfall;
+                self.downside_count -= 1;
+            }
+        }
+
+        self.sum += input;
+        if let Some(shortfall) = self.shortfall(input) {
+            self.downside += shortfall;
+            self.downside_count += 1;
+        }
+        // No rounding residue once the last shortfall left the window.
+        if self.downside_count == 0 || self.downside < 0.0 {
+            self.downside = 0.0;
+        }
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let n = self.count as f64;
+        let mar = self.mar / self.bars_per_year;
+        if self.downside > 0.0 {
+            (self.sum / n - mar) / (self.downside / n).sqrt() * self.bars_per_year.sqrt()
+        } else if self.deque[..self.count].iter().any(|&r| r > mar) {
+            // Checked on the returns, the running sum may be off by a rounding residue.
+            f64::INFINITY
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for RollingSortino {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        match self.prev_close.replace(close) {
+            Some(prev) if prev != 0.0 => self.next(close / prev - 1.0),
+            _ => 0.0,
+        }
+    }
+}
+
+impl Reset for RollingSortino {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        self.downside = 0.0;
+        self.downside_count = 0;
+        self.prev_close = None;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for RollingSortino {
+    fn default() -> Self {
+        Self::new(252, 252.0).unwrap()
+    }
+}
+
+impl fmt::Display for RollingSortino {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.mar == 0.0 {
+            write!(f, "SORTINO({}, {})", self.period, self.bars_per_year)
+        } else {
+            write!(
+                f,
+                "SORTINO({}, {}, {})",
+                self.period, self.bars_per_year, self.mar
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingSortino);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingSortino::new(0, 252.0).is_err());
+        assert!(RollingSortino::new(20, -1.0).is_err());
+        assert!(RollingSortino::new(1, 1.0).is_ok());
+
+        let sortino = RollingSortino::new(20, 252.0).unwrap();
+        assert!(sortino.clone().with_mar(f64::INFINITY).is_err());
+        assert_eq!(sortino.with_mar(0.05).unwrap().mar(), 0.05);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sortino = RollingSortino::new(4, 12.0).unwrap();
+
+        assert_eq!(sortino.next(0.02), f64::INFINITY);
+        assert_eq!(round(sortino.next(-0.01)), 2.449);
+        assert_eq!(round(sortino.next(0.03)), 8.0);
+        assert_eq!(round(sortino.next(0.0)), 6.928);
+        // 0.02 left the window
+        assert_eq!(round(sortino.next(-0.02)), 0.0);
+        // -0.01 left the window
+        assert_eq!(round(sortino.next(0.05)), 5.196);
+    }
+
+    #[test]
+    fn test_next_mar() {
+        let mut sortino = RollingSortino::new(4, 12.0)
+            .unwrap()
+            .with_mar(0.12)
+            .unwrap();
+
+        // MAR of 1% a month
+        sortino.next(0.02);
+        sortino.next(-0.01);
+        sortino.next(0.03);
+        assert_eq!(round(sortino.next(0.0)), 0.0);
+        assert_eq!(round(sortino.next(0.01)), -0.775);
+        assert_eq!(round(sortino.next(-0.01)), -0.775);
+    }
+
+    #[test]
+    fn test_next_losses_leave_window() {
+        let mut sortino = RollingSortino::new(2, 12.0).unwrap();
+
+        assert_eq!(round(sortino.next(-0.03)), -3.464);
+        sortino.next(0.01);
+        assert_eq!(sortino.next(0.02), f64::INFINITY);
+        assert_eq!(sortino.next(0.0), f64::INFINITY);
+        assert_eq!(sortino.next(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut sortino = RollingSortino::new(4, 12.0).unwrap();
+
+        assert_eq!(sortino.next(&Bar::new().close(100)), 0.0);
+        assert_eq!(sortino.next(&Bar::new().close(102)), f64::INFINITY);
+        assert_eq!(round(sortino.next(&Bar::new().close(100.98))), 2.449);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sortino = RollingSortino::new(4, 12.0).unwrap();
+        sortino.next(0.02);
+        sortino.next(-0.01);
+
+        sortino.reset();
+        assert_eq!(round(sortino.next(-0.01)), -3.464);
+    }
+
+    #[test]
+    fn test_default() {
+        let sortino = RollingSortino::default();
+        assert_eq!(sortino.period(), 252);
+        assert_eq!(sortino.bars_per_year(), 252.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let sortino = RollingSortino::new(36, 12.0).unwrap();
+        assert_eq!(format!("{}", sortino), "SORTINO(36, 12)");
+        let sortino = sortino.with_mar(0.05).unwrap();
+        assert_eq!(format!("{}", sortino), "SORTINO(36, 12, 0.05)");
+    }
+}
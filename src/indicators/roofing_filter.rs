@@ -0,0 +1,244 @@
+use std::f64::consts::{PI, SQRT_2};
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SuperSmoother;
+use crate::math;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ehlers Roofing Filter.
+///
+/// Developed by John Ehlers, a two-pole high-pass filter that removes the trend, the cycles
+/// longer than _hp_period_, followed by a [Super Smoother](struct.SuperSmoother.html) that
+/// removes the noise, the cycles shorter than _lp_period_. What is left oscillates around 0 at
+/// the cycles in between, and is a better input for oscillators like the
+/// [stochastic](struct.StochasticOf.html) or the [RSI](struct.RelativeStrengthIndex.html)
+/// than the raw price.
+///
+/// # Formula
+///
+/// α = (cos(√2 * π / _hp_period_) + sin(√2 * π / _hp_period_) - 1) / cos(√2 * π / _hp_period_)
+///
+/// HP<sub>t</sub> = (1 - α / 2)<sup>2</sup> * (p<sub>t</sub> - 2 * p<sub>t-1</sub> + p<sub>t-2</sub>) + 2 * (1 - α) * HP<sub>t-1</sub> - (1 - α)<sup>2</sup> * HP<sub>t-2</sub>
+///
+/// RF<sub>t</sub> = SuperSmoother(_lp_period_) of HP<sub>t</sub>
+///
+/// Where _p<sub>t</sub>_ is the input at a point of time _t_. The high-pass filter is 0 for the
+/// first two inputs.
+///
+/// # Parameters
+///
+/// * _hp_period_ - cutoff period of the high-pass filter (integer greater than _lp_period_).
+///   Default is 48.
+/// * _lp_period_ - cutoff period of the Super Smoother (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RoofingFilter;
+/// use ta::Next;
+///
+/// let mut roof = RoofingFilter::new(48, 10).unwrap();
+///
+/// // A steady trend is removed entirely.
+/// for i in 0..20 {
+///     assert_eq!(roof.next(10.0 + i as f64).round(), 0.0);
+/// }
+/// ```
+///
+#[doc(alias = "RF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RoofingFilter {
+    hp_period: usize,
+    k1: f64,
+    k2: f64,
+    k3: f64,
+    count: usize,
+    // The last two inputs and high-pass outputs.
+    input1: f64,
+    input2: f64,
+    hp1: f64,
+    hp2: f64,
+    smoother: SuperSmoother,
+}
+
+impl RoofingFilter {
+    pub fn new(hp_period: usize, lp_period: usize) -> Result<Self> {
+        if hp_period <= lp_period {
+            return Err(TaError::InvalidParameter);
+        }
+        let angle = SQRT_2 * PI / hp_period as f64;
+        let alpha = (math::cos(angle) + math::sin(angle) - 1.0) / math::cos(angle);
+        Ok(Self {
+            hp_period,
+            k1: (1.0 - alpha / 2.0) * (1.0 - alpha / 2.0),
+            k2: 2.0 * (1.0 - alpha),
+            k3: -(1.0 - alpha) * (1.0 - alpha),
+            count: 0,
+            input1: 0.0,
+            input2: 0.0,
+            hp1: 0.0,
+            hp2: 0.0,
+            smoother: SuperSmoother::new(lp_period)?,
+        })
+    }
+
+    pub fn hp_period(&self) -> usize {
+        self.hp_period
+    }
+
+    pub fn lp_period(&self) -> usize {
+        self.smoother.period()
+    }
+}
+
+impl Lookback for RoofingFilter {
+    fn lookback(&self) -> usize {
+        self.hp_period - 1
+    }
+}
+
+impl Next<f64> for RoofingFilter {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let hp = if self.count < 2 {
+            self.count += 1;
+            0.0
+        } else {
+            self.k1 * (input - 2.0 * self.input1 + self.input2)
+                + self.k2 * self.hp1
+                + self.k3 * self.hp2
+        };
+
+        self.input2 = self.input1;
+        self.input1 = input;
+        self.hp2 = self.hp1;
+        self.hp1 = hp;
+        self.smoother.next(hp)
+    }
+}
+
+impl<T: Close> Next<&T> for RoofingFilter {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RoofingFilter {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.input1 = 0.0;
+        self.input2 = 0.0;
+        self.hp1 = 0.0;
+        self.hp2 = 0.0;
+        self.smoother.reset();
+    }
+}
+
+impl Default for RoofingFilter {
+    fn default() -> Self {
+        Self::new(48, 10).unwrap()
+    }
+}
+
+impl fmt::Display for RoofingFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ROOF({}, {})", self.hp_period, self.smoother.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RoofingFilter);
+
+    #[test]
+    fn test_new() {
+        assert!(RoofingFilter::new(10, 0).is_err());
+        assert!(RoofingFilter::new(10, 10).is_err());
+        assert!(RoofingFilter::new(10, 20).is_err());
+        assert!(RoofingFilter::new(2, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut roof = RoofingFilter::new(20, 5).unwrap();
+
+        let test_data = vec![
+            // input, roofing filter
+            (10.0, 0.0),
+            (11.0, 0.0),
+            (12.0, 0.0),
+            (11.0, -0.527),
+            (13.0, -0.852),
+            (14.0, -0.414),
+            (12.0, -0.853),
+            (11.0, -2.11),
+            (10.0, -2.965),
+            (12.0, -2.502),
+        ];
+
+        for (input, expected) in test_data {
+            assert_eq!(round(roof.next(input)), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut roof = RoofingFilter::new(20, 5).unwrap();
+        for close in [10.0, 11.0, 12.0].iter() {
+            roof.next(&Bar::new().close(*close));
+        }
+        assert_eq!(round(roof.next(&Bar::new().close(11))), -0.527);
+    }
+
+    #[test]
+    fn test_next_constant() {
+        let mut roof = RoofingFilter::new(48, 10).unwrap();
+        for _ in 0..10 {
+            assert_eq!(roof.next(100.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut roof = RoofingFilter::new(20, 5).unwrap();
+        roof.next(10.0);
+        roof.next(11.0);
+        roof.next(12.0);
+        roof.next(11.0);
+
+        roof.reset();
+        assert_eq!(roof.next(10.0), 0.0);
+        assert_eq!(roof.next(11.0), 0.0);
+        assert_eq!(round(roof.next(12.0)), 0.0);
+        assert_eq!(round(roof.next(11.0)), -0.527);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let roof = RoofingFilter::new(48, 10).unwrap();
+        assert_eq!(roof.lookback(), 47);
+    }
+
+    #[test]
+    fn test_default() {
+        let roof = RoofingFilter::default();
+        assert_eq!(roof.hp_period(), 48);
+        assert_eq!(roof.lp_period(), 10);
+    }
+
+    #[test]
+    fn test_display() {
+        let roof = RoofingFilter::new(48, 10).unwrap();
+        assert_eq!(format!("{}", roof), "ROOF(48, 10)");
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset, SetPeriod};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Running moving average (RMA), also known as smoothed moving average (SMMA) or
+/// Wilder's moving average.
+///
+/// It is an exponential moving average with _α_ = 1 / _period_. This is the smoothing
+/// J. Welles Wilder used for RSI, ATR and ADX.
+///
+/// # Formula
+///
+/// RMA<sub>t</sub> = (RMA<sub>t-1</sub> * (_period_ - 1) + p<sub>t</sub>) / _period_
+///
+/// Where:
+///
+/// * _RMA<sub>t</sub>_ - value of the running moving average at a point of time _t_
+/// * _p<sub>t</sub>_ - input value at a point of time _t_
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0)
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RunningMovingAverage;
+/// use ta::Next;
+///
+/// let mut rma = RunningMovingAverage::new(4).unwrap();
+/// assert_eq!(rma.next(2.0), 2.0);
+/// assert_eq!(rma.next(6.0), 3.0);
+/// assert_eq!(rma.next(7.0), 4.0);
+/// ```
+///
+/// # Links
+///
+/// * [Modified moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Modified_moving_average)
+///
+#[doc(alias = "RMA")]
+#[doc(alias = "SMMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RunningMovingAverage {
+    period: usize,
+    k: f64,
+    current: f64,
+    is_new: bool,
+}
+
+impl RunningMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                k: 1.0 / period as f64,
+                current: 0.0,
+                is_new: true,
+            }),
+        }
+    }
+}
+
+impl NewWithPeriod for RunningMovingAverage {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl Period for RunningMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for RunningMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl SetPeriod for RunningMovingAverage {
+    fn set_period(&mut self, period: usize) -> Result<()> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        self.period = period;
+        self.k = 1.0 / period as f64;
+        Ok(())
+    }
+}
+
+impl Next<f64> for RunningMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.is_new {
+            self.is_new = false;
+            self.current = input;
+        } else {
+            self.current = self.k * input + (1.0 - self.k) * self.current;
+        }
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for RunningMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RunningMovingAverage {
+    fn reset(&mut self) {
+        self.current = 0.0;
+        self.is_new = true;
+    }
+}
+
+impl Default for RunningMovingAverage {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for RunningMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(RunningMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(RunningMovingAverage::new(0).is_err());
+        assert!(RunningMovingAverage::new(1).is_ok());
+        assert!(RunningMovingAverage::new_with_period(0).is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rma = RunningMovingAverage::new(4).unwrap();
+
+        assert_eq!(rma.next(2.0), 2.0);
+        assert_eq!(rma.next(6.0), 3.0);
+        assert_eq!(rma.next(7.0), 4.0);
+        assert_eq!(rma.next(0.0), 3.0);
+
+        let mut rma = RunningMovingAverage::new(4).unwrap();
+        let bar1 = Bar::new().close(2);
+        let bar2 = Bar::new().close(6);
+        assert_eq!(rma.next(&bar1), 2.0);
+        assert_eq!(rma.next(&bar2), 3.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rma = RunningMovingAverage::new(5).unwrap();
+
+        assert_eq!(rma.next(4.0), 4.0);
+        rma.next(10.0);
+        rma.next(15.0);
+        assert_ne!(rma.next(4.0), 4.0);
+
+        rma.reset();
+        assert_eq!(rma.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        RunningMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rma = RunningMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", rma), "RMA(7)");
+    }
+}
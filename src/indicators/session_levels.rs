@@ -0,0 +1,308 @@
+use std::fmt;
+
+use crate::calendar::{Calendar, Sessions};
+use crate::errors::Result;
+use crate::{Close, High, Lookback, Low, Next, Open, Reset, Timestamp};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Session open, high and low.
+///
+/// Tracks the open, the high and the low of the current session as the bars arrive, along
+/// with the open, high, low and close of the previous session. Opening range breakouts, pivot
+/// points and gap strategies are built on these levels.
+///
+/// Sessions are intervals of _session_length_ seconds aligned on the Unix epoch shifted by
+/// _session_offset_, or the trading days of a [Calendar](../calendar/struct.Calendar.html) with
+/// [with_calendar](#method.with_calendar), found from the
+/// [timestamp](../trait.Timestamp.html) of the bars, like the sessions of the
+/// [anchored VWAP](struct.AnchoredVwap.html). Inputs without a timestamp never start a new
+/// session.
+///
+/// # Parameters
+///
+/// * _session_length_ - length of a session in seconds (integer greater than 0). Default is
+///   86400, one day.
+/// * _session_offset_ - start of the sessions relative to the Unix epoch in seconds, e.g.
+///   `13 * 3600 + 30 * 60` for sessions starting at 13:30 UTC. Default is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SessionLevels;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |open: f64, high: f64, low: f64, close: f64, timestamp: i64| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .timestamp(timestamp)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut session = SessionLevels::daily();
+/// session.next(&bar(10.0, 11.0, 9.5, 10.5, 0));
+/// let out = session.next(&bar(10.5, 12.0, 10.0, 11.5, 3600));
+/// assert_eq!((out.open, out.high, out.low), (10.0, 12.0, 9.5));
+///
+/// // The next day
+/// let out = session.next(&bar(11.0, 11.2, 10.8, 11.0, 86_400));
+/// assert!(out.new_session);
+/// assert_eq!((out.open, out.high, out.low), (11.0, 11.2, 10.8));
+/// assert_eq!(out.previous.unwrap().close, 11.5);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SessionLevels {
+    sessions: Sessions,
+    current_session: Option<i64>,
+    current: Option<SessionOhlc>,
+    previous: Option<SessionOhlc>,
+}
+
+/// Open, high, low and close of a session.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SessionOhlc {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionLevelsOutput {
+    /// The bar is the first of a session.
+    pub new_session: bool,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    /// The previous session, `None` during the first session.
+    pub previous: Option<SessionOhlc>,
+}
+
+impl SessionLevels {
+    pub fn new(session_length: i64, session_offset: i64) -> Result<Self> {
+        Ok(Self::with_sessions(Sessions::interval(
+            session_length,
+            session_offset,
+        )?))
+    }
+
+    /// Creates a tracker of the trading days of a calendar.
+    pub fn with_calendar(calendar: Calendar) -> Self {
+        Self::with_sessions(Sessions::Calendar(calendar))
+    }
+
+    fn with_sessions(sessions: Sessions) -> Self {
+        Self {
+            sessions,
+            current_session: None,
+            current: None,
+            previous: None,
+        }
+    }
+
+    /// Creates a tracker of sessions starting at midnight UTC every day.
+    pub fn daily() -> Self {
+        Self::new(24 * 60 * 60, 0).unwrap()
+    }
+
+    pub fn sessions(&self) -> &Sessions {
+        &self.sessions
+    }
+
+    /// Returns the start of the session of a timestamp.
+    pub fn session_start(&self, timestamp: i64) -> i64 {
+        self.sessions.session_start(timestamp)
+    }
+}
+
+impl Lookback for SessionLevels {
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+impl<T: Open + High + Low + Close + Timestamp> Next<&T> for SessionLevels {
+    type Output = SessionLevelsOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        if let Some(timestamp) = input.timestamp() {
+            let session = self.sessions.session(timestamp);
+            if self
+                .current_session
+                .is_some_and(|current| current != session)
+            {
+                self.previous = self.current.take();
+            }
+            self.current_session = Some(session);
+        }
+
+        let new_session = self.current.is_none();
+        let current = match self.current {
+            Some(ref mut current) => {
+                current.high = current.high.max(input.high());
+                current.low = current.low.min(input.low());
+                current.close = input.close();
+                *current
+            }
+            None => *self.current.insert(SessionOhlc {
+                open: input.open(),
+                high: input.high(),
+                low: input.low(),
+                close: input.close(),
+            }),
+        };
+
+        SessionLevelsOutput {
+            new_session,
+            open: current.open,
+            high: current.high,
+            low: current.low,
+            previous: self.previous,
+        }
+    }
+}
+
+impl Reset for SessionLevels {
+    fn reset(&mut self) {
+        self.current_session = None;
+        self.current = None;
+        self.previous = None;
+    }
+}
+
+impl Default for SessionLevels {
+    fn default() -> Self {
+        Self::daily()
+    }
+}
+
+impl fmt::Display for SessionLevels {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SESSION({})", self.sessions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64, timestamp: i64) -> Bar {
+        Bar::new()
+            .open(close)
+            .high(high)
+            .low(low)
+            .close(close)
+            .timestamp(timestamp)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(SessionLevels::new(0, 0).is_err());
+        assert!(SessionLevels::new(3600, -1800).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        // Hourly sessions starting at half past.
+        let mut session = SessionLevels::new(3600, 1800).unwrap();
+
+        let out = session.next(&bar(11.0, 9.0, 10.0, 0));
+        assert!(out.new_session);
+        assert_eq!((out.open, out.high, out.low), (10.0, 11.0, 9.0));
+        assert_eq!(out.previous, None);
+
+        let out = session.next(&bar(12.0, 10.0, 11.0, 1200));
+        assert!(!out.new_session);
+        assert_eq!((out.open, out.high, out.low), (10.0, 12.0, 9.0));
+
+        let out = session.next(&bar(13.0, 12.0, 12.5, 1800));
+        assert!(out.new_session);
+        assert_eq!((out.open, out.high, out.low), (12.5, 13.0, 12.0));
+        assert_eq!(
+            out.previous,
+            Some(SessionOhlc {
+                open: 10.0,
+                high: 12.0,
+                low: 9.0,
+                close: 11.0,
+            })
+        );
+
+        let out = session.next(&bar(12.8, 8.0, 8.5, 5000));
+        assert_eq!((out.open, out.high, out.low), (12.5, 13.0, 8.0));
+        assert_eq!(out.previous.unwrap().close, 11.0);
+    }
+
+    #[test]
+    fn test_with_calendar() {
+        let mut session = SessionLevels::with_calendar(Calendar::us_equities());
+        // Monday 2024-01-08 00:00 UTC
+        let monday = 1_704_672_000;
+
+        // Friday 15:00 EST
+        session.next(&bar(11.0, 9.0, 10.0, monday - 3 * 86_400 + 20 * 3600));
+        // The pre-market of Monday starts the session of Monday.
+        let out = session.next(&bar(12.0, 10.0, 11.0, monday + 13 * 3600));
+        assert!(out.new_session);
+        assert_eq!(out.previous.unwrap().close, 10.0);
+        let out = session.next(&bar(13.0, 10.5, 12.0, monday + 15 * 3600));
+        assert!(!out.new_session);
+        assert_eq!(session.session_start(monday), monday + 14 * 3600 + 1800);
+
+        // After the close
+        let out = session.next(&bar(12.0, 11.0, 11.5, monday + 21 * 3600 + 1800));
+        assert!(out.new_session);
+        assert_eq!(format!("{}", session), "SESSION(09:30-16:00 UTC-05:00)");
+    }
+
+    #[test]
+    fn test_session_start() {
+        let session = SessionLevels::new(3600, 1800).unwrap();
+        assert_eq!(session.session_start(0), -1800);
+        assert_eq!(session.session_start(1800), 1800);
+        assert_eq!(session.session_start(5399), 1800);
+    }
+
+    #[test]
+    fn test_next_without_timestamp() {
+        let mut session = SessionLevels::daily();
+        session.next(&bar(11.0, 9.0, 10.0, 0));
+
+        let out = session.next(&Bar::new().open(10).high(15).low(10).close(14));
+        assert!(!out.new_session);
+        assert_eq!((out.open, out.high), (10.0, 15.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut session = SessionLevels::daily();
+        session.next(&bar(11.0, 9.0, 10.0, 0));
+        session.next(&bar(11.0, 9.0, 10.0, 86_400));
+
+        session.reset();
+        let out = session.next(&bar(5.0, 4.0, 4.5, 172_800));
+        assert!(out.new_session);
+        assert_eq!(out.previous, None);
+        assert_eq!(out.open, 4.5);
+    }
+
+    #[test]
+    fn test_default() {
+        SessionLevels::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = SessionLevels::new(3600, 1800).unwrap();
+        assert_eq!(format!("{}", indicator), "SESSION(3600, 1800)");
+    }
+}
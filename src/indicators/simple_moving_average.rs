@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset, SetPeriod};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -64,12 +64,49 @@ impl SimpleMovingAverage {
     }
 }
 
+impl NewWithPeriod for SimpleMovingAverage {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
 impl Period for SimpleMovingAverage {
     fn period(&self) -> usize {
         self.period
     }
 }
 
+impl Lookback for SimpleMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl SetPeriod for SimpleMovingAverage {
+    fn set_period(&mut self, period: usize) -> Result<()> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        if period == self.period {
+            return Ok(());
+        }
+
+        // Keep the most recent inputs, oldest first.
+        let count = self.count.min(period);
+        let mut deque = vec![0.0; period].into_boxed_slice();
+        for (i, value) in deque.iter_mut().take(count).enumerate() {
+            *value = self.deque[(self.index + self.period - count + i) % self.period];
+        }
+
+        self.sum = deque.iter().sum();
+        self.period = period;
+        self.count = count;
+        self.index = if count < period { count } else { 0 };
+        self.deque = deque;
+        Ok(())
+    }
+}
+
 impl Next<f64> for SimpleMovingAverage {
     type Output = f64;
 
@@ -172,6 +209,32 @@ mod tests {
         assert_eq!(sma.next(99.0), 99.0);
     }
 
+    #[test]
+    fn test_set_period() {
+        let mut sma = SimpleMovingAverage::new(4).unwrap();
+        for input in &[1.0, 2.0, 3.0, 4.0, 5.0] {
+            sma.next(*input);
+        }
+        assert!(sma.set_period(0).is_err());
+
+        // Shrinking keeps the last inputs.
+        sma.set_period(2).unwrap();
+        assert_eq!(sma.period(), 2);
+        assert_eq!(sma.next(6.0), 5.5);
+
+        // Growing averages the available inputs until the window is full.
+        sma.set_period(3).unwrap();
+        assert_eq!(sma.next(7.0), 6.0);
+        assert_eq!(sma.next(8.0), 7.0);
+        assert_eq!(sma.next(9.0), 8.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(SimpleMovingAverage::new(1).unwrap().lookback(), 0);
+        assert_eq!(SimpleMovingAverage::new(5).unwrap().lookback(), 4);
+    }
+
     #[test]
     fn test_default() {
         SimpleMovingAverage::default();
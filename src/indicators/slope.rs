@@ -0,0 +1,246 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::math;
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Slope of an indicator.
+///
+/// The change per period of the output of an indicator over the last _bars_ periods, usually of
+/// a moving average, used as a trend filter: the trend is up while the slope is positive.
+///
+/// The slope can also be read as an angle with [angle](#method.angle). Since price and time
+/// have different units, the angle depends on the _price_scale_, the price change that is drawn
+/// as high as one period is wide; 45 degrees means a change of one _price_scale_ per period.
+///
+/// # Formula
+///
+/// slope = (x<sub>t</sub> - x<sub>t-n</sub>) / n
+///
+/// angle = atan(slope / _price_scale_) in degrees
+///
+/// Where:
+///
+/// * x<sub>t</sub> - output of the inner indicator for the current period
+/// * _n_ - _bars_. While there are less than _n_ previous outputs, the oldest output is used.
+///
+/// # Parameters
+///
+/// * _indicator_ - inner indicator producing `f64` values.
+/// * _bars_ - number of periods of the change (integer greater than 0). Default is 1.
+/// * _price_scale_ - price change drawn as high as a period is wide (greater than 0). Default
+///   is 1.0, set with [price_scale](#method.price_scale).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{SimpleMovingAverage, Slope};
+/// use ta::Next;
+///
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+/// let mut slope = Slope::new(sma, 2).unwrap().price_scale(0.5).unwrap();
+///
+/// assert_eq!(slope.next(10.0), 0.0);
+/// assert_eq!(slope.next(12.0), 0.5);
+/// assert_eq!(slope.next(14.0), 1.5);
+/// assert_eq!(slope.angle().round(), 72.0);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Slope<I> {
+    indicator: I,
+    bars: usize,
+    price_scale: f64,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+    slope: f64,
+}
+
+impl<I> Slope<I> {
+    pub fn new(indicator: I, bars: usize) -> Result<Self> {
+        match bars {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                indicator,
+                bars,
+                price_scale: 1.0,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; bars + 1].into_boxed_slice(),
+                slope: 0.0,
+            }),
+        }
+    }
+
+    /// Sets the price change drawn as high as one period is wide, used by
+    /// [angle](#method.angle).
+    pub fn price_scale(mut self, price_scale: f64) -> Result<Self> {
+        if !(price_scale > 0.0 && price_scale.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+        self.price_scale = price_scale;
+        Ok(self)
+    }
+
+    /// Returns the angle of the last slope in degrees, from -90 to 90.
+    pub fn angle(&self) -> f64 {
+        math::atan(self.slope / self.price_scale).to_degrees()
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<I> Period for Slope<I> {
+    fn period(&self) -> usize {
+        self.bars
+    }
+}
+
+impl<I: Lookback> Lookback for Slope<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback() + self.bars
+    }
+}
+
+impl<In, I: Next<In, Output = f64>> Next<In> for Slope<I> {
+    type Output = f64;
+
+    fn next(&mut self, input: In) -> Self::Output {
+        let value = self.indicator.next(input);
+        let len = self.deque.len();
+
+        self.deque[self.index] = value;
+        self.index = if self.index + 1 < len {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < len {
+            self.count += 1;
+        }
+
+        let oldest = if self.count == len {
+            self.deque[self.index]
+        } else {
+            self.deque[0]
+        };
+        self.slope = (value - oldest) / self.bars as f64;
+        self.slope
+    }
+}
+
+impl<I: Reset> Reset for Slope<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.index = 0;
+        self.count = 0;
+        self.slope = 0.0;
+        for i in 0..self.deque.len() {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl<I: Default> Default for Slope<I> {
+    fn default() -> Self {
+        Self::new(I::default(), 1).unwrap()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Slope<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SLOPE({}, {})", self.indicator, self.bars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage, SimpleMovingAverage};
+    use crate::test_helper::*;
+
+    type Sma = SimpleMovingAverage;
+
+    #[test]
+    fn test_new() {
+        assert!(Slope::new(Sma::default(), 0).is_err());
+        assert!(Slope::new(Sma::default(), 1).is_ok());
+        assert!(Slope::new(Sma::default(), 1)
+            .unwrap()
+            .price_scale(0.0)
+            .is_err());
+        assert!(Slope::new(Sma::default(), 1)
+            .unwrap()
+            .price_scale(f64::NAN)
+            .is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        // SMA of period 1 passes the input through.
+        let mut slope = Slope::new(Sma::new(1).unwrap(), 3).unwrap();
+
+        assert_eq!(slope.next(10.0), 0.0);
+        assert_eq!(slope.next(13.0), 1.0);
+        assert_eq!(slope.next(16.0), 2.0);
+        assert_eq!(slope.next(19.0), 3.0);
+        assert_eq!(slope.next(16.0), 1.0);
+        assert_eq!(slope.next(7.0), -3.0);
+    }
+
+    #[test]
+    fn test_angle() {
+        let mut slope = Slope::new(Sma::new(1).unwrap(), 1).unwrap();
+        assert_eq!(slope.angle(), 0.0);
+
+        slope.next(10.0);
+        slope.next(11.0);
+        assert_eq!(round(slope.angle()), 45.0);
+        slope.next(10.0);
+        assert_eq!(round(slope.angle()), -45.0);
+
+        let mut slope = slope.price_scale(2.0).unwrap();
+        slope.next(12.0);
+        assert_eq!(round(slope.angle()), 45.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let ema = ExponentialMovingAverage::new(3).unwrap();
+        let mut slope = Slope::new(ema, 1).unwrap();
+
+        assert_eq!(slope.next(&Bar::new().close(2)), 0.0);
+        assert_eq!(slope.next(&Bar::new().close(5)), 1.5);
+        assert_eq!(slope.next(&Bar::new().close(1)), -1.25);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut slope = Slope::new(Sma::new(1).unwrap(), 1).unwrap();
+        slope.next(10.0);
+        slope.next(20.0);
+
+        slope.reset();
+        assert_eq!(slope.angle(), 0.0);
+        assert_eq!(slope.next(5.0), 0.0);
+        assert_eq!(slope.next(6.0), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Slope::<Sma>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let slope = Slope::new(Sma::new(20).unwrap(), 5).unwrap();
+        assert_eq!(format!("{}", slope), "SLOPE(SMA(20), 5)");
+    }
+}
@@ -2,7 +2,8 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, FastStochastic};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::units::{OutputUnit, Percent};
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -67,6 +68,16 @@ impl Reset for SlowStochastic {
     }
 }
 
+impl Lookback for SlowStochastic {
+    fn lookback(&self) -> usize {
+        self.fast_stochastic.lookback() + self.ema.lookback()
+    }
+}
+
+impl OutputUnit for SlowStochastic {
+    type Unit = Percent;
+}
+
 impl Default for SlowStochastic {
     fn default() -> Self {
         Self::new(14, 3).unwrap()
@@ -0,0 +1,160 @@
+use std::fmt;
+
+use crate::{Close, High, Lookback, Low, Next, Open, Period, Reset, Source, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Feeds a selected value of every bar to an `f64` indicator.
+///
+/// Most indicators use the close price when they are given bars. This wrapper lets any
+/// indicator implementing `Next<f64>` consume another [Source](../enum.Source.html) instead,
+/// e.g. volume or the high - low spread, without reimplementing it.
+///
+/// # Parameters
+///
+/// * _indicator_ - inner indicator implementing `Next<f64>`.
+/// * _source_ - value of the bar to feed. Default is [Source::Close](../enum.Source.html).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{RelativeStrengthIndex, Sourced};
+/// use ta::{DataItem, Next, Source};
+///
+/// // RSI of volume
+/// let rsi = RelativeStrengthIndex::new(3).unwrap();
+/// let mut volume_rsi = Sourced::new(rsi, Source::Volume);
+///
+/// let bar = |volume| {
+///     DataItem::builder()
+///         .open(10.0)
+///         .high(11.0)
+///         .low(9.0)
+///         .close(10.5)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// assert_eq!(volume_rsi.next(&bar(1000.0)), 50.0);
+/// assert_eq!(volume_rsi.next(&bar(2000.0)).round(), 100.0);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Sourced<I> {
+    indicator: I,
+    source: Source,
+}
+
+impl<I> Sourced<I> {
+    pub fn new(indicator: I, source: Source) -> Self {
+        Self { indicator, source }
+    }
+
+    pub fn source(&self) -> Source {
+        self.source
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<I: Period> Period for Sourced<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: Lookback> Lookback for Sourced<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
+
+impl<I: Next<f64>> Next<f64> for Sourced<I> {
+    type Output = I::Output;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.indicator.next(input)
+    }
+}
+
+impl<I: Next<f64>, T: Open + High + Low + Close + Volume> Next<&T> for Sourced<I> {
+    type Output = I::Output;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.indicator.next(self.source.value(input))
+    }
+}
+
+impl<I: Reset> Reset for Sourced<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<I: Default> Default for Sourced<I> {
+    fn default() -> Self {
+        Self::new(I::default(), Source::default())
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Sourced<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}[{}]", self.indicator, self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{RelativeStrengthIndex, SimpleMovingAverage};
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_with_bars() {
+        let sma = SimpleMovingAverage::new(2).unwrap();
+        let mut spread_sma = Sourced::new(sma, Source::Spread);
+
+        assert_eq!(spread_sma.next(&Bar::new().high(4).low(1).close(2)), 3.0);
+        assert_eq!(spread_sma.next(&Bar::new().high(6).low(5).close(6)), 2.0);
+    }
+
+    #[test]
+    fn test_next_matches_inner() {
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+        let mut volume_rsi = Sourced::new(rsi.clone(), Source::Volume);
+
+        for volume in &[100.0, 150.0, 120.0, 90.0, 200.0] {
+            let bar = Bar::new().close(10).volume(*volume);
+            assert_eq!(volume_rsi.next(&bar), rsi.next(*volume));
+        }
+        assert_eq!(volume_rsi.next(300.0), rsi.next(300.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let sma = SimpleMovingAverage::new(2).unwrap();
+        let mut sourced = Sourced::new(sma, Source::High);
+
+        sourced.next(&Bar::new().high(10));
+        sourced.reset();
+        assert_eq!(sourced.next(&Bar::new().high(4)), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        let sourced = Sourced::<RelativeStrengthIndex>::default();
+        assert_eq!(sourced.source(), Source::Close);
+    }
+
+    #[test]
+    fn test_display() {
+        let rsi = RelativeStrengthIndex::new(14).unwrap();
+        let indicator = Sourced::new(rsi, Source::Volume);
+        assert_eq!(format!("{}", indicator), "RSI(14)[volume]");
+    }
+}
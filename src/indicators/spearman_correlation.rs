@@ -0,0 +1,196 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::sorted_window::SortedWindow;
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling Spearman rank correlation.
+///
+/// The correlation of the ranks of two series over the last _period_ periods, from -1 to 1. It
+/// measures how consistently the two series move together, whether their relation is linear
+/// or not, and a single outlier weighs no more than any other value. Fed with the closes of two
+/// assets it detects co-movement; fed with a price and an indicator it checks whether the
+/// indicator follows the price.
+///
+/// The input is a pair of values `(x, y)` of the same period.
+///
+/// # Formula
+///
+/// ρ = Σ (rx<sub>i</sub> - r̄)(ry<sub>i</sub> - r̄) / √(Σ (rx<sub>i</sub> - r̄)<sup>2</sup> Σ (ry<sub>i</sub> - r̄)<sup>2</sup>)
+///
+/// Where:
+///
+/// * _rx<sub>i</sub>_, _ry<sub>i</sub>_ - ranks of x<sub>i</sub> and y<sub>i</sub> in their
+///   windows, from 1 to n. Tied values share the average of their ranks.
+/// * _r̄_ - average rank, (n + 1) / 2
+///
+/// The correlation is 0 while one of the series is constant over the window.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SpearmanCorrelation;
+/// use ta::Next;
+///
+/// let mut spearman = SpearmanCorrelation::new(3).unwrap();
+/// spearman.next((1.0, 1.0));
+/// spearman.next((2.0, 10.0));
+///
+/// // Not linear, but y always rises with x.
+/// assert_eq!(spearman.next((3.0, 100.0)), 1.0);
+/// assert_eq!(spearman.next((4.0, 2.0)), -0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Spearman's rank correlation coefficient, Wikipedia](https://en.wikipedia.org/wiki/Spearman%27s_rank_correlation_coefficient)
+///
+#[doc(alias = "Spearman")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SpearmanCorrelation {
+    period: usize,
+    x: SortedWindow,
+    y: SortedWindow,
+}
+
+impl SpearmanCorrelation {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            x: SortedWindow::new(period),
+            y: SortedWindow::new(period),
+        })
+    }
+}
+
+impl Period for SpearmanCorrelation {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for SpearmanCorrelation {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<(f64, f64)> for SpearmanCorrelation {
+    type Output = f64;
+
+    fn next(&mut self, (x, y): (f64, f64)) -> Self::Output {
+        self.x.push(x);
+        self.y.push(y);
+
+        let mean = (self.x.len() + 1) as f64 / 2.0;
+        let mut covariance = 0.0;
+        let mut x_variance = 0.0;
+        let mut y_variance = 0.0;
+        // Both windows are pushed in lockstep, so their values line up.
+        for (&x, &y) in self.x.values().iter().zip(self.y.values()) {
+            let dx = self.x.rank(x) - mean;
+            let dy = self.y.rank(y) - mean;
+            covariance += dx * dy;
+            x_variance += dx * dx;
+            y_variance += dy * dy;
+        }
+
+        if x_variance == 0.0 || y_variance == 0.0 {
+            return 0.0;
+        }
+        covariance / (x_variance * y_variance).sqrt()
+    }
+}
+
+impl Reset for SpearmanCorrelation {
+    fn reset(&mut self) {
+        self.x.clear();
+        self.y.clear();
+    }
+}
+
+impl Default for SpearmanCorrelation {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for SpearmanCorrelation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SPEARMAN({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SpearmanCorrelation::new(0).is_err());
+        assert!(SpearmanCorrelation::new(1).is_err());
+        assert!(SpearmanCorrelation::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut spearman = SpearmanCorrelation::new(4).unwrap();
+
+        let test_data = vec![
+            // x, y, correlation
+            (1.0, 10.0, 0.0),
+            (2.0, 20.0, 1.0),
+            (3.0, 15.0, 0.5),
+            (4.0, 40.0, 0.8),
+            (5.0, 5.0, -0.4),
+            // ties
+            (5.0, 30.0, -0.211),
+            (2.0, 30.0, -0.5),
+        ];
+
+        for (x, y, expected) in test_data {
+            assert_eq!(round(spearman.next((x, y))), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_constant() {
+        let mut spearman = SpearmanCorrelation::new(3).unwrap();
+        for y in &[1.0, 5.0, 3.0] {
+            assert_eq!(spearman.next((2.0, *y)), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut spearman = SpearmanCorrelation::new(3).unwrap();
+        spearman.next((1.0, 3.0));
+        spearman.next((2.0, 2.0));
+
+        spearman.reset();
+        assert_eq!(spearman.next((1.0, 1.0)), 0.0);
+        assert_eq!(spearman.next((2.0, 2.0)), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        SpearmanCorrelation::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = SpearmanCorrelation::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "SPEARMAN(20)");
+    }
+}
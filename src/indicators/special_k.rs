@@ -0,0 +1,228 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RateOfChange, SimpleMovingAverage};
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pring's Special K (SK).
+///
+/// Developed by Martin Pring, the Special K extends the [KST](struct.KnowSureThing.html) from
+/// four to twelve smoothed [rates of change](struct.RateOfChange.html), from short term to
+/// long term cycles, so a single line reflects the primary trend and the shorter cycles riding
+/// on it. The peaks and troughs of the Special K tend to coincide with those of the price
+/// trend; a crossing of its own moving average, e.g. an
+/// [SMA](struct.SimpleMovingAverage.html), is used as a signal.
+///
+/// # Formula
+///
+/// SK = Σ _weight<sub>i</sub>_ * SMA(_sma_period<sub>i</sub>_) of ROC(_roc_period<sub>i</sub>_), for i from 1 to 12
+///
+/// # Parameters
+///
+/// * _roc_periods_ - periods of the twelve ROCs (integers greater than 0). Default is
+///   [10, 15, 20, 30, 40, 65, 75, 100, 195, 265, 390, 530].
+/// * _sma_periods_ - smoothing periods of the twelve ROCs (integers greater than 0). Default is
+///   [10, 10, 10, 15, 50, 65, 75, 100, 130, 130, 130, 195].
+/// * _weights_ - weights of the twelve smoothed ROCs. Default is
+///   [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0].
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SpecialK;
+/// use ta::Next;
+///
+/// let weights = [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0];
+/// let mut sk = SpecialK::new([1; 12], [1; 12], weights).unwrap();
+/// assert_eq!(sk.next(10.0), 0.0);
+///
+/// // All the ROCs are 10%, and the weights sum to 30.
+/// assert_eq!(sk.next(11.0).round(), 300.0);
+/// ```
+///
+#[doc(alias = "SK")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SpecialK {
+    rocs: Vec<RateOfChange>,
+    smas: Vec<SimpleMovingAverage>,
+    weights: [f64; 12],
+}
+
+impl SpecialK {
+    pub fn new(
+        roc_periods: [usize; 12],
+        sma_periods: [usize; 12],
+        weights: [f64; 12],
+    ) -> Result<Self> {
+        Ok(Self {
+            rocs: roc_periods
+                .iter()
+                .map(|&period| RateOfChange::new(period))
+                .collect::<Result<_>>()?,
+            smas: sma_periods
+                .iter()
+                .map(|&period| SimpleMovingAverage::new(period))
+                .collect::<Result<_>>()?,
+            weights,
+        })
+    }
+
+    pub fn weights(&self) -> [f64; 12] {
+        self.weights
+    }
+}
+
+impl Lookback for SpecialK {
+    fn lookback(&self) -> usize {
+        self.rocs
+            .iter()
+            .zip(self.smas.iter())
+            .map(|(roc, sma)| roc.lookback() + sma.lookback())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Next<f64> for SpecialK {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let mut sk = 0.0;
+        for i in 0..12 {
+            sk += self.weights[i] * self.smas[i].next(self.rocs[i].next(input));
+        }
+        sk
+    }
+}
+
+impl<T: Close> Next<&T> for SpecialK {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SpecialK {
+    fn reset(&mut self) {
+        for roc in self.rocs.iter_mut() {
+            roc.reset();
+        }
+        for sma in self.smas.iter_mut() {
+            sma.reset();
+        }
+    }
+}
+
+impl Default for SpecialK {
+    fn default() -> Self {
+        Self::new(
+            [10, 15, 20, 30, 40, 65, 75, 100, 195, 265, 390, 530],
+            [10, 10, 10, 15, 50, 65, 75, 100, 130, 130, 130, 195],
+            [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0],
+        )
+        .unwrap()
+    }
+}
+
+impl fmt::Display for SpecialK {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SK(")?;
+        for (i, (roc, sma)) in self.rocs.iter().zip(self.smas.iter()).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}/{}", roc.period(), sma.period())?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    const WEIGHTS: [f64; 12] = [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0];
+
+    fn sk() -> SpecialK {
+        SpecialK::new(
+            [1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3],
+            [1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2],
+            WEIGHTS,
+        )
+        .unwrap()
+    }
+
+    test_indicator!(SpecialK);
+
+    #[test]
+    fn test_new() {
+        let mut periods = [1; 12];
+        assert!(SpecialK::new(periods, periods, WEIGHTS).is_ok());
+        periods[11] = 0;
+        assert!(SpecialK::new(periods, [1; 12], WEIGHTS).is_err());
+        assert!(SpecialK::new([1; 12], periods, WEIGHTS).is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sk = sk();
+
+        let test_data = vec![
+            // input, sk
+            (10.0, 0.0),
+            (11.0, 250.0),
+            (12.0, 440.909),
+            (11.0, 66.667),
+            (13.0, 406.061),
+            (14.0, 523.893),
+            (12.0, -90.992),
+        ];
+
+        for (input, expected) in test_data {
+            assert_eq!(round(sk.next(input)), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut sk = sk();
+        sk.next(&Bar::new().close(10));
+        assert_eq!(round(sk.next(&Bar::new().close(11))), 250.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(sk().lookback(), 4);
+        assert_eq!(SpecialK::default().lookback(), 724);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sk = sk();
+        sk.next(10.0);
+        sk.next(11.0);
+
+        sk.reset();
+        assert_eq!(sk.next(20.0), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(SpecialK::default().weights(), WEIGHTS);
+    }
+
+    #[test]
+    fn test_display() {
+        let sk = SpecialK::default();
+        assert_eq!(
+            format!("{}", sk),
+            "SK(10/10, 15/10, 20/10, 30/15, 40/50, 65/65, 75/75, 100/100, 195/130, 265/130, \
+             390/130, 530/195)"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{SimpleMovingAverage, TrendDirection};
+use crate::{Close, High, Lookback, Low, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// SSL Channel.
+///
+/// Two moving averages, one of highs and one of lows, that swap places depending on the
+/// trend: the direction turns up when the close is above the moving average of highs and turns
+/// down when the close is below the moving average of lows. The crossings of the _up_ and
+/// _down_ lines mark trend changes.
+///
+/// # Formula
+///
+/// direction = up if close > MA(high), down if close < MA(low), otherwise unchanged
+///
+/// * _up_ = MA(high) in an uptrend, MA(low) in a downtrend
+/// * _down_ = MA(low) in an uptrend, MA(high) in a downtrend
+///
+/// Where _MA_ is a moving average, [SMA](struct.SimpleMovingAverage.html) by default.
+///
+/// # Parameters
+///
+/// * _period_ - period of the moving averages (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{SimpleMovingAverage as Sma, SslChannel, TrendDirection};
+/// use ta::{DataItem, Next};
+///
+/// let mut ssl = SslChannel::<Sma>::new(3).unwrap();
+/// let bar = DataItem::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let out = ssl.next(&bar);
+/// assert_eq!(out.up, 11.0);
+/// assert_eq!(out.down, 9.0);
+/// assert_eq!(out.direction, TrendDirection::Up);
+/// ```
+///
+/// # Links
+///
+/// * [SSL Channel, TradingView](https://www.tradingview.com/script/xzIoaIJC-SSL-channel/)
+///
+#[doc(alias = "SSL")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SslChannel<MA = SimpleMovingAverage> {
+    high_ma: MA,
+    low_ma: MA,
+    direction: TrendDirection,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SslChannelOutput {
+    pub up: f64,
+    pub down: f64,
+    pub direction: TrendDirection,
+}
+
+impl<MA: NewWithPeriod> SslChannel<MA> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            high_ma: MA::new_with_period(period)?,
+            low_ma: MA::new_with_period(period)?,
+            direction: TrendDirection::Up,
+        })
+    }
+}
+
+impl<MA: Period> Period for SslChannel<MA> {
+    fn period(&self) -> usize {
+        self.high_ma.period()
+    }
+}
+
+impl<MA: Lookback> Lookback for SslChannel<MA> {
+    fn lookback(&self) -> usize {
+        self.high_ma.lookback()
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: High + Low + Close> Next<&T> for SslChannel<MA> {
+    type Output = SslChannelOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let high_ma = self.high_ma.next(input.high());
+        let low_ma = self.low_ma.next(input.low());
+        let close = input.close();
+
+        if close > high_ma {
+            self.direction = TrendDirection::Up;
+        } else if close < low_ma {
+            self.direction = TrendDirection::Down;
+        }
+
+        let (up, down) = match self.direction {
+            TrendDirection::Up => (high_ma, low_ma),
+            TrendDirection::Down => (low_ma, high_ma),
+        };
+
+        SslChannelOutput {
+            up,
+            down,
+            direction: self.direction,
+        }
+    }
+}
+
+impl<MA: Reset> Reset for SslChannel<MA> {
+    fn reset(&mut self) {
+        self.high_ma.reset();
+        self.low_ma.reset();
+        self.direction = TrendDirection::Up;
+    }
+}
+
+impl Default for SslChannel {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl<MA: Period> fmt::Display for SslChannel<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SSL({})", self.high_ma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SslChannel::<Sma>::new(0).is_err());
+        assert!(SslChannel::<Sma>::new(1).is_ok());
+        assert!(SslChannel::<Ema>::new(0).is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ssl = SslChannel::<Sma>::new(2).unwrap();
+
+        let test_data = vec![
+            // high, low, close, up, down, direction
+            (11.0, 9.0, 10.0, 11.0, 9.0, TrendDirection::Up),
+            (13.0, 11.0, 12.5, 12.0, 10.0, TrendDirection::Up),
+            (12.0, 10.0, 10.5, 12.5, 10.5, TrendDirection::Up),
+            (10.0, 8.0, 8.5, 9.0, 11.0, TrendDirection::Down),
+            (10.0, 8.5, 9.5, 8.25, 10.0, TrendDirection::Down),
+            (11.0, 9.5, 10.8, 10.5, 9.0, TrendDirection::Up),
+        ];
+
+        for (high, low, close, up, down, direction) in test_data {
+            let out = ssl.next(&Bar::new().high(high).low(low).close(close));
+            assert_eq!(out.up, up);
+            assert_eq!(out.down, down);
+            assert_eq!(out.direction, direction);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ssl = SslChannel::<Sma>::new(2).unwrap();
+
+        ssl.next(&Bar::new().high(12).low(10).close(10.5));
+        let out = ssl.next(&Bar::new().high(10).low(8).close(8.5));
+        assert_eq!(out.direction, TrendDirection::Down);
+
+        ssl.reset();
+        let out = ssl.next(&Bar::new().high(11).low(9).close(10));
+        assert_eq!(out.direction, TrendDirection::Up);
+        assert_eq!(out.up, 11.0);
+    }
+
+    #[test]
+    fn test_default() {
+        SslChannel::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = SslChannel::<Sma>::new(10).unwrap();
+        assert_eq!(format!("{}", indicator), "SSL(10)");
+    }
+}
@@ -1,13 +1,15 @@
 use std::fmt;
 
-use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::errors::Result;
+use crate::indicators::{Variance, VarianceMode};
+use crate::{Close, Lookback, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Standard deviation (SD).
 ///
-/// Returns the standard deviation of the last n values.
+/// Returns the standard deviation of the last n values, the square root of the
+/// [variance](struct.Variance.html).
 ///
 /// # Formula
 ///
@@ -19,19 +21,28 @@ use serde::{Deserialize, Serialize};
 /// * _N_ - number of probes in observation.
 /// * _x<sub>i</sub>_ - i-th observed value from N elements observation.
 ///
+/// The sample standard deviation divides by N - 1 instead of N.
+///
 /// # Parameters
 ///
-/// * _period_ - number of periods (integer greater than 0)
+/// * _period_ - number of periods (integer greater than 0, greater than 1 for the sample
+///   standard deviation). Default is 9.
+/// * _mode_ - [VarianceMode](enum.VarianceMode.html), population or sample. Default is
+///   population.
 ///
 /// # Example
 ///
 /// ```
-/// use ta::indicators::StandardDeviation;
+/// use ta::indicators::{StandardDeviation, VarianceMode};
 /// use ta::Next;
 ///
 /// let mut sd = StandardDeviation::new(3).unwrap();
 /// assert_eq!(sd.next(10.0), 0.0);
 /// assert_eq!(sd.next(20.0), 5.0);
+///
+/// let mut sd = StandardDeviation::with_mode(3, VarianceMode::Sample).unwrap();
+/// assert_eq!(sd.next(10.0), 0.0);
+/// assert_eq!((sd.next(20.0) * 1000.0).round(), 7071.0);
 /// ```
 ///
 /// # Links
@@ -42,37 +53,38 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct StandardDeviation {
-    period: usize,
-    index: usize,
-    count: usize,
-    m: f64,
-    m2: f64,
-    deque: Box<[f64]>,
+    variance: Variance,
 }
 
 impl StandardDeviation {
     pub fn new(period: usize) -> Result<Self> {
-        match period {
-            0 => Err(TaError::InvalidParameter),
-            _ => Ok(Self {
-                period,
-                index: 0,
-                count: 0,
-                m: 0.0,
-                m2: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
-            }),
-        }
+        Self::with_mode(period, VarianceMode::Population)
+    }
+
+    pub fn with_mode(period: usize, mode: VarianceMode) -> Result<Self> {
+        Ok(Self {
+            variance: Variance::with_mode(period, mode)?,
+        })
+    }
+
+    pub fn mode(&self) -> VarianceMode {
+        self.variance.mode()
     }
 
     pub(super) fn mean(&self) -> f64 {
-        self.m
+        self.variance.mean()
     }
 }
 
 impl Period for StandardDeviation {
     fn period(&self) -> usize {
-        self.period
+        self.variance.period()
+    }
+}
+
+impl Lookback for StandardDeviation {
+    fn lookback(&self) -> usize {
+        self.variance.lookback()
     }
 }
 
@@ -80,33 +92,7 @@ impl Next<f64> for StandardDeviation {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        let old_val = self.deque[self.index];
-        self.deque[self.index] = input;
-
-        self.index = if self.index + 1 < self.period {
-            self.index + 1
-        } else {
-            0
-        };
-
-        if self.count < self.period {
-            self.count += 1;
-            let delta = input - self.m;
-            self.m += delta / self.count as f64;
-            let delta2 = input - self.m;
-            self.m2 += delta * delta2;
-        } else {
-            let delta = input - old_val;
-            let old_m = self.m;
-            self.m += delta / self.period as f64;
-            let delta2 = input - self.m + old_val - old_m;
-            self.m2 += delta * delta2;
-        }
-        if self.m2 < 0.0 {
-            self.m2 = 0.0;
-        }
-
-        (self.m2 / self.count as f64).sqrt()
+        self.variance.next(input).sqrt()
     }
 }
 
@@ -120,13 +106,7 @@ impl<T: Close> Next<&T> for StandardDeviation {
 
 impl Reset for StandardDeviation {
     fn reset(&mut self) {
-        self.index = 0;
-        self.count = 0;
-        self.m = 0.0;
-        self.m2 = 0.0;
-        for i in 0..self.period {
-            self.deque[i] = 0.0;
-        }
+        self.variance.reset();
     }
 }
 
@@ -138,7 +118,10 @@ impl Default for StandardDeviation {
 
 impl fmt::Display for StandardDeviation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SD({})", self.period)
+        match self.mode() {
+            VarianceMode::Population => write!(f, "SD({})", self.period()),
+            VarianceMode::Sample => write!(f, "SD({}, {})", self.period(), self.mode()),
+        }
     }
 }
 
@@ -153,6 +136,7 @@ mod tests {
     fn test_new() {
         assert!(StandardDeviation::new(0).is_err());
         assert!(StandardDeviation::new(1).is_ok());
+        assert!(StandardDeviation::with_mode(1, VarianceMode::Sample).is_err());
     }
 
     #[test]
@@ -166,6 +150,17 @@ mod tests {
         assert_eq!(round(sd.next(100.0)), 35.355);
     }
 
+    #[test]
+    fn test_next_sample() {
+        let mut sd = StandardDeviation::with_mode(4, VarianceMode::Sample).unwrap();
+        assert_eq!(sd.next(10.0), 0.0);
+        assert_eq!(round(sd.next(20.0)), 7.071);
+        assert_eq!(round(sd.next(30.0)), 10.0);
+        assert_eq!(round(sd.next(20.0)), 8.165);
+        assert_eq!(round(sd.next(10.0)), 8.165);
+        assert_eq!(round(sd.next(100.0)), 40.825);
+    }
+
     #[test]
     fn test_next_floating_point_error() {
         let mut sd = StandardDeviation::new(6).unwrap();
@@ -222,5 +217,7 @@ mod tests {
     fn test_display() {
         let sd = StandardDeviation::new(5).unwrap();
         assert_eq!(format!("{}", sd), "SD(5)");
+        let sd = StandardDeviation::with_mode(5, VarianceMode::Sample).unwrap();
+        assert_eq!(format!("{}", sd), "SD(5, sample)");
     }
 }
@@ -0,0 +1,211 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Maximum, Minimum};
+use crate::units::{OutputUnit, Percent};
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic of an indicator.
+///
+/// Applies the %K normalization of the [fast stochastic](struct.FastStochastic.html) to the
+/// output of any indicator: it shows where the current output is relative to the range of its
+/// last _period_ outputs. Wrapping [RSI](struct.RelativeStrengthIndex.html) gives the StochRSI,
+/// wrapping [MFI](struct.MoneyFlowIndex.html) or [CCI](struct.CommodityChannelIndex.html) gives
+/// Stoch-MFI and Stoch-CCI.
+///
+/// The wrapper accepts every input the inner indicator accepts.
+///
+/// # Formula
+///
+/// %K = (x<sub>t</sub> - L<sub>n</sub>) / (H<sub>n</sub> - L<sub>n</sub>) * 100
+///
+/// Where:
+///
+/// * x<sub>t</sub> - output of the inner indicator for the current period
+/// * L<sub>n</sub> - lowest output for the last _n_ periods
+/// * H<sub>n</sub> - highest output for the last _n_ periods
+///
+/// When the highest and the lowest outputs are the same, %K is 50.
+///
+/// # Parameters
+///
+/// * _indicator_ - inner indicator producing `f64` values.
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{RelativeStrengthIndex, StochasticOf};
+/// use ta::Next;
+///
+/// // StochRSI
+/// let rsi = RelativeStrengthIndex::new(3).unwrap();
+/// let mut stoch_rsi = StochasticOf::new(rsi, 3).unwrap();
+///
+/// assert_eq!(stoch_rsi.next(10.0), 50.0);
+/// assert_eq!(stoch_rsi.next(10.5), 100.0);
+/// assert_eq!(stoch_rsi.next(10.0), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic RSI, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:stochrsi)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StochasticOf<I> {
+    indicator: I,
+    period: usize,
+    highest: Maximum,
+    lowest: Minimum,
+}
+
+impl<I> StochasticOf<I> {
+    pub fn new(indicator: I, period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                indicator,
+                period,
+                highest: Maximum::new(period)?,
+                lowest: Minimum::new(period)?,
+            }),
+        }
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<I> Period for StochasticOf<I> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<I: Lookback> Lookback for StochasticOf<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback() + self.period - 1
+    }
+}
+
+impl<I> OutputUnit for StochasticOf<I> {
+    type Unit = Percent;
+}
+
+impl<In, I: Next<In, Output = f64>> Next<In> for StochasticOf<I> {
+    type Output = f64;
+
+    fn next(&mut self, input: In) -> Self::Output {
+        let value = self.indicator.next(input);
+        let highest = self.highest.next(value);
+        let lowest = self.lowest.next(value);
+
+        if highest == lowest {
+            50.0
+        } else {
+            (value - lowest) / (highest - lowest) * 100.0
+        }
+    }
+}
+
+impl<I: Reset> Reset for StochasticOf<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.highest.reset();
+        self.lowest.reset();
+    }
+}
+
+impl<I: Default> Default for StochasticOf<I> {
+    fn default() -> Self {
+        Self::new(I::default(), 14).unwrap()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for StochasticOf<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "STOCH({}, {})", self.indicator, self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        AverageTrueRange, CommodityChannelIndex, FastStochastic, RelativeStrengthIndex,
+        SimpleMovingAverage,
+    };
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(StochasticOf::new(RelativeStrengthIndex::default(), 0).is_err());
+        assert!(StochasticOf::new(RelativeStrengthIndex::default(), 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_matches_fast_stochastic() {
+        // SMA of period 1 passes the input through.
+        let sma = SimpleMovingAverage::new(1).unwrap();
+        let mut stoch = StochasticOf::new(sma, 3).unwrap();
+        let mut fast = FastStochastic::new(3).unwrap();
+
+        for input in &[0.0, 200.0, 100.0, 120.0, 115.0] {
+            assert_eq!(stoch.next(*input), fast.next(*input));
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let cci = CommodityChannelIndex::new(2, 0.015).unwrap();
+        let mut stoch_cci = StochasticOf::new(cci, 2).unwrap();
+
+        // CCI: 0, 66.667, -66.667, 0
+        assert_eq!(stoch_cci.next(&Bar::new().high(2).low(2).close(2)), 50.0);
+        assert_eq!(stoch_cci.next(&Bar::new().high(4).low(4).close(4)), 100.0);
+        assert_eq!(stoch_cci.next(&Bar::new().high(2).low(2).close(2)), 0.0);
+        assert_eq!(stoch_cci.next(&Bar::new().high(2).low(2).close(2)), 100.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let rsi = RelativeStrengthIndex::new(3).unwrap();
+        let mut stoch_rsi = StochasticOf::new(rsi, 3).unwrap();
+
+        stoch_rsi.next(10.0);
+        stoch_rsi.next(10.5);
+
+        stoch_rsi.reset();
+        assert_eq!(stoch_rsi.next(10.0), 50.0);
+        assert_eq!(stoch_rsi.next(10.5), 100.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let rsi = RelativeStrengthIndex::new(14).unwrap();
+        let stoch_rsi = StochasticOf::new(rsi, 14).unwrap();
+        assert_eq!(stoch_rsi.period(), 14);
+        assert_eq!(stoch_rsi.lookback(), 27);
+
+        let atr = AverageTrueRange::new(3).unwrap();
+        let stoch_atr = StochasticOf::new(atr, 5).unwrap();
+        assert_eq!(stoch_atr.lookback(), 7);
+    }
+
+    #[test]
+    fn test_default() {
+        StochasticOf::<RelativeStrengthIndex>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rsi = RelativeStrengthIndex::new(14).unwrap();
+        let indicator = StochasticOf::new(rsi, 10).unwrap();
+        assert_eq!(format!("{}", indicator), "STOCH(RSI(14), 10)");
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{FastStochastic, SimpleMovingAverage};
+use crate::{Close, High, Lookback, Low, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Full stochastic oscillator.
+///
+/// The [fast stochastic](struct.FastStochastic.html) %K smoothed with a moving average, and its
+/// signal line %D. The fast and the [slow](struct.SlowStochastic.html) stochastic oscillators
+/// are special cases: a %K smoothing period of 1 gives the fast %K, 3 gives the slow %K.
+///
+/// # Formula
+///
+/// %K = MA(_k_period_) of fast %K(_period_)
+///
+/// %D = MA(_d_period_) of %K
+///
+/// Where _MA_ is a moving average, [SMA](struct.SimpleMovingAverage.html) by default.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the raw %K (integer greater than 0). Default is 14.
+/// * _k_period_ - smoothing period of %K (integer greater than 0). Default is 3.
+/// * _d_period_ - smoothing period of %D (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{SimpleMovingAverage as Sma, StochasticOscillator};
+/// use ta::Next;
+///
+/// let mut stoch = StochasticOscillator::<Sma>::new(3, 2, 2).unwrap();
+///
+/// let out = stoch.next(10.0);
+/// assert_eq!(out.k, 50.0);
+/// assert_eq!(out.d, 50.0);
+///
+/// let out = stoch.next(20.0);
+/// assert_eq!(out.k, 75.0);
+/// assert_eq!(out.d, 62.5);
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:stochastic_oscillator_fast_slow_and_full)
+///
+#[doc(alias = "STOCH")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StochasticOscillator<MA = SimpleMovingAverage> {
+    fast_stochastic: FastStochastic,
+    k_ma: MA,
+    d_ma: MA,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticOscillatorOutput {
+    pub k: f64,
+    pub d: f64,
+}
+
+impl<MA: NewWithPeriod> StochasticOscillator<MA> {
+    pub fn new(period: usize, k_period: usize, d_period: usize) -> Result<Self> {
+        Ok(Self {
+            fast_stochastic: FastStochastic::new(period)?,
+            k_ma: MA::new_with_period(k_period)?,
+            d_ma: MA::new_with_period(d_period)?,
+        })
+    }
+}
+
+impl<MA: Next<f64, Output = f64>> StochasticOscillator<MA> {
+    fn smooth(&mut self, fast_k: f64) -> StochasticOscillatorOutput {
+        let k = self.k_ma.next(fast_k);
+        let d = self.d_ma.next(k);
+        StochasticOscillatorOutput { k, d }
+    }
+}
+
+impl<MA> Period for StochasticOscillator<MA> {
+    fn period(&self) -> usize {
+        self.fast_stochastic.period()
+    }
+}
+
+impl<MA: Lookback> Lookback for StochasticOscillator<MA> {
+    fn lookback(&self) -> usize {
+        self.fast_stochastic.lookback() + self.k_ma.lookback() + self.d_ma.lookback()
+    }
+}
+
+impl<MA: Next<f64, Output = f64>> Next<f64> for StochasticOscillator<MA> {
+    type Output = StochasticOscillatorOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let fast_k = self.fast_stochastic.next(input);
+        self.smooth(fast_k)
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: High + Low + Close> Next<&T> for StochasticOscillator<MA> {
+    type Output = StochasticOscillatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let fast_k = self.fast_stochastic.next(input);
+        self.smooth(fast_k)
+    }
+}
+
+impl<MA: Reset> Reset for StochasticOscillator<MA> {
+    fn reset(&mut self) {
+        self.fast_stochastic.reset();
+        self.k_ma.reset();
+        self.d_ma.reset();
+    }
+}
+
+impl Default for StochasticOscillator {
+    fn default() -> Self {
+        Self::new(14, 3, 3).unwrap()
+    }
+}
+
+impl<MA: Period> fmt::Display for StochasticOscillator<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STOCH({}, {}, {})",
+            self.fast_stochastic.period(),
+            self.k_ma.period(),
+            self.d_ma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+    use crate::test_helper::*;
+
+    test_indicator!(StochasticOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(StochasticOscillator::<Sma>::new(0, 1, 1).is_err());
+        assert!(StochasticOscillator::<Sma>::new(1, 0, 1).is_err());
+        assert!(StochasticOscillator::<Sma>::new(1, 1, 0).is_err());
+        assert!(StochasticOscillator::<Sma>::new(1, 1, 1).is_ok());
+        assert!(StochasticOscillator::<Ema>::new(14, 3, 3).is_ok());
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let test_data = vec![
+            // high, low , close, k, d
+            (20.0, 20.0, 20.0, 50.0, 50.0),  // fast %K = 50
+            (30.0, 10.0, 25.0, 62.5, 56.25), // fast %K = 75
+            (40.0, 20.0, 16.0, 47.5, 55.0),  // fast %K = 20
+            (35.0, 15.0, 19.0, 25.0, 36.25), // fast %K = 30
+            (30.0, 20.0, 25.0, 35.0, 30.0),  // fast %K = 40
+            (35.0, 25.0, 30.0, 57.5, 46.25), // fast %K = 75
+        ];
+
+        let mut stoch = StochasticOscillator::<Sma>::new(3, 2, 2).unwrap();
+
+        for (high, low, close, k, d) in test_data {
+            let input_bar = Bar::new().high(high).low(low).close(close);
+            let out = stoch.next(&input_bar);
+            assert_eq!(out.k, k);
+            assert_eq!(out.d, d);
+        }
+    }
+
+    #[test]
+    fn test_next_unsmoothed() {
+        let mut stoch = StochasticOscillator::<Sma>::new(3, 1, 1).unwrap();
+        let mut fast = FastStochastic::new(3).unwrap();
+
+        for input in &[0.0, 200.0, 100.0, 120.0, 115.0] {
+            let out = stoch.next(*input);
+            assert_eq!(out.k, fast.next(*input));
+            assert_eq!(out.d, out.k);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stoch = StochasticOscillator::<Sma>::new(3, 2, 2).unwrap();
+        stoch.next(10.0);
+        stoch.next(20.0);
+
+        stoch.reset();
+        let out = stoch.next(30.0);
+        assert_eq!(out.k, 50.0);
+        assert_eq!(out.d, 50.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let stoch = StochasticOscillator::<Sma>::new(14, 3, 5).unwrap();
+        assert_eq!(stoch.lookback(), 19);
+    }
+
+    #[test]
+    fn test_default() {
+        StochasticOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = StochasticOscillator::<Sma>::new(14, 3, 5).unwrap();
+        assert_eq!(format!("{}", indicator), "STOCH(14, 3, 5)");
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+
+use crate::{Close, Lookback, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Up/down streak.
+///
+/// The number of consecutive periods the price has closed up, as a positive number, or down,
+/// as a negative number. An unchanged close ends the streak. Long streaks are used as
+/// mean-reversion triggers, and the RSI of the streak is a component of Connors RSI.
+///
+/// # Formula
+///
+/// * streak<sub>t</sub> = max(streak<sub>t-1</sub>, 0) + 1 if p<sub>t</sub> > p<sub>t-1</sub>
+/// * streak<sub>t</sub> = min(streak<sub>t-1</sub>, 0) - 1 if p<sub>t</sub> < p<sub>t-1</sub>
+/// * streak<sub>t</sub> = 0 otherwise
+///
+/// The first streak is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Streak;
+/// use ta::Next;
+///
+/// let mut streak = Streak::new();
+/// assert_eq!(streak.next(10.0), 0.0);
+/// assert_eq!(streak.next(11.0), 1.0);
+/// assert_eq!(streak.next(12.0), 2.0);
+/// assert_eq!(streak.next(11.5), -1.0);
+/// assert_eq!(streak.next(11.5), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Connors RSI, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:connorsrsi)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Streak {
+    streak: f64,
+    prev_close: Option<f64>,
+}
+
+impl Streak {
+    pub fn new() -> Self {
+        Self {
+            streak: 0.0,
+            prev_close: None,
+        }
+    }
+}
+
+impl Lookback for Streak {
+    fn lookback(&self) -> usize {
+        1
+    }
+}
+
+impl Next<f64> for Streak {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.streak = match self.prev_close {
+            Some(prev) if input > prev => self.streak.max(0.0) + 1.0,
+            Some(prev) if input < prev => self.streak.min(0.0) - 1.0,
+            _ => 0.0,
+        };
+        self.prev_close = Some(input);
+        self.streak
+    }
+}
+
+impl<T: Close> Next<&T> for Streak {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Streak {
+    fn reset(&mut self) {
+        self.streak = 0.0;
+        self.prev_close = None;
+    }
+}
+
+impl Default for Streak {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Streak {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "STREAK")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Streak);
+
+    #[test]
+    fn test_next() {
+        let mut streak = Streak::new();
+
+        let test_data = vec![
+            (10.0, 0.0),
+            (9.0, -1.0),
+            (8.0, -2.0),
+            (7.5, -3.0),
+            (8.0, 1.0),
+            (8.5, 2.0),
+            (8.5, 0.0),
+            (8.4, -1.0),
+            (8.6, 1.0),
+        ];
+
+        for (input, expected) in test_data {
+            assert_eq!(streak.next(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut streak = Streak::new();
+        assert_eq!(streak.next(&Bar::new().close(5)), 0.0);
+        assert_eq!(streak.next(&Bar::new().close(6)), 1.0);
+        assert_eq!(streak.next(&Bar::new().close(4)), -1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut streak = Streak::new();
+        streak.next(10.0);
+        streak.next(11.0);
+
+        streak.reset();
+        assert_eq!(streak.next(12.0), 0.0);
+        assert_eq!(streak.next(13.0), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Streak::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Streak::new()), "STREAK");
+    }
+}
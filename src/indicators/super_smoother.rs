@@ -0,0 +1,234 @@
+use std::f64::consts::{PI, SQRT_2};
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::math;
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ehlers Super Smoother.
+///
+/// Developed by John Ehlers, a two-pole Butterworth low-pass filter applied to the average of
+/// the last two inputs. It removes the cycles shorter than _period_ much more sharply than an
+/// [EMA](struct.ExponentialMovingAverage.html) of similar lag, so it is smoother for the same
+/// lag. It implements [NewWithPeriod](../trait.NewWithPeriod.html), so it can smooth
+/// composite indicators like the [ATR](struct.AverageTrueRange.html) or the
+/// [RSI](struct.RelativeStrengthIndex.html).
+///
+/// # Formula
+///
+/// a = exp(-√2 * π / _period_)
+///
+/// c<sub>2</sub> = 2 * a * cos(√2 * π / _period_), c<sub>3</sub> = -a<sup>2</sup>,
+/// c<sub>1</sub> = 1 - c<sub>2</sub> - c<sub>3</sub>
+///
+/// SS<sub>t</sub> = c<sub>1</sub> * (p<sub>t</sub> + p<sub>t-1</sub>) / 2 + c<sub>2</sub> * SS<sub>t-1</sub> + c<sub>3</sub> * SS<sub>t-2</sub>
+///
+/// Where _p<sub>t</sub>_ is the input at a point of time _t_. The first two values are the
+/// inputs.
+///
+/// # Parameters
+///
+/// * _period_ - cutoff period of the filter (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SuperSmoother;
+/// use ta::Next;
+///
+/// let mut ss = SuperSmoother::new(10).unwrap();
+/// assert_eq!(ss.next(10.0), 10.0);
+/// assert_eq!(ss.next(11.0), 11.0);
+/// assert_eq!((ss.next(12.0) * 1000.0).round(), 11538.0);
+/// ```
+///
+#[doc(alias = "SS")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SuperSmoother {
+    period: usize,
+    c1: f64,
+    c2: f64,
+    c3: f64,
+    count: usize,
+    prev_input: f64,
+    // The last two outputs.
+    filt1: f64,
+    filt2: f64,
+}
+
+impl SuperSmoother {
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        let angle = SQRT_2 * PI / period as f64;
+        let a = math::exp(-angle);
+        let c2 = 2.0 * a * math::cos(angle);
+        let c3 = -a * a;
+        Ok(Self {
+            period,
+            c1: 1.0 - c2 - c3,
+            c2,
+            c3,
+            count: 0,
+            prev_input: 0.0,
+            filt1: 0.0,
+            filt2: 0.0,
+        })
+    }
+}
+
+impl NewWithPeriod for SuperSmoother {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl Period for SuperSmoother {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for SuperSmoother {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for SuperSmoother {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let filt = if self.count < 2 {
+            self.count += 1;
+            input
+        } else {
+            self.c1 * (input + self.prev_input) / 2.0 + self.c2 * self.filt1 + self.c3 * self.filt2
+        };
+
+        self.prev_input = input;
+        self.filt2 = self.filt1;
+        self.filt1 = filt;
+        filt
+    }
+}
+
+impl<T: Close> Next<&T> for SuperSmoother {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SuperSmoother {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.prev_input = 0.0;
+        self.filt1 = 0.0;
+        self.filt2 = 0.0;
+    }
+}
+
+impl Default for SuperSmoother {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for SuperSmoother {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SS({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{AverageTrueRange, RelativeStrengthIndex};
+    use crate::test_helper::*;
+
+    test_indicator!(SuperSmoother);
+
+    #[test]
+    fn test_new() {
+        assert!(SuperSmoother::new(0).is_err());
+        assert!(SuperSmoother::new(1).is_ok());
+        assert!(SuperSmoother::new_with_period(0).is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ss = SuperSmoother::new(3).unwrap();
+
+        assert_eq!(ss.next(10.0), 10.0);
+        assert_eq!(ss.next(11.0), 11.0);
+        assert_eq!(round(ss.next(12.0)), 11.557);
+        assert_eq!(round(ss.next(11.0)), 11.528);
+        assert_eq!(round(ss.next(13.0)), 12.004);
+        assert_eq!(round(ss.next(14.0)), 13.541);
+        assert_eq!(round(ss.next(12.0)), 13.074);
+
+        let mut ss = SuperSmoother::new(3).unwrap();
+        ss.next(&Bar::new().close(10));
+        ss.next(&Bar::new().close(11));
+        assert_eq!(round(ss.next(&Bar::new().close(12))), 11.557);
+    }
+
+    #[test]
+    fn test_next_step() {
+        let mut ss = SuperSmoother::new(4).unwrap();
+        for _ in 0..3 {
+            assert_eq!(round(ss.next(10.0)), 10.0);
+        }
+
+        // A small overshoot, then it settles on the new level.
+        let expected = [14.08, 19.353, 20.453, 20.203, 20.01, 19.981, 19.993];
+        for value in expected.iter() {
+            assert_eq!(round(ss.next(20.0)), *value);
+        }
+    }
+
+    #[test]
+    fn test_smoothing() {
+        let mut atr = AverageTrueRange::<SuperSmoother>::with_ma(10).unwrap();
+        assert_eq!(atr.next(&Bar::new().high(10).low(8).close(9)), 2.0);
+        assert_eq!(format!("{}", atr), "ATR(10)");
+
+        let mut rsi = RelativeStrengthIndex::<SuperSmoother>::with_ma(10).unwrap();
+        assert_eq!(rsi.next(10.0), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ss = SuperSmoother::new(3).unwrap();
+        ss.next(10.0);
+        ss.next(11.0);
+        ss.next(12.0);
+
+        ss.reset();
+        assert_eq!(ss.next(5.0), 5.0);
+        assert_eq!(ss.next(6.0), 6.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let ss = SuperSmoother::new(10).unwrap();
+        assert_eq!(ss.lookback(), 9);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(SuperSmoother::default().period(), 10);
+    }
+
+    #[test]
+    fn test_display() {
+        let ss = SuperSmoother::new(10).unwrap();
+        assert_eq!(format!("{}", ss), "SS(10)");
+    }
+}
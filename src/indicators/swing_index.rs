@@ -0,0 +1,198 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, High, Lookback, Low, Next, Open, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Swing Index (SI).
+///
+/// Developed by J. Welles Wilder, the swing index compares the open, high, low and close of a
+/// bar with the previous bar to measure the strength of the swing from one bar to the next. It
+/// is positive for an up swing and negative for a down swing. It is mostly used accumulated
+/// into the [Accumulative Swing Index](struct.AccumulativeSwingIndex.html).
+///
+/// The first bar has no previous bar, so it only sets the reference prices and returns 0.
+///
+/// # Formula
+///
+/// SI = 50 * (C - C<sub>p</sub> + 0.5 * (C - O) + 0.25 * (C<sub>p</sub> - O<sub>p</sub>)) / R * K / T
+///
+/// Where:
+///
+/// * _O_, _H_, _L_, _C_ - prices of the current bar
+/// * _O<sub>p</sub>_, _C<sub>p</sub>_ - open and close of the previous bar
+/// * _K_ - max(|H - C<sub>p</sub>|, |L - C<sub>p</sub>|)
+/// * _T_ - _limit_move_
+/// * _R_ - depends on the largest of |H - C<sub>p</sub>|, |L - C<sub>p</sub>| and H - L:
+///   * |H - C<sub>p</sub>| - 0.5 * |L - C<sub>p</sub>| + 0.25 * |C<sub>p</sub> - O<sub>p</sub>| if it is |H - C<sub>p</sub>|
+///   * |L - C<sub>p</sub>| - 0.5 * |H - C<sub>p</sub>| + 0.25 * |C<sub>p</sub> - O<sub>p</sub>| if it is |L - C<sub>p</sub>|
+///   * H - L + 0.25 * |C<sub>p</sub> - O<sub>p</sub>| if it is H - L
+///
+/// SI is 0 when _R_ is 0.
+///
+/// # Parameters
+///
+/// * _limit_move_ - maximum price change allowed in one bar by the exchange (greater than 0).
+///   It only scales the index, so markets without a limit can use any fixed value. Default is 1.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SwingIndex;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |open: f64, high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut si = SwingIndex::new(3.0).unwrap();
+/// assert_eq!(si.next(&bar(10.0, 11.0, 9.5, 10.5)), 0.0);
+/// assert_eq!(si.next(&bar(10.5, 12.0, 10.4, 11.8)).round(), 30.0);
+/// ```
+///
+#[doc(alias = "SI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SwingIndex {
+    limit_move: f64,
+    // Open and close of the previous bar.
+    prev: Option<(f64, f64)>,
+}
+
+impl SwingIndex {
+    pub fn new(limit_move: f64) -> Result<Self> {
+        if limit_move <= 0.0 || !limit_move.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            limit_move,
+            prev: None,
+        })
+    }
+
+    pub fn limit_move(&self) -> f64 {
+        self.limit_move
+    }
+}
+
+impl Lookback for SwingIndex {
+    fn lookback(&self) -> usize {
+        1
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for SwingIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (open, high, low, close) = (input.open(), input.high(), input.low(), input.close());
+        let (prev_open, prev_close) = match self.prev.replace((open, close)) {
+            Some(prev) => prev,
+            None => return 0.0,
+        };
+
+        let high_move = (high - prev_close).abs();
+        let low_move = (low - prev_close).abs();
+        let range = high - low;
+        let prev_body = (prev_close - prev_open).abs();
+        let r = if high_move >= low_move && high_move >= range {
+            high_move - 0.5 * low_move + 0.25 * prev_body
+        } else if low_move >= range {
+            low_move - 0.5 * high_move + 0.25 * prev_body
+        } else {
+            range + 0.25 * prev_body
+        };
+        if r == 0.0 {
+            return 0.0;
+        }
+
+        let k = high_move.max(low_move);
+        let n = close - prev_close + 0.5 * (close - open) + 0.25 * (prev_close - prev_open);
+        50.0 * n / r * k / self.limit_move
+    }
+}
+
+impl Reset for SwingIndex {
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+impl Default for SwingIndex {
+    fn default() -> Self {
+        Self::new(1.0).unwrap()
+    }
+}
+
+impl fmt::Display for SwingIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SI({})", self.limit_move)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(SwingIndex::new(0.0).is_err());
+        assert!(SwingIndex::new(f64::INFINITY).is_err());
+        assert!(SwingIndex::new(0.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut si = SwingIndex::new(3.0).unwrap();
+
+        assert_eq!(si.next(&bar(10.0, 11.0, 9.5, 10.5)), 0.0);
+        assert_eq!(round(si.next(&bar(10.5, 12.0, 10.4, 11.8))), 30.072);
+        assert_eq!(round(si.next(&bar(11.9, 12.2, 11.0, 11.2))), -5.464);
+        assert_eq!(round(si.next(&bar(11.0, 11.5, 10.0, 10.2))), -18.806);
+        assert_eq!(round(si.next(&bar(10.2, 10.8, 10.1, 10.6))), 4.444);
+        // gap up, the high move is the largest
+        assert_eq!(round(si.next(&bar(11.5, 12.0, 11.4, 11.9))), 33.939);
+        // gap down, the low move is the largest
+        assert_eq!(round(si.next(&bar(10.5, 10.6, 10.0, 10.2))), -41.049);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut si = SwingIndex::new(3.0).unwrap();
+        si.next(&bar(10.0, 10.0, 10.0, 10.0));
+        assert_eq!(si.next(&bar(10.0, 10.0, 10.0, 10.0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut si = SwingIndex::new(3.0).unwrap();
+        si.next(&bar(10.0, 11.0, 9.5, 10.5));
+
+        si.reset();
+        assert_eq!(si.next(&bar(10.5, 12.0, 10.4, 11.8)), 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(SwingIndex::default().limit_move(), 1.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let si = SwingIndex::new(0.5).unwrap();
+        assert_eq!(format!("{}", si), "SI(0.5)");
+    }
+}
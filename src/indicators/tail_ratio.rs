@@ -0,0 +1,167 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::sorted_window::SortedWindow;
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling tail ratio.
+///
+/// Compares the right tail of the distribution of returns to its left tail over the last
+/// _period_ periods: the 95th percentile of the returns divided by the absolute 5th percentile.
+/// A ratio above 1 means the largest gains outsize the largest losses, e.g. a ratio of 0.8 means
+/// the losses in the tails are 25% larger than the gains.
+///
+/// The inputs are returns, e.g. the daily percentage changes of a strategy.
+///
+/// # Formula
+///
+/// tail ratio = |P<sub>95</sub>| / |P<sub>5</sub>|
+///
+/// Where the percentiles of the returns are interpolated linearly, like the
+/// [percentile bands](struct.PercentileBands.html). The ratio is 1 when both percentiles are 0
+/// and infinite when only the 5th percentile is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TailRatio;
+/// use ta::Next;
+///
+/// let mut tail_ratio = TailRatio::new(5).unwrap();
+/// for r in &[0.02, -0.01, 0.03, -0.04] {
+///     tail_ratio.next(*r);
+/// }
+/// // P95 = 0.028, P5 = -0.034
+/// assert_eq!((tail_ratio.next(0.01) * 1000.0).round(), 824.0);
+/// ```
+///
+/// # Links
+///
+/// * [Tail ratio, pyfolio](https://github.com/quantopian/empyrical/blob/master/empyrical/stats.py)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TailRatio {
+    period: usize,
+    window: SortedWindow,
+}
+
+impl TailRatio {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                window: SortedWindow::new(period),
+            }),
+        }
+    }
+}
+
+impl Period for TailRatio {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for TailRatio {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for TailRatio {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.window.push(input);
+
+        let gains = self.window.quantile(0.95).abs();
+        let losses = self.window.quantile(0.05).abs();
+        if losses > 0.0 {
+            gains / losses
+        } else if gains > 0.0 {
+            f64::INFINITY
+        } else {
+            1.0
+        }
+    }
+}
+
+impl Reset for TailRatio {
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+impl Default for TailRatio {
+    fn default() -> Self {
+        Self::new(252).unwrap()
+    }
+}
+
+impl fmt::Display for TailRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TAIL({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(TailRatio::new(0).is_err());
+        assert!(TailRatio::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut tail_ratio = TailRatio::new(5).unwrap();
+
+        assert_eq!(tail_ratio.next(0.02), 1.0);
+        assert_eq!(round(tail_ratio.next(-0.01)), 2.176);
+        assert_eq!(round(tail_ratio.next(0.03)), 4.143);
+        assert_eq!(round(tail_ratio.next(-0.04)), 0.803);
+        assert_eq!(round(tail_ratio.next(0.01)), 0.824);
+        // 0.02 left the window
+        assert_eq!(round(tail_ratio.next(0.05)), 1.353);
+    }
+
+    #[test]
+    fn test_next_without_losses() {
+        let mut tail_ratio = TailRatio::new(3).unwrap();
+        assert_eq!(tail_ratio.next(0.0), 1.0);
+        assert_eq!(tail_ratio.next(0.0), 1.0);
+        assert_eq!(tail_ratio.next(0.01), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tail_ratio = TailRatio::new(5).unwrap();
+        tail_ratio.next(0.02);
+        tail_ratio.next(-0.01);
+
+        tail_ratio.reset();
+        assert_eq!(tail_ratio.next(-0.01), 1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(TailRatio::default().period(), 252);
+    }
+
+    #[test]
+    fn test_display() {
+        let tail_ratio = TailRatio::new(20).unwrap();
+        assert_eq!(format!("{}", tail_ratio), "TAIL(20)");
+    }
+}
@@ -1,7 +1,8 @@
 use std::fmt;
 
 use crate::helpers::max3;
-use crate::{Close, High, Low, Next, Reset};
+use crate::units::{OutputUnit, Price};
+use crate::{Close, High, Lookback, Low, Next, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -61,6 +62,16 @@ impl TrueRange {
     }
 }
 
+impl Lookback for TrueRange {
+    fn lookback(&self) -> usize {
+        1
+    }
+}
+
+impl OutputUnit for TrueRange {
+    type Unit = Price;
+}
+
 impl Default for TrueRange {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,231 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// True Strength Index (TSI).
+///
+/// Developed by William Blau, the TSI is a momentum oscillator from -100 to 100: the price
+/// change smoothed twice by [EMAs](struct.ExponentialMovingAverage.html), relative to the
+/// absolute price change smoothed the same way. The double smoothing removes most of the noise
+/// while lagging less than a single long average. The signal line is an EMA of the TSI;
+/// crossings of the two are used as signals.
+///
+/// # Formula
+///
+/// m = close - close<sub>t-1</sub>
+///
+/// TSI = EMA(_short_period_) of EMA(_long_period_) of m / EMA(_short_period_) of EMA(_long_period_) of |m| * 100
+///
+/// signal = EMA(_signal_period_) of TSI
+///
+/// The output of the first period, without a price change, is 0. The TSI is 0 while the price
+/// has not changed.
+///
+/// # Parameters
+///
+/// * _long_period_ - period of the first smoothing (integer greater than 0). Default is 25.
+/// * _short_period_ - period of the second smoothing (integer greater than 0). Default is 13.
+/// * _signal_period_ - period of the signal EMA (integer greater than 0). Default is 7.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TrueStrengthIndex;
+/// use ta::Next;
+///
+/// let mut tsi = TrueStrengthIndex::new(3, 2, 2).unwrap();
+/// assert_eq!(tsi.next(10.0).tsi, 0.0);
+/// assert_eq!(tsi.next(11.0).tsi, 100.0);
+/// assert_eq!(tsi.next(10.5).tsi.round(), 60.0);
+/// ```
+///
+/// # Links
+///
+/// * [True Strength Index, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:true_strength_index)
+///
+#[doc(alias = "TSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TrueStrengthIndex {
+    long_ema: Ema,
+    short_ema: Ema,
+    abs_long_ema: Ema,
+    abs_short_ema: Ema,
+    signal_ema: Ema,
+    prev_close: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrueStrengthIndexOutput {
+    pub tsi: f64,
+    pub signal: f64,
+}
+
+impl TrueStrengthIndex {
+    pub fn new(long_period: usize, short_period: usize, signal_period: usize) -> Result<Self> {
+        Ok(Self {
+            long_ema: Ema::new(long_period)?,
+            short_ema: Ema::new(short_period)?,
+            abs_long_ema: Ema::new(long_period)?,
+            abs_short_ema: Ema::new(short_period)?,
+            signal_ema: Ema::new(signal_period)?,
+            prev_close: None,
+        })
+    }
+}
+
+impl Period for TrueStrengthIndex {
+    fn period(&self) -> usize {
+        self.long_ema.period()
+    }
+}
+
+impl Lookback for TrueStrengthIndex {
+    fn lookback(&self) -> usize {
+        1 + self.long_ema.lookback() + self.short_ema.lookback() + self.signal_ema.lookback()
+    }
+}
+
+impl Next<f64> for TrueStrengthIndex {
+    type Output = TrueStrengthIndexOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let momentum = match self.prev_close.replace(input) {
+            Some(prev_close) => input - prev_close,
+            None => {
+                return TrueStrengthIndexOutput {
+                    tsi: 0.0,
+                    signal: 0.0,
+                }
+            }
+        };
+
+        let smoothed = self.short_ema.next(self.long_ema.next(momentum));
+        let abs_smoothed = self
+            .abs_short_ema
+            .next(self.abs_long_ema.next(momentum.abs()));
+        let tsi = if abs_smoothed == 0.0 {
+            0.0
+        } else {
+            smoothed / abs_smoothed * 100.0
+        };
+        let signal = self.signal_ema.next(tsi);
+
+        TrueStrengthIndexOutput { tsi, signal }
+    }
+}
+
+impl<T: Close> Next<&T> for TrueStrengthIndex {
+    type Output = TrueStrengthIndexOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for TrueStrengthIndex {
+    fn reset(&mut self) {
+        self.long_ema.reset();
+        self.short_ema.reset();
+        self.abs_long_ema.reset();
+        self.abs_short_ema.reset();
+        self.signal_ema.reset();
+        self.prev_close = None;
+    }
+}
+
+impl Default for TrueStrengthIndex {
+    fn default() -> Self {
+        Self::new(25, 13, 7).unwrap()
+    }
+}
+
+impl fmt::Display for TrueStrengthIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "TSI({}, {}, {})",
+            self.long_ema.period(),
+            self.short_ema.period(),
+            self.signal_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(TrueStrengthIndex::new(0, 13, 7).is_err());
+        assert!(TrueStrengthIndex::new(25, 0, 7).is_err());
+        assert!(TrueStrengthIndex::new(25, 13, 0).is_err());
+        assert!(TrueStrengthIndex::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut tsi = TrueStrengthIndex::new(3, 2, 2).unwrap();
+
+        let test_data = vec![
+            // input, tsi, signal
+            (10.0, 0.0, 0.0),
+            (11.0, 100.0, 100.0),
+            (10.5, 60.0, 73.333),
+            (12.0, 72.973, 73.093),
+            (13.0, 83.26, 79.871),
+            (12.5, 50.221, 60.105),
+        ];
+
+        for (input, expected_tsi, expected_signal) in test_data {
+            let out = tsi.next(input);
+            assert_eq!(round(out.tsi), expected_tsi);
+            assert_eq!(round(out.signal), expected_signal);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut tsi = TrueStrengthIndex::new(3, 2, 2).unwrap();
+        tsi.next(&Bar::new().close(10));
+        assert_eq!(tsi.next(&Bar::new().close(9)).tsi, -100.0);
+        assert_eq!(tsi.next(&Bar::new().close(9)).tsi, -100.0);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut tsi = TrueStrengthIndex::new(3, 2, 2).unwrap();
+        tsi.next(10.0);
+        assert_eq!(tsi.next(10.0).tsi, 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tsi = TrueStrengthIndex::new(3, 2, 2).unwrap();
+        tsi.next(10.0);
+        tsi.next(11.0);
+
+        tsi.reset();
+        assert_eq!(tsi.next(11.0).tsi, 0.0);
+        assert_eq!(tsi.next(10.0).tsi, -100.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let tsi = TrueStrengthIndex::default();
+        assert_eq!(tsi.period(), 25);
+        assert_eq!(tsi.lookback(), 43);
+    }
+
+    #[test]
+    fn test_display() {
+        let tsi = TrueStrengthIndex::default();
+        assert_eq!(format!("{}", tsi), "TSI(25, 13, 7)");
+    }
+}
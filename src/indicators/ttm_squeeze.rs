@@ -0,0 +1,225 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{BollingerBands, KeltnerChannel, Maximum, Minimum};
+use crate::rolling_regression::RollingRegression;
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// TTM Squeeze.
+///
+/// Developed by John Carter, the squeeze compares the volatility of the close, measured by
+/// [Bollinger Bands](struct.BollingerBands.html), with the range of the bars, measured by a
+/// [Keltner Channel](struct.KeltnerChannel.html). When the Bollinger Bands are inside the
+/// Keltner Channel, volatility is compressed and the squeeze is on; a breakout often follows when
+/// it turns off. The momentum histogram gives the likely direction of the breakout.
+///
+/// # Formula
+///
+/// squeeze = BB lower > KC lower and BB upper < KC upper
+///
+/// delta = close - ((highest high + lowest low) / 2 + SMA(close)) / 2
+///
+/// momentum = value of the linear regression of the last _period_ deltas at the current period
+///
+/// Where the highest high, the lowest low and the SMA are taken over the last _period_ periods.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _bb_multiplier_ - number of standard deviations of the Bollinger Bands (greater than 0).
+///   Default is 2.0.
+/// * _kc_multiplier_ - number of ATRs of the Keltner Channel (greater than 0). Default is 1.5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TtmSqueeze;
+/// use ta::{DataItem, Next};
+///
+/// let mut squeeze = TtmSqueeze::new(20, 2.0, 1.5).unwrap();
+/// let bar = DataItem::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.0)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let out = squeeze.next(&bar);
+/// assert!(out.squeeze);
+/// assert_eq!(out.momentum, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [TTM Squeeze, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:ttm_squeeze)
+///
+#[doc(alias = "Squeeze")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TtmSqueeze {
+    period: usize,
+    bb: BollingerBands,
+    kc: KeltnerChannel,
+    highest: Maximum,
+    lowest: Minimum,
+    regression: RollingRegression,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TtmSqueezeOutput {
+    pub squeeze: bool,
+    pub momentum: f64,
+}
+
+impl TtmSqueeze {
+    pub fn new(period: usize, bb_multiplier: f64, kc_multiplier: f64) -> Result<Self> {
+        if !(bb_multiplier > 0.0
+            && bb_multiplier.is_finite()
+            && kc_multiplier > 0.0
+            && kc_multiplier.is_finite())
+        {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            bb: BollingerBands::new(period, bb_multiplier)?,
+            kc: KeltnerChannel::new(period, kc_multiplier)?,
+            highest: Maximum::new(period)?,
+            lowest: Minimum::new(period)?,
+            regression: RollingRegression::new(period),
+        })
+    }
+
+    pub fn bb_multiplier(&self) -> f64 {
+        self.bb.multiplier()
+    }
+
+    pub fn kc_multiplier(&self) -> f64 {
+        self.kc.multiplier()
+    }
+}
+
+impl Period for TtmSqueeze {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for TtmSqueeze {
+    fn lookback(&self) -> usize {
+        self.bb.lookback().max(self.kc.lookback()) + self.period - 1
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for TtmSqueeze {
+    type Output = TtmSqueezeOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let bb = self.bb.next(input.close());
+        let kc = self.kc.next(input);
+        let highest = self.highest.next(input.high());
+        let lowest = self.lowest.next(input.low());
+
+        let delta = input.close() - ((highest + lowest) / 2.0 + bb.average) / 2.0;
+        self.regression.push(delta);
+
+        TtmSqueezeOutput {
+            squeeze: bb.lower > kc.lower && bb.upper < kc.upper,
+            momentum: self.regression.forecast(0.0),
+        }
+    }
+}
+
+impl Reset for TtmSqueeze {
+    fn reset(&mut self) {
+        self.bb.reset();
+        self.kc.reset();
+        self.highest.reset();
+        self.lowest.reset();
+        self.regression.clear();
+    }
+}
+
+impl Default for TtmSqueeze {
+    fn default() -> Self {
+        Self::new(20, 2.0, 1.5).unwrap()
+    }
+}
+
+impl fmt::Display for TtmSqueeze {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SQUEEZE({}, {}, {})",
+            self.period,
+            self.bb.multiplier(),
+            self.kc.multiplier()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(TtmSqueeze::new(0, 2.0, 1.5).is_err());
+        assert!(TtmSqueeze::new(20, 0.0, 1.5).is_err());
+        assert!(TtmSqueeze::new(20, 2.0, 0.0).is_err());
+        assert!(TtmSqueeze::new(20, f64::NAN, 1.5).is_err());
+        assert!(TtmSqueeze::new(20, 2.0, f64::INFINITY).is_err());
+        assert!(TtmSqueeze::new(1, 2.0, 1.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut squeeze = TtmSqueeze::new(4, 2.0, 1.5).unwrap();
+
+        let test_data = vec![
+            // high, low, close, squeeze, momentum
+            (11.0, 9.0, 10.0, true, 0.0),
+            (11.0, 9.0, 10.2, true, 0.15),
+            (11.0, 9.0, 9.9, true, -0.047),
+            (11.0, 9.0, 10.1, true, 0.021),
+            (12.0, 10.0, 11.5, true, 0.715),
+            (14.0, 11.0, 13.5, true, 1.933),
+            (16.0, 13.0, 15.8, false, 3.17),
+            (17.0, 15.0, 16.9, false, 3.336),
+        ];
+
+        for (high, low, close, on, momentum) in test_data {
+            let out = squeeze.next(&Bar::new().high(high).low(low).close(close));
+            assert_eq!(out.squeeze, on);
+            assert_eq!(round(out.momentum), momentum);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut squeeze = TtmSqueeze::new(4, 2.0, 1.5).unwrap();
+        squeeze.next(&Bar::new().high(11).low(9).close(10.2));
+        squeeze.next(&Bar::new().high(16).low(13).close(15.8));
+
+        squeeze.reset();
+        let out = squeeze.next(&Bar::new().high(11).low(9).close(10));
+        assert!(out.squeeze);
+        assert_eq!(out.momentum, 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        TtmSqueeze::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = TtmSqueeze::new(20, 2.0, 1.5).unwrap();
+        assert_eq!(format!("{}", indicator), "SQUEEZE(20, 2, 1.5)");
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+
+use crate::units::OutputUnit;
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Returns the output of an indicator in its [unit](../units/index.html).
+///
+/// The wrapped indicator must implement [OutputUnit](../units/trait.OutputUnit.html), e.g.
+/// [ATR](struct.AverageTrueRange.html) returns a [Price](../units/struct.Price.html) and
+/// [RSI](struct.RelativeStrengthIndex.html) a [Percent](../units/struct.Percent.html).
+///
+/// # Parameters
+///
+/// * _indicator_ - inner indicator producing `f64` values.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{RelativeStrengthIndex, Typed};
+/// use ta::units::Percent;
+/// use ta::Next;
+///
+/// let mut rsi = Typed::new(RelativeStrengthIndex::new(3).unwrap());
+/// assert_eq!(rsi.next(10.0), Percent(50.0));
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Typed<I> {
+    indicator: I,
+}
+
+impl<I> Typed<I> {
+    pub fn new(indicator: I) -> Self {
+        Self { indicator }
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<I: Period> Period for Typed<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: Lookback> Lookback for Typed<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
+
+impl<In, I: Next<In, Output = f64> + OutputUnit> Next<In> for Typed<I> {
+    type Output = I::Unit;
+
+    fn next(&mut self, input: In) -> Self::Output {
+        I::Unit::from(self.indicator.next(input))
+    }
+}
+
+impl<I: Reset> Reset for Typed<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<I: Default> Default for Typed<I> {
+    fn default() -> Self {
+        Self::new(I::default())
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Typed<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.indicator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{AverageTrueRange, OnBalanceVolume, RelativeStrengthIndex};
+    use crate::test_helper::*;
+    use crate::units::{Percent, Price, VolumeUnits};
+
+    #[test]
+    fn test_next() {
+        let mut atr = Typed::new(AverageTrueRange::default());
+        let mut obv = Typed::new(OnBalanceVolume::new());
+        let mut rsi = Typed::new(RelativeStrengthIndex::new(3).unwrap());
+
+        let bar = Bar::new().high(12).low(9).close(10).volume(500.0);
+        assert_eq!(atr.next(&bar), Price(3.0));
+        assert_eq!(obv.next(&bar), VolumeUnits(500.0));
+        assert_eq!(rsi.next(&bar), Percent(50.0));
+        assert_eq!(rsi.next(10.5).value().round(), 86.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rsi = Typed::new(RelativeStrengthIndex::new(3).unwrap());
+        rsi.next(10.0);
+        rsi.next(9.0);
+        rsi.reset();
+        assert_eq!(rsi.next(12.5), Percent(50.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Typed::<AverageTrueRange>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rsi = Typed::new(RelativeStrengthIndex::new(9).unwrap());
+        assert_eq!(format!("{}", rsi), "RSI(9)");
+    }
+}
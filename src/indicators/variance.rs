@@ -0,0 +1,282 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Variance (VAR).
+///
+/// Returns the variance of the last n values, the square of the
+/// [standard deviation](struct.StandardDeviation.html). It is updated in constant time with
+/// Welford's algorithm, which does not lose precision on large values like the naive sum of
+/// squares does.
+///
+/// # Formula
+///
+/// σ<sup>2</sup> = Σ (x<sub>i</sub> - μ)<sup>2</sup> / N
+///
+/// σ<sup>2</sup><sub>sample</sub> = Σ (x<sub>i</sub> - μ)<sup>2</sup> / (N - 1)
+///
+/// Where:
+///
+/// * _N_ - number of values in the window, up to _period_.
+/// * _μ_ - mean of the values in the window.
+///
+/// The sample variance is 0 while there is a single value.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0, greater than 1 for the sample
+///   variance). Default is 9.
+/// * _mode_ - [VarianceMode](enum.VarianceMode.html), population or sample. Default is
+///   population.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Variance, VarianceMode};
+/// use ta::Next;
+///
+/// let mut var = Variance::new(3).unwrap();
+/// assert_eq!(var.next(10.0), 0.0);
+/// assert_eq!(var.next(20.0), 25.0);
+///
+/// let mut var = Variance::with_mode(3, VarianceMode::Sample).unwrap();
+/// assert_eq!(var.next(10.0), 0.0);
+/// assert_eq!(var.next(20.0), 50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Variance, Wikipedia](https://en.wikipedia.org/wiki/Variance)
+///
+#[doc(alias = "VAR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Variance {
+    period: usize,
+    mode: VarianceMode,
+    index: usize,
+    count: usize,
+    m: f64,
+    m2: f64,
+    deque: Box<[f64]>,
+}
+
+/// Divisor of the [Variance](struct.Variance.html) and the
+/// [StandardDeviation](struct.StandardDeviation.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VarianceMode {
+    /// The values are the whole population, the sum of squares is divided by N.
+    #[default]
+    Population,
+    /// The values are a sample of a larger population, the sum of squares is divided by N - 1
+    /// (Bessel's correction).
+    Sample,
+}
+
+impl fmt::Display for VarianceMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            VarianceMode::Population => "population",
+            VarianceMode::Sample => "sample",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Variance {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_mode(period, VarianceMode::Population)
+    }
+
+    pub fn with_mode(period: usize, mode: VarianceMode) -> Result<Self> {
+        let min_period = match mode {
+            VarianceMode::Population => 1,
+            VarianceMode::Sample => 2,
+        };
+        if period < min_period {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            mode,
+            index: 0,
+            count: 0,
+            m: 0.0,
+            m2: 0.0,
+            deque: vec![0.0; period].into_boxed_slice(),
+        })
+    }
+
+    pub fn mode(&self) -> VarianceMode {
+        self.mode
+    }
+
+    pub(super) fn mean(&self) -> f64 {
+        self.m
+    }
+}
+
+impl Period for Variance {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for Variance {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl Next<f64> for Variance {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+            let delta = input - self.m;
+            self.m += delta / self.count as f64;
+            let delta2 = input - self.m;
+            self.m2 += delta * delta2;
+        } else {
+            let delta = input - old_val;
+            let old_m = self.m;
+            self.m += delta / self.period as f64;
+            let delta2 = input - self.m + old_val - old_m;
+            self.m2 += delta * delta2;
+        }
+        if self.m2 < 0.0 {
+            self.m2 = 0.0;
+        }
+
+        match self.mode {
+            VarianceMode::Population => self.m2 / self.count as f64,
+            VarianceMode::Sample if self.count > 1 => self.m2 / (self.count - 1) as f64,
+            VarianceMode::Sample => 0.0,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for Variance {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Variance {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.m = 0.0;
+        self.m2 = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for Variance {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for Variance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mode {
+            VarianceMode::Population => write!(f, "VAR({})", self.period),
+            VarianceMode::Sample => write!(f, "VAR({}, {})", self.period, self.mode),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Variance);
+
+    #[test]
+    fn test_new() {
+        assert!(Variance::new(0).is_err());
+        assert!(Variance::new(1).is_ok());
+        assert!(Variance::with_mode(1, VarianceMode::Sample).is_err());
+        assert!(Variance::with_mode(2, VarianceMode::Sample).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut var = Variance::new(4).unwrap();
+        assert_eq!(var.next(10.0), 0.0);
+        assert_eq!(var.next(20.0), 25.0);
+        assert_eq!(round(var.next(30.0)), 66.667);
+        assert_eq!(round(var.next(20.0)), 50.0);
+        assert_eq!(round(var.next(10.0)), 50.0);
+        assert_eq!(round(var.next(100.0)), 1250.0);
+    }
+
+    #[test]
+    fn test_next_sample() {
+        let mut var = Variance::with_mode(4, VarianceMode::Sample).unwrap();
+        assert_eq!(var.next(10.0), 0.0);
+        assert_eq!(var.next(20.0), 50.0);
+        assert_eq!(round(var.next(30.0)), 100.0);
+        assert_eq!(round(var.next(20.0)), 66.667);
+        assert_eq!(round(var.next(10.0)), 66.667);
+        assert_eq!(round(var.next(100.0)), 1666.667);
+    }
+
+    #[test]
+    fn test_next_large_values() {
+        // The sum of squares of such values would lose the variance in rounding.
+        let mut var = Variance::new(3).unwrap();
+        for _ in 0..100 {
+            var.next(1e9 + 1.0);
+            var.next(1e9 + 2.0);
+            var.next(1e9 + 3.0);
+        }
+        assert_eq!(round(var.next(1e9 + 1.0)), 0.667);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut var = Variance::with_mode(4, VarianceMode::Sample).unwrap();
+        var.next(10.0);
+        var.next(20.0);
+
+        var.reset();
+        assert_eq!(var.next(20.0), 0.0);
+        assert_eq!(var.next(30.0), 50.0);
+    }
+
+    #[test]
+    fn test_default() {
+        let var = Variance::default();
+        assert_eq!(var.period(), 9);
+        assert_eq!(var.mode(), VarianceMode::Population);
+    }
+
+    #[test]
+    fn test_display() {
+        let var = Variance::new(5).unwrap();
+        assert_eq!(format!("{}", var), "VAR(5)");
+        let var = Variance::with_mode(5, VarianceMode::Sample).unwrap();
+        assert_eq!(format!("{}", var), "VAR(5, sample)");
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Maximum, Minimum};
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Vertical Horizontal Filter (VHF).
+///
+/// Developed by Adam White, it compares the range of the closes over the last _period_ bars,
+/// the vertical move, with the distance travelled by the closes to cover it, the horizontal
+/// move. A trending market covers its range in few back-and-forth moves and has a high VHF,
+/// a choppy market travels a lot without leaving its range and has a low VHF. It is used to
+/// choose between trend-following and range-bound tools rather than as a signal itself.
+///
+/// The highest and lowest closes are tracked with [Maximum](struct.Maximum.html) and
+/// [Minimum](struct.Minimum.html) and the changes with a running sum, so `next` is amortized
+/// `O(1)`.
+///
+/// # Formula
+///
+/// VHF = (highest close - lowest close) / Σ |close<sub>i</sub> - close<sub>i-1</sub>|
+///
+/// Where the highest and lowest closes are taken over the last _period_ closes and the sum runs
+/// over the last _period_ close changes. The first bar has no change and the VHF is 0 while the
+/// close does not move.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 28.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VerticalHorizontalFilter;
+/// use ta::Next;
+///
+/// let mut vhf = VerticalHorizontalFilter::new(3).unwrap();
+/// assert_eq!(vhf.next(10.0), 0.0);
+/// assert_eq!(vhf.next(12.0), 1.0);
+/// assert_eq!(vhf.next(14.0), 1.0);
+/// // The closes turn back inside their range.
+/// assert_eq!(vhf.next(13.0), 0.4);
+/// assert_eq!(vhf.next(12.0), 0.5);
+/// ```
+///
+#[doc(alias = "VHF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VerticalHorizontalFilter {
+    period: usize,
+    highest: Maximum,
+    lowest: Minimum,
+    prev_close: Option<f64>,
+    // Absolute changes of the last period closes.
+    changes: Box<[f64]>,
+    index: usize,
+    count: usize,
+    sum: f64,
+}
+
+impl VerticalHorizontalFilter {
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            highest: Maximum::new(period)?,
+            lowest: Minimum::new(period)?,
+            prev_close: None,
+            changes: vec![0.0; period].into_boxed_slice(),
+            index: 0,
+            count: 0,
+            sum: 0.0,
+        })
+    }
+}
+
+impl Period for VerticalHorizontalFilter {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for VerticalHorizontalFilter {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for VerticalHorizontalFilter {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let range = self.highest.next(input) - self.lowest.next(input);
+
+        if let Some(prev_close) = self.prev_close.replace(input) {
+            let change = (input - prev_close).abs();
+            if self.count == self.period {
+                self.sum -= self.changes[self.index];
+            } else {
+                self.count += 1;
+            }
+            self.changes[self.index] = change;
+            self.sum += change;
+            self.index = if self.index + 1 < self.period {
+                self.index + 1
+            } else {
+                0
+            };
+        }
+
+        if range > 0.0 && self.sum > 0.0 {
+            // The running sum may be off by a rounding residue.
+            (range / self.sum).min(1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for VerticalHorizontalFilter {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for VerticalHorizontalFilter {
+    fn reset(&mut self) {
+        self.highest.reset();
+        self.lowest.reset();
+        self.prev_close = None;
+        for change in self.changes.iter_mut() {
+            *change = 0.0;
+        }
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+    }
+}
+
+impl Default for VerticalHorizontalFilter {
+    fn default() -> Self {
+        Self::new(28).unwrap()
+    }
+}
+
+impl fmt::Display for VerticalHorizontalFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VHF({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(VerticalHorizontalFilter);
+
+    #[test]
+    fn test_new() {
+        assert!(VerticalHorizontalFilter::new(0).is_err());
+        assert!(VerticalHorizontalFilter::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vhf = VerticalHorizontalFilter::new(3).unwrap();
+
+        assert_eq!(vhf.next(10.0), 0.0);
+        assert_eq!(vhf.next(11.0), 1.0);
+        assert_eq!(vhf.next(12.0), 1.0);
+        assert_eq!(round(vhf.next(11.0)), 0.333);
+        assert_eq!(vhf.next(13.0), 0.5);
+        assert_eq!(round(vhf.next(13.0)), 0.667);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut vhf = VerticalHorizontalFilter::new(3).unwrap();
+        for _ in 0..5 {
+            assert_eq!(vhf.next(10.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_next_long_series() {
+        let mut vhf = VerticalHorizontalFilter::new(5).unwrap();
+        let closes: Vec<f64> = (0..200).map(|i| 100.0 + ((i * 37) % 11) as f64).collect();
+
+        for (i, &close) in closes.iter().enumerate() {
+            let actual = vhf.next(close);
+            if i < 5 {
+                continue;
+            }
+            let window = &closes[i - 4..=i];
+            let highest = window.iter().cloned().fold(f64::MIN, f64::max);
+            let lowest = window.iter().cloned().fold(f64::MAX, f64::min);
+            let sum: f64 = (i - 4..=i).map(|j| (closes[j] - closes[j - 1]).abs()).sum();
+            assert_eq!(round(actual), round((highest - lowest) / sum));
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut vhf = VerticalHorizontalFilter::new(3).unwrap();
+        vhf.next(&Bar::new().close(10));
+        assert_eq!(vhf.next(&Bar::new().close(12)), 1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vhf = VerticalHorizontalFilter::new(3).unwrap();
+        vhf.next(10.0);
+        vhf.next(11.0);
+        vhf.next(10.0);
+
+        vhf.reset();
+        assert_eq!(vhf.next(10.0), 0.0);
+        assert_eq!(vhf.next(11.0), 1.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let vhf = VerticalHorizontalFilter::new(28).unwrap();
+        assert_eq!(vhf.lookback(), 28);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(VerticalHorizontalFilter::default().period(), 28);
+    }
+
+    #[test]
+    fn test_display() {
+        let vhf = VerticalHorizontalFilter::new(28).unwrap();
+        assert_eq!(format!("{}", vhf), "VHF(28)");
+    }
+}
@@ -0,0 +1,261 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Variable Index Dynamic Average (VIDYA).
+///
+/// Developed by Tushar Chande, an [EMA](struct.ExponentialMovingAverage.html) whose smoothing
+/// factor is scaled by the absolute Chande Momentum Oscillator (CMO) of the last _cmo_period_
+/// price changes: the average follows the price closely in a strong trend, either way, and
+/// flattens when gains and losses cancel out.
+///
+/// # Formula
+///
+/// CMO = (Σ up - Σ down) / (Σ up + Σ down) over the last _cmo_period_ price changes
+///
+/// VIDYA<sub>t</sub> = VIDYA<sub>t-1</sub> + α * |CMO| * (p<sub>t</sub> - VIDYA<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _up_ - price change when positive, otherwise 0
+/// * _down_ - absolute price change when negative, otherwise 0
+/// * _α_ - 2 / (_period_ + 1)
+///
+/// The first VIDYA is the first price. While there are less than _cmo_period_ price changes,
+/// the CMO is computed on the available ones. When the price does not move, the CMO is 0.
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMA (integer greater than 0). Default is 9.
+/// * _cmo_period_ - number of price changes of the CMO (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Vidya;
+/// use ta::Next;
+///
+/// let mut vidya = Vidya::new(3, 2).unwrap();
+/// assert_eq!(vidya.next(10.0), 10.0);
+///
+/// // Pure uptrend, smoothed like an EMA(3).
+/// assert_eq!(vidya.next(14.0), 12.0);
+///
+/// // Gains and losses cancel out, VIDYA does not move.
+/// assert_eq!(vidya.next(10.0), 12.0);
+/// ```
+///
+#[doc(alias = "VIDYA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Vidya {
+    period: usize,
+    cmo_period: usize,
+    alpha: f64,
+    index: usize,
+    count: usize,
+    // Last cmo_period price changes.
+    deque: Box<[f64]>,
+    prev: f64,
+    current: f64,
+    is_new: bool,
+}
+
+impl Vidya {
+    pub fn new(period: usize, cmo_period: usize) -> Result<Self> {
+        if period == 0 || cmo_period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            cmo_period,
+            alpha: 2.0 / (period + 1) as f64,
+            index: 0,
+            count: 0,
+            deque: vec![0.0; cmo_period].into_boxed_slice(),
+            prev: 0.0,
+            current: 0.0,
+            is_new: true,
+        })
+    }
+
+    pub fn cmo_period(&self) -> usize {
+        self.cmo_period
+    }
+}
+
+impl NewWithPeriod for Vidya {
+    /// Creates a VIDYA with the CMO period of Chande, 9.
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period, 9)
+    }
+}
+
+impl Period for Vidya {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for Vidya {
+    fn lookback(&self) -> usize {
+        self.cmo_period
+    }
+}
+
+impl Next<f64> for Vidya {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        if self.is_new {
+            self.is_new = false;
+            self.prev = input;
+            self.current = input;
+            return self.current;
+        }
+
+        self.deque[self.index] = input - self.prev;
+        self.index = if self.index + 1 < self.cmo_period {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.cmo_period {
+            self.count += 1;
+        }
+        self.prev = input;
+
+        // Summed over the window on every period, so the CMO is exactly 0 on a flat window.
+        let mut up = 0.0;
+        let mut down = 0.0;
+        for &change in self.deque[..self.count].iter() {
+            if change > 0.0 {
+                up += change;
+            } else {
+                down -= change;
+            }
+        }
+        let cmo = if up + down > 0.0 {
+            (up - down) / (up + down)
+        } else {
+            0.0
+        };
+
+        self.current += self.alpha * cmo.abs() * (input - self.current);
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for Vidya {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Vidya {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.prev = 0.0;
+        self.current = 0.0;
+        self.is_new = true;
+        for i in 0..self.cmo_period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for Vidya {
+    fn default() -> Self {
+        Self::new(9, 9).unwrap()
+    }
+}
+
+impl fmt::Display for Vidya {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VIDYA({}, {})", self.period, self.cmo_period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::AverageTrueRange;
+    use crate::test_helper::*;
+
+    test_indicator!(Vidya);
+
+    #[test]
+    fn test_new() {
+        assert!(Vidya::new(0, 9).is_err());
+        assert!(Vidya::new(9, 0).is_err());
+        assert!(Vidya::new(1, 1).is_ok());
+        assert_eq!(Vidya::new_with_period(20).unwrap().cmo_period(), 9);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vidya = Vidya::new(3, 2).unwrap();
+
+        assert_eq!(vidya.next(10.0), 10.0);
+        assert_eq!(vidya.next(14.0), 12.0);
+        assert_eq!(vidya.next(10.0), 12.0);
+        // changes -4, +3: CMO = -1/7
+        assert_eq!(round(vidya.next(13.0)), 12.071);
+        // changes +3, +2: CMO = 1
+        assert_eq!(round(vidya.next(15.0)), 13.536);
+
+        let mut vidya = Vidya::new(3, 2).unwrap();
+        assert_eq!(vidya.next(&Bar::new().close(10)), 10.0);
+        assert_eq!(vidya.next(&Bar::new().close(14)), 12.0);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut vidya = Vidya::new(3, 2).unwrap();
+        assert_eq!(vidya.next(10.0), 10.0);
+        assert_eq!(vidya.next(10.0), 10.0);
+        assert_eq!(vidya.next(10.0), 10.0);
+    }
+
+    #[test]
+    fn test_atr_smoothing() {
+        let mut atr = AverageTrueRange::<Vidya>::with_ma(3).unwrap();
+        assert_eq!(atr.next(&Bar::new().high(10).low(8).close(9)), 2.0);
+        assert_eq!(format!("{}", atr), "ATR(3)");
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vidya = Vidya::new(3, 2).unwrap();
+        vidya.next(10.0);
+        vidya.next(14.0);
+
+        vidya.reset();
+        assert_eq!(vidya.next(4.0), 4.0);
+        assert_eq!(vidya.next(8.0), 6.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let vidya = Vidya::new(14, 9).unwrap();
+        assert_eq!(vidya.period(), 14);
+        assert_eq!(vidya.lookback(), 9);
+    }
+
+    #[test]
+    fn test_default() {
+        Vidya::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vidya = Vidya::new(14, 9).unwrap();
+        assert_eq!(format!("{}", vidya), "VIDYA(14, 9)");
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{AverageTrueRange, ExponentialMovingAverage, Momentum};
+use crate::{Close, High, Lookback, Low, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volatility-adjusted momentum.
+///
+/// The [momentum](struct.Momentum.html) over the last _period_ periods expressed in
+/// [ATRs](struct.AverageTrueRange.html), so momentum can be compared across instruments of
+/// different prices and volatilities, e.g. to rank them. Any moving average implementing
+/// [NewWithPeriod](../trait.NewWithPeriod.html) can smooth the ATR, the EMA by default.
+///
+/// # Formula
+///
+/// VAM = MOM(_period_) / ATR(_period_)
+///
+/// The output is 0 while the ATR is 0.
+///
+/// # Parameters
+///
+/// * _period_ - period of the momentum and of the ATR (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ExponentialMovingAverage as Ema, VolAdjMomentum};
+/// use ta::Next;
+///
+/// let mut vam = VolAdjMomentum::<Ema>::new(2).unwrap();
+/// assert_eq!(vam.next(10.0), 0.0);
+/// // The ATR of the closes is an EMA of their absolute changes, starting at 0.
+/// assert_eq!(vam.next(11.0), 1.5); // 1 / (2 / 3)
+/// assert_eq!((vam.next(13.0) * 1000.0).round(), 1929.0); // 3 / (2 / 3 + 2 / 3 * (2 - 2 / 3))
+/// ```
+///
+#[doc(alias = "VAM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolAdjMomentum<MA = ExponentialMovingAverage> {
+    momentum: Momentum,
+    atr: AverageTrueRange<MA>,
+}
+
+impl<MA: NewWithPeriod> VolAdjMomentum<MA> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            momentum: Momentum::new(period)?,
+            atr: AverageTrueRange::with_ma(period)?,
+        })
+    }
+}
+
+impl<MA: NewWithPeriod> NewWithPeriod for VolAdjMomentum<MA> {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl<MA> Period for VolAdjMomentum<MA> {
+    fn period(&self) -> usize {
+        self.momentum.period()
+    }
+}
+
+impl<MA: Lookback> Lookback for VolAdjMomentum<MA> {
+    fn lookback(&self) -> usize {
+        self.momentum.lookback().max(self.atr.lookback())
+    }
+}
+
+fn ratio(momentum: f64, atr: f64) -> f64 {
+    if atr == 0.0 {
+        0.0
+    } else {
+        momentum / atr
+    }
+}
+
+impl<MA: Next<f64, Output = f64>> Next<f64> for VolAdjMomentum<MA> {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        ratio(self.momentum.next(input), self.atr.next(input))
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: High + Low + Close> Next<&T> for VolAdjMomentum<MA> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        ratio(self.momentum.next(input), self.atr.next(input))
+    }
+}
+
+impl<MA: Reset> Reset for VolAdjMomentum<MA> {
+    fn reset(&mut self) {
+        self.momentum.reset();
+        self.atr.reset();
+    }
+}
+
+impl Default for VolAdjMomentum {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<MA> fmt::Display for VolAdjMomentum<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VAM({})", self.momentum.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ExponentialMovingAverage as Ema;
+    use crate::test_helper::*;
+
+    test_indicator!(VolAdjMomentum);
+
+    #[test]
+    fn test_new() {
+        assert!(VolAdjMomentum::<Ema>::new(0).is_err());
+        assert!(VolAdjMomentum::<Ema>::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vam = VolAdjMomentum::<Ema>::new(2).unwrap();
+
+        let test_data = vec![
+            // high, low, close, expected
+            (10.0, 7.5, 9.0, 0.0),
+            (11.0, 9.0, 9.5, 0.231),
+            (9.0, 5.0, 8.0, -0.269),
+            (11.0, 8.0, 10.5, 0.309),
+        ];
+
+        for (high, low, close, expected) in test_data {
+            let bar = Bar::new().high(high).low(low).close(close);
+            assert_eq!(round(vam.next(&bar)), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut vam = VolAdjMomentum::<Ema>::new(3).unwrap();
+        let bar = Bar::new().high(10).low(10).close(10);
+        assert_eq!(vam.next(&bar), 0.0);
+        assert_eq!(vam.next(&bar), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vam = VolAdjMomentum::<Ema>::new(2).unwrap();
+        vam.next(10.0);
+        vam.next(11.0);
+
+        vam.reset();
+        assert_eq!(vam.next(20.0), 0.0);
+        assert_eq!(vam.next(19.0), -1.5);
+    }
+
+    #[test]
+    fn test_lookback() {
+        assert_eq!(VolAdjMomentum::<Ema>::new(14).unwrap().lookback(), 14);
+    }
+
+    #[test]
+    fn test_default() {
+        VolAdjMomentum::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = VolAdjMomentum::<Ema>::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "VAM(14)");
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{AverageTrueRange, ExponentialMovingAverage, TrueRange};
+use crate::{Close, High, Lookback, Low, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volatility ratio (VR).
+///
+/// Described by Jack Schwager, the volatility ratio compares the true range of the current
+/// period with the average true range. Values well above 1 (commonly above 2) flag range
+/// expansion bars, which often start or end a trend.
+///
+/// # Formula
+///
+/// VR<sub>t</sub> = TR<sub>t</sub> / ATR(period)<sub>t</sub>
+///
+/// Where:
+///
+/// * _TR<sub>t</sub>_ - [true range](struct.TrueRange.html) for period _t_
+/// * _ATR(period)<sub>t</sub>_ - [average true range](struct.AverageTrueRange.html), smoothed
+///   with _MA_, [EMA](struct.ExponentialMovingAverage.html) by default
+///
+/// When the average true range is 0, the ratio is 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of the average true range (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ExponentialMovingAverage as Ema, VolatilityRatio};
+/// use ta::{DataItem, Next};
+///
+/// let mut vr = VolatilityRatio::<Ema>::new(14).unwrap();
+/// let bar = DataItem::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(vr.next(&bar), 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Volatility Ratio, Investopedia](https://www.investopedia.com/terms/v/volatility-ratio.asp)
+///
+#[doc(alias = "VR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolatilityRatio<MA = ExponentialMovingAverage> {
+    true_range: TrueRange,
+    atr: AverageTrueRange<MA>,
+}
+
+impl<MA: NewWithPeriod> VolatilityRatio<MA> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            true_range: TrueRange::new(),
+            atr: AverageTrueRange::with_ma(period)?,
+        })
+    }
+}
+
+impl<MA: NewWithPeriod> NewWithPeriod for VolatilityRatio<MA> {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl<MA: Period> Period for VolatilityRatio<MA> {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl<MA: Lookback> Lookback for VolatilityRatio<MA> {
+    fn lookback(&self) -> usize {
+        self.atr.lookback()
+    }
+}
+
+impl<MA: Next<f64, Output = f64>> Next<f64> for VolatilityRatio<MA> {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let tr = self.true_range.next(input);
+        let atr = self.atr.next(input);
+        if atr == 0.0 {
+            0.0
+        } else {
+            tr / atr
+        }
+    }
+}
+
+impl<MA: Next<f64, Output = f64>, T: High + Low + Close> Next<&T> for VolatilityRatio<MA> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let tr = self.true_range.next(input);
+        let atr = self.atr.next(input);
+        if atr == 0.0 {
+            0.0
+        } else {
+            tr / atr
+        }
+    }
+}
+
+impl<MA: Reset> Reset for VolatilityRatio<MA> {
+    fn reset(&mut self) {
+        self.true_range.reset();
+        self.atr.reset();
+    }
+}
+
+impl Default for VolatilityRatio {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<MA: Period> fmt::Display for VolatilityRatio<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VR({})", self.atr.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage as Ema, RunningMovingAverage as Rma};
+    use crate::test_helper::*;
+
+    test_indicator!(VolatilityRatio);
+
+    #[test]
+    fn test_new() {
+        assert!(VolatilityRatio::<Ema>::new(0).is_err());
+        assert!(VolatilityRatio::<Ema>::new(1).is_ok());
+        assert!(VolatilityRatio::<Rma>::new(0).is_err());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vr = VolatilityRatio::<Ema>::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(7.5).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(9.5);
+        let bar3 = Bar::new().high(9).low(5).close(8);
+
+        // TR: 2.5, 2, 4.5; ATR: 2.5, 2.25, 3.375
+        assert_eq!(vr.next(&bar1), 1.0);
+        assert_eq!(round(vr.next(&bar2)), 0.889);
+        assert_eq!(round(vr.next(&bar3)), 1.333);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut vr = VolatilityRatio::<Ema>::new(3).unwrap();
+        assert_eq!(vr.next(&Bar::new().high(1).low(1).close(1)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vr = VolatilityRatio::<Ema>::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(7.5).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(9.5);
+
+        vr.next(&bar1);
+        vr.next(&bar2);
+
+        vr.reset();
+        assert_eq!(vr.next(&bar1), 1.0);
+        assert_eq!(round(vr.next(&bar2)), 0.889);
+    }
+
+    #[test]
+    fn test_default() {
+        VolatilityRatio::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = VolatilityRatio::<Ema>::new(8).unwrap();
+        assert_eq!(format!("{}", indicator), "VR(8)");
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageTrueRange;
+use crate::sorted_window::SortedWindow;
+use crate::{Lookback, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volatility regime.
+///
+/// Raw volatility values, such as [ATR](struct.AverageTrueRange.html), are hard to interpret
+/// without historical context. This indicator ranks the current volatility against the
+/// volatility of the previous _rank_period_ periods and classifies it as low, normal or high.
+///
+/// Any indicator that outputs `f64` and can be created from a period can be used as the
+/// volatility measure, e.g. [NATR](struct.NormalizedAverageTrueRange.html) to rank volatility
+/// relative to price. [ATR](struct.AverageTrueRange.html) is used by default.
+///
+/// # Formula
+///
+/// rank = 100 * (number of previous _rank_period_ volatility values below the current one) / _rank_period_
+///
+/// * _Low_ if rank < _low_
+/// * _High_ if rank > _high_
+/// * _Normal_ otherwise
+///
+/// While the history is not full, the rank is computed against the available values. The
+/// first rank is 50.
+///
+/// # Parameters
+///
+/// * _volatility_period_ - period of the volatility indicator (integer greater than 0). Default is 14.
+/// * _rank_period_ - number of previous volatility values to rank against (integer greater than 0). Default is 252.
+/// * _low_ - rank below which volatility is low (0 to 100). Default is 25.0.
+/// * _high_ - rank above which volatility is high (_low_ to 100). Default is 75.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{AverageTrueRange, Regime, VolatilityRegime};
+/// use ta::{DataItem, Next};
+///
+/// let mut regime = VolatilityRegime::<AverageTrueRange>::new(14, 100, 25.0, 75.0).unwrap();
+/// let bar = DataItem::builder()
+///     .open(10.0)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.5)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// let out = regime.next(&bar);
+/// assert_eq!(out.volatility, 2.0);
+/// assert_eq!(out.percent_rank, 50.0);
+/// assert_eq!(out.regime, Regime::Normal);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolatilityRegime<V = AverageTrueRange> {
+    volatility: V,
+    history: SortedWindow,
+    low: f64,
+    high: f64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Regime {
+    Low,
+    Normal,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolatilityRegimeOutput {
+    pub volatility: f64,
+    pub percent_rank: f64,
+    pub regime: Regime,
+}
+
+impl<V: NewWithPeriod> VolatilityRegime<V> {
+    pub fn new(volatility_period: usize, rank_period: usize, low: f64, high: f64) -> Result<Self> {
+        if rank_period == 0 || !(0.0..=100.0).contains(&low) || !(low..=100.0).contains(&high) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            volatility: V::new_with_period(volatility_period)?,
+            history: SortedWindow::new(rank_period),
+            low,
+            high,
+        })
+    }
+}
+
+impl<V> VolatilityRegime<V> {
+    pub fn low(&self) -> f64 {
+        self.low
+    }
+
+    pub fn high(&self) -> f64 {
+        self.high
+    }
+
+    fn classify(&mut self, volatility: f64) -> VolatilityRegimeOutput {
+        let percent_rank = match self.history.len() {
+            0 => 50.0,
+            len => 100.0 * self.history.count_below(volatility) as f64 / len as f64,
+        };
+        self.history.push(volatility);
+
+        let regime = if percent_rank < self.low {
+            Regime::Low
+        } else if percent_rank > self.high {
+            Regime::High
+        } else {
+            Regime::Normal
+        };
+
+        VolatilityRegimeOutput {
+            volatility,
+            percent_rank,
+            regime,
+        }
+    }
+}
+
+impl<V> Period for VolatilityRegime<V> {
+    fn period(&self) -> usize {
+        self.history.capacity()
+    }
+}
+
+impl<V: Lookback> Lookback for VolatilityRegime<V> {
+    fn lookback(&self) -> usize {
+        self.volatility.lookback() + self.history.capacity()
+    }
+}
+
+impl<V: Next<f64, Output = f64>> Next<f64> for VolatilityRegime<V> {
+    type Output = VolatilityRegimeOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let volatility = self.volatility.next(input);
+        self.classify(volatility)
+    }
+}
+
+impl<'a, T, V: Next<&'a T, Output = f64>> Next<&'a T> for VolatilityRegime<V> {
+    type Output = VolatilityRegimeOutput;
+
+    fn next(&mut self, input: &'a T) -> Self::Output {
+        let volatility = self.volatility.next(input);
+        self.classify(volatility)
+    }
+}
+
+impl<V: Reset> Reset for VolatilityRegime<V> {
+    fn reset(&mut self) {
+        self.volatility.reset();
+        self.history.clear();
+    }
+}
+
+impl Default for VolatilityRegime {
+    fn default() -> Self {
+        Self::new(14, 252, 25.0, 75.0).unwrap()
+    }
+}
+
+impl<V: Period> fmt::Display for VolatilityRegime<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "VREGIME({}, {}, {}, {})",
+            self.volatility.period(),
+            self.history.capacity(),
+            self.low,
+            self.high
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{AverageTrueRange, NormalizedAverageTrueRange};
+    use crate::test_helper::*;
+
+    type Atr = AverageTrueRange;
+
+    #[test]
+    fn test_new() {
+        assert!(VolatilityRegime::<Atr>::new(0, 10, 25.0, 75.0).is_err());
+        assert!(VolatilityRegime::<Atr>::new(14, 0, 25.0, 75.0).is_err());
+        assert!(VolatilityRegime::<Atr>::new(14, 10, -1.0, 75.0).is_err());
+        assert!(VolatilityRegime::<Atr>::new(14, 10, 25.0, 101.0).is_err());
+        assert!(VolatilityRegime::<Atr>::new(14, 10, 75.0, 25.0).is_err());
+        assert!(VolatilityRegime::<Atr>::new(1, 1, 50.0, 50.0).is_ok());
+        assert!(VolatilityRegime::<NormalizedAverageTrueRange>::new(14, 10, 25.0, 75.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        // Volatility of period 1 is the true range of the bar.
+        let mut vr = VolatilityRegime::<Atr>::new(1, 4, 25.0, 75.0).unwrap();
+
+        let test_data = vec![
+            // high, low, rank, regime
+            (11.0, 9.0, 50.0, Regime::Normal),
+            (11.0, 10.0, 0.0, Regime::Low),
+            (12.0, 9.0, 100.0, Regime::High),
+            (11.0, 9.0, 33.333, Regime::Normal),
+            (11.0, 9.5, 25.0, Regime::Normal),
+            // 2 is ranked against 1, 3, 2, 1.5 - the first value is out of the window
+            (11.0, 9.0, 50.0, Regime::Normal),
+            (15.0, 10.0, 100.0, Regime::High),
+        ];
+
+        for (high, low, rank, regime) in test_data {
+            let out = vr.next(&Bar::new().high(high).low(low).close(10));
+            assert_eq!(round(out.percent_rank), rank);
+            assert_eq!(out.regime, regime);
+        }
+    }
+
+    #[test]
+    fn test_next_f64() {
+        let mut vr = VolatilityRegime::<Atr>::new(1, 10, 25.0, 75.0).unwrap();
+
+        // True range of a single value is 0.
+        assert_eq!(vr.next(10.0).volatility, 0.0);
+        assert_eq!(vr.next(12.0).regime, Regime::High);
+        assert_eq!(vr.next(12.0).regime, Regime::Low);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vr = VolatilityRegime::<Atr>::new(1, 4, 25.0, 75.0).unwrap();
+
+        vr.next(&Bar::new().high(11).low(9).close(10));
+        vr.next(&Bar::new().high(11).low(10).close(10));
+
+        vr.reset();
+        let out = vr.next(&Bar::new().high(11).low(10).close(10));
+        assert_eq!(out.percent_rank, 50.0);
+        assert_eq!(out.regime, Regime::Normal);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let vr = VolatilityRegime::<Atr>::new(14, 100, 25.0, 75.0).unwrap();
+        assert_eq!(vr.lookback(), 114);
+    }
+
+    #[test]
+    fn test_default() {
+        VolatilityRegime::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = VolatilityRegime::<Atr>::new(14, 100, 20.0, 80.0).unwrap();
+        assert_eq!(format!("{}", indicator), "VREGIME(14, 100, 20, 80)");
+    }
+}
@@ -0,0 +1,282 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{ExponentialMovingAverage, TrueRange};
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volatility spike detector.
+///
+/// Emits an event when the [true range](struct.TrueRange.html) of a bar exceeds _multiplier_
+/// times the [ATR](struct.AverageTrueRange.html) of the previous bars, typically a news bar.
+/// Strategies use the events to stand aside, to widen their stops, or to trade the breakout.
+/// After an event, no event is emitted for the next _cooldown_ bars, so a burst of volatility
+/// is reported once.
+///
+/// The spike bars are kept in the ATR, which follows the new volatility. No event is emitted
+/// before the ATR has seen _period_ bars.
+///
+/// # Formula
+///
+/// event when TR<sub>t</sub> > _multiplier_ * ATR<sub>t-1</sub>
+///
+/// Where the ATR is an EMA of the true range over _period_ periods. The direction of the event
+/// is up when the close is at or above the previous close, down otherwise.
+///
+/// # Parameters
+///
+/// * _period_ - period of the ATR (integer greater than 0). Default is 14.
+/// * _multiplier_ - number of ATRs beyond which a bar is a spike (greater than 0). Default is 3.0.
+/// * _cooldown_ - number of bars without events after an event. Default is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{SpikeDirection, VolatilitySpike};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut spike = VolatilitySpike::new(3, 2.0, 0).unwrap();
+/// for _ in 0..3 {
+///     assert!(spike.next(&bar(11.0, 10.0, 10.5)).is_none());
+/// }
+///
+/// let event = spike.next(&bar(14.0, 10.5, 13.5)).unwrap();
+/// assert_eq!(event.direction, SpikeDirection::Up);
+/// assert_eq!(event.ratio, 3.5);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolatilitySpike {
+    period: usize,
+    multiplier: f64,
+    cooldown: usize,
+    true_range: TrueRange,
+    atr: ExponentialMovingAverage,
+    atr_value: f64,
+    count: usize,
+    // Bars left before the next event can be emitted.
+    remaining_cooldown: usize,
+    prev_close: Option<f64>,
+}
+
+/// Event emitted by the [VolatilitySpike](struct.VolatilitySpike.html) detector.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VolatilitySpikeEvent {
+    pub direction: SpikeDirection,
+    pub true_range: f64,
+    /// ATR before the spike.
+    pub atr: f64,
+    /// True range divided by the ATR.
+    pub ratio: f64,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpikeDirection {
+    Up,
+    Down,
+}
+
+impl VolatilitySpike {
+    pub fn new(period: usize, multiplier: f64, cooldown: usize) -> Result<Self> {
+        if !(multiplier > 0.0 && multiplier.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            multiplier,
+            cooldown,
+            true_range: TrueRange::new(),
+            atr: ExponentialMovingAverage::new(period)?,
+            atr_value: 0.0,
+            count: 0,
+            remaining_cooldown: 0,
+            prev_close: None,
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    pub fn cooldown(&self) -> usize {
+        self.cooldown
+    }
+}
+
+impl Period for VolatilitySpike {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for VolatilitySpike {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for VolatilitySpike {
+    type Output = Option<VolatilitySpikeEvent>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let true_range = self.true_range.next(input);
+        let spike = self.count >= self.period && true_range > self.multiplier * self.atr_value;
+
+        let event = if spike && self.remaining_cooldown == 0 {
+            self.remaining_cooldown = self.cooldown;
+            let up = self.prev_close.is_none_or(|prev| input.close() >= prev);
+            Some(VolatilitySpikeEvent {
+                direction: if up {
+                    SpikeDirection::Up
+                } else {
+                    SpikeDirection::Down
+                },
+                true_range,
+                atr: self.atr_value,
+                ratio: true_range / self.atr_value,
+            })
+        } else {
+            self.remaining_cooldown = self.remaining_cooldown.saturating_sub(1);
+            None
+        };
+
+        self.atr_value = self.atr.next(true_range);
+        self.count += 1;
+        self.prev_close = Some(input.close());
+        event
+    }
+}
+
+impl Reset for VolatilitySpike {
+    fn reset(&mut self) {
+        self.true_range.reset();
+        self.atr.reset();
+        self.atr_value = 0.0;
+        self.count = 0;
+        self.remaining_cooldown = 0;
+        self.prev_close = None;
+    }
+}
+
+impl Default for VolatilitySpike {
+    fn default() -> Self {
+        Self::new(14, 3.0, 0).unwrap()
+    }
+}
+
+impl fmt::Display for VolatilitySpike {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SPIKE({}, {}, {})",
+            self.period, self.multiplier, self.cooldown
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    fn bars() -> Vec<Bar> {
+        vec![
+            bar(11.0, 10.0, 10.5),
+            bar(11.0, 10.0, 10.5),
+            bar(11.0, 10.0, 10.5),
+            // TR = 3.5, ATR = 1
+            bar(14.0, 10.5, 13.5),
+            // TR = 5.5, ATR = 2.25
+            bar(9.0, 8.0, 8.5),
+            // TR = 5.5, ATR = 3.875
+            bar(4.0, 3.0, 3.5),
+            // TR = 10.5, ATR = 4.6875
+            bar(14.0, 13.0, 13.5),
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(VolatilitySpike::new(0, 3.0, 0).is_err());
+        assert!(VolatilitySpike::new(14, 0.0, 0).is_err());
+        assert!(VolatilitySpike::new(14, f64::NAN, 0).is_err());
+        assert!(VolatilitySpike::new(14, f64::INFINITY, 0).is_err());
+        assert!(VolatilitySpike::new(1, 1.0, 0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut spike = VolatilitySpike::new(3, 2.0, 0).unwrap();
+
+        let events: Vec<_> = bars().iter().map(|bar| spike.next(bar)).collect();
+        assert_eq!(events[..3], [None, None, None]);
+        assert_eq!(
+            events[3],
+            Some(VolatilitySpikeEvent {
+                direction: SpikeDirection::Up,
+                true_range: 3.5,
+                atr: 1.0,
+                ratio: 3.5,
+            })
+        );
+
+        let event = events[4].unwrap();
+        assert_eq!(event.direction, SpikeDirection::Down);
+        assert_eq!(round(event.ratio), 2.444);
+        assert_eq!(events[5], None);
+
+        let event = events[6].unwrap();
+        assert_eq!(event.direction, SpikeDirection::Up);
+        assert_eq!(round(event.ratio), 2.24);
+    }
+
+    #[test]
+    fn test_next_cooldown() {
+        let mut spike = VolatilitySpike::new(3, 2.0, 1).unwrap();
+
+        let events: Vec<_> = bars().iter().map(|bar| spike.next(bar)).collect();
+        let found: Vec<bool> = events.iter().map(|e| e.is_some()).collect();
+        assert_eq!(found, vec![false, false, false, true, false, false, true]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut spike = VolatilitySpike::new(3, 2.0, 0).unwrap();
+        for bar in &bars()[..4] {
+            spike.next(bar);
+        }
+
+        spike.reset();
+        assert!(spike.next(&bar(14.0, 10.5, 13.5)).is_none());
+    }
+
+    #[test]
+    fn test_default() {
+        VolatilitySpike::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = VolatilitySpike::new(14, 3.0, 5).unwrap();
+        assert_eq!(format!("{}", indicator), "SPIKE(14, 3, 5)");
+    }
+}
@@ -0,0 +1,620 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::price_histogram::PriceHistogram;
+use crate::{High, Lookback, Low, Next, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Price levels of a [VolumeProfile](struct.VolumeProfile.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Binning {
+    /// Bins of a fixed price width aligned on multiples of it, e.g. the tick size of the
+    /// instrument. The number of bins follows the range of the prices.
+    TickSize(f64),
+    /// A fixed number of bins of the same width, spanning the lowest low to the highest high of
+    /// the range.
+    Count(usize),
+}
+
+impl fmt::Display for Binning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Binning::TickSize(size) => write!(f, "tick {}", size),
+            Binning::Count(count) => write!(f, "{} bins", count),
+        }
+    }
+}
+
+/// Volume profile.
+///
+/// Bins the volume traded over a range of bars by price level. The range is either the last
+/// _period_ bars, or every bar since the profile was anchored, see [anchored](#method.anchored)
+/// and [anchor](#method.anchor). The volume of a bar is spread evenly over its high - low
+/// range, so every bin gets the share of the volume of the prices it covers.
+///
+/// On every period it returns the point of control (POC), the middle of the bin with the most
+/// volume, and the value area, the price range around the POC holding _value_area_ of the
+/// volume.
+///
+/// With a tick size, the bins do not move: every bar is added to its bins, and removed from
+/// them when it leaves a rolling range, so an anchored profile keeps only its bins. With a
+/// count of bins, the bins follow the range of the prices, so the profile keeps its bars and is
+/// rebuilt when its lowest low or highest high changes.
+///
+/// Bars with a non-finite high, low or volume are ignored, as well as bars spanning more than
+/// 100,000 bins of the tick size. While the range holds no bar, the outputs are NaN.
+///
+/// # Profile queries
+///
+/// The profile of the range can be queried between updates, e.g. by execution logic, with
+/// [histogram](#method.histogram), [volume_between](#method.volume_between),
+/// [node_above](#method.node_above) and [node_below](#method.node_below) for the nearest high
+/// volume nodes around a price, and [gaps](#method.gaps) for the low volume areas between them.
+/// A node is a bin holding at least a given share of the volume of the range. Only the bins
+/// covered by a bar of the range are part of the profile.
+///
+/// # Formula
+///
+/// The value area starts with the POC bin and grows one bin at a time, on the side of the
+/// adjacent covered bin with the most volume (above on a tie), until it holds at least
+/// _value_area_ of the total volume. Its high and low are the edges of its outermost bins.
+/// When several bins have the most volume, the POC is the lowest one.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars of a rolling profile (integer greater than 0). Default is 100.
+/// * _binning_ - size or number of the price bins, see [Binning](enum.Binning.html). The tick
+///   size must be greater than 0 and the count greater than 0. Default is 24 bins.
+/// * _value_area_ - share of the volume in the value area, set with
+///   [with_value_area](#method.with_value_area) (greater than 0, at most 1). Default is 0.7.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Binning, VolumeProfile};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |high: f64, low: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(low)
+///         .high(high)
+///         .low(low)
+///         .close(high)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut profile = VolumeProfile::new(3, Binning::TickSize(1.0)).unwrap();
+/// profile.next(&bar(12.0, 10.0, 200.0));
+/// let out = profile.next(&bar(11.0, 10.0, 300.0));
+///
+/// // 400 traded from 10 to 11, 100 from 11 to 12
+/// assert_eq!(out.poc, 10.5);
+/// assert_eq!((out.value_area_low, out.value_area_high), (10.0, 11.0));
+/// assert_eq!(profile.histogram(), vec![(10.5, 400.0), (11.5, 100.0)]);
+/// assert_eq!(profile.volume_between(11.0, 12.0), 100.0);
+///
+/// // The nearest bin below 11.5 with at least half of the volume.
+/// assert_eq!(profile.node_below(11.5, 0.5), Some(10.5));
+/// ```
+///
+#[doc(alias = "VP")]
+#[doc(alias = "VPVR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeProfile {
+    period: Option<usize>,
+    binning: Binning,
+    value_area: f64,
+    // High, low and volume of the bars of a rolling range, or of any range binned by count.
+    bars: VecDeque<(f64, f64, f64)>,
+    // Lowest low and highest high of the bars, when binned by count.
+    low: f64,
+    high: f64,
+    bins: PriceHistogram,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeProfileOutput {
+    pub poc: f64,
+    pub value_area_high: f64,
+    pub value_area_low: f64,
+}
+
+// Most bins a single bar can span with a tick size.
+const MAX_BAR_BINS: f64 = 100_000.0;
+
+impl VolumeProfile {
+    /// Creates a profile of the last _period_ bars.
+    pub fn new(period: usize, binning: Binning) -> Result<Self> {
+        if period == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Self::build(Some(period), binning)
+    }
+
+    /// Creates a profile of all the bars since the last [anchor](#method.anchor).
+    pub fn anchored(binning: Binning) -> Result<Self> {
+        Self::build(None, binning)
+    }
+
+    fn build(period: Option<usize>, binning: Binning) -> Result<Self> {
+        let width = match binning {
+            Binning::TickSize(size) if size > 0.0 && size.is_finite() => size,
+            Binning::Count(count) if count > 0 => 0.0,
+            _ => return Err(TaError::InvalidParameter),
+        };
+        Ok(Self {
+            period,
+            binning,
+            value_area: 0.7,
+            bars: VecDeque::new(),
+            low: f64::INFINITY,
+            high: f64::NEG_INFINITY,
+            bins: PriceHistogram::new(0.0, width),
+        })
+    }
+
+    /// Sets the share of the volume in the value area.
+    pub fn with_value_area(mut self, value_area: f64) -> Result<Self> {
+        if value_area <= 0.0 || value_area > 1.0 || value_area.is_nan() {
+            return Err(TaError::InvalidParameter);
+        }
+        self.value_area = value_area;
+        Ok(self)
+    }
+
+    pub fn binning(&self) -> Binning {
+        self.binning
+    }
+
+    pub fn value_area(&self) -> f64 {
+        self.value_area
+    }
+
+    /// Starts a new range at the next period.
+    pub fn anchor(&mut self) {
+        self.bars.clear();
+        self.low = f64::INFINITY;
+        self.high = f64::NEG_INFINITY;
+        let width = self.bins.width();
+        self.bins.clear(0.0, width);
+    }
+
+    /// Returns the middle price and the volume of the covered bins, from the lowest price.
+    pub fn histogram(&self) -> Vec<(f64, f64)> {
+        self.bins.histogram()
+    }
+
+    /// Returns the volume of the bins overlapping the prices from _low_ to _high_.
+    pub fn volume_between(&self, low: f64, high: f64) -> f64 {
+        self.bins.volume_between(low, high)
+    }
+
+    /// Returns the middle price of the nearest bin above the bin of _price_ holding at least
+    /// _min_share_ of the volume of the range.
+    pub fn node_above(&self, price: f64, min_share: f64) -> Option<f64> {
+        self.bins.node_above(price, min_share)
+    }
+
+    /// Returns the middle price of the nearest bin below the bin of _price_ holding at least
+    /// _min_share_ of the volume of the range.
+    pub fn node_below(&self, price: f64, min_share: f64) -> Option<f64> {
+        self.bins.node_below(price, min_share)
+    }
+
+    /// Returns the price ranges, from the lowest, where all the bins hold at most _max_share_
+    /// of the volume of the range, and that are surrounded by heavier bins.
+    pub fn gaps(&self, max_share: f64) -> Vec<(f64, f64)> {
+        self.bins.gaps(max_share)
+    }
+
+    // First and last bins covered by a bar. A high on the edge of a bin does not reach into
+    // it, unlike a flat bar.
+    fn span(&self, high: f64, low: f64) -> (i64, i64) {
+        let first = self.bins.bin(low);
+        let last = if high > low {
+            self.bins.bin_below(high)
+        } else {
+            self.bins.bin(high)
+        };
+        match self.binning {
+            Binning::TickSize(_) => (first, last),
+            Binning::Count(count) => {
+                let top = count as i64 - 1;
+                (first.clamp(0, top), last.clamp(first.clamp(0, top), top))
+            }
+        }
+    }
+
+    // Adds the volume of a bar to its bins, or removes it.
+    fn spread(&mut self, (high, low, volume): (f64, f64, f64), remove: bool) {
+        let (first, last) = self.span(high, low);
+        let range = high - low;
+        for bin in first..=last {
+            let weight = if range > 0.0 {
+                let bin_low = self.bins.lower_edge(bin);
+                let overlap = high.min(bin_low + self.bins.width()) - low.max(bin_low);
+                volume * overlap.max(0.0) / range
+            } else {
+                volume
+            };
+            if remove {
+                self.bins.remove(bin, weight);
+            } else {
+                self.bins.add(bin, weight);
+            }
+        }
+    }
+
+    fn rebuild(&mut self, count: usize) {
+        self.low = f64::INFINITY;
+        self.high = f64::NEG_INFINITY;
+        for &(high, low, _) in self.bars.iter() {
+            self.low = self.low.min(low);
+            self.high = self.high.max(high);
+        }
+        self.bins
+            .clear(self.low, (self.high - self.low) / count as f64);
+        for i in 0..self.bars.len() {
+            self.spread(self.bars[i], false);
+        }
+    }
+
+    fn output(&self) -> VolumeProfileOutput {
+        let bins: Vec<(i64, f64)> = self.bins.iter().collect();
+        if bins.is_empty() {
+            return VolumeProfileOutput {
+                poc: f64::NAN,
+                value_area_high: f64::NAN,
+                value_area_low: f64::NAN,
+            };
+        }
+
+        let mut poc = 0;
+        for (i, &(_, volume)) in bins.iter().enumerate() {
+            if volume > bins[poc].1 {
+                poc = i;
+            }
+        }
+
+        let total: f64 = bins.iter().map(|&(_, volume)| volume).sum();
+        let (mut low, mut high) = (poc, poc);
+        let mut volume = bins[poc].1;
+        while volume < self.value_area * total && (low > 0 || high + 1 < bins.len()) {
+            let above = bins.get(high + 1).map(|&(_, volume)| volume);
+            let below = if low > 0 { Some(bins[low - 1].1) } else { None };
+            match (above, below) {
+                (Some(above), Some(below)) if below > above => {
+                    low -= 1;
+                    volume += below;
+                }
+                (Some(above), _) => {
+                    high += 1;
+                    volume += above;
+                }
+                (None, Some(below)) => {
+                    low -= 1;
+                    volume += below;
+                }
+                (None, None) => break,
+            }
+        }
+
+        VolumeProfileOutput {
+            poc: self.bins.middle(bins[poc].0),
+            value_area_high: self.bins.lower_edge(bins[high].0.saturating_add(1)),
+            value_area_low: self.bins.lower_edge(bins[low].0),
+        }
+    }
+}
+
+impl Lookback for VolumeProfile {
+    fn lookback(&self) -> usize {
+        self.period.map_or(0, |period| period - 1)
+    }
+}
+
+impl<T: High + Low + Volume> Next<&T> for VolumeProfile {
+    type Output = VolumeProfileOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low, volume) = (input.high(), input.low(), input.volume());
+        if !(high.is_finite() && low.is_finite() && volume.is_finite()) {
+            return self.output();
+        }
+        let (high, low) = (high.max(low), high.min(low));
+        let bar = (high, low, volume);
+
+        match self.binning {
+            Binning::TickSize(size) => {
+                if (high / size).ceil() - (low / size).floor() > MAX_BAR_BINS {
+                    return self.output();
+                }
+                if self.period.is_some_and(|period| self.bars.len() == period) {
+                    let old = self.bars.pop_front().unwrap();
+                    self.spread(old, true);
+                }
+                if self.period.is_some() {
+                    self.bars.push_back(bar);
+                }
+                self.spread(bar, false);
+            }
+            Binning::Count(count) => {
+                let mut outdated = high > self.high || low < self.low;
+                if self.period.is_some_and(|period| self.bars.len() == period) {
+                    let old = self.bars.pop_front().unwrap();
+                    if old.0 >= self.high || old.1 <= self.low {
+                        outdated = true;
+                    } else if !outdated {
+                        self.spread(old, true);
+                    }
+                }
+                self.bars.push_back(bar);
+                if outdated {
+                    self.rebuild(count);
+                } else {
+                    self.spread(bar, false);
+                }
+            }
+        }
+        self.output()
+    }
+}
+
+impl Reset for VolumeProfile {
+    fn reset(&mut self) {
+        self.anchor();
+    }
+}
+
+impl Default for VolumeProfile {
+    fn default() -> Self {
+        Self::new(100, Binning::Count(24)).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.period {
+            Some(period) => write!(f, "VP({}, {})", period, self.binning),
+            None => write!(f, "VP(anchored, {})", self.binning),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, volume: f64) -> Bar {
+        Bar::new().high(high).low(low).close(low).volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeProfile::new(0, Binning::Count(10)).is_err());
+        assert!(VolumeProfile::new(10, Binning::Count(0)).is_err());
+        assert!(VolumeProfile::new(10, Binning::TickSize(0.0)).is_err());
+        assert!(VolumeProfile::anchored(Binning::TickSize(-1.0)).is_err());
+        assert!(VolumeProfile::new(1, Binning::TickSize(0.25)).is_ok());
+        assert!(VolumeProfile::anchored(Binning::Count(1)).is_ok());
+
+        let profile = VolumeProfile::default();
+        assert!(profile.clone().with_value_area(0.0).is_err());
+        assert!(profile.clone().with_value_area(1.1).is_err());
+        assert_eq!(profile.with_value_area(1.0).unwrap().value_area(), 1.0);
+    }
+
+    #[test]
+    fn test_next_tick_size() {
+        let mut profile = VolumeProfile::new(3, Binning::TickSize(1.0)).unwrap();
+
+        let out = profile.next(&bar(12.0, 10.0, 200.0));
+        assert_eq!(out.poc, 10.5);
+        assert_eq!((out.value_area_low, out.value_area_high), (10.0, 12.0));
+
+        profile.next(&bar(11.0, 10.0, 300.0));
+        let out = profile.next(&bar(13.5, 11.5, 600.0));
+        assert_eq!(
+            profile.histogram(),
+            vec![(10.5, 400.0), (11.5, 250.0), (12.5, 300.0), (13.5, 150.0)]
+        );
+        assert_eq!(out.poc, 10.5);
+        // 400 + 300 from above + 250 from above = 950 >= 770
+        assert_eq!((out.value_area_low, out.value_area_high), (10.0, 13.0));
+
+        // the first bar left the window
+        let out = profile.next(&bar(13.0, 13.0, 100.0));
+        assert_eq!(
+            profile.histogram(),
+            vec![(10.5, 300.0), (11.5, 150.0), (12.5, 300.0), (13.5, 250.0)]
+        );
+        assert_eq!(out.poc, 10.5);
+        assert_eq!((out.value_area_low, out.value_area_high), (10.0, 13.0));
+    }
+
+    #[test]
+    fn test_next_count() {
+        let mut profile = VolumeProfile::new(10, Binning::Count(4)).unwrap();
+
+        // flat range, a single bin
+        let out = profile.next(&bar(10.0, 10.0, 100.0));
+        assert_eq!(out.poc, 10.0);
+        assert_eq!(profile.histogram(), vec![(10.0, 100.0)]);
+
+        let out = profile.next(&bar(14.0, 12.0, 100.0));
+        // no bar covers the bin from 11 to 12
+        assert_eq!(
+            profile.histogram(),
+            vec![(10.5, 100.0), (12.5, 50.0), (13.5, 50.0)]
+        );
+        assert_eq!(out.poc, 10.5);
+        // the bin below the POC does not exist
+        assert_eq!((out.value_area_low, out.value_area_high), (10.0, 13.0));
+    }
+
+    #[test]
+    fn test_value_area() {
+        let mut profile = VolumeProfile::new(10, Binning::TickSize(1.0))
+            .unwrap()
+            .with_value_area(0.5)
+            .unwrap();
+        profile.next(&bar(10.0, 10.0, 100.0));
+        profile.next(&bar(11.0, 11.0, 300.0));
+        profile.next(&bar(12.0, 12.0, 200.0));
+        let out = profile.next(&bar(13.0, 13.0, 400.0));
+
+        assert_eq!(out.poc, 13.5);
+        // 400 + 200 >= 500
+        assert_eq!((out.value_area_low, out.value_area_high), (12.0, 14.0));
+    }
+
+    #[test]
+    fn test_anchored() {
+        let mut profile = VolumeProfile::anchored(Binning::TickSize(1.0)).unwrap();
+        profile.next(&bar(10.0, 10.0, 100.0));
+        profile.next(&bar(11.0, 11.0, 300.0));
+        assert_eq!(profile.histogram(), vec![(10.5, 100.0), (11.5, 300.0)]);
+        assert_eq!(profile.lookback(), 0);
+
+        profile.anchor();
+        assert_eq!(profile.histogram(), vec![]);
+        let out = profile.next(&bar(20.0, 20.0, 100.0));
+        assert_eq!(out.poc, 20.5);
+        assert_eq!(profile.histogram(), vec![(20.5, 100.0)]);
+    }
+
+    #[test]
+    fn test_volume_between() {
+        let mut profile = VolumeProfile::anchored(Binning::TickSize(1.0)).unwrap();
+        assert_eq!(profile.volume_between(0.0, 100.0), 0.0);
+
+        profile.next(&bar(12.0, 10.0, 200.0));
+        profile.next(&bar(14.0, 14.0, 100.0));
+
+        assert_eq!(profile.volume_between(10.5, 11.5), 200.0);
+        assert_eq!(profile.volume_between(11.0, 11.0), 100.0);
+        assert_eq!(profile.volume_between(0.0, 100.0), 300.0);
+        assert_eq!(profile.volume_between(12.0, 13.9), 0.0);
+        assert_eq!(profile.volume_between(15.1, 20.0), 0.0);
+        assert_eq!(profile.volume_between(5.0, 9.0), 0.0);
+        assert_eq!(profile.volume_between(12.0, 11.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_rolling_count() {
+        // The incremental updates match a profile of the bars of the window only.
+        let bars: Vec<Bar> = (0..30)
+            .map(|i| {
+                let low = 10.0 + (i % 7) as f64 * 0.5;
+                bar(low + (i % 3) as f64, low, 100.0 + i as f64)
+            })
+            .collect();
+        let mut profile = VolumeProfile::new(5, Binning::Count(6)).unwrap();
+        for (i, input) in bars.iter().enumerate() {
+            let out = profile.next(input);
+            let mut fresh = VolumeProfile::new(5, Binning::Count(6)).unwrap();
+            let mut expected = None;
+            for input in &bars[i.saturating_sub(4)..=i] {
+                expected = Some(fresh.next(input));
+            }
+            assert_eq!(Some(out), expected);
+            let histogram: Vec<(f64, f64)> = profile
+                .histogram()
+                .iter()
+                .map(|&(price, volume)| (round(price), round(volume)))
+                .collect();
+            let fresh_histogram: Vec<(f64, f64)> = fresh
+                .histogram()
+                .iter()
+                .map(|&(price, volume)| (round(price), round(volume)))
+                .collect();
+            assert_eq!(histogram, fresh_histogram);
+        }
+    }
+
+    #[test]
+    fn test_next_invalid_bars() {
+        let mut profile = VolumeProfile::new(3, Binning::TickSize(0.01)).unwrap();
+        let out = profile.next(&bar(f64::INFINITY, 1.0, 100.0));
+        assert!(out.poc.is_nan());
+        assert!(profile.next(&bar(1e12, 1.0, 100.0)).poc.is_nan());
+        assert!(profile.next(&bar(2.0, f64::NAN, 100.0)).poc.is_nan());
+        assert_eq!(profile.histogram(), vec![]);
+
+        profile.next(&bar(1.0, 1.0, 100.0));
+        let out = profile.next(&bar(2.0, 1.0, f64::NAN));
+        assert_eq!(round(out.poc), 1.005);
+        assert_eq!(profile.histogram().len(), 1);
+
+        let mut profile = VolumeProfile::anchored(Binning::Count(10)).unwrap();
+        profile.next(&bar(f64::NEG_INFINITY, 1.0, 100.0));
+        let out = profile.next(&bar(2.0, 1.0, 100.0));
+        assert_eq!(out.poc, 1.05);
+    }
+
+    #[test]
+    fn test_profile_queries() {
+        let mut profile = VolumeProfile::anchored(Binning::TickSize(1.0)).unwrap();
+        profile.next(&bar(11.0, 10.0, 300.0));
+        profile.next(&bar(12.0, 11.0, 100.0));
+        profile.next(&bar(14.0, 13.0, 300.0));
+        profile.next(&bar(15.0, 14.0, 100.0));
+
+        assert_eq!(profile.node_above(11.5, 0.25), Some(13.5));
+        assert_eq!(profile.node_above(13.5, 0.25), None);
+        assert_eq!(profile.node_above(13.5, 0.1), Some(14.5));
+        assert_eq!(profile.node_below(13.2, 0.25), Some(10.5));
+        assert_eq!(profile.node_below(10.9, 0.0), None);
+
+        assert_eq!(profile.gaps(0.2), vec![(11.0, 13.0)]);
+        assert_eq!(profile.gaps(0.1), vec![(12.0, 13.0)]);
+        assert!(profile.gaps(0.5).is_empty());
+    }
+
+    #[test]
+    fn test_next_without_volume() {
+        let mut profile = VolumeProfile::new(3, Binning::TickSize(1.0)).unwrap();
+        let out = profile.next(&bar(12.0, 10.0, 0.0));
+        assert_eq!(out.poc, 10.5);
+        assert_eq!((out.value_area_low, out.value_area_high), (10.0, 11.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut profile = VolumeProfile::new(3, Binning::TickSize(1.0)).unwrap();
+        profile.next(&bar(12.0, 10.0, 200.0));
+
+        profile.reset();
+        let out = profile.next(&bar(21.0, 20.0, 100.0));
+        assert_eq!(out.poc, 20.5);
+        assert_eq!(profile.histogram(), vec![(20.5, 100.0)]);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let profile = VolumeProfile::new(20, Binning::Count(10)).unwrap();
+        assert_eq!(profile.lookback(), 19);
+    }
+
+    #[test]
+    fn test_default() {
+        let profile = VolumeProfile::default();
+        assert_eq!(profile.binning(), Binning::Count(24));
+        assert_eq!(profile.value_area(), 0.7);
+    }
+
+    #[test]
+    fn test_display() {
+        let profile = VolumeProfile::new(20, Binning::TickSize(0.25)).unwrap();
+        assert_eq!(format!("{}", profile), "VP(20, tick 0.25)");
+        let profile = VolumeProfile::anchored(Binning::Count(24)).unwrap();
+        assert_eq!(format!("{}", profile), "VP(anchored, 24 bins)");
+    }
+}
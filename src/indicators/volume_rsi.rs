@@ -0,0 +1,244 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage;
+use crate::units::{OutputUnit, Percent};
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume-weighted relative strength index (Volume RSI).
+///
+/// A variant of the [RSI](struct.RelativeStrengthIndex.html) where each up or down move is
+/// multiplied by the volume of its bar before it is smoothed. A move made on heavy volume
+/// weighs more than the same move on thin volume, so the oscillator separates conviction
+/// moves from drifts.
+///
+/// Like the RSI, the moves are smoothed with an exponential moving average by default, but any
+/// moving average implementing [NewWithPeriod](../trait.NewWithPeriod.html) can be used
+/// instead, e.g. [RunningMovingAverage](struct.RunningMovingAverage.html).
+///
+/// The oscillator returns output in the range of 0..100.
+///
+/// # Formula
+///
+/// VRSI<sub>t</sub> = MA<sub>Ut</sub> * 100 / (MA<sub>Ut</sub> + MA<sub>Dt</sub>)
+///
+/// If the close is higher than the previous close:
+///
+/// U = (close<sub>t</sub> - close<sub>t-1</sub>) * volume<sub>t</sub>
+///
+/// D = 0
+///
+/// Otherwise:
+///
+/// U = 0
+///
+/// D = (close<sub>t-1</sub> - close<sub>t</sub>) * volume<sub>t</sub>
+///
+/// The first bar has no previous close, so both moves are 0. While both averages are 0 the
+/// output is 50.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of the moving average (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ExponentialMovingAverage as Ema, VolumeRsi};
+/// use ta::{DataItem, Next};
+///
+/// let bar = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut vrsi = VolumeRsi::<Ema>::new(3).unwrap();
+/// assert_eq!(vrsi.next(&bar(10.0, 1000.0)), 50.0);
+/// assert_eq!(vrsi.next(&bar(10.5, 2000.0)), 100.0);
+/// assert_eq!(vrsi.next(&bar(10.0, 1000.0)), 50.0);
+/// // The same drop on four times the volume.
+/// assert_eq!(vrsi.next(&bar(9.5, 4000.0)), 10.0);
+/// ```
+///
+#[doc(alias = "VRSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeRsi<MA = ExponentialMovingAverage> {
+    up_ma: MA,
+    down_ma: MA,
+    prev_close: Option<f64>,
+}
+
+impl<MA: NewWithPeriod> VolumeRsi<MA> {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            up_ma: MA::new_with_period(period)?,
+            down_ma: MA::new_with_period(period)?,
+            prev_close: None,
+        })
+    }
+}
+
+impl<MA: NewWithPeriod> NewWithPeriod for VolumeRsi<MA> {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl<MA: Period> Period for VolumeRsi<MA> {
+    fn period(&self) -> usize {
+        self.up_ma.period()
+    }
+}
+
+impl<MA: Period> Lookback for VolumeRsi<MA> {
+    fn lookback(&self) -> usize {
+        self.up_ma.period()
+    }
+}
+
+impl<MA> OutputUnit for VolumeRsi<MA> {
+    type Unit = Percent;
+}
+
+impl<MA: Next<f64, Output = f64>, T: Close + Volume> Next<&T> for VolumeRsi<MA> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        let change = match self.prev_close.replace(close) {
+            Some(prev_close) => (close - prev_close) * input.volume(),
+            None => 0.0,
+        };
+
+        let up_ma = self.up_ma.next(change.max(0.0));
+        let down_ma = self.down_ma.next((-change).max(0.0));
+        if up_ma + down_ma == 0.0 {
+            50.0
+        } else {
+            100.0 * up_ma / (up_ma + down_ma)
+        }
+    }
+}
+
+impl<MA: Reset> Reset for VolumeRsi<MA> {
+    fn reset(&mut self) {
+        self.up_ma.reset();
+        self.down_ma.reset();
+        self.prev_close = None;
+    }
+}
+
+impl Default for VolumeRsi {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl<MA: Period> fmt::Display for VolumeRsi<MA> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VRSI({})", self.up_ma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage as Ema, RunningMovingAverage as Rma};
+    use crate::test_helper::*;
+
+    fn bar(close: f64, volume: f64) -> Bar {
+        Bar::new().close(close).volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeRsi::<Ema>::new(0).is_err());
+        assert!(VolumeRsi::<Ema>::new(1).is_ok());
+        assert!(VolumeRsi::<Rma>::new(0).is_err());
+        assert!(VolumeRsi::<Rma>::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vrsi = VolumeRsi::<Ema>::new(3).unwrap();
+
+        let test_data = vec![
+            // close, volume, vrsi
+            (10.0, 1000.0, 50.0),
+            (10.5, 2000.0, 100.0),
+            (10.0, 1000.0, 50.0),
+            (9.5, 4000.0, 10.0),
+            (10.0, 500.0, 25.0),
+            (10.5, 3000.0, 75.0),
+        ];
+
+        for (close, volume, expected) in test_data {
+            assert_eq!(round(vrsi.next(&bar(close, volume))), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_with_rma() {
+        let mut vrsi = VolumeRsi::<Rma>::new(3).unwrap();
+
+        let test_data = vec![
+            // close, volume, vrsi
+            (10.0, 1000.0, 50.0),
+            (10.5, 2000.0, 100.0),
+            (10.0, 1000.0, 57.143),
+            (9.5, 4000.0, 16.0),
+            (10.0, 500.0, 25.991),
+        ];
+
+        for (close, volume, expected) in test_data {
+            assert_eq!(round(vrsi.next(&bar(close, volume))), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut vrsi = VolumeRsi::<Ema>::new(3).unwrap();
+        vrsi.next(&bar(10.0, 1000.0));
+        assert_eq!(vrsi.next(&bar(10.0, 1000.0)), 50.0);
+        // No volume, no move.
+        assert_eq!(vrsi.next(&bar(11.0, 0.0)), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vrsi = VolumeRsi::<Ema>::new(3).unwrap();
+        vrsi.next(&bar(10.0, 1000.0));
+        vrsi.next(&bar(10.5, 2000.0));
+
+        vrsi.reset();
+        assert_eq!(vrsi.next(&bar(10.0, 1000.0)), 50.0);
+        assert_eq!(vrsi.next(&bar(9.5, 1000.0)), 0.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let vrsi = VolumeRsi::<Ema>::new(14).unwrap();
+        assert_eq!(vrsi.period(), 14);
+        assert_eq!(vrsi.lookback(), 14);
+    }
+
+    #[test]
+    fn test_default() {
+        VolumeRsi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vrsi = VolumeRsi::<Ema>::new(14).unwrap();
+        assert_eq!(format!("{}", vrsi), "VRSI(14)");
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::units::{OutputUnit, Price};
+use crate::{Close, Lookback, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume weighted moving average (VWMA).
+///
+/// The average close of the last _period_ periods weighted by volume, so the periods with the
+/// most trading count the most. Unlike the [anchored VWAP](struct.AnchoredVwap.html), it is
+/// computed over a rolling window.
+///
+/// # Formula
+///
+/// VWMA = Σ (close * volume) / Σ volume
+///
+/// Where the sums run over the last _period_ periods.
+///
+/// When the volume of the whole window is 0, the closes are weighted equally, like an
+/// [SMA](struct.SimpleMovingAverage.html).
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedMovingAverage;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |close: f64, volume: f64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+/// assert_eq!(vwma.next(&bar(10.0, 100.0)), 10.0);
+/// assert_eq!(vwma.next(&bar(13.0, 200.0)), 12.0);
+/// assert_eq!(vwma.next(&bar(16.0, 200.0)), 14.5);
+/// ```
+///
+/// # Links
+///
+/// * [Volume Weighted Moving Average, Investopedia](https://www.investopedia.com/articles/trading/11/trading-with-vwap-mvwap.asp)
+///
+#[doc(alias = "VWMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeWeightedMovingAverage {
+    period: usize,
+    index: usize,
+    count: usize,
+    // Close and volume of the last period periods.
+    deque: Box<[(f64, f64)]>,
+    price_sum: f64,
+    price_volume_sum: f64,
+    volume_sum: f64,
+    // Number of periods with volume in the window.
+    volume_count: usize,
+}
+
+impl VolumeWeightedMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![(0.0, 0.0); period].into_boxed_slice(),
+                price_sum: 0.0,
+                price_volume_sum: 0.0,
+                volume_sum: 0.0,
+                volume_count: 0,
+            }),
+        }
+    }
+}
+
+impl Period for VolumeWeightedMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for VolumeWeightedMovingAverage {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for VolumeWeightedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (close, volume) = (input.close(), input.volume());
+
+        if self.count == self.period {
+            let (old_close, old_volume) = self.deque[self.index];
+            self.price_sum -= old_close;
+            self.price_volume_sum -= old_close * old_volume;
+            self.volume_sum -= old_volume;
+            if old_volume != 0.0 {
+                self.volume_count -= 1;
+            }
+        } else {
+            self.count += 1;
+        }
+
+        self.deque[self.index] = (close, volume);
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        self.price_sum += close;
+        self.price_volume_sum += close * volume;
+        self.volume_sum += volume;
+        if volume != 0.0 {
+            self.volume_count += 1;
+        }
+
+        if self.volume_count == 0 {
+            // Drop the rounding errors left by the volume that went out of the window.
+            self.price_volume_sum = 0.0;
+            self.volume_sum = 0.0;
+            self.price_sum / self.count as f64
+        } else {
+            self.price_volume_sum / self.volume_sum
+        }
+    }
+}
+
+impl Reset for VolumeWeightedMovingAverage {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.price_sum = 0.0;
+        self.price_volume_sum = 0.0;
+        self.volume_sum = 0.0;
+        self.volume_count = 0;
+        for i in 0..self.period {
+            self.deque[i] = (0.0, 0.0);
+        }
+    }
+}
+
+impl OutputUnit for VolumeWeightedMovingAverage {
+    type Unit = Price;
+}
+
+impl Default for VolumeWeightedMovingAverage {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeWeightedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VWMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Vwma = VolumeWeightedMovingAverage;
+
+    fn bar(close: f64, volume: f64) -> Bar {
+        Bar::new().close(close).volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Vwma::new(0).is_err());
+        assert!(Vwma::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vwma = Vwma::new(3).unwrap();
+
+        assert_eq!(vwma.next(&bar(10.0, 100.0)), 10.0);
+        assert_eq!(vwma.next(&bar(12.0, 300.0)), 11.5);
+        assert_eq!(round(vwma.next(&bar(11.0, 100.0))), 11.4);
+        assert_eq!(round(vwma.next(&bar(14.0, 100.0))), 12.2);
+        assert_eq!(round(vwma.next(&bar(9.0, 400.0))), 10.167);
+    }
+
+    #[test]
+    fn test_next_without_volume() {
+        let mut vwma = Vwma::new(2).unwrap();
+
+        assert_eq!(vwma.next(&bar(10.0, 0.0)), 10.0);
+        assert_eq!(vwma.next(&bar(12.0, 0.0)), 11.0);
+        assert_eq!(round(vwma.next(&bar(13.0, 0.1))), 13.0);
+        assert_eq!(round(vwma.next(&bar(15.0, 0.2))), 14.333);
+
+        // The volume leaves the window.
+        assert_eq!(round(vwma.next(&bar(17.0, 0.0))), 15.0);
+        assert_eq!(vwma.next(&bar(19.0, 0.0)), 18.0);
+        assert_eq!(vwma.next(&bar(21.0, 0.0)), 20.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vwma = Vwma::new(3).unwrap();
+        vwma.next(&bar(10.0, 100.0));
+        vwma.next(&bar(12.0, 300.0));
+
+        vwma.reset();
+        assert_eq!(vwma.next(&bar(5.0, 10.0)), 5.0);
+        assert_eq!(vwma.next(&bar(8.0, 20.0)), 7.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Vwma::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Vwma::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "VWMA(20)");
+    }
+}
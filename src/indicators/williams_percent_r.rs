@@ -0,0 +1,195 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Maximum, Minimum};
+use crate::units::{OutputUnit, Percent};
+use crate::{Close, High, Lookback, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams %R.
+///
+/// Developed by Larry Williams, %R is a momentum oscillator that shows where the close is
+/// relative to the highest high of the last _period_ periods. It ranges from -100 to 0:
+/// readings above -20 are considered overbought, readings below -80 oversold.
+///
+/// It is the inverted [fast stochastic oscillator](struct.FastStochastic.html). The highest high
+/// and the lowest low are tracked by [Maximum](struct.Maximum.html) and
+/// [Minimum](struct.Minimum.html), in amortized `O(1)` per period.
+///
+/// # Formula
+///
+/// %R = (H<sub>n</sub> - C<sub>t</sub>) / (H<sub>n</sub> - L<sub>n</sub>) * -100
+///
+/// Where:
+///
+/// * C<sub>t</sub> - close price of the current period
+/// * L<sub>n</sub> - lowest price for the last _n_ periods
+/// * H<sub>n</sub> - highest price for the last _n_ periods
+///
+/// When the highest and the lowest prices are the same, %R is -50.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::WilliamsPercentR;
+/// use ta::Next;
+///
+/// let mut wr = WilliamsPercentR::new(5).unwrap();
+/// assert_eq!(wr.next(20.0), -50.0);
+/// assert_eq!(wr.next(30.0), 0.0);
+/// assert_eq!(wr.next(40.0), 0.0);
+/// assert_eq!(wr.next(35.0), -25.0);
+/// assert_eq!(wr.next(15.0), -100.0);
+/// ```
+///
+/// # Links
+///
+/// * [Williams %R, Wikipedia](https://en.wikipedia.org/wiki/Williams_%25R)
+///
+#[doc(alias = "%R")]
+#[doc(alias = "WPR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WilliamsPercentR {
+    period: usize,
+    highest: Maximum,
+    lowest: Minimum,
+}
+
+impl WilliamsPercentR {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                highest: Maximum::new(period)?,
+                lowest: Minimum::new(period)?,
+            }),
+        }
+    }
+
+    fn calc(highest: f64, lowest: f64, close: f64) -> f64 {
+        if highest == lowest {
+            -50.0
+        } else {
+            (highest - close) / (highest - lowest) * -100.0
+        }
+    }
+}
+
+impl Period for WilliamsPercentR {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for WilliamsPercentR {
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+}
+
+impl OutputUnit for WilliamsPercentR {
+    type Unit = Percent;
+}
+
+impl Next<f64> for WilliamsPercentR {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let highest = self.highest.next(input);
+        let lowest = self.lowest.next(input);
+        Self::calc(highest, lowest, input)
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for WilliamsPercentR {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let highest = self.highest.next(input.high());
+        let lowest = self.lowest.next(input.low());
+        Self::calc(highest, lowest, input.close())
+    }
+}
+
+impl Reset for WilliamsPercentR {
+    fn reset(&mut self) {
+        self.highest.reset();
+        self.lowest.reset();
+    }
+}
+
+impl Default for WilliamsPercentR {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for WilliamsPercentR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WPR({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(WilliamsPercentR);
+
+    #[test]
+    fn test_new() {
+        assert!(WilliamsPercentR::new(0).is_err());
+        assert!(WilliamsPercentR::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let test_data = vec![
+            // high, low , close, %R
+            (20.0, 20.0, 20.0, -50.0), // min = 20, max = 20
+            (30.0, 10.0, 25.0, -25.0), // min = 10, max = 30
+            (40.0, 20.0, 16.0, -80.0), // min = 10, max = 40
+            (35.0, 15.0, 19.0, -70.0), // min = 10, max = 40
+            (30.0, 20.0, 25.0, -60.0), // min = 15, max = 40
+            (35.0, 25.0, 30.0, -25.0), // min = 15, max = 35
+        ];
+
+        let mut wr = WilliamsPercentR::new(3).unwrap();
+
+        for (high, low, close, expected) in test_data {
+            let input_bar = Bar::new().high(high).low(low).close(close);
+            assert_eq!(wr.next(&input_bar), expected);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut wr = WilliamsPercentR::new(3).unwrap();
+        assert_eq!(wr.next(10.0), -50.0);
+        assert_eq!(wr.next(20.0), 0.0);
+        assert_eq!(wr.next(15.0), -50.0);
+
+        wr.reset();
+        assert_eq!(wr.next(15.0), -50.0);
+        assert_eq!(wr.next(10.0), -100.0);
+    }
+
+    #[test]
+    fn test_default() {
+        WilliamsPercentR::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = WilliamsPercentR::new(21).unwrap();
+        assert_eq!(format!("{}", indicator), "WPR(21)");
+    }
+}
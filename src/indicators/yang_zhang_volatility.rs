@@ -0,0 +1,229 @@
+use std::fmt;
+
+use super::rogers_satchell_volatility::rogers_satchell_variance;
+use crate::errors::{Result, TaError};
+use crate::indicators::{SimpleMovingAverage, Variance, VarianceMode};
+use crate::math;
+use crate::{Close, High, Lookback, Low, Next, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Yang-Zhang volatility.
+///
+/// Volatility estimator by Dennis Yang and Qiang Zhang, combining the overnight volatility
+/// (close to next open), the open-to-close volatility and the
+/// [Rogers-Satchell](struct.RogersSatchellVolatility.html) range volatility of the last
+/// _period_ bars. Unlike the
+/// [Parkinson](struct.ParkinsonVolatility.html) and
+/// [Garman-Klass](struct.GarmanKlassVolatility.html) estimators it handles both opening gaps
+/// and drift, which makes it the most accurate of the range-based estimators on stocks.
+///
+/// The output is annualized, e.g. 0.25 for a volatility of 25% a year. The prices must be
+/// positive. The first bar has no previous close, so it only sets the reference close and
+/// returns 0.
+///
+/// # Formula
+///
+/// σ = √((σ<sub>o</sub><sup>2</sup> + k * σ<sub>c</sub><sup>2</sup> + (1 - k) * σ<sub>rs</sub><sup>2</sup>) * _bars_per_year_)
+///
+/// Where:
+///
+/// * _σ<sub>o</sub><sup>2</sup>_ - sample variance of the overnight returns
+///   ln(O<sub>i</sub> / C<sub>i-1</sub>)
+/// * _σ<sub>c</sub><sup>2</sup>_ - sample variance of the open-to-close returns
+///   ln(C<sub>i</sub> / O<sub>i</sub>)
+/// * _σ<sub>rs</sub><sup>2</sup>_ - mean of ln(H<sub>i</sub> / C<sub>i</sub>) * ln(H<sub>i</sub> / O<sub>i</sub>) + ln(L<sub>i</sub> / C<sub>i</sub>) * ln(L<sub>i</sub> / O<sub>i</sub>)
+/// * _k_ - 0.34 / (1.34 + (n + 1) / (n - 1))
+///
+/// All of them run over the _n_ bars of the window that have a previous close. The volatility
+/// is 0 while there are less than 2 of them.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 1). Default is 20.
+/// * _bars_per_year_ - number of bars in a year, e.g. 252 for daily bars of stocks or 52 for
+///   weekly bars (greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::YangZhangVolatility;
+/// use ta::{DataItem, Next};
+///
+/// let bar = |open: f64, high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut yz = YangZhangVolatility::new(3, 252.0).unwrap();
+/// assert_eq!(yz.next(&bar(100.0, 102.0, 99.0, 101.0)), 0.0);
+/// yz.next(&bar(102.0, 103.0, 100.0, 100.5));
+/// assert_eq!((yz.next(&bar(99.0, 101.0, 98.0, 100.0)) * 1000.0).round(), 413.0);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct YangZhangVolatility {
+    period: usize,
+    bars_per_year: f64,
+    count: usize,
+    overnight: Variance,
+    open_to_close: Variance,
+    rogers_satchell: SimpleMovingAverage,
+    prev_close: Option<f64>,
+}
+
+impl YangZhangVolatility {
+    pub fn new(period: usize, bars_per_year: f64) -> Result<Self> {
+        if period < 2 || bars_per_year <= 0.0 || !bars_per_year.is_finite() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            bars_per_year,
+            count: 0,
+            overnight: Variance::with_mode(period, VarianceMode::Sample)?,
+            open_to_close: Variance::with_mode(period, VarianceMode::Sample)?,
+            rogers_satchell: SimpleMovingAverage::new(period)?,
+            prev_close: None,
+        })
+    }
+
+    pub fn bars_per_year(&self) -> f64 {
+        self.bars_per_year
+    }
+}
+
+impl Period for YangZhangVolatility {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Lookback for YangZhangVolatility {
+    fn lookback(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for YangZhangVolatility {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (open, high, low, close) = (input.open(), input.high(), input.low(), input.close());
+        let prev_close = match self.prev_close.replace(close) {
+            Some(prev_close) => prev_close,
+            None => return 0.0,
+        };
+
+        let overnight = self.overnight.next(math::ln(open / prev_close));
+        let open_to_close = self.open_to_close.next(math::ln(close / open));
+        let rogers_satchell = self
+            .rogers_satchell
+            .next(rogers_satchell_variance(open, high, low, close));
+        if self.count < self.period {
+            self.count += 1;
+        }
+        if self.count < 2 {
+            return 0.0;
+        }
+
+        let n = self.count as f64;
+        let k = 0.34 / (1.34 + (n + 1.0) / (n - 1.0));
+        let variance = overnight + k * open_to_close + (1.0 - k) * rogers_satchell;
+        (variance.max(0.0) * self.bars_per_year).sqrt()
+    }
+}
+
+impl Reset for YangZhangVolatility {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.overnight.reset();
+        self.open_to_close.reset();
+        self.rogers_satchell.reset();
+        self.prev_close = None;
+    }
+}
+
+impl Default for YangZhangVolatility {
+    fn default() -> Self {
+        Self::new(20, 252.0).unwrap()
+    }
+}
+
+impl fmt::Display for YangZhangVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "YZ({}, {})", self.period, self.bars_per_year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(YangZhangVolatility::new(1, 252.0).is_err());
+        assert!(YangZhangVolatility::new(20, 0.0).is_err());
+        assert!(YangZhangVolatility::new(2, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut yz = YangZhangVolatility::new(3, 252.0).unwrap();
+
+        assert_eq!(yz.next(&bar(100.0, 102.0, 99.0, 101.0)), 0.0);
+        assert_eq!(yz.next(&bar(102.0, 103.0, 100.0, 100.5)), 0.0);
+        assert_eq!(round(yz.next(&bar(99.0, 101.0, 98.0, 100.0))), 0.413);
+        assert_eq!(round(yz.next(&bar(100.0, 100.5, 97.0, 97.5))), 0.357);
+        // the second bar left the window
+        assert_eq!(round(yz.next(&bar(98.0, 99.0, 97.5, 98.5))), 0.314);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut yz = YangZhangVolatility::new(3, 252.0).unwrap();
+        for _ in 0..4 {
+            assert_eq!(yz.next(&bar(100.0, 100.0, 100.0, 100.0)), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut yz = YangZhangVolatility::new(3, 252.0).unwrap();
+        yz.next(&bar(100.0, 102.0, 99.0, 101.0));
+        yz.next(&bar(102.0, 103.0, 100.0, 100.5));
+
+        yz.reset();
+        assert_eq!(yz.next(&bar(99.0, 101.0, 98.0, 100.0)), 0.0);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let yz = YangZhangVolatility::new(20, 252.0).unwrap();
+        assert_eq!(yz.period(), 20);
+        assert_eq!(yz.lookback(), 20);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(YangZhangVolatility::default().bars_per_year(), 252.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let yz = YangZhangVolatility::new(20, 252.0).unwrap();
+        assert_eq!(format!("{}", yz), "YZ(20, 252)");
+    }
+}
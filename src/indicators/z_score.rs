@@ -0,0 +1,277 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::StandardDeviation;
+use crate::{Close, Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling z-score.
+///
+/// Number of standard deviations between the value and the mean of the last _period_ values,
+/// e.g. 2 when the price is two standard deviations above its average. It puts any series on
+/// a common scale, prices, spreads or the output of another indicator, and is the base of most
+/// mean reversion rules.
+///
+/// Large outliers can be bounded [with a clamp](#method.with_clamp). When the values of the
+/// window are flat the z-score is undefined: it is 0 by default, or NaN with
+/// [FlatWindow::Nan](enum.FlatWindow.html) so that it can be told apart.
+///
+/// # Formula
+///
+/// Z = (x - μ) / σ
+///
+/// Where:
+///
+/// * _μ_ - mean of the last _period_ values, including _x_.
+/// * _σ_ - population [standard deviation](struct.StandardDeviation.html) of the same values.
+///
+/// The window is flat when _σ_ is 0 or less than a billionth of _|μ|_, which only rounding
+/// residues produce.
+///
+/// # Parameters
+///
+/// * _period_ - number of values (integer greater than 1). Default is 20.
+/// * _clamp_ - bound of the absolute z-score, set with [with_clamp](#method.with_clamp)
+///   (greater than 0). Not clamped by default.
+/// * _flat_ - [FlatWindow](enum.FlatWindow.html) output, set with
+///   [with_flat](#method.with_flat). Default is 0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{FlatWindow, ZScore};
+/// use ta::Next;
+///
+/// let mut z = ZScore::new(4).unwrap();
+/// z.next(10.0);
+/// z.next(12.0);
+/// z.next(11.0);
+/// assert_eq!((z.next(15.0) * 1000.0).round(), 1604.0);
+///
+/// let mut z = ZScore::new(3).unwrap().with_flat(FlatWindow::Nan);
+/// assert!(z.next(10.0).is_nan());
+/// ```
+///
+/// # Links
+///
+/// * [Standard score, Wikipedia](https://en.wikipedia.org/wiki/Standard_score)
+///
+#[doc(alias = "Z")]
+#[doc(alias = "Standard score")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ZScore {
+    sd: StandardDeviation,
+    clamp: Option<f64>,
+    flat: FlatWindow,
+}
+
+/// Output of the [ZScore](struct.ZScore.html) when the values of its window are flat.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlatWindow {
+    /// The value is at the mean, 0.
+    #[default]
+    Zero,
+    /// NaN, e.g. to skip the value downstream.
+    Nan,
+}
+
+impl ZScore {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            sd: StandardDeviation::new(period)?,
+            clamp: None,
+            flat: FlatWindow::Zero,
+        })
+    }
+
+    /// Bounds the z-score from -_clamp_ to _clamp_.
+    pub fn with_clamp(mut self, clamp: f64) -> Result<Self> {
+        if clamp <= 0.0 || clamp.is_nan() {
+            return Err(TaError::InvalidParameter);
+        }
+        self.clamp = Some(clamp);
+        Ok(self)
+    }
+
+    pub fn with_flat(mut self, flat: FlatWindow) -> Self {
+        self.flat = flat;
+        self
+    }
+
+    pub fn clamp(&self) -> Option<f64> {
+        self.clamp
+    }
+
+    pub fn flat(&self) -> FlatWindow {
+        self.flat
+    }
+}
+
+impl Period for ZScore {
+    fn period(&self) -> usize {
+        self.sd.period()
+    }
+}
+
+impl Lookback for ZScore {
+    fn lookback(&self) -> usize {
+        self.sd.lookback()
+    }
+}
+
+impl Next<f64> for ZScore {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let sd = self.sd.next(input);
+        let mean = self.sd.mean();
+        if sd == 0.0 || sd < mean.abs() * 1e-9 {
+            return match self.flat {
+                FlatWindow::Zero => 0.0,
+                FlatWindow::Nan => f64::NAN,
+            };
+        }
+
+        let z = (input - mean) / sd;
+        match self.clamp {
+            Some(clamp) => z.clamp(-clamp, clamp),
+            None => z,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for ZScore {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ZScore {
+    fn reset(&mut self) {
+        self.sd.reset();
+    }
+}
+
+impl Default for ZScore {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for ZScore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.clamp {
+            Some(clamp) => write!(f, "ZSCORE({}, {})", self.period(), clamp),
+            None => write!(f, "ZSCORE({})", self.period()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ZScore);
+
+    #[test]
+    fn test_new() {
+        assert!(ZScore::new(1).is_err());
+        assert!(ZScore::new(2).is_ok());
+
+        let z = ZScore::new(20).unwrap();
+        assert!(z.clone().with_clamp(0.0).is_err());
+        assert!(z.clone().with_clamp(f64::NAN).is_err());
+        assert_eq!(z.with_clamp(3.0).unwrap().clamp(), Some(3.0));
+    }
+
+    #[test]
+    fn test_next() {
+        let mut z = ZScore::new(4).unwrap();
+
+        assert_eq!(z.next(10.0), 0.0);
+        assert_eq!(round(z.next(12.0)), 1.0);
+        assert_eq!(round(z.next(11.0)), 0.0);
+        assert_eq!(round(z.next(15.0)), 1.604);
+        // 10 left the window
+        assert_eq!(round(z.next(9.0)), -1.27);
+    }
+
+    #[test]
+    fn test_next_clamp() {
+        let mut z = ZScore::new(4).unwrap().with_clamp(1.5).unwrap();
+
+        z.next(10.0);
+        z.next(12.0);
+        z.next(11.0);
+        assert_eq!(z.next(15.0), 1.5);
+        assert_eq!(round(z.next(9.0)), -1.27);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut z = ZScore::new(3).unwrap();
+        assert_eq!(z.next(4.2), 0.0);
+        assert_eq!(z.next(4.2), 0.0);
+
+        let mut z = ZScore::new(3).unwrap().with_flat(FlatWindow::Nan);
+        assert!(z.next(4.2).is_nan());
+        assert!(z.next(4.2).is_nan());
+        assert_eq!(round(z.next(5.2)), 1.414);
+    }
+
+    #[test]
+    fn test_next_flat_after_moves() {
+        // Rounding residues of the running deviation are not scaled up to a z-score.
+        let mut z = ZScore::new(3).unwrap().with_flat(FlatWindow::Nan);
+        z.next(1.872);
+        z.next(0.1);
+        z.next(1e6);
+        for _ in 0..3 {
+            z.next(1.1);
+        }
+        assert!(z.next(1.1).is_nan());
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut z = ZScore::new(4).unwrap();
+        z.next(&Bar::new().close(10));
+        assert_eq!(round(z.next(&Bar::new().close(12))), 1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut z = ZScore::new(4).unwrap();
+        z.next(10.0);
+        z.next(12.0);
+
+        z.reset();
+        assert_eq!(z.next(12.0), 0.0);
+        assert_eq!(round(z.next(10.0)), -1.0);
+    }
+
+    #[test]
+    fn test_default() {
+        let z = ZScore::default();
+        assert_eq!(z.period(), 20);
+        assert_eq!(z.clamp(), None);
+        assert_eq!(z.flat(), FlatWindow::Zero);
+    }
+
+    #[test]
+    fn test_display() {
+        let z = ZScore::new(20).unwrap();
+        assert_eq!(format!("{}", z), "ZSCORE(20)");
+        let z = z.with_clamp(3.0).unwrap();
+        assert_eq!(format!("{}", z), "ZSCORE(20, 3)");
+    }
+}
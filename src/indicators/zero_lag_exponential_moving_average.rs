@@ -0,0 +1,214 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage;
+use crate::{Close, Lookback, NewWithPeriod, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Zero-lag exponential moving average (ZLEMA).
+///
+/// Developed by John Ehlers and Ric Way, ZLEMA removes most of the lag of an
+/// [EMA](struct.ExponentialMovingAverage.html) by feeding it the current price plus the
+/// change of the price over the lag of the EMA, instead of the price alone.
+///
+/// # Formula
+///
+/// lag = (_period_ - 1) / 2, rounded down
+///
+/// ZLEMA<sub>t</sub> = EMA(_period_) of (2 * p<sub>t</sub> - p<sub>t-lag</sub>)
+///
+/// While there are less than _lag_ previous prices, the oldest price is used.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ZeroLagExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut zlema = ZeroLagExponentialMovingAverage::new(3).unwrap();
+/// assert_eq!(zlema.next(2.0), 2.0);
+/// assert_eq!(zlema.next(5.0), 5.0);
+/// assert_eq!(zlema.next(1.0), 1.0);
+/// assert_eq!(zlema.next(6.25), 6.25);
+/// ```
+///
+/// # Links
+///
+/// * [Zero lag exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Zero_lag_exponential_moving_average)
+///
+#[doc(alias = "ZLEMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ZeroLagExponentialMovingAverage {
+    ema: ExponentialMovingAverage,
+    index: usize,
+    count: usize,
+    // Last lag + 1 prices.
+    deque: Box<[f64]>,
+}
+
+impl ZeroLagExponentialMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                ema: ExponentialMovingAverage::new(period)?,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; (period - 1) / 2 + 1].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl NewWithPeriod for ZeroLagExponentialMovingAverage {
+    fn new_with_period(period: usize) -> Result<Self> {
+        Self::new(period)
+    }
+}
+
+impl Period for ZeroLagExponentialMovingAverage {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl Lookback for ZeroLagExponentialMovingAverage {
+    fn lookback(&self) -> usize {
+        self.deque.len() - 1 + self.ema.lookback()
+    }
+}
+
+impl Next<f64> for ZeroLagExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let len = self.deque.len();
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < len {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < len {
+            self.count += 1;
+        }
+
+        let lagged = if self.count == len {
+            self.deque[self.index]
+        } else {
+            self.deque[0]
+        };
+        self.ema.next(2.0 * input - lagged)
+    }
+}
+
+impl<T: Close> Next<&T> for ZeroLagExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ZeroLagExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.deque.len() {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for ZeroLagExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for ZeroLagExponentialMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ZLEMA({})", self.ema.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::AverageTrueRange;
+    use crate::test_helper::*;
+
+    type Zlema = ZeroLagExponentialMovingAverage;
+
+    test_indicator!(Zlema);
+
+    #[test]
+    fn test_new() {
+        assert!(Zlema::new(0).is_err());
+        assert!(Zlema::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut zlema = Zlema::new(5).unwrap();
+
+        assert_eq!(zlema.next(10.0), 10.0);
+        assert_eq!(round(zlema.next(11.0)), 10.667);
+        assert_eq!(round(zlema.next(12.0)), 11.778);
+        assert_eq!(round(zlema.next(11.0)), 11.519);
+        assert_eq!(round(zlema.next(13.0)), 12.346);
+        assert_eq!(round(zlema.next(15.0)), 14.564);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut zlema = Zlema::new(3).unwrap();
+        assert_eq!(zlema.next(&Bar::new().close(2)), 2.0);
+        assert_eq!(zlema.next(&Bar::new().close(5)), 5.0);
+    }
+
+    #[test]
+    fn test_next_without_lag() {
+        // Periods 1 and 2 have no lag, ZLEMA is the EMA.
+        let mut zlema = Zlema::new(2).unwrap();
+        let mut ema = ExponentialMovingAverage::new(2).unwrap();
+        for input in &[3.0, 7.0, 1.0, 4.5] {
+            assert_eq!(zlema.next(*input), ema.next(*input));
+        }
+    }
+
+    #[test]
+    fn test_in_average_true_range() {
+        let mut atr = AverageTrueRange::<Zlema>::with_ma(3).unwrap();
+        assert_eq!(atr.next(&Bar::new().high(10).low(7.5).close(9)), 2.5);
+        assert_eq!(atr.next(&Bar::new().high(11).low(9).close(9.5)), 2.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut zlema = Zlema::new(5).unwrap();
+        zlema.next(10.0);
+        zlema.next(11.0);
+
+        zlema.reset();
+        assert_eq!(zlema.next(4.0), 4.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Zlema::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Zlema::new(7).unwrap();
+        assert_eq!(format!("{}", indicator), "ZLEMA(7)");
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Lookback, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Overbought / oversold duration of an oscillator.
+///
+/// Counts how many consecutive periods the output of an oscillator, e.g.
+/// [RSI](struct.RelativeStrengthIndex.html) or a [stochastic](struct.FastStochastic.html), has
+/// spent above the _overbought_ level or below the _oversold_ level. Rules like "RSI oversold for
+/// at least 3 periods" can then be checked directly on the output.
+///
+/// # Formula
+///
+/// * _overbought_ count - number of consecutive periods with output > _overbought_, 0 otherwise
+/// * _oversold_ count - number of consecutive periods with output < _oversold_, 0 otherwise
+///
+/// # Parameters
+///
+/// * _indicator_ - inner oscillator producing `f64` values.
+/// * _oversold_ - level below which the oscillator is oversold. Default is 30.0.
+/// * _overbought_ - level above which the oscillator is overbought (greater than or equal to
+///   _oversold_). Default is 70.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{RelativeStrengthIndex, ZoneDuration};
+/// use ta::Next;
+///
+/// let rsi = RelativeStrengthIndex::new(3).unwrap();
+/// let mut duration = ZoneDuration::new(rsi, 30.0, 70.0).unwrap();
+///
+/// assert_eq!(duration.next(10.0).oversold, 0);
+/// assert_eq!(duration.next(9.0).oversold, 1);
+/// assert_eq!(duration.next(8.0).oversold, 2);
+///
+/// let out = duration.next(7.0);
+/// assert_eq!(out.oversold, 3);
+/// assert!(out.value < 30.0);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ZoneDuration<I> {
+    indicator: I,
+    oversold: f64,
+    overbought: f64,
+    oversold_count: usize,
+    overbought_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneDurationOutput {
+    /// Output of the oscillator.
+    pub value: f64,
+    pub overbought: usize,
+    pub oversold: usize,
+}
+
+impl<I> ZoneDuration<I> {
+    pub fn new(indicator: I, oversold: f64, overbought: f64) -> Result<Self> {
+        if overbought < oversold {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            indicator,
+            oversold,
+            overbought,
+            oversold_count: 0,
+            overbought_count: 0,
+        })
+    }
+
+    pub fn oversold(&self) -> f64 {
+        self.oversold
+    }
+
+    pub fn overbought(&self) -> f64 {
+        self.overbought
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<I: Period> Period for ZoneDuration<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: Lookback> Lookback for ZoneDuration<I> {
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
+
+impl<In, I: Next<In, Output = f64>> Next<In> for ZoneDuration<I> {
+    type Output = ZoneDurationOutput;
+
+    fn next(&mut self, input: In) -> Self::Output {
+        let value = self.indicator.next(input);
+
+        self.overbought_count = if value > self.overbought {
+            self.overbought_count + 1
+        } else {
+            0
+        };
+        self.oversold_count = if value < self.oversold {
+            self.oversold_count + 1
+        } else {
+            0
+        };
+
+        ZoneDurationOutput {
+            value,
+            overbought: self.overbought_count,
+            oversold: self.oversold_count,
+        }
+    }
+}
+
+impl<I: Reset> Reset for ZoneDuration<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.oversold_count = 0;
+        self.overbought_count = 0;
+    }
+}
+
+impl<I: Default> Default for ZoneDuration<I> {
+    fn default() -> Self {
+        Self::new(I::default(), 30.0, 70.0).unwrap()
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for ZoneDuration<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "DURATION({}, {}, {})",
+            self.indicator, self.oversold, self.overbought
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{FastStochastic, RelativeStrengthIndex, SimpleMovingAverage};
+    use crate::test_helper::*;
+
+    type Sma = SimpleMovingAverage;
+
+    #[test]
+    fn test_new() {
+        assert!(ZoneDuration::new(Sma::default(), 70.0, 30.0).is_err());
+        assert!(ZoneDuration::new(Sma::default(), 50.0, 50.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        // SMA of period 1 passes the input through.
+        let mut duration = ZoneDuration::new(Sma::new(1).unwrap(), 30.0, 70.0).unwrap();
+
+        let test_data = vec![
+            // input, overbought, oversold
+            (50.0, 0, 0),
+            (71.0, 1, 0),
+            (80.0, 2, 0),
+            // on the level is not beyond it
+            (70.0, 0, 0),
+            (75.0, 1, 0),
+            (20.0, 0, 1),
+            (29.9, 0, 2),
+            (30.0, 0, 0),
+        ];
+
+        for (input, overbought, oversold) in test_data {
+            let out = duration.next(input);
+            assert_eq!(out.value, input);
+            assert_eq!(out.overbought, overbought);
+            assert_eq!(out.oversold, oversold);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let stoch = FastStochastic::new(3).unwrap();
+        let mut duration = ZoneDuration::new(stoch, 20.0, 80.0).unwrap();
+
+        duration.next(&Bar::new().high(10).low(8).close(9));
+        let out = duration.next(&Bar::new().high(12).low(9).close(12));
+        assert_eq!(out.value, 100.0);
+        assert_eq!(out.overbought, 1);
+        let out = duration.next(&Bar::new().high(13).low(11).close(12.8));
+        assert_eq!(out.overbought, 2);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut duration = ZoneDuration::new(Sma::new(1).unwrap(), 30.0, 70.0).unwrap();
+        duration.next(80.0);
+        duration.next(80.0);
+
+        duration.reset();
+        assert_eq!(duration.next(80.0).overbought, 1);
+    }
+
+    #[test]
+    fn test_default() {
+        ZoneDuration::<RelativeStrengthIndex>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rsi = RelativeStrengthIndex::new(14).unwrap();
+        let indicator = ZoneDuration::new(rsi, 30.0, 70.0).unwrap();
+        assert_eq!(format!("{}", indicator), "DURATION(RSI(14), 30, 70)");
+    }
+}
@@ -9,6 +9,9 @@
 //!
 //! Since `Next<T>` is a generic trait, most of the indicators can work with both input types: `f64` and more complex
 //! structures like [DataItem](struct.DataItem.html).
+//! Indicators working with `f64` can be fed another value of a bar than the close price, e.g.
+//! volume or the typical price, with [Sourced](indicators/struct.Sourced.html) or
+//! [WithSource](trait.WithSource.html).
 //!
 //! # Example
 //! ```
@@ -24,44 +27,183 @@
 //! assert_eq!(ema.next(6.25), 4.25);
 //! ```
 //!
+//! # Reproducibility
+//!
+//! The indicators only use IEEE 754 arithmetic, which Rust evaluates in the order written,
+//! without fused multiply-add contraction, and the square root, which is correctly rounded.
+//! Given the same inputs, they return bit-identical outputs on every platform, except for the
+//! few indicators computing a logarithm, an exponential, an arc tangent, a cosine or a sine
+//! (e.g. the [Fisher Transform](indicators/struct.FisherTransform.html),
+//! [geometric mean](indicators/struct.GeometricMean.html),
+//! [NWE](indicators/struct.NadarayaWatsonEnvelope.html), [Slope](indicators/struct.Slope.html),
+//! [SuperSmoother](indicators/struct.SuperSmoother.html) and
+//! [RoofingFilter](indicators/struct.RoofingFilter.html)):
+//! the standard library delegates these to the libm of the platform, whose results may differ
+//! in the last bit between platforms and versions.
+//!
+//! The `deterministic` feature computes them in portable Rust instead, so that all indicators
+//! are reproducible, e.g. to audit backtest results. The results are rounded as documented by
+//! the [libm](https://docs.rs/libm) crate, within 1 ulp of the exact values. Targets without
+//! SSE2, such as i586, evaluate `f64` with the x87 extended precision and are not covered.
+//!
 //! # List of indicators
 //!
 //! * Trend
 //!   * [Exponential Moving Average (EMA)](crate::indicators::ExponentialMovingAverage)
 //!   * [Simple Moving Average (SMA)](crate::indicators::SimpleMovingAverage)
+//!   * [HalfTrend](indicators/struct.HalfTrend.html)
+//!   * [Running Moving Average (RMA)](indicators/struct.RunningMovingAverage.html)
+//!   * [Average Directional Index (ADX)](indicators/struct.AverageDirectionalIndex.html)
+//!   * [Aroon](indicators/struct.Aroon.html)
+//!   * [SSL Channel](indicators/struct.SslChannel.html)
+//!   * [Kaufman Adaptive Moving Average (KAMA)](indicators/struct.KaufmanAdaptiveMovingAverage.html)
+//!   * [Slope of an indicator (trend angle)](indicators/struct.Slope.html)
+//!   * [Zero-Lag Exponential Moving Average (ZLEMA)](indicators/struct.ZeroLagExponentialMovingAverage.html)
+//!   * [Volume Weighted Moving Average (VWMA)](indicators/struct.VolumeWeightedMovingAverage.html)
+//!   * [Percentile Bands](indicators/struct.PercentileBands.html)
+//!   * [Linear Regression (LINREG)](indicators/struct.LinearRegression.html)
+//!   * [Linear Regression Channel (standard error bands)](indicators/struct.LinearRegressionChannel.html)
+//!   * [McGinley Dynamic (MD)](indicators/struct.McGinleyDynamic.html)
+//!   * [Variable Index Dynamic Average (VIDYA)](indicators/struct.Vidya.html)
+//!   * [Ehlers Super Smoother (SS)](indicators/struct.SuperSmoother.html)
+//!   * [Ehlers Roofing Filter (RF)](indicators/struct.RoofingFilter.html)
+//!   * [MESA Adaptive Moving Average (MAMA/FAMA)](indicators/struct.Mama.html)
+//!   * [Rolling Median](indicators/struct.RollingMedian.html)
 //! * Oscillators
 //!   * [Relative Strength Index (RSI)](indicators/struct.RelativeStrengthIndex.html)
+//!   * [Volume RSI](indicators/struct.VolumeRsi.html)
 //!   * [Fast Stochastic](indicators/struct.FastStochastic.html)
 //!   * [Slow Stochastic](indicators/struct.SlowStochastic.html)
 //!   * [Moving Average Convergence Divergence (MACD)](indicators/struct.MovingAverageConvergenceDivergence.html)
 //!   * [Percentage Price Oscillator (PPO)](indicators/struct.PercentagePriceOscillator.html)
 //!   * [Commodity Channel Index (CCI)](indicators/struct.CommodityChannelIndex.html)
 //!   * [Money Flow Index (MFI)](indicators/struct.MoneyFlowIndex.html)
+//!   * [Williams %R (WPR)](indicators/struct.WilliamsPercentR.html)
+//!   * [Full Stochastic Oscillator](indicators/struct.StochasticOscillator.html)
+//!   * [Stochastic of an indicator (e.g. StochRSI)](indicators/struct.StochasticOf.html)
+//!   * [Forecast Oscillator (FOSC)](indicators/struct.ForecastOscillator.html)
+//!   * [Ease of Movement (EMV)](indicators/struct.EaseOfMovement.html)
+//!   * [Know Sure Thing (KST)](indicators/struct.KnowSureThing.html)
+//!   * [Pring's Special K (SK)](indicators/struct.SpecialK.html)
+//!   * [DeMarker (DeM)](indicators/struct.DeMarker.html)
+//!   * [Fisher Transform](indicators/struct.FisherTransform.html)
+//!   * [Momentum (MOM)](indicators/struct.Momentum.html)
+//!   * [Volatility-adjusted momentum](indicators/struct.VolAdjMomentum.html)
+//!   * [Percentage Volume Oscillator (PVO)](indicators/struct.PercentageVolumeOscillator.html)
+//!   * [Relative Vigor Index (RVI)](indicators/struct.RelativeVigorIndex.html)
+//!   * [True Strength Index (TSI)](indicators/struct.TrueStrengthIndex.html)
+//!   * [Percentile Rank](indicators/struct.PercentileRank.html)
 //! * Other
 //!   * [Standard Deviation (SD)](indicators/struct.StandardDeviation.html)
+//!   * [Variance (VAR)](indicators/struct.Variance.html)
 //!   * [Mean Absolute Deviation (MAD)](indicators/struct.MeanAbsoluteDeviation.html)
 //!   * [Bollinger Bands (BB)](indicators/struct.BollingerBands.html)
 //!   * [Chandelier Exit (CE)](indicators/struct.ChandelierExit.html)
 //!   * [Keltner Channel (KC)](indicators/struct.KeltnerChannel.html)
+//!   * [ATR Bands](indicators/struct.AtrBands.html)
+//!   * [Acceleration Bands](indicators/struct.AccelerationBands.html)
 //!   * [Maximum](indicators/struct.Maximum.html)
 //!   * [Minimum](indicators/struct.Minimum.html)
 //!   * [True Range](indicators/struct.TrueRange.html)
 //!   * [Average True Range (ATR)](indicators/struct.AverageTrueRange.html)
 //!   * [Efficiency Ratio (ER)](indicators/struct.EfficiencyRatio.html)
+//!   * [Vertical Horizontal Filter (VHF)](indicators/struct.VerticalHorizontalFilter.html)
 //!   * [Rate of Change (ROC)](indicators/struct.RateOfChange.html)
 //!   * [On Balance Volume (OBV)](indicators/struct.OnBalanceVolume.html)
+//!   * [Price Volume Trend (PVT)](indicators/struct.PriceVolumeTrend.html)
+//!   * [Nadaraya-Watson Envelope (NWE)](indicators/struct.NadarayaWatsonEnvelope.html)
+//!   * [Normalized Average True Range (NATR)](indicators/struct.NormalizedAverageTrueRange.html)
+//!   * [Volatility Regime](indicators/struct.VolatilityRegime.html)
+//!   * [Volatility Ratio (VR)](indicators/struct.VolatilityRatio.html)
+//!   * [Rolling Mode](indicators/struct.RollingMode.html)
+//!   * [Anchored / Session VWAP](indicators/struct.AnchoredVwap.html)
+//!   * [Relative Volume (RVOL)](indicators/struct.RelativeVolume.html)
+//!   * [Session Relative Volume](indicators/struct.SessionRelativeVolume.html)
+//!   * [Accumulation/Distribution Line (A/D)](indicators/struct.AccumulationDistribution.html)
+//!   * [TTM Squeeze](indicators/struct.TtmSqueeze.html)
+//!   * [Overbought/oversold duration of an oscillator](indicators/struct.ZoneDuration.html)
+//!   * [Adaptive period driver](indicators/struct.Adaptive.html)
+//!   * [McClellan Summation Index (MSI)](indicators/struct.McClellanSummationIndex.html)
+//!   * [Up/down streak](indicators/struct.Streak.html)
+//!   * [Candle anatomy (CLV, body and wick ratios)](indicators/struct.CandleAnatomy.html)
+//!   * [Rolling Spearman rank correlation](indicators/struct.SpearmanCorrelation.html)
+//!   * [Volatility spike detector (true range over k ATR)](indicators/struct.VolatilitySpike.html)
+//!   * [Rolling geometric mean](indicators/struct.GeometricMean.html)
+//!   * [Rolling harmonic mean](indicators/struct.HarmonicMean.html)
+//!   * [Session open, high and low](indicators/struct.SessionLevels.html)
+//!   * [Opening Range Breakout (ORB)](indicators/struct.OpeningRangeBreakout.html)
+//!   * [Divergence score](indicators/struct.DivergenceScore.html)
+//!   * [Rolling drawdown of an indicator](indicators/struct.RollingDrawdown.html)
+//!   * [Pivot Points](indicators/struct.PivotPoints.html)
+//!   * [Hampel filter (rolling outlier detector)](indicators/struct.HampelFilter.html)
+//!   * [Ensemble of normalized indicators (technical rating)](indicators/struct.Ensemble.html)
+//!   * [Risk Parity (inverse-volatility weights)](indicators/struct.RiskParity.html)
+//!   * [Tail Ratio](indicators/struct.TailRatio.html)
+//!   * [Gain to Pain Ratio (GPR)](indicators/struct.GainPainRatio.html)
+//!   * [Mass Index (MI)](indicators/struct.MassIndex.html)
+//!   * [Williams Fractals](indicators/struct.Fractals.html)
+//!   * [Volume Profile (VP)](indicators/struct.VolumeProfile.html)
+//!   * [Rolling Correlation (CORREL)](indicators/struct.RollingCorrelation.html)
+//!   * [Rolling Beta (BETA)](indicators/struct.RollingBeta.html)
+//!   * [Rolling Sharpe Ratio (SHARPE)](indicators/struct.RollingSharpe.html)
+//!   * [Rolling Sortino Ratio (SORTINO)](indicators/struct.RollingSortino.html)
+//!   * [Parkinson Volatility](indicators/struct.ParkinsonVolatility.html)
+//!   * [Garman-Klass Volatility](indicators/struct.GarmanKlassVolatility.html)
+//!   * [Yang-Zhang Volatility](indicators/struct.YangZhangVolatility.html)
+//!   * [Rogers-Satchell Volatility](indicators/struct.RogersSatchellVolatility.html)
+//!   * [Z-Score](indicators/struct.ZScore.html)
+//!   * [Swing Index (SI)](indicators/struct.SwingIndex.html)
+//!   * [Accumulative Swing Index (ASI)](indicators/struct.AccumulativeSwingIndex.html)
 //!
 #[cfg(test)]
 #[macro_use]
 mod test_helper;
 
 mod helpers;
+mod math;
+mod median_heap;
+mod monotonic_queue;
+mod price_histogram;
+mod rolling_covariance;
+mod rolling_regression;
+mod sorted_window;
 
 pub mod errors;
 pub mod indicators;
 
+#[cfg(feature = "bench")]
+pub mod bench;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 mod traits;
 pub use crate::traits::*;
 
 mod data_item;
 pub use crate::data_item::DataItem;
+
+mod source;
+pub use crate::source::{Source, WithSource};
+
+pub mod units;
+
+pub mod calendar;
+
+mod sanitizer;
+pub use crate::sanitizer::{BarSanitizer, Diagnostics, SanitizedBar};
+
+mod resampler;
+pub use crate::resampler::Resampler;
+
+mod heikin_ashi;
+pub use crate::heikin_ashi::HeikinAshi;
+
+mod replay;
+pub use crate::replay::{Replay, ReplayOutput};
+
+mod drift;
+pub use crate::drift::{DriftCheck, DriftReport};
+
+mod features;
+pub use crate::features::{FeatureCollector, Features};
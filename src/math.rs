@@ -0,0 +1,80 @@
+// Transcendental functions used by the indicators.
+//
+// Arithmetic operations and the square root are correctly rounded by IEEE 754, and Rust never
+// contracts a multiplication and an addition into a fused multiply-add nor reorders floating
+// point operations, so they give the same results on every platform. The logarithm, the
+// exponential, the sine, the cosine and the arc tangent of the standard library call the libm
+// of the platform, whose results may differ in the last bit. With the `deterministic` feature
+// they are computed by the pure Rust port of the musl libm instead.
+
+#[cfg(feature = "deterministic")]
+pub(crate) fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub(crate) fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+#[cfg(feature = "deterministic")]
+pub(crate) fn exp(x: f64) -> f64 {
+    libm::exp(x)
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub(crate) fn exp(x: f64) -> f64 {
+    x.exp()
+}
+
+#[cfg(feature = "deterministic")]
+pub(crate) fn atan(x: f64) -> f64 {
+    libm::atan(x)
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub(crate) fn atan(x: f64) -> f64 {
+    x.atan()
+}
+
+#[cfg(feature = "deterministic")]
+pub(crate) fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub(crate) fn cos(x: f64) -> f64 {
+    x.cos()
+}
+
+#[cfg(feature = "deterministic")]
+pub(crate) fn sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub(crate) fn sin(x: f64) -> f64 {
+    x.sin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{E, FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn test_functions() {
+        assert_eq!(ln(1.0), 0.0);
+        assert!((ln(E) - 1.0).abs() < 1e-15);
+        assert!(ln(-1.0).is_nan());
+        assert_eq!(exp(0.0), 1.0);
+        assert!((exp(1.0) - E).abs() < 1e-15);
+        assert_eq!(exp(f64::NEG_INFINITY), 0.0);
+        assert_eq!(atan(0.0), 0.0);
+        assert!((atan(1.0) - FRAC_PI_4).abs() < 1e-15);
+        assert_eq!(cos(0.0), 1.0);
+        assert!((cos(PI) + 1.0).abs() < 1e-15);
+        assert_eq!(sin(0.0), 0.0);
+        assert!((sin(FRAC_PI_2) - 1.0).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,210 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A value of the window and its position in the input, which makes every entry unique and
+/// tells when it left the window.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    value: f64,
+    position: usize,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value
+            .total_cmp(&other.value)
+            .then(self.position.cmp(&other.position))
+    }
+}
+
+/// Median of a fixed size window of the latest values.
+///
+/// The lower half of the window is kept in a max-heap and the upper half in a min-heap, so the
+/// median is on top of them. The values leaving the window are not searched for: they are
+/// dropped once they reach the top of a heap, and the heaps are compacted when the dropped
+/// values make up half of them. `push` is amortized `O(log n)`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct MedianHeap {
+    capacity: usize,
+    /// Number of values pushed so far, used as the position of the next value.
+    position: usize,
+    deque: Box<[f64]>,
+    low: BinaryHeap<Entry>,
+    high: BinaryHeap<Reverse<Entry>>,
+    // Number of values of the window in each heap.
+    low_len: usize,
+    high_len: usize,
+}
+
+impl MedianHeap {
+    /// `capacity` must be greater than 0.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            position: 0,
+            deque: vec![0.0; capacity].into_boxed_slice(),
+            low: BinaryHeap::with_capacity(capacity),
+            high: BinaryHeap::with_capacity(capacity),
+            low_len: 0,
+            high_len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Adds a value to the window and returns the median of the window.
+    pub fn push(&mut self, value: f64) -> f64 {
+        let index = self.position % self.capacity;
+        if self.position >= self.capacity {
+            let old = Entry {
+                value: self.deque[index],
+                position: self.position - self.capacity,
+            };
+            // The top of the lower half is in the window, so it tells the half of the old value.
+            if self.low.peek().is_some_and(|&top| old <= top) {
+                self.low_len -= 1;
+            } else {
+                self.high_len -= 1;
+            }
+        }
+        self.deque[index] = value;
+        self.position += 1;
+        self.prune();
+
+        let entry = Entry {
+            value,
+            position: self.position - 1,
+        };
+        if self.high.peek().is_some_and(|top| entry > top.0) {
+            self.high.push(Reverse(entry));
+            self.high_len += 1;
+        } else {
+            self.low.push(entry);
+            self.low_len += 1;
+        }
+
+        // The lower half holds the middle value when the window has an odd length.
+        if self.low_len > self.high_len + 1 {
+            let top = self.low.pop().unwrap();
+            self.high.push(Reverse(top));
+            self.low_len -= 1;
+            self.high_len += 1;
+        } else if self.low_len < self.high_len {
+            let Reverse(top) = self.high.pop().unwrap();
+            self.low.push(top);
+            self.high_len -= 1;
+            self.low_len += 1;
+        }
+        self.prune();
+
+        if self.low.len() + self.high.len() > 2 * self.capacity {
+            self.compact();
+        }
+
+        let low = self.low.peek().unwrap().value;
+        if self.low_len > self.high_len {
+            low
+        } else {
+            let high = self.high.peek().unwrap().0.value;
+            low + (high - low) * 0.5
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.position = 0;
+        for value in self.deque.iter_mut() {
+            *value = 0.0;
+        }
+        self.low.clear();
+        self.high.clear();
+        self.low_len = 0;
+        self.high_len = 0;
+    }
+
+    fn is_stale(&self, entry: &Entry) -> bool {
+        entry.position + self.capacity < self.position
+    }
+
+    // Drops the values out of the window from the top of the heaps.
+    fn prune(&mut self) {
+        while self.low.peek().is_some_and(|top| self.is_stale(top)) {
+            self.low.pop();
+        }
+        while self.high.peek().is_some_and(|top| self.is_stale(&top.0)) {
+            self.high.pop();
+        }
+    }
+
+    fn compact(&mut self) {
+        let position = self.position;
+        let capacity = self.capacity;
+        self.low
+            .retain(|entry| entry.position + capacity >= position);
+        self.high
+            .retain(|entry| entry.0.position + capacity >= position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push() {
+        let mut heap = MedianHeap::new(3);
+        assert_eq!(heap.push(3.0), 3.0);
+        assert_eq!(heap.push(1.0), 2.0);
+        assert_eq!(heap.push(2.0), 2.0);
+        // 3 left the window
+        assert_eq!(heap.push(5.0), 2.0);
+        assert_eq!(heap.push(1.0), 2.0);
+        assert_eq!(heap.push(6.0), 5.0);
+    }
+
+    #[test]
+    fn test_push_against_sort() {
+        for capacity in 1..8 {
+            let mut heap = MedianHeap::new(capacity);
+            let inputs: Vec<f64> = (0..200).map(|i| ((i * 37 + 11) % 23) as f64).collect();
+            for i in 0..inputs.len() {
+                let mut window = inputs[(i + 1).saturating_sub(capacity)..=i].to_vec();
+                window.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let n = window.len();
+                let expected = (window[(n - 1) / 2] + window[n / 2]) / 2.0;
+                assert_eq!(heap.push(inputs[i]), expected);
+                assert!(heap.low.len() + heap.high.len() <= 2 * capacity);
+            }
+        }
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut heap = MedianHeap::new(2);
+        heap.push(1.0);
+        heap.push(2.0);
+        heap.clear();
+        assert_eq!(heap.push(3.0), 3.0);
+        assert_eq!(heap.push(5.0), 4.0);
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Which extreme a [MonotonicQueue](struct.MonotonicQueue.html) tracks.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Extreme {
+    Max,
+    Min,
+}
+
+/// Tracks the highest or the lowest of the latest `period` values.
+///
+/// Only the values that can still become the extreme are kept, in monotonic order, so every
+/// value is pushed and popped at most once: `push` is amortized `O(1)`. When the extreme is
+/// repeated, the most recent one is kept.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct MonotonicQueue {
+    extreme: Extreme,
+    period: usize,
+    /// Number of values pushed so far, used as the position of the next value.
+    position: usize,
+    deque: VecDeque<(usize, f64)>,
+}
+
+impl MonotonicQueue {
+    /// `period` must be greater than 0.
+    pub fn new(extreme: Extreme, period: usize) -> Self {
+        Self {
+            extreme,
+            period,
+            position: 0,
+            deque: VecDeque::with_capacity(period),
+        }
+    }
+
+    pub fn max(period: usize) -> Self {
+        Self::new(Extreme::Max, period)
+    }
+
+    pub fn min(period: usize) -> Self {
+        Self::new(Extreme::Min, period)
+    }
+
+    /// Adds a value to the window and returns the extreme of the window.
+    pub fn push(&mut self, value: f64) -> f64 {
+        while let Some(&(_, last)) = self.deque.back() {
+            let dominated = match self.extreme {
+                Extreme::Max => last <= value,
+                Extreme::Min => last >= value,
+            };
+            if !dominated {
+                break;
+            }
+            self.deque.pop_back();
+        }
+        self.deque.push_back((self.position, value));
+        self.position += 1;
+
+        while let Some(&(position, _)) = self.deque.front() {
+            if position + self.period >= self.position {
+                break;
+            }
+            self.deque.pop_front();
+        }
+
+        self.deque[0].1
+    }
+
+    /// Returns how many values ago the extreme was pushed, 0 for the latest value.
+    pub fn offset(&self) -> usize {
+        match self.deque.front() {
+            Some(&(position, _)) => self.position - 1 - position,
+            None => 0,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.position = 0;
+        self.deque.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max() {
+        let mut queue = MonotonicQueue::max(3);
+        let expected = [4.0, 4.0, 5.0, 5.0, 5.0, 4.0, 4.0, 0.0, -1.0];
+        for (value, max) in [4.0, 1.2, 5.0, 3.0, 4.0, 0.0, -1.0, -2.0, -1.5]
+            .iter()
+            .zip(expected.iter())
+        {
+            assert_eq!(queue.push(*value), *max);
+        }
+    }
+
+    #[test]
+    fn test_min() {
+        let mut queue = MonotonicQueue::min(2);
+        assert_eq!(queue.push(3.0), 3.0);
+        assert_eq!(queue.push(1.0), 1.0);
+        assert_eq!(queue.push(2.0), 1.0);
+        assert_eq!(queue.push(2.0), 2.0);
+        assert_eq!(queue.push(5.0), 2.0);
+    }
+
+    #[test]
+    fn test_offset() {
+        let mut queue = MonotonicQueue::max(3);
+        assert_eq!(queue.offset(), 0);
+        queue.push(4.0);
+        queue.push(1.0);
+        assert_eq!(queue.offset(), 1);
+        queue.push(4.0);
+        assert_eq!(queue.offset(), 0);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut queue = MonotonicQueue::max(3);
+        queue.push(10.0);
+        queue.clear();
+        assert_eq!(queue.push(1.0), 1.0);
+    }
+}
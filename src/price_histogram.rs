@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Weights of prices grouped in bins of the same width, e.g. the volume traded at every price
+/// level of a range of bars.
+///
+/// Bin `i` holds the prices from `origin + i * width` to `origin + (i + 1) * width`. Only the
+/// bins covered by at least one value are kept, so the histogram can be updated in `O(log n)`
+/// per bin whatever the spread of the prices, and empty price levels do not take memory.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct PriceHistogram {
+    origin: f64,
+    width: f64,
+    // Number of values and total weight of every covered bin.
+    bins: BTreeMap<i64, (usize, f64)>,
+}
+
+impl PriceHistogram {
+    /// `width` must not be negative. With a width of 0 every price is either in bin 0, at
+    /// `origin`, or out of the histogram.
+    pub fn new(origin: f64, width: f64) -> Self {
+        Self {
+            origin,
+            width,
+            bins: BTreeMap::new(),
+        }
+    }
+
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Returns the bin of `price`.
+    pub fn bin(&self, price: f64) -> i64 {
+        ((price - self.origin) / self.width).floor() as i64
+    }
+
+    /// Returns the bin of the prices just below `price`, which is the bin of `price` unless it
+    /// is on the lower edge of a bin.
+    pub fn bin_below(&self, price: f64) -> i64 {
+        (((price - self.origin) / self.width).ceil() as i64).saturating_sub(1)
+    }
+
+    pub fn lower_edge(&self, bin: i64) -> f64 {
+        self.origin + bin as f64 * self.width
+    }
+
+    pub fn middle(&self, bin: i64) -> f64 {
+        self.origin + (bin as f64 + 0.5) * self.width
+    }
+
+    pub fn add(&mut self, bin: i64, weight: f64) {
+        let entry = self.bins.entry(bin).or_insert((0, 0.0));
+        entry.0 += 1;
+        entry.1 += weight;
+    }
+
+    /// Removes a value previously added to `bin`. The bin is dropped with its last value.
+    pub fn remove(&mut self, bin: i64, weight: f64) {
+        if let Some(entry) = self.bins.get_mut(&bin) {
+            entry.0 -= 1;
+            entry.1 -= weight;
+            if entry.0 == 0 {
+                self.bins.remove(&bin);
+            }
+        }
+    }
+
+    /// Removes all the values and moves the bins.
+    pub fn clear(&mut self, origin: f64, width: f64) {
+        self.origin = origin;
+        self.width = width;
+        self.bins.clear();
+    }
+
+    /// Returns the covered bins and their weight, from the lowest price.
+    pub fn iter(&self) -> impl Iterator<Item = (i64, f64)> + '_ {
+        self.bins.iter().map(|(&bin, &(_, weight))| (bin, weight))
+    }
+
+    pub fn total(&self) -> f64 {
+        self.bins.values().map(|&(_, weight)| weight).sum()
+    }
+
+    /// Returns the middle price and the weight of the covered bins, from the lowest price.
+    pub fn histogram(&self) -> Vec<(f64, f64)> {
+        self.iter()
+            .map(|(bin, weight)| (self.middle(bin), weight))
+            .collect()
+    }
+
+    /// Returns the weight of the bins overlapping the prices from `low` to `high`.
+    pub fn volume_between(&self, low: f64, high: f64) -> f64 {
+        if low.is_nan() || high.is_nan() || low > high {
+            return 0.0;
+        }
+        self.bins
+            .range(self.bin(low)..=self.bin(high))
+            .map(|(_, &(_, weight))| weight)
+            .sum()
+    }
+
+    /// Returns the middle price of the nearest bin above the bin of `price` holding at least
+    /// `min_share` of the total weight.
+    pub fn node_above(&self, price: f64, min_share: f64) -> Option<f64> {
+        let threshold = min_share * self.total();
+        self.bins
+            .range(self.bin(price).saturating_add(1)..)
+            .find(|(_, &(_, weight))| weight > 0.0 && weight >= threshold)
+            .map(|(&bin, _)| self.middle(bin))
+    }
+
+    /// Returns the middle price of the nearest bin below the bin of `price` holding at least
+    /// `min_share` of the total weight.
+    pub fn node_below(&self, price: f64, min_share: f64) -> Option<f64> {
+        let threshold = min_share * self.total();
+        self.bins
+            .range(..self.bin(price))
+            .rev()
+            .find(|(_, &(_, weight))| weight > 0.0 && weight >= threshold)
+            .map(|(&bin, _)| self.middle(bin))
+    }
+
+    /// Returns the price ranges, from the lowest, where all the bins hold at most `max_share`
+    /// of the total weight, and that are surrounded by heavier bins.
+    pub fn gaps(&self, max_share: f64) -> Vec<(f64, f64)> {
+        let threshold = max_share * self.total();
+        let mut gaps = Vec::new();
+        let mut previous: Option<i64> = None;
+        for (bin, weight) in self.iter() {
+            if weight <= threshold {
+                continue;
+            }
+            if let Some(previous) = previous.filter(|&previous| bin > previous + 1) {
+                gaps.push((self.lower_edge(previous + 1), self.lower_edge(bin)));
+            }
+            previous = Some(bin);
+        }
+        gaps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_remove() {
+        let mut histogram = PriceHistogram::new(0.0, 0.5);
+        histogram.add(histogram.bin(10.2), 100.0);
+        histogram.add(histogram.bin(10.4), 0.0);
+        histogram.add(histogram.bin(11.0), 50.0);
+        assert_eq!(histogram.histogram(), vec![(10.25, 100.0), (11.25, 50.0)]);
+        assert_eq!(histogram.total(), 150.0);
+
+        // A bin stays while a value of no weight covers it.
+        histogram.remove(20, 100.0);
+        assert_eq!(histogram.histogram(), vec![(10.25, 0.0), (11.25, 50.0)]);
+        histogram.remove(20, 0.0);
+        assert_eq!(histogram.histogram(), vec![(11.25, 50.0)]);
+
+        histogram.clear(1.0, 2.0);
+        assert_eq!(histogram.histogram(), vec![]);
+        assert_eq!(histogram.bin(0.5), -1);
+        assert_eq!(histogram.middle(0), 2.0);
+    }
+
+    #[test]
+    fn test_bin_below() {
+        let histogram = PriceHistogram::new(10.0, 1.0);
+        assert_eq!(histogram.bin(12.0), 2);
+        assert_eq!(histogram.bin_below(12.0), 1);
+        assert_eq!(histogram.bin_below(12.5), 2);
+    }
+
+    #[test]
+    fn test_zero_width() {
+        let mut histogram = PriceHistogram::new(10.0, 0.0);
+        histogram.add(histogram.bin(10.0), 100.0);
+        assert_eq!(histogram.histogram(), vec![(10.0, 100.0)]);
+        assert_eq!(histogram.volume_between(9.0, 11.0), 100.0);
+        assert_eq!(histogram.volume_between(11.0, 12.0), 0.0);
+        assert_eq!(histogram.volume_between(8.0, 9.0), 0.0);
+    }
+}
@@ -0,0 +1,316 @@
+use crate::errors::{Result, TaError};
+use crate::{Close, DataItem, High, Low, Next, Open, Timestamp, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Replays historical bars the way a live feed delivers them.
+///
+/// A live feed updates the last bar several times before it closes. Strategies usually evaluate
+/// such an unfinished bar on a copy of their indicators (peek), and feed the bar to the
+/// indicators themselves (commit) only once it is complete. [run](#method.run) splits every
+/// historical bar into _steps_ states, peeks at the first _steps_ - 1 of them on a clone of the
+/// indicator and commits the last one, the complete bar. It also feeds the complete bars
+/// directly to another clone, like a backtest does, so both paths can be compared bar by bar
+/// with [is_consistent](struct.ReplayOutput.html#method.is_consistent).
+///
+/// A mismatch means that peeking leaks into the committed state, e.g. an indicator sharing state
+/// between its clones, and that the values seen live will not match the backtest. The peeked
+/// values of every bar are returned as well, to check that signals do not appear and vanish
+/// within a bar.
+///
+/// The intra-bar states are built from the open, high, low and close only: a bar closing up is
+/// assumed to go from the open to the low, then to the high and to the close, a bar closing down
+/// to the high first. The states are evenly spaced along that path and the volume grows
+/// linearly. They keep the timestamp of the bar.
+///
+/// # Parameters
+///
+/// * _steps_ - number of states of every bar, the last one being the complete bar (integer
+///   greater than 0).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{DataItem, Replay};
+///
+/// let bar = |open: f64, high: f64, low: f64, close: f64| {
+///     DataItem::builder()
+///         .open(open)
+///         .high(high)
+///         .low(low)
+///         .close(close)
+///         .volume(100.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let bars = [bar(10.0, 12.0, 9.0, 11.0), bar(11.0, 13.0, 10.0, 13.0)];
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+///
+/// let outputs = Replay::new(5).unwrap().run(&sma, &bars).unwrap();
+/// assert_eq!(outputs[0].intra_bar, vec![9.0, 10.0, 11.0, 12.0]);
+/// assert_eq!(outputs[0].live, 11.0);
+/// assert!(outputs.iter().all(|output| output.is_consistent()));
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Replay {
+    steps: usize,
+}
+
+/// Outputs of an indicator for one bar of a [Replay](struct.Replay.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayOutput<O> {
+    /// Outputs peeked at the unfinished states of the bar.
+    pub intra_bar: Vec<O>,
+    /// Output committed with the complete bar after peeking.
+    pub live: O,
+    /// Output of the complete bar without peeking.
+    pub backtest: O,
+}
+
+impl<O: PartialEq> ReplayOutput<O> {
+    /// Returns `true` when the live output matches the backtest output.
+    pub fn is_consistent(&self) -> bool {
+        self.live == self.backtest
+    }
+}
+
+impl Replay {
+    pub fn new(steps: usize) -> Result<Self> {
+        match steps {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self { steps }),
+        }
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Returns the states of a bar as it forms, the last one being the complete bar.
+    ///
+    /// Fails with `DataItemInvalid` when the bar is not a valid bar.
+    pub fn intra_bar<T>(&self, bar: &T) -> Result<Vec<DataItem>>
+    where
+        T: Open + High + Low + Close + Volume + Timestamp,
+    {
+        let (open, close) = (bar.open(), bar.close());
+        let path = if close >= open {
+            [open, bar.low(), bar.high(), close]
+        } else {
+            [open, bar.high(), bar.low(), close]
+        };
+        let length: f64 = path.windows(2).map(|w| (w[1] - w[0]).abs()).sum();
+
+        let mut states = Vec::with_capacity(self.steps);
+        for step in 1..self.steps {
+            let fraction = step as f64 / self.steps as f64;
+            let (price, high, low) = walk(&path, length * step as f64 / self.steps as f64);
+            states.push(build(
+                open,
+                high,
+                low,
+                price,
+                bar.volume() * fraction,
+                bar.timestamp(),
+            )?);
+        }
+        states.push(build(
+            open,
+            bar.high(),
+            bar.low(),
+            close,
+            bar.volume(),
+            bar.timestamp(),
+        )?);
+        Ok(states)
+    }
+
+    /// Replays _bars_ through clones of _indicator_, see [Replay](struct.Replay.html).
+    ///
+    /// Fails with `DataItemInvalid` when one of the bars is not a valid bar.
+    pub fn run<I, T, O>(&self, indicator: &I, bars: &[T]) -> Result<Vec<ReplayOutput<O>>>
+    where
+        I: Clone + for<'a> Next<&'a DataItem, Output = O>,
+        T: Open + High + Low + Close + Volume + Timestamp,
+    {
+        let mut live = indicator.clone();
+        let mut backtest = indicator.clone();
+
+        bars.iter()
+            .map(|bar| {
+                let mut states = self.intra_bar(bar)?;
+                let complete = states.pop().unwrap();
+                let intra_bar = states
+                    .iter()
+                    .map(|state| live.clone().next(state))
+                    .collect();
+
+                Ok(ReplayOutput {
+                    intra_bar,
+                    live: live.next(&complete),
+                    backtest: backtest.next(&complete),
+                })
+            })
+            .collect()
+    }
+}
+
+// Walks _distance_ along the path, returns the price reached and the extremes met on the way.
+fn walk(path: &[f64; 4], distance: f64) -> (f64, f64, f64) {
+    let mut remaining = distance;
+    let mut price = path[0];
+    let (mut high, mut low) = (price, price);
+
+    for w in path.windows(2) {
+        let segment = (w[1] - w[0]).abs();
+        if remaining < segment {
+            price = w[0] + (w[1] - w[0]) * remaining / segment;
+            high = high.max(price);
+            low = low.min(price);
+            break;
+        }
+        price = w[1];
+        remaining -= segment;
+        high = high.max(price);
+        low = low.min(price);
+    }
+    (price, high, low)
+}
+
+fn build(
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+    timestamp: Option<i64>,
+) -> Result<DataItem> {
+    let builder = DataItem::builder()
+        .open(open)
+        .high(high)
+        .low(low)
+        .close(close)
+        .volume(volume);
+    match timestamp {
+        Some(timestamp) => builder.timestamp(timestamp),
+        None => builder,
+    }
+    .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage, Maximum};
+    use crate::test_helper::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    // Counts the inputs in a counter shared by all its clones.
+    #[derive(Clone)]
+    struct SharedCounter(Rc<Cell<usize>>);
+
+    impl Next<&DataItem> for SharedCounter {
+        type Output = usize;
+
+        fn next(&mut self, _input: &DataItem) -> Self::Output {
+            self.0.set(self.0.get() + 1);
+            self.0.get()
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Replay::new(0).is_err());
+        assert!(Replay::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_intra_bar() {
+        let replay = Replay::new(3).unwrap();
+
+        // Down bar: open, high, low, close.
+        let bar = Bar::new()
+            .open(11)
+            .high(12)
+            .low(8)
+            .close(9)
+            .volume(30.0)
+            .timestamp(60);
+        let states = replay.intra_bar(&bar).unwrap();
+
+        let expected = vec![
+            // high, low, close, volume
+            (12.0, 11.0, 11.0, 10.0),
+            (12.0, 9.0, 9.0, 20.0),
+            (12.0, 8.0, 9.0, 30.0),
+        ];
+        assert_eq!(states.len(), expected.len());
+        for (state, (high, low, close, volume)) in states.iter().zip(expected) {
+            assert_eq!(state.open(), 11.0);
+            assert_eq!(state.high(), high);
+            assert_eq!(state.low(), low);
+            assert_eq!(state.close(), close);
+            assert_eq!(state.volume(), volume);
+            assert_eq!(state.timestamp(), Some(60));
+        }
+    }
+
+    #[test]
+    fn test_intra_bar_flat() {
+        let replay = Replay::new(3).unwrap();
+        let bar = Bar::new().open(5).high(5).low(5).close(5);
+        for state in replay.intra_bar(&bar).unwrap() {
+            assert_eq!(state.close(), 5.0);
+            assert_eq!(state.timestamp(), None);
+        }
+    }
+
+    #[test]
+    fn test_intra_bar_invalid() {
+        let replay = Replay::new(3).unwrap();
+        let bar = Bar::new().open(5).high(4).low(3).close(5);
+        assert!(replay.intra_bar(&bar).is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let replay = Replay::new(2).unwrap();
+        let bars = vec![
+            Bar::new().open(10).high(12).low(10).close(12),
+            Bar::new().open(12).high(14).low(8).close(8),
+            Bar::new().open(8).high(10).low(7).close(9),
+        ];
+
+        let max = Maximum::new(2).unwrap();
+        let outputs = replay.run(&max, &bars).unwrap();
+
+        let intra_bar: Vec<f64> = outputs.iter().map(|o| o.intra_bar[0]).collect();
+        assert_eq!(intra_bar, vec![11.0, 14.0, 14.0]);
+        let live: Vec<f64> = outputs.iter().map(|o| o.live).collect();
+        assert_eq!(live, vec![12.0, 14.0, 14.0]);
+        assert!(outputs.iter().all(|o| o.is_consistent()));
+
+        let ema = ExponentialMovingAverage::new(3).unwrap();
+        let outputs = Replay::new(7).unwrap().run(&ema, &bars).unwrap();
+        assert!(outputs.iter().all(|o| o.is_consistent()));
+    }
+
+    #[test]
+    fn test_run_leaking_state() {
+        let replay = Replay::new(3).unwrap();
+        let bars = vec![
+            Bar::new().open(10).high(12).low(9).close(11),
+            Bar::new().open(11).high(12).low(9).close(10),
+        ];
+
+        let counter = SharedCounter(Rc::new(Cell::new(0)));
+        let outputs = replay.run(&counter, &bars).unwrap();
+        assert!(!outputs[0].is_consistent());
+        assert!(!outputs[1].is_consistent());
+    }
+}
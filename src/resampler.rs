@@ -0,0 +1,380 @@
+use std::fmt;
+
+use crate::calendar::{Calendar, Sessions};
+use crate::errors::{Result, TaError};
+use crate::{Close, DataItem, High, Low, Next, Open, Reset, Timestamp, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Merges bars of a lower timeframe into bars of a higher timeframe.
+///
+/// Completed bars, e.g. 1 minute bars, are fed one by one. A bar of the higher timeframe is
+/// returned once it is complete, which is known when the first bar of the next one arrives.
+/// The bar being built can be read with [current](#method.current) and taken with
+/// [flush](#method.flush), e.g. at the end of the data.
+///
+/// With [new](#method.new) the bars are grouped by their
+/// [timestamp](trait.Timestamp.html) into intervals aligned on the Unix epoch shifted by
+/// _offset_, so daily bars can start at the open of the exchange. With
+/// [with_calendar](#method.with_calendar) they are grouped by the trading days of a
+/// [Calendar](calendar/struct.Calendar.html), following daylight saving time, weekends and
+/// holidays. Merged bars are stamped with the start of their interval or session. Inputs without a timestamp are added to the current bar. With
+/// [by_count](#method.by_count) every _bars_ consecutive bars are merged, whatever their time.
+///
+/// The open of a merged bar is the first open, the close the last close, the high and the low
+/// the extremes of the merged bars and the volume their sum. The inputs must be valid bars,
+/// see [BarSanitizer](struct.BarSanitizer.html). A merged bar that does not form a valid
+/// [DataItem](struct.DataItem.html) is dropped.
+///
+/// # Parameters
+///
+/// * _interval_ - length of the merged bars in seconds (integer greater than 0).
+/// * _offset_ - start of the intervals relative to the Unix epoch in seconds.
+///
+/// # Example
+///
+/// ```
+/// use ta::{Close, DataItem, High, Next, Resampler, Timestamp, Volume};
+///
+/// let bar = |close: f64, timestamp: i64| {
+///     DataItem::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(10.0)
+///         .timestamp(timestamp)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// // 1 minute bars to 5 minute bars
+/// let mut resampler = Resampler::new(300, 0).unwrap();
+/// assert!(resampler.next(&bar(10.0, 0)).is_none());
+/// assert!(resampler.next(&bar(12.0, 60)).is_none());
+/// assert!(resampler.next(&bar(11.0, 240)).is_none());
+///
+/// let five_minutes = resampler.next(&bar(15.0, 300)).unwrap();
+/// assert_eq!(five_minutes.high(), 12.0);
+/// assert_eq!(five_minutes.close(), 11.0);
+/// assert_eq!(five_minutes.volume(), 30.0);
+/// assert_eq!(five_minutes.timestamp(), Some(0));
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Resampler {
+    grouping: Grouping,
+    current: Option<PartialBar>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+enum Grouping {
+    Time(Sessions),
+    Count(usize),
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct PartialBar {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+    timestamp: Option<i64>,
+    // Interval of a time grouping, number of merged bars of a count grouping.
+    key: i64,
+}
+
+impl PartialBar {
+    fn to_data_item(&self) -> Option<DataItem> {
+        let builder = DataItem::builder()
+            .open(self.open)
+            .high(self.high)
+            .low(self.low)
+            .close(self.close)
+            .volume(self.volume);
+        match self.timestamp {
+            Some(timestamp) => builder.timestamp(timestamp),
+            None => builder,
+        }
+        .build()
+        .ok()
+    }
+}
+
+impl Resampler {
+    /// Creates a resampler that groups bars by time intervals.
+    pub fn new(interval: i64, offset: i64) -> Result<Self> {
+        Ok(Self {
+            grouping: Grouping::Time(Sessions::interval(interval, offset)?),
+            current: None,
+        })
+    }
+
+    /// Creates a resampler that merges the bars of every trading day of a calendar.
+    pub fn with_calendar(calendar: Calendar) -> Self {
+        Self {
+            grouping: Grouping::Time(Sessions::Calendar(calendar)),
+            current: None,
+        }
+    }
+
+    /// Creates a resampler that merges every _bars_ consecutive bars.
+    pub fn by_count(bars: usize) -> Result<Self> {
+        if bars == 0 {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            grouping: Grouping::Count(bars),
+            current: None,
+        })
+    }
+
+    /// Returns the bar being built, if any.
+    pub fn current(&self) -> Option<DataItem> {
+        self.current.as_ref().and_then(PartialBar::to_data_item)
+    }
+
+    /// Takes the bar being built, if any, and starts a new one.
+    pub fn flush(&mut self) -> Option<DataItem> {
+        self.current.take().and_then(|bar| bar.to_data_item())
+    }
+
+    fn merge<T: Open + High + Low + Close + Volume>(&mut self, input: &T) {
+        if let Some(ref mut bar) = self.current {
+            bar.high = bar.high.max(input.high());
+            bar.low = bar.low.min(input.low());
+            bar.close = input.close();
+            bar.volume += input.volume();
+        }
+    }
+
+    fn start<T: Open + High + Low + Close + Volume>(
+        &mut self,
+        input: &T,
+        timestamp: Option<i64>,
+        key: i64,
+    ) {
+        self.current = Some(PartialBar {
+            open: input.open(),
+            high: input.high(),
+            low: input.low(),
+            close: input.close(),
+            volume: input.volume(),
+            timestamp,
+            key,
+        });
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume + Timestamp> Next<&T> for Resampler {
+    type Output = Option<DataItem>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        match &self.grouping {
+            Grouping::Time(sessions) => {
+                let timestamp = input.timestamp();
+                let key = timestamp.map(|timestamp| sessions.session(timestamp));
+                let completed = match (&self.current, key) {
+                    (Some(bar), Some(key)) if bar.timestamp.is_some() && bar.key != key => {
+                        self.flush()
+                    }
+                    _ => None,
+                };
+
+                if self.current.is_some() {
+                    self.merge(input);
+                } else {
+                    self.start(input, None, 0);
+                }
+                // The bar is stamped by the first input with a timestamp.
+                if let (Grouping::Time(sessions), Some(bar), Some(timestamp)) =
+                    (&self.grouping, self.current.as_mut(), timestamp)
+                {
+                    if bar.timestamp.is_none() {
+                        bar.timestamp = Some(sessions.session_start(timestamp));
+                        bar.key = sessions.session(timestamp);
+                    }
+                }
+                completed
+            }
+            &Grouping::Count(bars) => {
+                if self.current.is_some() {
+                    self.merge(input);
+                } else {
+                    self.start(input, input.timestamp(), 0);
+                }
+                let bar = self.current.as_mut()?;
+                bar.key += 1;
+                if bar.key as usize == bars {
+                    self.flush()
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl Reset for Resampler {
+    fn reset(&mut self) {
+        self.current = None;
+    }
+}
+
+impl fmt::Display for Resampler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.grouping {
+            Grouping::Time(sessions) => write!(f, "RESAMPLE({})", sessions),
+            Grouping::Count(bars) => write!(f, "RESAMPLE({} bars)", bars),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new()
+            .open(open)
+            .high(high)
+            .low(low)
+            .close(close)
+            .volume(100.0)
+    }
+
+    fn ohlcv(bar: &DataItem) -> (f64, f64, f64, f64, f64) {
+        (bar.open(), bar.high(), bar.low(), bar.close(), bar.volume())
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Resampler::new(0, 0).is_err());
+        assert!(Resampler::new(60, -30).is_ok());
+        assert!(Resampler::by_count(0).is_err());
+        assert!(Resampler::by_count(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_by_time() {
+        let mut resampler = Resampler::new(900, 0).unwrap();
+
+        assert!(resampler
+            .next(&bar(10.0, 11.0, 9.5, 10.5).timestamp(900))
+            .is_none());
+        assert!(resampler
+            .next(&bar(10.5, 12.0, 10.0, 11.0).timestamp(1200))
+            .is_none());
+        // a bar without timestamp joins the current one
+        assert!(resampler.next(&bar(11.0, 11.5, 9.0, 9.5)).is_none());
+        assert_eq!(
+            ohlcv(&resampler.current().unwrap()),
+            (10.0, 12.0, 9.0, 9.5, 300.0)
+        );
+
+        // a gap, the intervals from 1800 to 2700 are empty
+        let out = resampler
+            .next(&bar(9.5, 10.0, 9.0, 9.8).timestamp(2710))
+            .unwrap();
+        assert_eq!(ohlcv(&out), (10.0, 12.0, 9.0, 9.5, 300.0));
+        assert_eq!(out.timestamp(), Some(900));
+
+        let out = resampler.flush().unwrap();
+        assert_eq!(ohlcv(&out), (9.5, 10.0, 9.0, 9.8, 100.0));
+        assert_eq!(out.timestamp(), Some(2700));
+        assert!(resampler.flush().is_none());
+    }
+
+    #[test]
+    fn test_next_without_timestamp() {
+        let mut resampler = Resampler::new(60, 0).unwrap();
+
+        assert!(resampler.next(&bar(10.0, 10.0, 10.0, 10.0)).is_none());
+        assert!(resampler
+            .next(&bar(11.0, 11.0, 11.0, 11.0).timestamp(130))
+            .is_none());
+        assert_eq!(resampler.current().unwrap().timestamp(), Some(120));
+
+        let out = resampler
+            .next(&bar(12.0, 12.0, 12.0, 12.0).timestamp(180))
+            .unwrap();
+        assert_eq!(ohlcv(&out), (10.0, 11.0, 10.0, 11.0, 200.0));
+        assert_eq!(out.timestamp(), Some(120));
+    }
+
+    #[test]
+    fn test_next_with_offset() {
+        // Daily bars starting at 13:30 UTC.
+        let mut resampler = Resampler::new(86_400, 13 * 3600 + 30 * 60).unwrap();
+
+        resampler.next(&bar(10.0, 10.0, 10.0, 10.0).timestamp(14 * 3600));
+        // the next day, before the start of the session
+        resampler.next(&bar(11.0, 11.0, 11.0, 11.0).timestamp(86_400 + 3600));
+
+        let out = resampler.next(&bar(12.0, 12.0, 12.0, 12.0).timestamp(86_400 + 14 * 3600));
+        let out = out.unwrap();
+        assert_eq!(ohlcv(&out), (10.0, 11.0, 10.0, 11.0, 200.0));
+        assert_eq!(out.timestamp(), Some(13 * 3600 + 30 * 60));
+    }
+
+    #[test]
+    fn test_next_with_calendar() {
+        let mut resampler = Resampler::with_calendar(Calendar::us_equities());
+        // Monday 2024-01-08 00:00 UTC
+        let monday = 1_704_672_000;
+
+        resampler.next(&bar(10.0, 10.0, 10.0, 10.0).timestamp(monday + 15 * 3600));
+        resampler.next(&bar(11.0, 11.0, 11.0, 11.0).timestamp(monday + 20 * 3600));
+
+        // Tuesday, before the open
+        let out = resampler.next(&bar(12.0, 12.0, 12.0, 12.0).timestamp(monday + 86_400 + 3600));
+        let out = out.unwrap();
+        assert_eq!(ohlcv(&out), (10.0, 11.0, 10.0, 11.0, 200.0));
+        assert_eq!(out.timestamp(), Some(monday + 14 * 3600 + 1800));
+        assert_eq!(
+            resampler.current().unwrap().timestamp(),
+            Some(monday + 86_400 + 14 * 3600 + 1800)
+        );
+    }
+
+    #[test]
+    fn test_next_by_count() {
+        let mut resampler = Resampler::by_count(3).unwrap();
+
+        assert!(resampler
+            .next(&bar(10.0, 11.0, 9.5, 10.5).timestamp(60))
+            .is_none());
+        assert!(resampler.next(&bar(10.5, 12.0, 10.0, 11.0)).is_none());
+        let out = resampler.next(&bar(11.0, 11.5, 9.0, 9.5)).unwrap();
+        assert_eq!(ohlcv(&out), (10.0, 12.0, 9.0, 9.5, 300.0));
+        assert_eq!(out.timestamp(), Some(60));
+
+        assert!(resampler.next(&bar(9.5, 10.0, 9.0, 9.8)).is_none());
+        assert_eq!(resampler.flush().unwrap().timestamp(), None);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut resampler = Resampler::new(60, 0).unwrap();
+        resampler.next(&bar(10.0, 11.0, 9.5, 10.5).timestamp(0));
+
+        resampler.reset();
+        assert!(resampler.current().is_none());
+        assert!(resampler
+            .next(&bar(10.0, 11.0, 9.5, 10.5).timestamp(60))
+            .is_none());
+    }
+
+    #[test]
+    fn test_display() {
+        let resampler = Resampler::new(300, 0).unwrap();
+        assert_eq!(format!("{}", resampler), "RESAMPLE(300, 0)");
+        let resampler = Resampler::by_count(5).unwrap();
+        assert_eq!(format!("{}", resampler), "RESAMPLE(5 bars)");
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Covariance of the latest `period` pairs of values, updated in `O(1)`.
+///
+/// The sums of squared and cross deviations from the means are maintained like the
+/// [standard deviation](indicators/struct.StandardDeviation.html) does, with Welford's method,
+/// rather than the raw sums of squares that lose precision on large values.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct RollingCovariance {
+    index: usize,
+    count: usize,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c_xy: f64,
+    deque: Box<[(f64, f64)]>,
+}
+
+impl RollingCovariance {
+    /// `period` must be greater than 0.
+    pub fn new(period: usize) -> Self {
+        Self {
+            index: 0,
+            count: 0,
+            mean_x: 0.0,
+            mean_y: 0.0,
+            m2_x: 0.0,
+            m2_y: 0.0,
+            c_xy: 0.0,
+            deque: vec![(0.0, 0.0); period].into_boxed_slice(),
+        }
+    }
+
+    pub fn push(&mut self, x: f64, y: f64) {
+        let period = self.deque.len();
+        if self.count < period {
+            self.count += 1;
+            let n = self.count as f64;
+            let dx = x - self.mean_x;
+            let dy = y - self.mean_y;
+            self.mean_x += dx / n;
+            self.mean_y += dy / n;
+            self.m2_x += dx * (x - self.mean_x);
+            self.m2_y += dy * (y - self.mean_y);
+            self.c_xy += dx * (y - self.mean_y);
+        } else {
+            // Replace the oldest pair, the deviations are taken from the previous means.
+            let (old_x, old_y) = self.deque[self.index];
+            let n = period as f64;
+            let (dx, old_dx) = (x - self.mean_x, old_x - self.mean_x);
+            let (dy, old_dy) = (y - self.mean_y, old_y - self.mean_y);
+            let (ax, ay) = (x - old_x, y - old_y);
+            self.mean_x += ax / n;
+            self.mean_y += ay / n;
+            self.m2_x += dx * dx - old_dx * old_dx - ax * ax / n;
+            self.m2_y += dy * dy - old_dy * old_dy - ay * ay / n;
+            self.c_xy += dx * dy - old_dx * old_dy - ax * ay / n;
+        }
+        if self.m2_x < 0.0 {
+            self.m2_x = 0.0;
+        }
+        if self.m2_y < 0.0 {
+            self.m2_y = 0.0;
+        }
+
+        self.deque[self.index] = (x, y);
+        self.index = if self.index + 1 < period {
+            self.index + 1
+        } else {
+            0
+        };
+    }
+
+    /// Pearson correlation of x and y, from -1 to 1. It is 0 while one of them is constant.
+    pub fn correlation(&self) -> f64 {
+        if self.m2_x > 0.0 && self.m2_y > 0.0 {
+            (self.c_xy / (self.m2_x * self.m2_y).sqrt()).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Slope of the least squares line of y on x, cov(x, y) / var(x). It is 0 while x is
+    /// constant.
+    pub fn slope(&self) -> f64 {
+        if self.m2_x > 0.0 {
+            self.c_xy / self.m2_x
+        } else {
+            0.0
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.mean_x = 0.0;
+        self.mean_y = 0.0;
+        self.m2_x = 0.0;
+        self.m2_y = 0.0;
+        self.c_xy = 0.0;
+        for pair in self.deque.iter_mut() {
+            *pair = (0.0, 0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_push() {
+        let mut cov = RollingCovariance::new(3);
+        cov.push(1.0, 2.0);
+        assert_eq!(cov.correlation(), 0.0);
+
+        cov.push(2.0, 4.0);
+        cov.push(3.0, 5.0);
+        assert_eq!(round(cov.correlation()), 0.982);
+        assert_eq!(round(cov.slope()), 1.5);
+
+        // (1, 2) left the window
+        cov.push(4.0, 3.0);
+        assert_eq!(round(cov.correlation()), -0.5);
+        assert_eq!(round(cov.slope()), -0.5);
+    }
+
+    #[test]
+    fn test_constant() {
+        let mut cov = RollingCovariance::new(2);
+        cov.push(1.0, 5.0);
+        cov.push(2.0, 5.0);
+        assert_eq!(cov.correlation(), 0.0);
+        cov.push(3.0, 5.0);
+        assert_eq!(cov.correlation(), 0.0);
+        assert_eq!(cov.slope(), 0.0);
+
+        let mut cov = RollingCovariance::new(2);
+        cov.push(5.0, 1.0);
+        cov.push(5.0, 2.0);
+        assert_eq!(cov.slope(), 0.0);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut cov = RollingCovariance::new(2);
+        cov.push(1.0, 2.0);
+        cov.push(2.0, 4.0);
+
+        cov.clear();
+        cov.push(20.0, 1.0);
+        cov.push(10.0, 3.0);
+        assert_eq!(cov.correlation(), -1.0);
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Least squares line fitted to the latest `period` values, updated in `O(1)`.
+///
+/// The values are placed at x = 0, 1, ..., n - 1 from the oldest to the most recent one, so the
+/// sums of x and x<sup>2</sup> only depend on the number of values and only the sums of y,
+/// y<sup>2</sup> and x * y have to be maintained.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct RollingRegression {
+    index: usize,
+    count: usize,
+    sum_y: f64,
+    sum_y2: f64,
+    sum_xy: f64,
+    deque: Box<[f64]>,
+}
+
+impl RollingRegression {
+    /// `period` must be greater than 0.
+    pub fn new(period: usize) -> Self {
+        Self {
+            index: 0,
+            count: 0,
+            sum_y: 0.0,
+            sum_y2: 0.0,
+            sum_xy: 0.0,
+            deque: vec![0.0; period].into_boxed_slice(),
+        }
+    }
+
+    pub fn push(&mut self, value: f64) {
+        let period = self.deque.len();
+        if self.count < period {
+            self.sum_xy += self.count as f64 * value;
+            self.sum_y += value;
+            self.sum_y2 += value * value;
+            self.count += 1;
+        } else {
+            // Drop the oldest value and shift the remaining ones one step to the left.
+            let oldest = self.deque[self.index];
+            self.sum_xy += (period - 1) as f64 * value - (self.sum_y - oldest);
+            self.sum_y += value - oldest;
+            self.sum_y2 += value * value - oldest * oldest;
+        }
+
+        self.deque[self.index] = value;
+        self.index = if self.index + 1 < period {
+            self.index + 1
+        } else {
+            0
+        };
+    }
+
+    /// Returns the slope and the intercept (the value at x = 0) of the line.
+    pub fn line(&self) -> (f64, f64) {
+        let n = self.count as f64;
+        if self.count < 2 {
+            return (0.0, if self.count == 1 { self.sum_y } else { 0.0 });
+        }
+        let sum_x = n * (n - 1.0) / 2.0;
+        let sum_x2 = (n - 1.0) * n * (2.0 * n - 1.0) / 6.0;
+        let slope = (n * self.sum_xy - sum_x * self.sum_y) / (n * sum_x2 - sum_x * sum_x);
+        let intercept = (self.sum_y - slope * sum_x) / n;
+        (slope, intercept)
+    }
+
+    /// Returns the value of the line `steps` periods after the most recent value.
+    pub fn forecast(&self, steps: f64) -> f64 {
+        let (slope, intercept) = self.line();
+        intercept + slope * (self.count as f64 - 1.0 + steps)
+    }
+
+    /// Returns the standard error of the regression, the standard deviation of the values
+    /// around the line with `n - 2` degrees of freedom. It is 0 for 2 values or less.
+    pub fn standard_error(&self) -> f64 {
+        if self.count <= 2 {
+            return 0.0;
+        }
+        let (slope, intercept) = self.line();
+        let residuals = self.sum_y2 - intercept * self.sum_y - slope * self.sum_xy;
+        (residuals.max(0.0) / (self.count - 2) as f64).sqrt()
+    }
+
+    pub fn clear(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum_y = 0.0;
+        self.sum_y2 = 0.0;
+        self.sum_xy = 0.0;
+        for value in self.deque.iter_mut() {
+            *value = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line() {
+        let mut regression = RollingRegression::new(3);
+        assert_eq!(regression.line(), (0.0, 0.0));
+
+        regression.push(2.0);
+        assert_eq!(regression.line(), (0.0, 2.0));
+
+        regression.push(4.0);
+        assert_eq!(regression.line(), (2.0, 2.0));
+
+        regression.push(9.0);
+        assert_eq!(regression.line(), (3.5, 1.5));
+
+        // 4, 9, 5
+        regression.push(5.0);
+        assert_eq!(regression.line(), (0.5, 5.5));
+    }
+
+    #[test]
+    fn test_forecast() {
+        let mut regression = RollingRegression::new(4);
+        for value in &[1.0, 3.0, 5.0, 7.0, 9.0] {
+            regression.push(*value);
+        }
+        assert_eq!(regression.forecast(0.0), 9.0);
+        assert_eq!(regression.forecast(1.0), 11.0);
+    }
+
+    #[test]
+    fn test_standard_error() {
+        let mut regression = RollingRegression::new(3);
+        regression.push(2.0);
+        regression.push(4.0);
+        assert_eq!(regression.standard_error(), 0.0);
+
+        // residuals 0.5, -1, 0.5
+        regression.push(9.0);
+        assert!((regression.standard_error() - 1.5f64.sqrt()).abs() < 1e-12);
+
+        // 4, 9, 5: residuals -1.5, 3, -1.5
+        regression.push(5.0);
+        assert!((regression.standard_error() - 13.5f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut regression = RollingRegression::new(2);
+        regression.push(1.0);
+        regression.push(5.0);
+        regression.clear();
+        regression.push(3.0);
+        assert_eq!(regression.line(), (0.0, 3.0));
+    }
+}
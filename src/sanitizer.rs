@@ -0,0 +1,371 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{ExponentialMovingAverage, TrueRange};
+use crate::{Close, DataItem, High, Low, Next, Open, Period, Reset, Timestamp, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Validates and cleans bars before they are fed to indicators.
+///
+/// Market data feeds occasionally deliver broken bars: a high below the low, repeated stale
+/// bars or bad ticks far away from the market. A single one of them can distort indicators for
+/// a long time. The sanitizer sits in front of the indicators and, for every incoming bar,
+/// returns the cleaned bar together with a [Diagnostics](struct.Diagnostics.html) report:
+///
+/// * bars with a non finite value, or that can not form a valid [DataItem](struct.DataItem.html)
+///   (e.g. a negative price), are dropped and flagged _invalid_;
+/// * crossed prices are clamped: the high becomes the highest of open, high, low and close, the
+///   low the lowest of them, and a negative volume becomes 0. Such bars are flagged _clamped_;
+/// * a zero range bar identical to the previous bar is dropped and flagged _duplicate_;
+/// * a bar whose true range is greater than _multiplier_ times the ATR of the previous bars is
+///   flagged _outlier_. Outliers are only reported, they are kept and update the ATR. No bar is
+///   flagged before the ATR has seen _period_ bars.
+///
+/// Dropped bars do not change the state of the sanitizer.
+///
+/// # Parameters
+///
+/// * _period_ - period of the ATR used to detect outliers (integer greater than 0). Default is 14.
+/// * _multiplier_ - number of ATRs beyond which a bar is an outlier (greater than 0). Default is 5.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{BarSanitizer, Close, DataItem, High, Low, Next, Open, Timestamp, Volume};
+///
+/// // A bar as received from a feed, it is not validated.
+/// struct RawBar(f64, f64, f64, f64, f64);
+///
+/// impl Open for RawBar { fn open(&self) -> f64 { self.0 } }
+/// impl High for RawBar { fn high(&self) -> f64 { self.1 } }
+/// impl Low for RawBar { fn low(&self) -> f64 { self.2 } }
+/// impl Close for RawBar { fn close(&self) -> f64 { self.3 } }
+/// impl Volume for RawBar { fn volume(&self) -> f64 { self.4 } }
+/// impl Timestamp for RawBar {}
+///
+/// let mut sanitizer = BarSanitizer::new(14, 5.0).unwrap();
+/// let mut sma = SimpleMovingAverage::new(10).unwrap();
+///
+/// // The high and the low are swapped.
+/// let out = sanitizer.next(&RawBar(10.0, 9.0, 11.0, 10.5, 1000.0));
+/// assert!(out.diagnostics.clamped);
+///
+/// let bar: DataItem = out.bar.unwrap();
+/// assert_eq!((bar.high(), bar.low()), (11.0, 9.0));
+/// assert_eq!(sma.next(&bar), 10.5);
+/// ```
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BarSanitizer {
+    period: usize,
+    multiplier: f64,
+    true_range: TrueRange,
+    atr: ExponentialMovingAverage,
+    atr_value: f64,
+    count: usize,
+    // open, high, low, close and volume of the previous kept bar
+    prev: Option<(f64, f64, f64, f64, f64)>,
+}
+
+/// Bar returned by the [BarSanitizer](struct.BarSanitizer.html).
+#[derive(Debug, Clone)]
+pub struct SanitizedBar {
+    /// Cleaned bar, `None` when the bar was dropped.
+    pub bar: Option<DataItem>,
+    pub diagnostics: Diagnostics,
+}
+
+/// What the [BarSanitizer](struct.BarSanitizer.html) found in a bar.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Diagnostics {
+    /// The bar had a non finite or invalid value and was dropped.
+    pub invalid: bool,
+    /// Crossed prices or a negative volume were clamped.
+    pub clamped: bool,
+    /// The bar had a zero range and repeated the previous bar, it was dropped.
+    pub duplicate: bool,
+    /// The true range of the bar exceeded the outlier threshold.
+    pub outlier: bool,
+}
+
+impl Diagnostics {
+    /// Returns `true` when nothing was found in the bar.
+    pub fn is_clean(&self) -> bool {
+        *self == Diagnostics::default()
+    }
+}
+
+impl BarSanitizer {
+    pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        if !(multiplier > 0.0 && multiplier.is_finite()) {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            period,
+            multiplier,
+            true_range: TrueRange::new(),
+            atr: ExponentialMovingAverage::new(period)?,
+            atr_value: 0.0,
+            count: 0,
+            prev: None,
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    fn clean(&mut self, values: (f64, f64, f64, f64, f64), timestamp: Option<i64>) -> SanitizedBar {
+        let mut diagnostics = Diagnostics::default();
+        let (open, high, low, close, volume) = values;
+
+        if ![open, high, low, close, volume]
+            .iter()
+            .all(|v| v.is_finite())
+        {
+            diagnostics.invalid = true;
+            return SanitizedBar {
+                bar: None,
+                diagnostics,
+            };
+        }
+
+        let top = open.max(high).max(low).max(close);
+        let bottom = open.min(high).min(low).min(close);
+        let volume = volume.max(0.0);
+        diagnostics.clamped = top != high || bottom != low || volume != values.4;
+
+        let cleaned = (open, top, bottom, close, volume);
+        if top == bottom && self.prev == Some(cleaned) {
+            diagnostics.duplicate = true;
+            return SanitizedBar {
+                bar: None,
+                diagnostics,
+            };
+        }
+
+        let mut builder = DataItem::builder()
+            .open(open)
+            .high(top)
+            .low(bottom)
+            .close(close)
+            .volume(volume);
+        if let Some(timestamp) = timestamp {
+            builder = builder.timestamp(timestamp);
+        }
+        let bar = match builder.build() {
+            Ok(bar) => bar,
+            Err(_) => {
+                diagnostics.invalid = true;
+                return SanitizedBar {
+                    bar: None,
+                    diagnostics,
+                };
+            }
+        };
+
+        let true_range = self.true_range.next(&bar);
+        diagnostics.outlier =
+            self.count >= self.period && true_range > self.multiplier * self.atr_value;
+        self.atr_value = self.atr.next(true_range);
+        self.count += 1;
+        self.prev = Some(cleaned);
+
+        SanitizedBar {
+            bar: Some(bar),
+            diagnostics,
+        }
+    }
+}
+
+impl Period for BarSanitizer {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume + Timestamp> Next<&T> for BarSanitizer {
+    type Output = SanitizedBar;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let values = (
+            input.open(),
+            input.high(),
+            input.low(),
+            input.close(),
+            input.volume(),
+        );
+        self.clean(values, input.timestamp())
+    }
+}
+
+impl Reset for BarSanitizer {
+    fn reset(&mut self) {
+        self.true_range.reset();
+        self.atr.reset();
+        self.atr_value = 0.0;
+        self.count = 0;
+        self.prev = None;
+    }
+}
+
+impl Default for BarSanitizer {
+    fn default() -> Self {
+        Self::new(14, 5.0).unwrap()
+    }
+}
+
+impl fmt::Display for BarSanitizer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SANITIZER({}, {})", self.period, self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> Bar {
+        Bar::new()
+            .open(open)
+            .high(high)
+            .low(low)
+            .close(close)
+            .volume(100.0)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(BarSanitizer::new(0, 5.0).is_err());
+        assert!(BarSanitizer::new(14, 0.0).is_err());
+        assert!(BarSanitizer::new(14, f64::NAN).is_err());
+        assert!(BarSanitizer::new(14, f64::INFINITY).is_err());
+        assert!(BarSanitizer::new(1, 0.5).is_ok());
+    }
+
+    #[test]
+    fn test_clean_bar() {
+        let mut sanitizer = BarSanitizer::new(3, 5.0).unwrap();
+        let out = sanitizer.next(&bar(10.0, 11.0, 9.0, 10.5).timestamp(60));
+
+        assert!(out.diagnostics.is_clean());
+        let bar = out.bar.unwrap();
+        assert_eq!(
+            (bar.open(), bar.high(), bar.low(), bar.close(), bar.volume()),
+            (10.0, 11.0, 9.0, 10.5, 100.0)
+        );
+        assert_eq!(bar.timestamp(), Some(60));
+    }
+
+    #[test]
+    fn test_clamp() {
+        let mut sanitizer = BarSanitizer::new(3, 5.0).unwrap();
+
+        // close above the high
+        let out = sanitizer.next(&bar(10.0, 11.0, 9.0, 12.0));
+        assert!(out.diagnostics.clamped);
+        assert_eq!(out.bar.unwrap().high(), 12.0);
+
+        // crossed high and low
+        let out = sanitizer.next(&bar(10.0, 9.5, 10.5, 10.0));
+        let bar = out.bar.unwrap();
+        assert_eq!((bar.high(), bar.low()), (10.5, 9.5));
+
+        let out = sanitizer.next(&Bar::new().high(2).low(1).close(1.5).open(1.5).volume(-5.0));
+        assert!(out.diagnostics.clamped);
+        assert_eq!(out.bar.unwrap().volume(), 0.0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut sanitizer = BarSanitizer::new(3, 5.0).unwrap();
+
+        let out = sanitizer.next(&bar(10.0, f64::NAN, 9.0, 10.0));
+        assert!(out.diagnostics.invalid);
+        assert!(out.bar.is_none());
+
+        let out = sanitizer.next(&bar(-1.0, 1.0, -1.0, 0.0));
+        assert!(out.diagnostics.invalid);
+        assert!(out.bar.is_none());
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let mut sanitizer = BarSanitizer::new(3, 5.0).unwrap();
+
+        assert!(sanitizer.next(&bar(10.0, 10.0, 10.0, 10.0)).bar.is_some());
+        let out = sanitizer.next(&bar(10.0, 10.0, 10.0, 10.0));
+        assert!(out.diagnostics.duplicate);
+        assert!(out.bar.is_none());
+
+        // a different volume is not a duplicate
+        let out = sanitizer.next(&bar(10.0, 10.0, 10.0, 10.0).volume(50.0));
+        assert!(out.bar.is_some());
+
+        // a duplicate with a range is kept
+        sanitizer.next(&bar(10.0, 11.0, 9.0, 10.0));
+        let out = sanitizer.next(&bar(10.0, 11.0, 9.0, 10.0));
+        assert!(!out.diagnostics.duplicate);
+    }
+
+    #[test]
+    fn test_outlier() {
+        let mut sanitizer = BarSanitizer::new(3, 3.0).unwrap();
+
+        // not flagged during the warm up
+        assert!(
+            !sanitizer
+                .next(&bar(10.0, 11.0, 9.0, 10.0))
+                .diagnostics
+                .outlier
+        );
+        assert!(
+            !sanitizer
+                .next(&bar(10.0, 30.0, 9.0, 10.0))
+                .diagnostics
+                .outlier
+        );
+        assert!(
+            !sanitizer
+                .next(&bar(10.0, 11.0, 9.0, 10.0))
+                .diagnostics
+                .outlier
+        );
+
+        // ATR is 6.75, the threshold 20.25
+        let out = sanitizer.next(&bar(10.0, 36.0, 9.0, 10.0));
+        assert!(out.diagnostics.outlier);
+        assert!(out.bar.is_some());
+
+        // ATR is 16.875, the threshold 50.625
+        let out = sanitizer.next(&bar(10.0, 36.0, 9.0, 10.0));
+        assert!(!out.diagnostics.outlier);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sanitizer = BarSanitizer::new(1, 2.0).unwrap();
+        sanitizer.next(&bar(10.0, 10.0, 10.0, 10.0));
+        sanitizer.reset();
+
+        let out = sanitizer.next(&bar(10.0, 10.0, 10.0, 10.0));
+        assert!(out.diagnostics.is_clean());
+        assert!(out.bar.is_some());
+    }
+
+    #[test]
+    fn test_default() {
+        BarSanitizer::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let sanitizer = BarSanitizer::new(20, 4.5).unwrap();
+        assert_eq!(format!("{}", sanitizer), "SANITIZER(20, 4.5)");
+    }
+}
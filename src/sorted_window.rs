@@ -0,0 +1,240 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A fixed size window of the latest values, that keeps a sorted copy of them to answer order
+/// statistic queries.
+///
+/// Lookups are `O(log n)`, inserting a value shifts the sorted copy, which is a `memmove` of at
+/// most `n` values.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct SortedWindow {
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+    sorted: Vec<f64>,
+}
+
+impl SortedWindow {
+    /// `capacity` must be greater than 0.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            index: 0,
+            count: 0,
+            deque: vec![0.0; capacity].into_boxed_slice(),
+            sorted: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.deque.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.count == self.deque.len()
+    }
+
+    /// Adds a value to the window and returns the value that was pushed out of it, if any.
+    pub fn push(&mut self, value: f64) -> Option<f64> {
+        let removed = if self.is_full() {
+            let old = self.deque[self.index];
+            let position = self.position_of(old);
+            self.sorted.remove(position);
+            Some(old)
+        } else {
+            self.count += 1;
+            None
+        };
+
+        self.deque[self.index] = value;
+        self.index = if self.index + 1 < self.deque.len() {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let position = self.sorted.partition_point(|&v| v < value);
+        self.sorted.insert(position, value);
+        removed
+    }
+
+    /// Returns the number of values in the window strictly less than `value`.
+    pub fn count_below(&self, value: f64) -> usize {
+        self.sorted.partition_point(|&v| v < value)
+    }
+
+    /// Returns the number of values in the window less than or equal to `value`.
+    pub fn count_not_above(&self, value: f64) -> usize {
+        self.sorted.partition_point(|&v| v <= value)
+    }
+
+    /// Returns the average rank of `value` in the window, from 1 to `len()`. Tied values share
+    /// the average of their ranks.
+    pub fn rank(&self, value: f64) -> f64 {
+        (self.count_below(value) + self.count_not_above(value) + 1) as f64 / 2.0
+    }
+
+    /// Returns the `q` quantile of the window, `q` from 0 to 1, linearly interpolated between
+    /// the closest values. The window must not be empty.
+    pub fn quantile(&self, q: f64) -> f64 {
+        let position = (self.count - 1) as f64 * q;
+        let below = position.floor() as usize;
+        let above = position.ceil() as usize;
+        let (low, high) = (self.sorted[below], self.sorted[above]);
+        low + (high - low) * (position - below as f64)
+    }
+
+    /// Returns the median of the absolute deviations of the values from their median. The
+    /// window must not be empty.
+    pub fn median_absolute_deviation(&self) -> f64 {
+        let median = self.quantile(0.5);
+        // The deviations of the values below and above the median are both sorted, merge them.
+        let split = self.sorted.partition_point(|&v| v < median);
+        let (mut below, mut above) = (split, split);
+        let mut deviations = Vec::with_capacity(self.count);
+        while deviations.len() < self.count {
+            let low = below.checked_sub(1).map(|i| median - self.sorted[i]);
+            let high = self.sorted.get(above).map(|v| v - median);
+            match (low, high) {
+                (Some(low), Some(high)) if low < high => {
+                    deviations.push(low);
+                    below -= 1;
+                }
+                (_, Some(high)) => {
+                    deviations.push(high);
+                    above += 1;
+                }
+                (Some(low), None) => {
+                    deviations.push(low);
+                    below -= 1;
+                }
+                (None, None) => break,
+            }
+        }
+        let n = deviations.len();
+        (deviations[(n - 1) / 2] + deviations[n / 2]) / 2.0
+    }
+
+    /// Returns the values of the window, in no particular order. Windows of the same capacity
+    /// fed in lockstep return their values in the same order.
+    pub fn values(&self) -> &[f64] {
+        &self.deque[..self.count]
+    }
+
+    pub fn clear(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for value in self.deque.iter_mut() {
+            *value = 0.0;
+        }
+        self.sorted.clear();
+    }
+
+    fn position_of(&self, value: f64) -> usize {
+        let position = self.sorted.partition_point(|&v| v < value);
+        if position < self.sorted.len() && self.sorted[position] == value {
+            position
+        } else {
+            // Values that are not ordered (NaN) are looked up linearly.
+            self.sorted
+                .iter()
+                .position(|v| v.to_bits() == value.to_bits())
+                .unwrap_or(self.sorted.len() - 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push() {
+        let mut window = SortedWindow::new(3);
+        assert_eq!(window.push(3.0), None);
+        assert_eq!(window.push(1.0), None);
+        assert_eq!(window.push(2.0), None);
+        assert!(window.is_full());
+        assert_eq!(window.push(5.0), Some(3.0));
+        assert_eq!(window.push(1.0), Some(1.0));
+        assert_eq!(window.len(), 3);
+        assert_eq!(window.count_below(5.0), 2);
+    }
+
+    #[test]
+    fn test_median_absolute_deviation() {
+        let mut window = SortedWindow::new(5);
+        window.push(3.0);
+        assert_eq!(window.median_absolute_deviation(), 0.0);
+        for value in &[1.0, 2.0, 4.0, 100.0] {
+            window.push(*value);
+        }
+        // median 3, deviations 0, 1, 1, 2, 97
+        assert_eq!(window.median_absolute_deviation(), 1.0);
+        // 1, 2, 4, 100, 9: median 4, deviations 0, 2, 3, 5, 96
+        window.push(9.0);
+        assert_eq!(window.median_absolute_deviation(), 3.0);
+
+        let mut window = SortedWindow::new(4);
+        for value in &[1.0, 2.0, 6.0, 10.0] {
+            window.push(*value);
+        }
+        // median 4, deviations 2, 2, 3, 6
+        assert_eq!(window.median_absolute_deviation(), 2.5);
+    }
+
+    #[test]
+    fn test_ranks() {
+        let mut window = SortedWindow::new(5);
+        for value in &[4.0, 2.0, 2.0, 8.0, 6.0] {
+            window.push(*value);
+        }
+        assert_eq!(window.count_below(2.0), 0);
+        assert_eq!(window.count_below(5.0), 3);
+        assert_eq!(window.count_below(9.0), 5);
+    }
+
+    #[test]
+    fn test_rank() {
+        let mut window = SortedWindow::new(5);
+        for value in &[4.0, 2.0, 2.0, 8.0, 6.0] {
+            window.push(*value);
+        }
+        assert_eq!(window.count_not_above(2.0), 2);
+        assert_eq!(window.rank(2.0), 1.5);
+        assert_eq!(window.rank(4.0), 3.0);
+        assert_eq!(window.rank(8.0), 5.0);
+        assert_eq!(window.values(), &[4.0, 2.0, 2.0, 8.0, 6.0]);
+    }
+
+    #[test]
+    fn test_quantile() {
+        let mut window = SortedWindow::new(5);
+        window.push(4.0);
+        assert_eq!(window.quantile(0.25), 4.0);
+
+        for value in &[2.0, 2.0, 8.0, 6.0] {
+            window.push(*value);
+        }
+        assert_eq!(window.quantile(0.0), 2.0);
+        assert_eq!(window.quantile(0.5), 4.0);
+        assert_eq!(window.quantile(0.9), 7.2);
+        assert_eq!(window.quantile(1.0), 8.0);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut window = SortedWindow::new(2);
+        window.push(1.0);
+        window.push(2.0);
+        window.clear();
+        assert_eq!(window.len(), 0);
+        assert_eq!(window.push(3.0), None);
+        assert_eq!(window.len(), 1);
+        assert_eq!(window.count_below(4.0), 1);
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt;
+
+use crate::indicators::Sourced;
+use crate::{Close, High, Low, Next, Open, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Value of a bar that is fed to an indicator.
+///
+/// Used by [Sourced](indicators/struct.Sourced.html) to run `f64` indicators on something
+/// other than the close price, e.g. RSI of volume or an EMA of the typical price. See
+/// [WithSource](trait.WithSource.html) to select it when building the indicator.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Source {
+    Open,
+    High,
+    Low,
+    #[default]
+    Close,
+    Volume,
+    /// high - low
+    Spread,
+    /// (high + low) / 2
+    Median,
+    /// (high + low + close) / 3
+    Typical,
+    /// (open + high + low + close) / 4
+    Average,
+    /// (high + low + 2 * close) / 4, the weighted close
+    Weighted,
+}
+
+impl Source {
+    /// Returns the value of the source for the given bar.
+    pub fn value<T: Open + High + Low + Close + Volume>(&self, bar: &T) -> f64 {
+        match self {
+            Source::Open => bar.open(),
+            Source::High => bar.high(),
+            Source::Low => bar.low(),
+            Source::Close => bar.close(),
+            Source::Volume => bar.volume(),
+            Source::Spread => bar.high() - bar.low(),
+            Source::Median => (bar.high() + bar.low()) / 2.0,
+            Source::Typical => (bar.high() + bar.low() + bar.close()) / 3.0,
+            Source::Average => (bar.open() + bar.high() + bar.low() + bar.close()) / 4.0,
+            Source::Weighted => (bar.high() + bar.low() + 2.0 * bar.close()) / 4.0,
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Source::Open => "open",
+            Source::High => "high",
+            Source::Low => "low",
+            Source::Close => "close",
+            Source::Volume => "volume",
+            Source::Spread => "spread",
+            Source::Median => "hl2",
+            Source::Typical => "hlc3",
+            Source::Average => "ohlc4",
+            Source::Weighted => "hlcc4",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Selects the [Source](enum.Source.html) of an `f64` indicator fed with bars.
+///
+/// Implemented by every indicator implementing `Next<f64>`, so the value of the bar is chosen
+/// where the indicator is built instead of wrapping it in [Sourced](indicators/struct.Sourced.html)
+/// by hand.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{DataItem, Next, Source, WithSource};
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap().with_source(Source::Median);
+///
+/// let bar = |high: f64, low: f64| {
+///     DataItem::builder()
+///         .open(low)
+///         .high(high)
+///         .low(low)
+///         .close(low)
+///         .volume(1000.0)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// assert_eq!(sma.next(&bar(12.0, 10.0)), 11.0);
+/// assert_eq!(sma.next(&bar(16.0, 12.0)), 12.5);
+/// assert_eq!(sma.to_string(), "SMA(2)[hl2]");
+/// ```
+pub trait WithSource: Next<f64> + Sized {
+    fn with_source(self, source: Source) -> Sourced<Self> {
+        Sourced::new(self, source)
+    }
+}
+
+impl<I: Next<f64>> WithSource for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{RelativeStrengthIndex, SimpleMovingAverage};
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_value() {
+        let bar = Bar::new().open(2).high(4).low(1).close(3).volume(100.0);
+
+        assert_eq!(Source::Open.value(&bar), 2.0);
+        assert_eq!(Source::High.value(&bar), 4.0);
+        assert_eq!(Source::Low.value(&bar), 1.0);
+        assert_eq!(Source::Close.value(&bar), 3.0);
+        assert_eq!(Source::Volume.value(&bar), 100.0);
+        assert_eq!(Source::Spread.value(&bar), 3.0);
+        assert_eq!(Source::Median.value(&bar), 2.5);
+        assert_eq!(Source::Typical.value(&bar), 8.0 / 3.0);
+        assert_eq!(Source::Average.value(&bar), 2.5);
+        assert_eq!(Source::Weighted.value(&bar), 2.75);
+    }
+
+    #[test]
+    fn test_with_source() {
+        let sma = SimpleMovingAverage::new(2).unwrap();
+        let mut typical = sma.with_source(Source::Typical);
+        assert_eq!(typical.source(), Source::Typical);
+        assert_eq!(typical.next(&Bar::new().high(5).low(1).close(3)), 3.0);
+
+        let mut weighted = RelativeStrengthIndex::new(3)
+            .unwrap()
+            .with_source(Source::Weighted);
+        assert_eq!(weighted.next(&Bar::new().high(4).low(2).close(3)), 50.0);
+        assert_eq!(format!("{}", weighted), "RSI(3)[hlcc4]");
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Source::default(), Source::Close);
+    }
+}
@@ -0,0 +1,311 @@
+//! Helpers to test indicators, including custom ones built on the traits of this crate.
+//!
+//! The module is enabled by the `testing` feature, typically as a dev-dependency:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! ta = { version = "*", features = ["testing"] }
+//! ```
+//!
+//! It provides a minimal [Bar](struct.Bar.html) implementing the price traits, a golden
+//! series of daily [AMZN](fixtures/constant.AMZN.html) bars, a
+//! [conformance check](fn.assert_conformance.html) of the behavior every indicator shares and
+//! an [approximate comparison](fn.assert_outputs.html) of outputs with expected values.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::indicators::SimpleMovingAverage;
+//! use ta::testing::{assert_conformance, assert_outputs, fixtures};
+//!
+//! let closes = fixtures::amzn_closes();
+//! assert_conformance(SimpleMovingAverage::new(5).unwrap(), &closes);
+//!
+//! let mut sma = SimpleMovingAverage::new(2).unwrap();
+//! assert_outputs(&mut sma, &closes[..3], &[753.67, 755.425, 768.815], 1e-3);
+//! ```
+
+use std::fmt;
+
+use crate::{Close, High, Lookback, Low, Next, Open, Reset, Timestamp, Volume};
+
+/// Bar with all the prices set to 0 by default, built field by field.
+///
+/// # Example
+///
+/// ```
+/// use ta::testing::Bar;
+/// use ta::Close;
+///
+/// let bar = Bar::new().high(11).low(9).close(10.5);
+/// // the builder methods shadow the trait methods
+/// assert_eq!(Close::close(&bar), 10.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+    timestamp: Option<i64>,
+}
+
+impl Bar {
+    pub fn new() -> Self {
+        Self {
+            open: 0.0,
+            close: 0.0,
+            low: 0.0,
+            high: 0.0,
+            volume: 0.0,
+            timestamp: None,
+        }
+    }
+
+    pub fn open<T: Into<f64>>(mut self, val: T) -> Self {
+        self.open = val.into();
+        self
+    }
+
+    pub fn high<T: Into<f64>>(mut self, val: T) -> Self {
+        self.high = val.into();
+        self
+    }
+
+    pub fn low<T: Into<f64>>(mut self, val: T) -> Self {
+        self.low = val.into();
+        self
+    }
+
+    pub fn close<T: Into<f64>>(mut self, val: T) -> Self {
+        self.close = val.into();
+        self
+    }
+
+    pub fn volume(mut self, val: f64) -> Self {
+        self.volume = val;
+        self
+    }
+
+    pub fn timestamp(mut self, val: i64) -> Self {
+        self.timestamp = Some(val);
+        self
+    }
+}
+
+impl Default for Bar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timestamp for Bar {
+    fn timestamp(&self) -> Option<i64> {
+        self.timestamp
+    }
+}
+
+impl Open for Bar {
+    fn open(&self) -> f64 {
+        self.open
+    }
+}
+
+impl Close for Bar {
+    fn close(&self) -> f64 {
+        self.close
+    }
+}
+
+impl Low for Bar {
+    fn low(&self) -> f64 {
+        self.low
+    }
+}
+
+impl High for Bar {
+    fn high(&self) -> f64 {
+        self.high
+    }
+}
+
+impl Volume for Bar {
+    fn volume(&self) -> f64 {
+        self.volume
+    }
+}
+
+/// Rounds to 3 decimal places.
+pub fn round(num: f64) -> f64 {
+    (num * 1000.0).round() / 1000.00
+}
+
+/// Checks the behavior every indicator shares, panicking on a failure.
+///
+/// * The inputs cover the warm-up: there are more of them than the
+///   [lookback](../trait.Lookback.html).
+/// * After a [reset](../trait.Reset.html) the indicator returns the same outputs as a fresh
+///   one.
+/// * The [Display](https://doc.rust-lang.org/std/fmt/trait.Display.html) name is not empty.
+pub fn assert_conformance<In, I>(mut indicator: I, inputs: &[In])
+where
+    In: Copy,
+    I: Next<In> + Reset + Lookback + fmt::Display,
+    I::Output: PartialEq + fmt::Debug,
+{
+    assert!(
+        inputs.len() > indicator.lookback(),
+        "{} inputs do not cover the lookback of {}",
+        inputs.len(),
+        indicator.lookback()
+    );
+
+    let outputs: Vec<I::Output> = inputs.iter().map(|&input| indicator.next(input)).collect();
+    indicator.reset();
+    for (i, (&input, expected)) in inputs.iter().zip(outputs).enumerate() {
+        assert_eq!(
+            indicator.next(input),
+            expected,
+            "output {} differs after reset",
+            i
+        );
+    }
+
+    assert!(!indicator.to_string().is_empty(), "empty Display name");
+}
+
+/// Feeds the inputs and compares the outputs with the expected values, panicking on the first
+/// one that differs by more than _tolerance_. NaN only matches NaN.
+pub fn assert_outputs<In, I>(indicator: &mut I, inputs: &[In], expected: &[f64], tolerance: f64)
+where
+    In: Copy,
+    I: Next<In, Output = f64>,
+{
+    assert_eq!(
+        inputs.len(),
+        expected.len(),
+        "one expected output per input"
+    );
+
+    for (i, (&input, &expected)) in inputs.iter().zip(expected).enumerate() {
+        let output = indicator.next(input);
+        let matches = if expected.is_nan() {
+            output.is_nan()
+        } else {
+            (output - expected).abs() <= tolerance
+        };
+        assert!(
+            matches,
+            "output {} is {}, expected {} within {}",
+            i, output, expected, tolerance
+        );
+    }
+}
+
+/// Golden series of market data.
+pub mod fixtures {
+    use super::Bar;
+
+    /// Daily open, high, low, close and volume of Amazon (AMZN) in January 2017, starting on
+    /// January 3rd.
+    pub const AMZN: [(f64, f64, f64, f64, f64); 20] = [
+        (757.919983, 758.760010, 747.700012, 753.669983, 3521100.0),
+        (758.390015, 759.679993, 754.200012, 757.179993, 2510500.0),
+        (761.549988, 782.400024, 760.260010, 780.450012, 5830100.0),
+        (782.359985, 799.440002, 778.479980, 795.989990, 5986200.0),
+        (798.000000, 801.770020, 791.770020, 796.919983, 3440100.0),
+        (796.599976, 798.000000, 789.539978, 795.900024, 2558400.0),
+        (793.659973, 799.500000, 789.510010, 799.020020, 2992800.0),
+        (800.309998, 814.130005, 799.500000, 813.640015, 4873900.0),
+        (814.320007, 821.650024, 811.400024, 817.140015, 3791900.0),
+        (815.700012, 816.000000, 803.440002, 809.719971, 3659400.0),
+        (809.500000, 811.729980, 804.270020, 807.479980, 2354200.0),
+        (810.000000, 813.510010, 807.320007, 809.039978, 2540800.0),
+        (815.280029, 816.020020, 806.260010, 808.330017, 3376200.0),
+        (806.799988, 818.500000, 805.080017, 817.880005, 2797500.0),
+        (822.000000, 823.989990, 814.500000, 822.440002, 2971700.0),
+        (825.789978, 837.419983, 825.289978, 836.520020, 3922600.0),
+        (835.530029, 843.840027, 833.000000, 839.150024, 3586300.0),
+        (839.000000, 839.700012, 829.440002, 835.770020, 2998700.0),
+        (833.000000, 833.500000, 816.380005, 830.380005, 3747300.0),
+        (823.750000, 826.989990, 819.559998, 823.479980, 3137200.0),
+    ];
+
+    /// The [AMZN](constant.AMZN.html) series as bars.
+    pub fn amzn_bars() -> Vec<Bar> {
+        AMZN.iter()
+            .map(|&(open, high, low, close, volume)| {
+                Bar::new()
+                    .open(open)
+                    .high(high)
+                    .low(low)
+                    .close(close)
+                    .volume(volume)
+            })
+            .collect()
+    }
+
+    /// Close prices of the [AMZN](constant.AMZN.html) series.
+    pub fn amzn_closes() -> Vec<f64> {
+        AMZN.iter().map(|&(_, _, _, close, _)| close).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{AverageTrueRange, SimpleMovingAverage as Sma};
+
+    #[test]
+    fn test_fixtures() {
+        let bars = fixtures::amzn_bars();
+        assert_eq!(bars.len(), 20);
+        assert_eq!(Close::close(&bars[0]), 753.669983);
+        assert_eq!(Volume::volume(&bars[19]), 3137200.0);
+        assert_eq!(fixtures::amzn_closes()[19], 823.47998);
+    }
+
+    #[test]
+    fn test_assert_outputs() {
+        let expected = [
+            753.67, 755.425, 763.767, 771.822, 776.842, 785.288, 793.656, 800.294, 804.524,
+            807.084, 809.4, 811.404, 810.342, 810.49, 813.034, 818.842, 824.864, 830.352, 832.852,
+            833.06,
+        ];
+        let mut sma = Sma::new(5).unwrap();
+        assert_outputs(&mut sma, &fixtures::amzn_closes(), &expected, 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "output 1 is 755.424988, expected 755")]
+    fn test_assert_outputs_mismatch() {
+        let mut sma = Sma::new(5).unwrap();
+        assert_outputs(
+            &mut sma,
+            &fixtures::amzn_closes()[..2],
+            &[753.67, 755.0],
+            1e-3,
+        );
+    }
+
+    #[test]
+    fn test_assert_outputs_nan() {
+        let mut sma = Sma::new(1).unwrap();
+        assert_outputs(&mut sma, &[f64::NAN], &[f64::NAN], 0.0);
+    }
+
+    #[test]
+    fn test_assert_conformance() {
+        let bars = fixtures::amzn_bars();
+        let refs: Vec<&Bar> = bars.iter().collect();
+        assert_conformance(AverageTrueRange::default(), &refs);
+        assert_conformance(Sma::new(20).unwrap(), &fixtures::amzn_closes());
+    }
+
+    #[test]
+    #[should_panic(expected = "do not cover the lookback")]
+    fn test_assert_conformance_short_inputs() {
+        assert_conformance(Sma::new(21).unwrap(), &fixtures::amzn_closes());
+    }
+}
@@ -1,6 +1,8 @@
 // Indicator traits
 //
 
+use crate::errors::Result;
+
 /// Resets an indicator to the initial state.
 pub trait Reset {
     fn reset(&mut self);
@@ -11,6 +13,33 @@ pub trait Period {
     fn period(&self) -> usize;
 }
 
+/// Number of leading outputs computed on incomplete data.
+///
+/// An indicator needs `lookback() + 1` inputs before its output is fully warmed up. Composite
+/// indicators add up the lookbacks of the indicators they are built from, e.g.
+/// [StochasticOf](indicators/struct.StochasticOf.html) wrapping an RSI of period 14 over a
+/// period of 14 has a lookback of 27, while its period is 14.
+pub trait Lookback {
+    fn lookback(&self) -> usize;
+}
+
+/// Creates an indicator from a single period parameter.
+///
+/// Typically implemented by moving averages, so they can be plugged into composite indicators
+/// like [AverageTrueRange](indicators/struct.AverageTrueRange.html) as a generic smoothing parameter.
+pub trait NewWithPeriod: Sized {
+    fn new_with_period(period: usize) -> Result<Self>;
+}
+
+/// Changes the period of an indicator while it is running.
+///
+/// The state is kept: smoothing indicators carry on from their current value with the new
+/// smoothing factor, window indicators keep their most recent inputs that fit in the new window.
+/// Used by [Adaptive](indicators/struct.Adaptive.html) to drive the period from another indicator.
+pub trait SetPeriod {
+    fn set_period(&mut self, period: usize) -> Result<()>;
+}
+
 /// Consumes a data item of type `T` and returns `Output`.
 ///
 /// Typically `T` can be `f64` or a struct similar to [DataItem](struct.DataItem.html), that implements
@@ -23,6 +52,40 @@ pub trait Period {
 pub trait Next<T> {
     type Output;
     fn next(&mut self, input: T) -> Self::Output;
+
+    /// Feeds all the inputs of an iterator and returns the last output with the number of
+    /// inputs consumed, or `None` when the iterator is empty.
+    ///
+    /// A shortcut for one-shot computations, e.g. the ATR at the end of a history, without
+    /// collecting the intermediate outputs. Any iterator works, including `&mut dyn Iterator`,
+    /// which is left positioned after its last item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::indicators::SimpleMovingAverage;
+    /// use ta::Next;
+    ///
+    /// let mut sma = SimpleMovingAverage::new(3).unwrap();
+    /// assert_eq!(sma.consume(vec![1.0, 2.0, 3.0, 4.0]), Some((3.0, 4)));
+    ///
+    /// let mut inputs = [5.0, 6.0].iter().copied();
+    /// let source: &mut dyn Iterator<Item = f64> = &mut inputs;
+    /// assert_eq!(sma.consume(source), Some((5.0, 2)));
+    /// assert_eq!(sma.consume(Vec::<f64>::new()), None);
+    /// ```
+    fn consume<I: IntoIterator<Item = T>>(&mut self, inputs: I) -> Option<(Self::Output, usize)>
+    where
+        Self: Sized,
+    {
+        let mut last = None;
+        let mut count = 0;
+        for input in inputs {
+            last = Some(self.next(input));
+            count += 1;
+        }
+        last.map(|output| (output, count))
+    }
 }
 
 /// Open price of a particular period.
@@ -49,3 +112,13 @@ pub trait High {
 pub trait Volume {
     fn volume(&self) -> f64;
 }
+
+/// Start time of a particular period, as a Unix timestamp in seconds.
+///
+/// Only needed by indicators that depend on calendar time, e.g. a session VWAP. The default
+/// implementation returns `None`, which means the time is unknown.
+pub trait Timestamp {
+    fn timestamp(&self) -> Option<i64> {
+        None
+    }
+}
@@ -0,0 +1,214 @@
+//! Typed units of indicator outputs.
+//!
+//! Indicators return plain `f64` values, so nothing prevents comparing an
+//! [ATR](../indicators/struct.AverageTrueRange.html), measured in price, with a
+//! [NATR](../indicators/struct.NormalizedAverageTrueRange.html), measured in percent. Wrapping an
+//! indicator in [Typed](../indicators/struct.Typed.html) makes it return one of the newtypes of
+//! this module instead, and mixing units becomes a compile-time error.
+//!
+//! Values of the same unit can be added, subtracted and compared, and scaled by `f64`. Going
+//! from one unit to another is explicit, e.g. [Price::percent_of](struct.Price.html#method.percent_of).
+//!
+//! # Example
+//!
+//! ```
+//! use ta::indicators::{AverageTrueRange, NormalizedAverageTrueRange, Typed};
+//! use ta::units::{Percent, Price};
+//! use ta::{DataItem, Next};
+//!
+//! let mut atr = Typed::new(AverageTrueRange::default());
+//! let mut natr = Typed::new(NormalizedAverageTrueRange::default());
+//!
+//! let bar = DataItem::builder()
+//!     .open(10.0)
+//!     .high(11.0)
+//!     .low(9.0)
+//!     .close(10.0)
+//!     .volume(1000.0)
+//!     .build()
+//!     .unwrap();
+//!
+//! let atr: Price = atr.next(&bar);
+//! let natr: Percent = natr.next(&bar);
+//!
+//! // `atr < natr` does not compile
+//! assert_eq!(atr.percent_of(Price(10.0)), natr);
+//! ```
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Unit of the output of an indicator.
+///
+/// Only implemented by indicators whose output has the same unit whatever the input is, e.g.
+/// [RSI](../indicators/struct.RelativeStrengthIndex.html) is always a percentage, while a moving
+/// average has the unit of its input.
+pub trait OutputUnit {
+    type Unit: From<f64>;
+}
+
+/// Amount in the unit of the price, e.g. a price level or a range.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Price(pub f64);
+
+/// Percentage, where 100 is the whole.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Percent(pub f64);
+
+/// Amount in units of traded volume.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct VolumeUnits(pub f64);
+
+macro_rules! impl_unit {
+    ($unit:ident) => {
+        impl $unit {
+            pub fn value(self) -> f64 {
+                self.0
+            }
+        }
+
+        impl From<f64> for $unit {
+            fn from(value: f64) -> Self {
+                $unit(value)
+            }
+        }
+
+        impl From<$unit> for f64 {
+            fn from(unit: $unit) -> Self {
+                unit.0
+            }
+        }
+
+        impl Add for $unit {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $unit(self.0 + other.0)
+            }
+        }
+
+        impl AddAssign for $unit {
+            fn add_assign(&mut self, other: Self) {
+                self.0 += other.0;
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $unit(self.0 - other.0)
+            }
+        }
+
+        impl SubAssign for $unit {
+            fn sub_assign(&mut self, other: Self) {
+                self.0 -= other.0;
+            }
+        }
+
+        impl Neg for $unit {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $unit(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $unit {
+            type Output = Self;
+
+            fn mul(self, factor: f64) -> Self {
+                $unit(self.0 * factor)
+            }
+        }
+
+        impl Div<f64> for $unit {
+            type Output = Self;
+
+            fn div(self, divisor: f64) -> Self {
+                $unit(self.0 / divisor)
+            }
+        }
+
+        // Ratio of two amounts of the same unit.
+        impl Div for $unit {
+            type Output = f64;
+
+            fn div(self, other: Self) -> f64 {
+                self.0 / other.0
+            }
+        }
+
+        impl fmt::Display for $unit {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+impl_unit!(Price);
+impl_unit!(Percent);
+impl_unit!(VolumeUnits);
+
+impl Price {
+    /// Expresses the amount as a percentage of _reference_, e.g. a range relative to the close.
+    pub fn percent_of(self, reference: Price) -> Percent {
+        Percent(self.0 / reference.0 * 100.0)
+    }
+}
+
+impl Percent {
+    /// Returns the amount this percentage represents of _reference_.
+    pub fn of(self, reference: Price) -> Price {
+        Price(self.0 / 100.0 * reference.0)
+    }
+
+    /// Returns the percentage as a fraction, where 1 is the whole.
+    pub fn fraction(self) -> f64 {
+        self.0 / 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut price = Price(10.0) + Price(2.5) - Price(0.5);
+        assert_eq!(price, Price(12.0));
+
+        price += Price(3.0);
+        price -= Price(1.0);
+        assert_eq!(price * 2.0, Price(28.0));
+        assert_eq!(price / 2.0, Price(7.0));
+        assert_eq!(price / Price(7.0), 2.0);
+        assert_eq!(-price, Price(-14.0));
+        assert!(Percent(20.0) > Percent(10.0));
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Price::from(2.5), Price(2.5));
+        assert_eq!(f64::from(VolumeUnits(100.0)), 100.0);
+        assert_eq!(Percent(12.5).value(), 12.5);
+
+        assert_eq!(Price(2.0).percent_of(Price(8.0)), Percent(25.0));
+        assert_eq!(Percent(25.0).of(Price(8.0)), Price(2.0));
+        assert_eq!(Percent(25.0).fraction(), 0.25);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Price(10.5)), "10.5");
+        assert_eq!(format!("{:.1}", Percent(33.333)), "33.3");
+    }
+}